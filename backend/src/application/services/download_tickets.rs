@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::domain::value_objects::photo_file::PhotoFile;
+
+/// 발급한 다운로드 표의 유효 시간 (브라우저가 곧바로 받으러 오므로 짧게)
+const TICKET_TTL: Duration = Duration::from_secs(60);
+
+/// 확인을 마친 다운로드 목록을 한 번만 쓸 수 있는 표로 맡아 두는 곳
+///
+/// 브라우저가 `<a download>` 로 GET 요청을 보내 디스크로 바로 받게 하려고,
+/// 선택 목록은 POST 로 먼저 확인하고 그 결과를 표 뒤에 둡니다.
+#[derive(Default)]
+pub struct DownloadTickets {
+    tickets: Mutex<HashMap<Uuid, (Instant, Vec<PhotoFile>)>>,
+}
+
+impl DownloadTickets {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 내려받을 파일 목록을 맡기고 표 발급
+    pub fn issue(&self, files: Vec<PhotoFile>) -> Uuid {
+        self.issue_at(files, Instant::now())
+    }
+
+    /// 표를 내고 파일 목록 받기 (모르는 표, 이미 쓴 표, 시간이 지난 표는 `None`)
+    pub fn redeem(&self, ticket: Uuid) -> Option<Vec<PhotoFile>> {
+        self.redeem_at(ticket, Instant::now())
+    }
+
+    fn issue_at(&self, files: Vec<PhotoFile>, now: Instant) -> Uuid {
+        let ticket = Uuid::new_v4();
+        let mut tickets = self.tickets.lock().unwrap_or_else(|e| e.into_inner());
+        // 받으러 오지 않은 표는 다음 발급 때 정리
+        tickets.retain(|_, (issued_at, _)| !is_expired(*issued_at, now));
+        tickets.insert(ticket, (now, files));
+        ticket
+    }
+
+    fn redeem_at(&self, ticket: Uuid, now: Instant) -> Option<Vec<PhotoFile>> {
+        let mut tickets = self.tickets.lock().unwrap_or_else(|e| e.into_inner());
+        let (issued_at, files) = tickets.remove(&ticket)?;
+        (!is_expired(issued_at, now)).then_some(files)
+    }
+}
+
+fn is_expired(issued_at: Instant, now: Instant) -> bool {
+    now.saturating_duration_since(issued_at) > TICKET_TTL
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files() -> Vec<PhotoFile> {
+        vec![PhotoFile {
+            photo_id: Uuid::new_v4(),
+            filename: "a.jpg".to_string(),
+            path: "a.jpg".to_string(),
+        }]
+    }
+
+    #[test]
+    fn test_ticket_can_be_redeemed_once() {
+        let tickets = DownloadTickets::new();
+        let files = files();
+        let ticket = tickets.issue(files.clone());
+
+        assert_eq!(tickets.redeem(ticket), Some(files));
+        assert_eq!(tickets.redeem(ticket), None);
+    }
+
+    #[test]
+    fn test_unknown_ticket_is_rejected() {
+        let tickets = DownloadTickets::new();
+        tickets.issue(files());
+
+        assert_eq!(tickets.redeem(Uuid::new_v4()), None);
+    }
+
+    #[test]
+    fn test_expired_ticket_is_rejected() {
+        let tickets = DownloadTickets::new();
+        let now = Instant::now();
+        let ticket = tickets.issue_at(files(), now);
+
+        assert_eq!(
+            tickets.redeem_at(ticket, now + TICKET_TTL + Duration::from_secs(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_expired_tickets_are_dropped_on_next_issue() {
+        let tickets = DownloadTickets::new();
+        let now = Instant::now();
+        tickets.issue_at(files(), now);
+        tickets.issue_at(files(), now + TICKET_TTL + Duration::from_secs(1));
+
+        assert_eq!(tickets.tickets.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use uuid::Uuid;
+
+use crate::domain::repositories::embedding_repository::EmbeddingRepository;
+use crate::domain::value_objects::image_embedding::{ImageEmbedding, StoredEmbedding};
+use crate::error::AppResult;
+
+/// 라이브러리 소유자 한 명의 사진 벡터를 메모리에 두고 정확한 최근접 이웃을 찾는 색인
+///
+/// 개인 라이브러리 규모(수십만 장 이하)에서는 전체 비교도 충분히 빠르므로 근사 색인을 쓰지 않습니다.
+#[derive(Default)]
+pub struct EmbeddingIndex {
+    state: RwLock<IndexState>,
+}
+
+#[derive(Default)]
+struct IndexState {
+    vectors: HashMap<Uuid, ImageEmbedding>,
+    /// 가장 마지막으로 반영한 `embedded_at`
+    synced_until: Option<DateTime<Utc>>,
+}
+
+impl EmbeddingIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.read(|state| state.vectors.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn synced_until(&self) -> Option<DateTime<Utc>> {
+        self.read(|state| state.synced_until)
+    }
+
+    /// 새로 저장되었거나 다시 계산된 벡터 반영
+    pub fn apply(&self, stored: Vec<StoredEmbedding>) {
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        for entry in stored {
+            state.synced_until = state.synced_until.max(Some(entry.embedded_at));
+            state.vectors.insert(entry.photo_id, entry.embedding);
+        }
+    }
+
+    /// `photo_id` 와 가장 비슷한 사진 (자기 자신 제외, 유사도 높은 순, 최대 `limit`개)
+    pub fn nearest(&self, photo_id: Uuid, limit: usize) -> Vec<(Uuid, f32)> {
+        self.read(|state| {
+            let Some(query) = state.vectors.get(&photo_id) else {
+                return Vec::new();
+            };
+            let mut scored: Vec<(Uuid, f32)> = state
+                .vectors
+                .iter()
+                .filter(|(id, _)| **id != photo_id)
+                .map(|(id, embedding)| (*id, query.similarity(embedding)))
+                .collect();
+            scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+            scored.truncate(limit);
+            scored
+        })
+    }
+
+    fn read<T>(&self, f: impl FnOnce(&IndexState) -> T) -> T {
+        f(&self.state.read().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+/// 마지막 동기화 이후 저장된 벡터를 읽어 색인에 반영
+pub async fn sync_index<R: EmbeddingRepository>(
+    index: &EmbeddingIndex,
+    repository: &R,
+    owner_id: Uuid,
+) -> AppResult<()> {
+    let stored = repository
+        .embeddings_since(owner_id, index.synced_until())
+        .await?;
+    index.apply(stored);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn stored(photo_id: Uuid, seconds: i64, values: Vec<f32>) -> StoredEmbedding {
+        StoredEmbedding {
+            photo_id,
+            embedded_at: Utc.timestamp_opt(seconds, 0).unwrap(),
+            embedding: ImageEmbedding::new(values),
+        }
+    }
+
+    #[test]
+    fn test_nearest_orders_by_similarity_and_skips_query() {
+        let (query, close, far) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let index = EmbeddingIndex::new();
+        index.apply(vec![
+            stored(query, 1, vec![1.0, 0.0]),
+            stored(far, 2, vec![0.0, 1.0]),
+            stored(close, 3, vec![0.9, 0.1]),
+        ]);
+
+        let ids: Vec<Uuid> = index
+            .nearest(query, 10)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids, [close, far]);
+        assert_eq!(index.nearest(query, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_unknown_photo_has_no_neighbours() {
+        let index = EmbeddingIndex::new();
+        index.apply(vec![stored(Uuid::new_v4(), 1, vec![1.0])]);
+
+        assert!(index.nearest(Uuid::new_v4(), 5).is_empty());
+    }
+
+    #[test]
+    fn test_apply_replaces_vectors_and_advances_sync_point() {
+        let photo = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let index = EmbeddingIndex::new();
+        index.apply(vec![
+            stored(photo, 5, vec![1.0, 0.0]),
+            stored(other, 2, vec![0.0, 1.0]),
+        ]);
+        index.apply(vec![stored(photo, 9, vec![0.0, 1.0])]);
+
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.synced_until(), Some(Utc.timestamp_opt(9, 0).unwrap()));
+        assert!((index.nearest(photo, 1)[0].1 - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,31 @@
+use image::RgbImage;
+
+use crate::domain::services::image_store::ImageStore;
+use crate::error::{AppError, AppResult};
+
+/// 디코딩/분석 같은 CPU 작업을 blocking 스레드에서 실행 (async 런타임 스레드를 붙잡지 않도록)
+pub async fn run_blocking<T, F>(work: F) -> AppResult<T>
+where
+    F: FnOnce() -> AppResult<T> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(work)
+        .await
+        .map_err(|err| AppError::Image(format!("blocking task failed: {err}")))?
+}
+
+/// 이미지를 `max_size` 로 읽어 `analyze` 한 결과 (읽기와 분석 모두 blocking 스레드에서 실행)
+pub async fn analyze_image<S, T, F>(
+    images: &S,
+    path: &str,
+    max_size: u32,
+    analyze: F,
+) -> AppResult<T>
+where
+    S: ImageStore + Clone + 'static,
+    F: FnOnce(RgbImage) -> AppResult<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let (images, path) = (images.clone(), path.to_string());
+    run_blocking(move || analyze(images.load_rgb(&path, max_size)?)).await
+}
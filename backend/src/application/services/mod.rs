@@ -0,0 +1,4 @@
+pub mod download_tickets;
+pub mod embedding_index;
+pub mod image_analysis;
+pub mod person_clustering;
@@ -0,0 +1,88 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::person_repository::PersonRepository;
+use crate::domain::value_objects::face::{FaceEmbedding, PersonCentroid};
+use crate::error::AppResult;
+
+/// 같은 인물로 볼 최소 코사인 유사도
+pub const MATCH_THRESHOLD: f32 = 0.8;
+
+/// 임계값 이상으로 가장 가까운 인물의 위치 (`excluded` 인물은 후보에서 제외)
+///
+/// 한 사진에 같은 사람이 두 번 나올 수는 없으므로 같은 사진에서 이미 배정된 인물을 제외합니다.
+pub fn nearest_person(
+    people: &[PersonCentroid],
+    embedding: &FaceEmbedding,
+    excluded: &[Uuid],
+) -> Option<usize> {
+    people
+        .iter()
+        .enumerate()
+        .filter(|(_, person)| !excluded.contains(&person.id))
+        .map(|(index, person)| (index, person.centroid.similarity(embedding)))
+        .filter(|(_, similarity)| *similarity >= MATCH_THRESHOLD)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(index, _)| index)
+}
+
+/// 소속 얼굴 벡터의 평균으로 인물 중심을 다시 계산 (병합/분리 후)
+pub async fn refresh_centroid<R: PersonRepository>(
+    repository: &R,
+    person_id: Uuid,
+) -> AppResult<()> {
+    let embeddings = repository.face_embeddings(person_id).await?;
+    let centroid = FaceEmbedding::centroid(&embeddings);
+    repository
+        .update_centroid(person_id, centroid.as_ref())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn person(values: Vec<f32>) -> PersonCentroid {
+        PersonCentroid {
+            id: Uuid::new_v4(),
+            centroid: FaceEmbedding::new(values),
+        }
+    }
+
+    #[test]
+    fn test_picks_the_most_similar_person() {
+        let people = [person(vec![1.0, 0.2]), person(vec![1.0, 0.0])];
+        let face = FaceEmbedding::new(vec![1.0, 0.05]);
+
+        assert_eq!(nearest_person(&people, &face, &[]), Some(1));
+    }
+
+    #[test]
+    fn test_skips_people_already_in_the_photo() {
+        let people = [person(vec![1.0, 0.2]), person(vec![1.0, 0.0])];
+        let face = FaceEmbedding::new(vec![1.0, 0.05]);
+
+        assert_eq!(nearest_person(&people, &face, &[people[1].id]), Some(0));
+        assert_eq!(
+            nearest_person(&people, &face, &[people[0].id, people[1].id]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ignores_people_below_the_threshold() {
+        // cos 45° ≈ 0.71 < MATCH_THRESHOLD
+        let people = [person(vec![1.0, 0.0]), person(vec![0.0, 1.0])];
+        let face = FaceEmbedding::new(vec![1.0, 1.0]);
+
+        assert_eq!(nearest_person(&people, &face, &[]), None);
+        assert_eq!(nearest_person(&[], &face, &[]), None);
+    }
+
+    #[test]
+    fn test_ignores_centroids_of_another_dimension() {
+        let people = [person(vec![1.0, 0.0, 0.0])];
+        let face = FaceEmbedding::new(vec![1.0, 0.0]);
+
+        assert_eq!(nearest_person(&people, &face, &[]), None);
+    }
+}
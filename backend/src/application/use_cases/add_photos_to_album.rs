@@ -0,0 +1,29 @@
+use uuid::Uuid;
+
+use crate::application::use_cases::photo_batch::{MAX_PHOTO_BATCH, normalize_photo_ids};
+use crate::domain::repositories::album_repository::AlbumRepository;
+use crate::error::{AppError, AppResult};
+
+pub struct AddPhotosToAlbumUseCase<'a, R: AlbumRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: AlbumRepository> AddPhotosToAlbumUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// 추가한 사진 수를 반환 (이미 앨범에 있는 사진 제외)
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        album_id: Uuid,
+        photo_ids: &[Uuid],
+    ) -> AppResult<u64> {
+        let ids = normalize_photo_ids(photo_ids, MAX_PHOTO_BATCH)?;
+        self.repository
+            .add_photos(owner_id, album_id, &ids)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+}
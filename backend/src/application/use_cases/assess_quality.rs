@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use crate::application::services::image_analysis::analyze_image;
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::services::image_store::ImageStore;
+use crate::domain::services::quality_analyzer::QualityAnalyzer;
+use crate::domain::value_objects::image_quality::ImageQuality;
+use crate::domain::value_objects::processing_task::ImageSource;
+use crate::error::AppResult;
+
+/// 평가에 사용하는 이미지 크기 (긴 변, 선명도 점수는 이 크기를 기준으로 함)
+const QUALITY_IMAGE_SIZE: u32 = 512;
+
+/// `quality_assessment` 작업: 선명도/노출 점수 저장
+pub struct AssessQualityUseCase<'a, R, S, Q: ?Sized> {
+    repository: &'a R,
+    images: &'a S,
+    analyzer: Arc<Q>,
+}
+
+impl<'a, R, S, Q> AssessQualityUseCase<'a, R, S, Q>
+where
+    R: PhotoRepository,
+    S: ImageStore + Clone + 'static,
+    Q: QualityAnalyzer + ?Sized + 'static,
+{
+    pub fn new(repository: &'a R, images: &'a S, analyzer: Arc<Q>) -> Self {
+        Self {
+            repository,
+            images,
+            analyzer,
+        }
+    }
+
+    pub async fn execute(&self, source: &ImageSource) -> AppResult<ImageQuality> {
+        let analyzer = Arc::clone(&self.analyzer);
+        let quality = analyze_image(
+            self.images,
+            &source.path,
+            QUALITY_IMAGE_SIZE,
+            move |image| Ok(analyzer.assess(&image)),
+        )
+        .await?;
+
+        self.repository
+            .save_quality(source.photo_id, &quality)
+            .await?;
+        Ok(quality)
+    }
+}
@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use crate::application::services::image_analysis::analyze_image;
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::domain::services::image_classifier::ImageClassifier;
+use crate::domain::services::image_store::ImageStore;
+use crate::domain::value_objects::image_label::ImageLabel;
+use crate::domain::value_objects::processing_task::ImageSource;
+use crate::error::AppResult;
+
+/// 분류에 사용하는 이미지 크기 (긴 변, 모델 입력 크기로는 분류기가 다시 줄임)
+const CLASSIFY_IMAGE_SIZE: u32 = 512;
+/// 사진 한 장에 붙이는 최대 자동 태그 수
+pub const MAX_AUTO_TAGS: usize = 10;
+
+/// `ai_tags` 작업: 분류 결과 중 신뢰도 기준을 넘는 태그 저장
+pub struct AutoTagPhotoUseCase<'a, R, S, C: ?Sized> {
+    repository: &'a R,
+    images: &'a S,
+    classifier: Arc<C>,
+    min_confidence: f32,
+}
+
+impl<'a, R, S, C> AutoTagPhotoUseCase<'a, R, S, C>
+where
+    R: TagRepository,
+    S: ImageStore + Clone + 'static,
+    C: ImageClassifier + ?Sized + 'static,
+{
+    pub fn new(repository: &'a R, images: &'a S, classifier: Arc<C>, min_confidence: f32) -> Self {
+        Self {
+            repository,
+            images,
+            classifier,
+            min_confidence,
+        }
+    }
+
+    pub async fn execute(&self, source: &ImageSource) -> AppResult<Vec<ImageLabel>> {
+        let classifier = Arc::clone(&self.classifier);
+        let labels = analyze_image(
+            self.images,
+            &source.path,
+            CLASSIFY_IMAGE_SIZE,
+            move |image| classifier.classify(&image),
+        )
+        .await?;
+        let labels = accepted_labels(labels, self.min_confidence);
+
+        self.repository
+            .save_ai_tags(source.photo_id, &labels)
+            .await?;
+        Ok(labels)
+    }
+}
+
+/// 기준 미만과 빈 태그를 버리고, 같은 태그는 가장 높은 신뢰도만 남겨 높은 순으로 정렬
+fn accepted_labels(labels: Vec<ImageLabel>, min_confidence: f32) -> Vec<ImageLabel> {
+    let mut accepted: Vec<ImageLabel> = labels
+        .into_iter()
+        .filter(|label| !label.tag.is_empty() && label.confidence >= min_confidence)
+        .collect();
+    accepted.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+
+    let mut seen = Vec::new();
+    accepted.retain(|label| {
+        let is_new = !seen.contains(&label.tag);
+        seen.push(label.tag.clone());
+        is_new
+    });
+    accepted.truncate(MAX_AUTO_TAGS);
+    accepted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::vision::stub_image_classifier::StubImageClassifier;
+    use image::{Rgb, RgbImage};
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    /// 항상 같은 단색 이미지를 돌려주는 저장소
+    #[derive(Clone)]
+    struct SolidImageStore([u8; 3]);
+
+    impl ImageStore for SolidImageStore {
+        fn load_rgb(&self, _path: &str, _max_size: u32) -> AppResult<RgbImage> {
+            Ok(RgbImage::from_pixel(16, 16, Rgb(self.0)))
+        }
+
+        fn encode_jpeg(&self, _image: &RgbImage) -> AppResult<Vec<u8>> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryTagRepository {
+        saved: Mutex<Vec<(Uuid, Vec<ImageLabel>)>>,
+    }
+
+    impl TagRepository for MemoryTagRepository {
+        async fn save_ai_tags(&self, photo_id: Uuid, labels: &[ImageLabel]) -> AppResult<()> {
+            self.saved.lock().unwrap().push((photo_id, labels.to_vec()));
+            Ok(())
+        }
+
+        async fn add_tags(
+            &self,
+            _owner_id: Uuid,
+            _photo_ids: &[Uuid],
+            _names: &[String],
+        ) -> AppResult<u64> {
+            Ok(0)
+        }
+    }
+
+    fn source() -> ImageSource {
+        ImageSource {
+            photo_id: Uuid::new_v4(),
+            owner_id: Uuid::new_v4(),
+            path: "photo.jpg".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_saves_only_labels_above_threshold() {
+        let repository = MemoryTagRepository::default();
+        let images = SolidImageStore([20, 30, 220]);
+        let source = source();
+
+        let labels =
+            AutoTagPhotoUseCase::new(&repository, &images, Arc::new(StubImageClassifier), 0.7)
+                .execute(&source)
+                .await
+                .unwrap();
+
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].tag, "blue");
+        let saved = repository.saved.lock().unwrap();
+        assert_eq!(saved.as_slice(), [(source.photo_id, labels)]);
+    }
+
+    #[tokio::test]
+    async fn test_saves_empty_result_when_nothing_is_confident() {
+        let repository = MemoryTagRepository::default();
+        let images = SolidImageStore([128, 128, 128]);
+
+        let labels =
+            AutoTagPhotoUseCase::new(&repository, &images, Arc::new(StubImageClassifier), 0.5)
+                .execute(&source())
+                .await
+                .unwrap();
+
+        assert!(labels.is_empty());
+        assert_eq!(repository.saved.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_keeps_highest_confidence_per_tag() {
+        let labels = accepted_labels(
+            vec![
+                ImageLabel::new("Beach", "scene", 0.6),
+                ImageLabel::new("dog", "object", 0.3),
+                ImageLabel::new("beach", "scene", 0.9),
+                ImageLabel::new(" ", "object", 0.95),
+            ],
+            0.5,
+        );
+
+        assert_eq!(labels, [ImageLabel::new("beach", "scene", 0.9)]);
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use crate::application::services::image_analysis::analyze_image;
+use crate::domain::repositories::embedding_repository::EmbeddingRepository;
+use crate::domain::services::image_embedder::ImageEmbedder;
+use crate::domain::services::image_store::ImageStore;
+use crate::domain::value_objects::image_embedding::ImageEmbedding;
+use crate::domain::value_objects::processing_task::ImageSource;
+use crate::error::AppResult;
+
+/// 벡터 계산에 사용하는 이미지 크기 (긴 변)
+const EMBEDDING_IMAGE_SIZE: u32 = 256;
+
+/// `image_embedding` 작업: 비슷한 사진 검색용 특징 벡터 저장
+pub struct ComputeEmbeddingUseCase<'a, R, S, E: ?Sized> {
+    repository: &'a R,
+    images: &'a S,
+    embedder: Arc<E>,
+}
+
+impl<'a, R, S, E> ComputeEmbeddingUseCase<'a, R, S, E>
+where
+    R: EmbeddingRepository,
+    S: ImageStore + Clone + 'static,
+    E: ImageEmbedder + ?Sized + 'static,
+{
+    pub fn new(repository: &'a R, images: &'a S, embedder: Arc<E>) -> Self {
+        Self {
+            repository,
+            images,
+            embedder,
+        }
+    }
+
+    pub async fn execute(&self, source: &ImageSource) -> AppResult<ImageEmbedding> {
+        let embedder = Arc::clone(&self.embedder);
+        let embedding = analyze_image(
+            self.images,
+            &source.path,
+            EMBEDDING_IMAGE_SIZE,
+            move |image| Ok(embedder.embed(&image)),
+        )
+        .await?;
+
+        self.repository
+            .save_embedding(source.photo_id, &embedding)
+            .await?;
+        Ok(embedding)
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use crate::application::services::image_analysis::analyze_image;
+use crate::domain::repositories::duplicate_repository::DuplicateRepository;
+use crate::domain::services::image_hasher::ImageHasher;
+use crate::domain::services::image_store::ImageStore;
+use crate::domain::value_objects::duplicate::PerceptualHash;
+use crate::domain::value_objects::processing_task::ImageSource;
+use crate::error::AppResult;
+
+/// 해시 계산에 사용하는 이미지 크기 (긴 변, 9×8 로 줄이므로 작아도 충분)
+const HASH_IMAGE_SIZE: u32 = 256;
+
+/// `perceptual_hash` 작업: 유사 중복 검출용 지각 해시 저장
+pub struct ComputePerceptualHashUseCase<'a, R, S, H: ?Sized> {
+    repository: &'a R,
+    images: &'a S,
+    hasher: Arc<H>,
+}
+
+impl<'a, R, S, H> ComputePerceptualHashUseCase<'a, R, S, H>
+where
+    R: DuplicateRepository,
+    S: ImageStore + Clone + 'static,
+    H: ImageHasher + ?Sized + 'static,
+{
+    pub fn new(repository: &'a R, images: &'a S, hasher: Arc<H>) -> Self {
+        Self {
+            repository,
+            images,
+            hasher,
+        }
+    }
+
+    pub async fn execute(&self, source: &ImageSource) -> AppResult<PerceptualHash> {
+        let hasher = Arc::clone(&self.hasher);
+        let hash = analyze_image(self.images, &source.path, HASH_IMAGE_SIZE, move |image| {
+            Ok(hasher.hash(&image))
+        })
+        .await?;
+
+        self.repository
+            .save_perceptual_hash(source.photo_id, hash)
+            .await?;
+        Ok(hash)
+    }
+}
@@ -0,0 +1,28 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::album_repository::AlbumRepository;
+use crate::domain::value_objects::album::Album;
+use crate::error::{AppError, AppResult};
+
+/// 앨범 제목 최대 길이 (`albums.title`)
+const MAX_TITLE_LENGTH: usize = 255;
+
+pub struct CreateAlbumUseCase<'a, R: AlbumRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: AlbumRepository> CreateAlbumUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, owner_id: Uuid, title: &str) -> AppResult<Album> {
+        let title = title.trim();
+        if title.is_empty() || title.chars().count() > MAX_TITLE_LENGTH {
+            return Err(AppError::BadRequest(format!(
+                "title must be 1 to {MAX_TITLE_LENGTH} characters"
+            )));
+        }
+        self.repository.create(owner_id, title).await
+    }
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::image_analysis::analyze_image;
+use crate::application::services::person_clustering::{nearest_person, refresh_centroid};
+use crate::domain::repositories::person_repository::PersonRepository;
+use crate::domain::services::face_analyzer::{FaceDetector, FaceEmbedder};
+use crate::domain::services::image_store::ImageStore;
+use crate::domain::value_objects::face::{DetectedFace, FaceEmbedding, NewFace, PersonCentroid};
+use crate::domain::value_objects::processing_task::ImageSource;
+use crate::error::AppResult;
+
+/// 얼굴 검출/임베딩에 사용하는 이미지 크기 (긴 변)
+pub const ANALYSIS_SIZE: u32 = 640;
+/// 이보다 신뢰도가 낮은 검출 결과는 저장하지 않음
+const MIN_CONFIDENCE: f32 = 0.5;
+
+/// `face_detection` 작업: 얼굴을 찾아 기존 인물 군집에 점진적으로 배정
+pub struct DetectFacesUseCase<'a, R, S, D: ?Sized, E: ?Sized> {
+    repository: &'a R,
+    images: &'a S,
+    detector: Arc<D>,
+    embedder: Arc<E>,
+}
+
+impl<'a, R, S, D, E> DetectFacesUseCase<'a, R, S, D, E>
+where
+    R: PersonRepository,
+    S: ImageStore + Clone + 'static,
+    D: FaceDetector + ?Sized + 'static,
+    E: FaceEmbedder + ?Sized + 'static,
+{
+    pub fn new(repository: &'a R, images: &'a S, detector: Arc<D>, embedder: Arc<E>) -> Self {
+        Self {
+            repository,
+            images,
+            detector,
+            embedder,
+        }
+    }
+
+    /// 저장한 얼굴 수를 반환
+    ///
+    /// 다시 처리한 사진이면 예전 얼굴이 빠진 인물까지 중심을 소속 얼굴로 다시 계산하고,
+    /// 얼굴이 하나도 남지 않은 인물은 삭제합니다.
+    pub async fn execute(&self, source: &ImageSource) -> AppResult<usize> {
+        let faces = self.detect(source).await?;
+        let people = self.repository.person_centroids(source.owner_id).await?;
+        let mut assigned: Vec<NewFace> = Vec::with_capacity(faces.len());
+
+        for face in faces {
+            let taken: Vec<Uuid> = assigned.iter().map(|f| f.person_id).collect();
+            let person_id = self
+                .assign(source.owner_id, &people, &face.embedding, &taken)
+                .await?;
+            assigned.push(NewFace { person_id, face });
+        }
+
+        let removed = self
+            .repository
+            .replace_faces(source.photo_id, &assigned)
+            .await?;
+        for person_id in affected_people(&removed, &assigned) {
+            refresh_centroid(self.repository, person_id).await?;
+        }
+        self.repository
+            .delete_empty_people(source.owner_id, &removed)
+            .await?;
+        Ok(assigned.len())
+    }
+
+    async fn detect(&self, source: &ImageSource) -> AppResult<Vec<DetectedFace>> {
+        let (detector, embedder) = (Arc::clone(&self.detector), Arc::clone(&self.embedder));
+        analyze_image(self.images, &source.path, ANALYSIS_SIZE, move |image| {
+            Ok(detector
+                .detect(&image)
+                .into_iter()
+                .filter(|candidate| {
+                    candidate.confidence >= MIN_CONFIDENCE && candidate.bounds.is_valid()
+                })
+                .map(|candidate| DetectedFace {
+                    bounds: candidate.bounds,
+                    confidence: candidate.confidence,
+                    embedding: embedder.embed(&image, candidate.bounds),
+                })
+                .collect())
+        })
+        .await
+    }
+
+    /// 가장 가까운 인물에 배정, 비슷한 인물이 없으면 새 인물 생성
+    ///
+    /// 한 인물에는 사진마다 얼굴 하나만 배정되므로 같은 사진을 처리하는 동안 중심은 바뀌지 않습니다.
+    async fn assign(
+        &self,
+        owner_id: Uuid,
+        people: &[PersonCentroid],
+        embedding: &FaceEmbedding,
+        taken: &[Uuid],
+    ) -> AppResult<Uuid> {
+        if let Some(index) = nearest_person(people, embedding, taken) {
+            return Ok(people[index].id);
+        }
+
+        self.repository
+            .create_person(owner_id, None, Some(embedding))
+            .await
+    }
+}
+
+/// 중심을 다시 계산할 인물 (예전 얼굴이 지워진 인물과 새로 배정된 인물)
+fn affected_people(removed: &[Uuid], assigned: &[NewFace]) -> Vec<Uuid> {
+    let mut person_ids: Vec<Uuid> = removed
+        .iter()
+        .copied()
+        .chain(assigned.iter().map(|face| face.person_id))
+        .collect();
+    person_ids.sort_unstable();
+    person_ids.dedup();
+    person_ids
+}
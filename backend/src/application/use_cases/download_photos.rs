@@ -0,0 +1,200 @@
+use std::io::{self, BufWriter, Write};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use crate::application::services::image_analysis::run_blocking;
+use crate::application::use_cases::photo_batch::normalize_photo_ids;
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::services::photo_archiver::PhotoArchiver;
+use crate::domain::value_objects::photo_file::PhotoFile;
+use crate::error::{AppError, AppResult};
+
+/// 한 번에 내려받을 수 있는 최대 사진 수
+const MAX_DOWNLOAD_BATCH: usize = 200;
+/// 한 번에 내려받을 수 있는 원본 크기 합 (2 GiB)
+pub const MAX_DOWNLOAD_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+/// 압축 파일을 내보내는 조각 크기
+const CHUNK_SIZE: usize = 256 * 1024;
+/// 아직 보내지 못한 조각을 쌓아 둘 수 (느린 클라이언트여도 메모리는 이만큼만 사용)
+const PENDING_CHUNKS: usize = 4;
+
+/// 압축 파일을 앞에서부터 나눠 받는 채널 (중간에 실패하면 `Err` 가 마지막으로 옴)
+pub type ArchiveChunks = mpsc::Receiver<io::Result<Vec<u8>>>;
+
+pub struct DownloadPhotosUseCase<'a, R: PhotoRepository, A: PhotoArchiver + Clone + 'static> {
+    repository: &'a R,
+    archiver: &'a A,
+}
+
+impl<'a, R: PhotoRepository, A: PhotoArchiver + Clone + 'static> DownloadPhotosUseCase<'a, R, A> {
+    pub fn new(repository: &'a R, archiver: &'a A) -> Self {
+        Self {
+            repository,
+            archiver,
+        }
+    }
+
+    /// 내려받을 수 있는지 확인한 선택 사진의 원본 파일 목록
+    ///
+    /// 원본 크기 합이 `MAX_DOWNLOAD_BYTES` 를 넘으면 압축을 시작하기 전에 거절합니다.
+    pub async fn prepare(&self, owner_id: Uuid, photo_ids: &[Uuid]) -> AppResult<Vec<PhotoFile>> {
+        let ids = normalize_photo_ids(photo_ids, MAX_DOWNLOAD_BATCH)?;
+        let files = self.repository.photo_files(owner_id, &ids).await?;
+        if files.is_empty() {
+            return Err(AppError::NotFound);
+        }
+        let archiver = self.archiver.clone();
+        run_blocking(move || {
+            ensure_within_limit(&archiver, &files, MAX_DOWNLOAD_BYTES)?;
+            Ok(files)
+        })
+        .await
+    }
+
+    /// `prepare` 로 확인한 원본을 묶은 압축 파일
+    ///
+    /// 압축은 blocking 스레드에서 만들며 조각 단위로 내보냅니다.
+    pub fn stream(&self, files: Vec<PhotoFile>) -> ArchiveChunks {
+        stream_archive(self.archiver.clone(), files)
+    }
+}
+
+/// 원본 크기 합이 `max_bytes` 이하인지 확인
+fn ensure_within_limit<A: PhotoArchiver>(
+    archiver: &A,
+    files: &[PhotoFile],
+    max_bytes: u64,
+) -> AppResult<()> {
+    let total = archiver.total_size(files)?;
+    if total > max_bytes {
+        return Err(AppError::BadRequest(format!(
+            "selected originals total {total} bytes, the limit is {max_bytes} bytes"
+        )));
+    }
+    Ok(())
+}
+
+/// blocking 스레드에서 압축 파일을 쓰며 조각을 채널로 보냄
+///
+/// 받는 쪽이 끊기면(다운로드 취소) 다음 쓰기가 실패해 압축도 멈춥니다.
+fn stream_archive<A: PhotoArchiver + 'static>(archiver: A, files: Vec<PhotoFile>) -> ArchiveChunks {
+    let (sender, receiver) = mpsc::channel(PENDING_CHUNKS);
+    tokio::task::spawn_blocking(move || {
+        let mut output = BufWriter::with_capacity(CHUNK_SIZE, ChunkWriter(sender.clone()));
+        let result = archiver
+            .archive(&files, &mut output)
+            .and_then(|_| Ok(output.flush()?));
+        if let Err(err) = result {
+            let _ = sender.blocking_send(Err(io::Error::other(err.to_string())));
+        }
+    });
+    receiver
+}
+
+/// 쓴 내용을 조각으로 채널에 보내는 writer
+struct ChunkWriter(mpsc::Sender<io::Result<Vec<u8>>>);
+
+impl Write for ChunkWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .blocking_send(Ok(buf.to_vec()))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "download cancelled"))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 파일 이름을 이어 붙여 "압축" 하는 가짜 archiver (크기는 파일마다 `size`)
+    #[derive(Clone)]
+    struct ConcatArchiver {
+        size: u64,
+        fail_after: Option<usize>,
+    }
+
+    impl PhotoArchiver for ConcatArchiver {
+        fn total_size(&self, files: &[PhotoFile]) -> AppResult<u64> {
+            Ok(self.size * files.len() as u64)
+        }
+
+        fn archive(&self, files: &[PhotoFile], output: &mut dyn Write) -> AppResult<()> {
+            for (index, file) in files.iter().enumerate() {
+                if self.fail_after == Some(index) {
+                    return Err(AppError::Image("unreadable original".to_string()));
+                }
+                output.write_all(file.filename.repeat(CHUNK_SIZE / 4).as_bytes())?;
+            }
+            Ok(())
+        }
+    }
+
+    fn files(names: &[&str]) -> Vec<PhotoFile> {
+        names
+            .iter()
+            .map(|name| PhotoFile {
+                photo_id: Uuid::new_v4(),
+                filename: name.to_string(),
+                path: name.to_string(),
+            })
+            .collect()
+    }
+
+    async fn collect(mut chunks: ArchiveChunks) -> (Vec<u8>, Option<String>) {
+        let mut bytes = Vec::new();
+        while let Some(chunk) = chunks.recv().await {
+            match chunk {
+                Ok(chunk) => bytes.extend(chunk),
+                Err(err) => return (bytes, Some(err.to_string())),
+            }
+        }
+        (bytes, None)
+    }
+
+    #[test]
+    fn test_rejects_downloads_over_the_size_limit() {
+        let archiver = ConcatArchiver {
+            size: 600,
+            fail_after: None,
+        };
+        let files = files(&["a", "b"]);
+
+        assert!(ensure_within_limit(&archiver, &files, 1200).is_ok());
+        let err = ensure_within_limit(&archiver, &files, 1199).unwrap_err();
+        assert!(matches!(err, AppError::BadRequest(_)));
+    }
+
+    #[tokio::test]
+    async fn test_streams_the_whole_archive_in_chunks() {
+        let archiver = ConcatArchiver {
+            size: 1,
+            fail_after: None,
+        };
+        let chunks = stream_archive(archiver, files(&["ab", "cd"]));
+
+        let (bytes, error) = collect(chunks).await;
+
+        let expected = "ab".repeat(CHUNK_SIZE / 4) + &"cd".repeat(CHUNK_SIZE / 4);
+        assert_eq!(bytes, expected.as_bytes());
+        assert_eq!(error, None);
+    }
+
+    #[tokio::test]
+    async fn test_failure_midway_ends_the_stream_with_an_error() {
+        let archiver = ConcatArchiver {
+            size: 1,
+            fail_after: Some(1),
+        };
+        let chunks = stream_archive(archiver, files(&["ab", "cd"]));
+
+        let (bytes, error) = collect(chunks).await;
+
+        assert!(bytes.len() <= CHUNK_SIZE / 2);
+        assert!(error.unwrap().contains("unreadable original"));
+    }
+}
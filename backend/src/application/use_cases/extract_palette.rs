@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use crate::application::services::image_analysis::analyze_image;
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::services::image_store::ImageStore;
+use crate::domain::services::palette_extractor::PaletteExtractor;
+use crate::domain::value_objects::color::PaletteColor;
+use crate::domain::value_objects::processing_task::ImageSource;
+use crate::error::AppResult;
+
+/// 색 추출에 사용하는 이미지 크기 (긴 변, 대표 색에는 작은 이미지로 충분)
+const PALETTE_IMAGE_SIZE: u32 = 256;
+/// 저장할 팔레트 색 수
+pub const PALETTE_SIZE: usize = 6;
+
+/// `color_palette` 작업: 프리뷰 이미지의 대표 색을 추출해 저장
+pub struct ExtractPaletteUseCase<'a, R, S, X: ?Sized> {
+    repository: &'a R,
+    images: &'a S,
+    extractor: Arc<X>,
+}
+
+impl<'a, R, S, X> ExtractPaletteUseCase<'a, R, S, X>
+where
+    R: PhotoRepository,
+    S: ImageStore + Clone + 'static,
+    X: PaletteExtractor + ?Sized + 'static,
+{
+    pub fn new(repository: &'a R, images: &'a S, extractor: Arc<X>) -> Self {
+        Self {
+            repository,
+            images,
+            extractor,
+        }
+    }
+
+    pub async fn execute(&self, source: &ImageSource) -> AppResult<Vec<PaletteColor>> {
+        let extractor = Arc::clone(&self.extractor);
+        let palette = analyze_image(
+            self.images,
+            &source.path,
+            PALETTE_IMAGE_SIZE,
+            move |image| Ok(extractor.extract(&image, PALETTE_SIZE)),
+        )
+        .await?;
+
+        self.repository
+            .save_palette(source.photo_id, &palette)
+            .await?;
+        Ok(palette)
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::repositories::duplicate_repository::DuplicateRepository;
+use crate::domain::value_objects::duplicate::{DuplicateGroup, DuplicatePhoto, DuplicateReview};
+use crate::error::AppResult;
+
+/// 유사 중복으로 보는 최대 해밍 거리 (`phash_bands` 구간 수 8 보다 작아야 후보에서 빠지지 않음)
+pub const MAX_DUPLICATE_DISTANCE: u32 = 6;
+/// 한 번에 검토할 사진 쌍 수 (넘으면 해밍 거리가 가까운 쌍부터)
+const MAX_PAIRS: usize = 5000;
+
+/// 지각 해시가 가까운 사진을 묶어 중복 검토 그룹으로 반환
+pub struct FindDuplicatesUseCase<'a, R: DuplicateRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: DuplicateRepository> FindDuplicatesUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, owner_id: Uuid) -> AppResult<DuplicateReview> {
+        let mut pairs = self
+            .repository
+            .similar_pairs(owner_id, MAX_DUPLICATE_DISTANCE, MAX_PAIRS + 1)
+            .await?;
+        let truncated = pairs.len() > MAX_PAIRS;
+        pairs.truncate(MAX_PAIRS);
+        let groups = connected_groups(&pairs);
+
+        let ids: Vec<Uuid> = groups.iter().flatten().copied().collect();
+        let mut photos: HashMap<Uuid, DuplicatePhoto> = self
+            .repository
+            .duplicate_photos(owner_id, &ids)
+            .await?
+            .into_iter()
+            .map(|photo| (photo.photo.id, photo))
+            .collect();
+
+        let mut result: Vec<DuplicateGroup> = groups
+            .into_iter()
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|id| photos.remove(id))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|members| members.len() > 1)
+            .map(DuplicateGroup::new)
+            .collect();
+        result.sort_by_key(|group| std::cmp::Reverse(group.photos.len()));
+        Ok(DuplicateReview {
+            groups: result,
+            truncated,
+        })
+    }
+}
+
+/// 쌍으로 이어진 사진들을 하나의 그룹으로 (union-find)
+fn connected_groups(pairs: &[(Uuid, Uuid)]) -> Vec<Vec<Uuid>> {
+    let mut parent: HashMap<Uuid, Uuid> = HashMap::new();
+    for (a, b) in pairs {
+        let (root_a, root_b) = (find_root(&mut parent, *a), find_root(&mut parent, *b));
+        if root_a != root_b {
+            parent.insert(root_a, root_b);
+        }
+    }
+
+    let ids: Vec<Uuid> = parent.keys().copied().collect();
+    let mut groups: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for id in ids {
+        let group_root = find_root(&mut parent, id);
+        groups.entry(group_root).or_default().push(id);
+    }
+    groups.into_values().collect()
+}
+
+/// 경로 압축을 하며 그룹 대표를 찾음
+fn find_root(parent: &mut HashMap<Uuid, Uuid>, id: Uuid) -> Uuid {
+    let next = *parent.entry(id).or_insert(id);
+    if next == id {
+        return id;
+    }
+    let found = find_root(parent, next);
+    parent.insert(id, found);
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(count: usize) -> Vec<Uuid> {
+        (0..count).map(|_| Uuid::new_v4()).collect()
+    }
+
+    fn sorted(mut groups: Vec<Vec<Uuid>>) -> Vec<Vec<Uuid>> {
+        groups.iter_mut().for_each(|group| group.sort());
+        groups.sort();
+        groups
+    }
+
+    #[test]
+    fn test_chains_of_pairs_form_one_group() {
+        let id = ids(4);
+        let pairs = [(id[0], id[1]), (id[2], id[3]), (id[1], id[2])];
+
+        let groups = sorted(connected_groups(&pairs));
+
+        assert_eq!(groups, sorted(vec![id.clone()]));
+    }
+
+    #[test]
+    fn test_unrelated_pairs_stay_separate() {
+        let id = ids(5);
+        let pairs = [
+            (id[0], id[1]),
+            (id[2], id[3]),
+            (id[3], id[4]),
+            (id[4], id[2]),
+        ];
+
+        let groups = sorted(connected_groups(&pairs));
+
+        assert_eq!(
+            groups,
+            sorted(vec![vec![id[0], id[1]], vec![id[2], id[3], id[4]]])
+        );
+    }
+
+    #[test]
+    fn test_no_pairs_no_groups() {
+        assert!(connected_groups(&[]).is_empty());
+    }
+}
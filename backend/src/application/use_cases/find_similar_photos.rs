@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::application::services::embedding_index::{EmbeddingIndex, sync_index};
+use crate::domain::repositories::embedding_repository::EmbeddingRepository;
+use crate::domain::value_objects::image_embedding::SimilarPhoto;
+use crate::error::AppResult;
+
+pub const DEFAULT_SIMILAR: usize = 24;
+pub const MAX_SIMILAR: usize = 100;
+
+/// 특징 벡터가 가장 가까운 사진 검색 (유사도 높은 순)
+///
+/// 아직 벡터가 없는 사진이면 빈 결과를 돌려줍니다.
+pub struct FindSimilarPhotosUseCase<'a, R: EmbeddingRepository> {
+    repository: &'a R,
+    index: &'a EmbeddingIndex,
+}
+
+impl<'a, R: EmbeddingRepository> FindSimilarPhotosUseCase<'a, R> {
+    pub fn new(repository: &'a R, index: &'a EmbeddingIndex) -> Self {
+        Self { repository, index }
+    }
+
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        photo_id: Uuid,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<SimilarPhoto>> {
+        let limit = limit.unwrap_or(DEFAULT_SIMILAR).clamp(1, MAX_SIMILAR);
+        sync_index(self.index, self.repository, owner_id).await?;
+
+        // 휴지통으로 옮긴 사진은 색인에 남아 있을 수 있으므로 여유 있게 찾음
+        let neighbours = self.index.nearest(photo_id, limit * 2);
+        let ids: Vec<Uuid> = neighbours.iter().map(|(id, _)| *id).collect();
+        let mut photos: HashMap<Uuid, _> = self
+            .repository
+            .photos_by_ids(owner_id, &ids)
+            .await?
+            .into_iter()
+            .map(|photo| (photo.id, photo))
+            .collect();
+
+        Ok(neighbours
+            .into_iter()
+            .filter_map(|(id, similarity)| {
+                let photo = photos.remove(&id)?;
+                Some(SimilarPhoto { photo, similarity })
+            })
+            .take(limit)
+            .collect())
+    }
+}
@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::album_repository::AlbumRepository;
+use crate::domain::value_objects::album::Album;
+use crate::error::{AppError, AppResult};
+
+pub struct GetAlbumUseCase<'a, R: AlbumRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: AlbumRepository> GetAlbumUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, owner_id: Uuid, album_id: Uuid) -> AppResult<Album> {
+        self.repository
+            .find(owner_id, album_id)
+            .await?
+            .ok_or(AppError::NotFound)
+    }
+}
@@ -0,0 +1,51 @@
+use image::imageops::{self, FilterType};
+use uuid::Uuid;
+
+use crate::application::services::image_analysis::analyze_image;
+use crate::domain::repositories::person_repository::PersonRepository;
+use crate::domain::services::image_store::ImageStore;
+use crate::error::{AppError, AppResult};
+
+/// 얼굴을 잘라낼 원본 이미지 크기 (긴 변)
+const SOURCE_SIZE: u32 = 1600;
+/// 잘라낸 얼굴 썸네일 크기 (긴 변)
+const CROP_SIZE: u32 = 256;
+/// 얼굴 영역 바깥으로 더 포함할 여백 비율
+const CROP_MARGIN: f32 = 0.25;
+
+/// 인물 대표 얼굴 등에 쓰는 얼굴 썸네일 (JPEG)
+pub struct GetFaceCropUseCase<'a, R: PersonRepository, S: ImageStore + Clone + 'static> {
+    repository: &'a R,
+    images: &'a S,
+}
+
+impl<'a, R: PersonRepository, S: ImageStore + Clone + 'static> GetFaceCropUseCase<'a, R, S> {
+    pub fn new(repository: &'a R, images: &'a S) -> Self {
+        Self { repository, images }
+    }
+
+    pub async fn execute(&self, owner_id: Uuid, face_id: Uuid) -> AppResult<Vec<u8>> {
+        let (path, bounds) = self
+            .repository
+            .face_source(owner_id, face_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        let images = self.images.clone();
+        analyze_image(self.images, &path, SOURCE_SIZE, move |image| {
+            let (x, y, width, height) =
+                bounds.to_pixels(image.width(), image.height(), CROP_MARGIN);
+            let face = imageops::crop_imm(&image, x, y, width, height).to_image();
+
+            let scale = CROP_SIZE as f32 / width.max(height) as f32;
+            let face = if scale < 1.0 {
+                let size = |value: u32| ((value as f32 * scale) as u32).max(1);
+                imageops::resize(&face, size(width), size(height), FilterType::Triangle)
+            } else {
+                face
+            };
+            images.encode_jpeg(&face)
+        })
+        .await
+    }
+}
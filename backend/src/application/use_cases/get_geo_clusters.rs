@@ -0,0 +1,74 @@
+use uuid::Uuid;
+
+use crate::application::use_cases::search_photos::{normalize_filter, validate_filter};
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::value_objects::geo_area::{GeoArea, GeoCluster};
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::error::AppResult;
+
+pub const MAX_ZOOM: u8 = 20;
+const MAX_CLUSTERS: usize = 500;
+/// 256px 타일 한 변에 놓이는 최대 클러스터 수 (마커 간격 약 64px)
+const CLUSTERS_PER_TILE: f64 = 4.0;
+
+pub struct GetGeoClustersUseCase<'a, R: PhotoRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PhotoRepository> GetGeoClustersUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// `area` 안의 사진을 지도 배율(`zoom`)에 맞는 격자로 묶어 반환
+    ///
+    /// `filter` 의 나머지 검색 조건도 함께 적용됩니다.
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        filter: SearchFilter,
+        area: GeoArea,
+        zoom: u8,
+    ) -> AppResult<Vec<GeoCluster>> {
+        let filter = SearchFilter {
+            area: Some(area),
+            ..filter
+        };
+        validate_filter(&filter)?;
+
+        self.repository
+            .geo_clusters(
+                owner_id,
+                &normalize_filter(filter),
+                cell_degrees(zoom.min(MAX_ZOOM)),
+                MAX_CLUSTERS,
+            )
+            .await
+    }
+}
+
+/// 배율별 클러스터 격자 한 칸의 크기 (경위도, 도 단위)
+fn cell_degrees(zoom: u8) -> f64 {
+    360.0 / f64::from(1u32 << zoom) / CLUSTERS_PER_TILE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cells_halve_with_each_zoom_level() {
+        assert_eq!(cell_degrees(0), 90.0);
+        assert_eq!(cell_degrees(1), 45.0);
+        assert_eq!(cell_degrees(10), 360.0 / 1024.0 / 4.0);
+        for zoom in 0..MAX_ZOOM {
+            assert_eq!(cell_degrees(zoom), cell_degrees(zoom + 1) * 2.0);
+        }
+    }
+
+    #[test]
+    fn test_deepest_zoom_still_has_positive_cells() {
+        let smallest = cell_degrees(MAX_ZOOM);
+        assert!(smallest > 0.0 && smallest < 0.001);
+    }
+}
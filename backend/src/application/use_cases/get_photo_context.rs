@@ -0,0 +1,65 @@
+use uuid::Uuid;
+
+use crate::application::use_cases::search_photos::{
+    PhotoPage, SearchPhotosUseCase, normalize_filter,
+};
+use crate::domain::entities::photo::Photo;
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::value_objects::photo_cursor::PhotoCursor;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::error::{AppError, AppResult};
+
+pub const DEFAULT_NEIGHBOURS: usize = 20;
+pub const MAX_NEIGHBOURS: usize = 100;
+
+/// 사진 한 장과 타임라인에서 앞뒤로 이웃한 사진 (링크로 바로 연 사진 미리보기)
+#[derive(Debug)]
+pub struct PhotoContext {
+    pub photo: Photo,
+    /// 더 최근 사진 (타임라인 순서, 마지막이 바로 앞 사진)
+    pub previous: Vec<Photo>,
+    /// 이후 사진과 그다음 페이지 커서
+    pub next: PhotoPage,
+}
+
+pub struct GetPhotoContextUseCase<'a, R: PhotoRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PhotoRepository> GetPhotoContextUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// 이웃 사진은 `filter` 조건에 맞는 사진 중에서 앞뒤로 최대 `limit`장씩 찾습니다.
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        photo_id: Uuid,
+        filter: SearchFilter,
+        limit: Option<usize>,
+    ) -> AppResult<PhotoContext> {
+        let photo = self
+            .repository
+            .find(owner_id, photo_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let limit = limit.unwrap_or(DEFAULT_NEIGHBOURS).clamp(1, MAX_NEIGHBOURS);
+        let cursor = PhotoCursor::after(&photo);
+
+        let next = SearchPhotosUseCase::new(self.repository)
+            .execute(owner_id, filter.clone(), Some(cursor), Some(limit))
+            .await?;
+        let mut previous = self
+            .repository
+            .search_newer(owner_id, &normalize_filter(filter), cursor, limit)
+            .await?;
+        previous.reverse();
+
+        Ok(PhotoContext {
+            photo,
+            previous,
+            next,
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use uuid::Uuid;
+
+use crate::application::use_cases::search_photos::{normalize_filter, validate_filter};
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::value_objects::exif_filter::{ExifField, NumericRange};
+use crate::domain::value_objects::search_facets::SearchFacets;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::error::AppResult;
+
+const MAX_LENS_FACETS: usize = 30;
+
+pub struct GetSearchFacetsUseCase<'a, R: PhotoRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PhotoRepository + Sync> GetSearchFacetsUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// 각 패싯은 자기 필드의 조건만 뺀 필터로 집계하여,
+    /// 이미 선택한 값 외에 다른 값으로 바꿨을 때의 결과 수를 보여줄 수 있게 합니다.
+    pub async fn execute(&self, owner_id: Uuid, filter: SearchFilter) -> AppResult<SearchFacets> {
+        validate_filter(&filter)?;
+        let filter = normalize_filter(filter);
+        let repository = self.repository;
+
+        let without_lens = SearchFilter {
+            lens: None,
+            ..filter.clone()
+        };
+        let without_flash = {
+            let mut f = filter.clone();
+            f.exif.flash = None;
+            f
+        };
+        let [focal, aperture, shutter, iso] =
+            ExifField::ALL.map(|field| without_range(&filter, field));
+
+        let (lenses, focal_length, aperture, shutter_speed, iso, flash) = tokio::try_join!(
+            repository.lens_facets(owner_id, &without_lens, MAX_LENS_FACETS),
+            repository.range_facets(owner_id, &focal, ExifField::FocalLength),
+            repository.range_facets(owner_id, &aperture, ExifField::Aperture),
+            repository.range_facets(owner_id, &shutter, ExifField::ShutterSpeed),
+            repository.range_facets(owner_id, &iso, ExifField::Iso),
+            repository.flash_facets(owner_id, &without_flash),
+        )?;
+
+        Ok(SearchFacets {
+            lenses,
+            focal_length,
+            aperture,
+            shutter_speed,
+            iso,
+            flash,
+        })
+    }
+}
+
+fn without_range(filter: &SearchFilter, field: ExifField) -> SearchFilter {
+    let mut filter = filter.clone();
+    *field.range_mut(&mut filter.exif) = NumericRange::default();
+    filter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_facet_filter_drops_only_its_own_range() {
+        let mut filter = SearchFilter {
+            lens: Some("50mm".to_string()),
+            ..SearchFilter::default()
+        };
+        filter.exif.aperture = NumericRange::new(Some(2.0), Some(4.0));
+        filter.exif.iso = NumericRange::new(None, Some(800.0));
+        filter.exif.flash = Some(false);
+
+        let without_aperture = without_range(&filter, ExifField::Aperture);
+
+        assert!(without_aperture.exif.aperture.is_unbounded());
+        assert_eq!(without_aperture.exif.iso, filter.exif.iso);
+        assert_eq!(without_aperture.exif.flash, Some(false));
+        assert_eq!(without_aperture.lens, filter.lens);
+    }
+}
@@ -0,0 +1,28 @@
+use uuid::Uuid;
+
+use crate::application::use_cases::search_photos::{normalize_filter, validate_filter};
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::domain::value_objects::timeline::TimelineMonth;
+use crate::error::AppResult;
+
+/// 검색 조건에 맞는 사진의 월별 개수 (타임라인 스크러버)
+pub struct GetTimelineUseCase<'a, R: PhotoRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PhotoRepository> GetTimelineUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        filter: SearchFilter,
+    ) -> AppResult<Vec<TimelineMonth>> {
+        validate_filter(&filter)?;
+        let filter = normalize_filter(filter);
+        self.repository.month_counts(owner_id, &filter).await
+    }
+}
@@ -0,0 +1,19 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::album_repository::AlbumRepository;
+use crate::domain::value_objects::album::Album;
+use crate::error::AppResult;
+
+pub struct ListAlbumsUseCase<'a, R: AlbumRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: AlbumRepository> ListAlbumsUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, owner_id: Uuid) -> AppResult<Vec<Album>> {
+        self.repository.list(owner_id).await
+    }
+}
@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use crate::domain::entities::photo::Photo;
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::value_objects::image_quality::QualityIssue;
+use crate::error::AppResult;
+
+pub const DEFAULT_SUGGESTIONS: usize = 100;
+pub const MAX_SUGGESTIONS: usize = 500;
+
+/// 정리 후보 사진과 그 이유
+#[derive(Clone, Debug)]
+pub struct CleanupSuggestion {
+    pub photo: Photo,
+    pub issues: Vec<QualityIssue>,
+}
+
+/// 흐리거나 노출이 나쁜 사진을 가장 나쁜 순으로 제안
+pub struct ListCleanupSuggestionsUseCase<'a, R: PhotoRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PhotoRepository> ListCleanupSuggestionsUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<CleanupSuggestion>> {
+        let limit = limit
+            .unwrap_or(DEFAULT_SUGGESTIONS)
+            .clamp(1, MAX_SUGGESTIONS);
+        let photos = self.repository.cleanup_candidates(owner_id, limit).await?;
+
+        Ok(photos
+            .into_iter()
+            .filter_map(|photo| {
+                let issues = photo.quality?.issues();
+                (!issues.is_empty()).then_some(CleanupSuggestion { photo, issues })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,32 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::person_repository::PersonRepository;
+use crate::domain::value_objects::face::{Person, PersonFace};
+use crate::error::AppResult;
+
+pub struct ListPeopleUseCase<'a, R: PersonRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PersonRepository> ListPeopleUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// 이름이 있는 인물을 먼저, 같은 그룹 안에서는 얼굴이 많은 순
+    pub async fn execute(&self, owner_id: Uuid) -> AppResult<Vec<Person>> {
+        let mut people = self.repository.list_people(owner_id).await?;
+        people.sort_by(|a, b| {
+            a.name
+                .is_none()
+                .cmp(&b.name.is_none())
+                .then_with(|| b.face_count.cmp(&a.face_count))
+        });
+        Ok(people)
+    }
+
+    /// 인물 한 명의 얼굴 목록
+    pub async fn faces(&self, owner_id: Uuid, person_id: Uuid) -> AppResult<Vec<PersonFace>> {
+        self.repository.person_faces(owner_id, person_id).await
+    }
+}
@@ -0,0 +1,48 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::place_repository::PlaceRepository;
+use crate::domain::value_objects::place::{CountryPlaces, PlaceCount};
+use crate::error::AppResult;
+
+pub struct ListPlacesUseCase<'a, R: PlaceRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PlaceRepository> ListPlacesUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// 국가별로 도시를 묶고, 사진이 많은 국가부터 반환
+    pub async fn execute(&self, owner_id: Uuid) -> AppResult<Vec<CountryPlaces>> {
+        let cities = self.repository.city_counts(owner_id).await?;
+        let mut countries = group_by_country(cities);
+        countries.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.country.cmp(&b.country))
+        });
+        Ok(countries)
+    }
+}
+
+/// `city_counts` 는 국가명 순으로 정렬되어 있으므로 인접한 도시끼리 묶음
+fn group_by_country(cities: Vec<PlaceCount>) -> Vec<CountryPlaces> {
+    let mut countries: Vec<CountryPlaces> = Vec::new();
+
+    for city in cities {
+        match countries.last_mut() {
+            Some(country) if country.country_code == city.place.country_code => {
+                country.count += city.count;
+                country.cities.push(city);
+            }
+            _ => countries.push(CountryPlaces {
+                country: city.place.country.clone(),
+                country_code: city.place.country_code.clone(),
+                count: city.count,
+                cities: vec![city],
+            }),
+        }
+    }
+    countries
+}
@@ -0,0 +1,42 @@
+use uuid::Uuid;
+
+use crate::application::services::person_clustering::refresh_centroid;
+use crate::domain::repositories::person_repository::PersonRepository;
+use crate::error::{AppError, AppResult};
+
+pub struct MergePeopleUseCase<'a, R: PersonRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PersonRepository> MergePeopleUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// `source_ids` 인물을 `target_id` 로 합치고 옮긴 얼굴 수를 반환
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        target_id: Uuid,
+        source_ids: &[Uuid],
+    ) -> AppResult<u64> {
+        let mut sources: Vec<Uuid> = source_ids
+            .iter()
+            .copied()
+            .filter(|id| *id != target_id)
+            .collect();
+        sources.sort_unstable();
+        sources.dedup();
+        if sources.is_empty() {
+            return Err(AppError::BadRequest("합칠 인물을 선택하세요".to_string()));
+        }
+
+        let moved = self
+            .repository
+            .merge(owner_id, target_id, &sources)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        refresh_centroid(self.repository, target_id).await?;
+        Ok(moved)
+    }
+}
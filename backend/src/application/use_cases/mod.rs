@@ -0,0 +1,31 @@
+pub mod add_photos_to_album;
+pub mod assess_quality;
+pub mod auto_tag_photo;
+pub mod compute_embedding;
+pub mod compute_perceptual_hash;
+pub mod create_album;
+pub mod detect_faces;
+pub mod download_photos;
+pub mod extract_palette;
+pub mod find_duplicates;
+pub mod find_similar_photos;
+pub mod get_album;
+pub mod get_face_crop;
+pub mod get_geo_clusters;
+pub mod get_photo_context;
+pub mod get_search_facets;
+pub mod get_timeline;
+pub mod list_albums;
+pub mod list_cleanup_suggestions;
+pub mod list_people;
+pub mod list_places;
+pub mod merge_people;
+pub mod photo_batch;
+pub mod rename_person;
+pub mod reverse_geocode_photo;
+pub mod search_photos;
+pub mod set_favorites;
+pub mod split_person;
+pub mod tag_face;
+pub mod tag_photos;
+pub mod trash_photos;
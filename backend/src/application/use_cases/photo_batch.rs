@@ -0,0 +1,19 @@
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+
+/// 여러 사진을 한 번에 처리하는 요청의 기본 최대 사진 수
+pub const MAX_PHOTO_BATCH: usize = 500;
+
+/// 중복을 제거하고 1~`max`장인지 확인
+pub fn normalize_photo_ids(photo_ids: &[Uuid], max: usize) -> AppResult<Vec<Uuid>> {
+    let mut ids = photo_ids.to_vec();
+    ids.sort_unstable();
+    ids.dedup();
+    if ids.is_empty() || ids.len() > max {
+        return Err(AppError::BadRequest(format!(
+            "photo_ids must contain 1 to {max} photos"
+        )));
+    }
+    Ok(ids)
+}
@@ -0,0 +1,72 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::person_repository::PersonRepository;
+use crate::error::{AppError, AppResult};
+
+/// `people.name` 최대 길이
+const MAX_NAME_LENGTH: usize = 255;
+
+pub struct RenamePersonUseCase<'a, R: PersonRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PersonRepository> RenamePersonUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// 공백뿐인 이름은 이름 제거로 취급
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        person_id: Uuid,
+        name: Option<&str>,
+    ) -> AppResult<()> {
+        let name = name.map(str::trim).filter(|name| !name.is_empty());
+        if name.is_some_and(|name| name.chars().count() > MAX_NAME_LENGTH) {
+            return Err(AppError::BadRequest(format!(
+                "이름은 {MAX_NAME_LENGTH}자 이하여야 합니다"
+            )));
+        }
+
+        match self.repository.rename(owner_id, person_id, name).await? {
+            true => Ok(()),
+            false => Err(AppError::NotFound),
+        }
+    }
+}
+
+/// 앞뒤 공백을 제거하고, 공백뿐인 이름은 이름 없음으로 취급
+pub fn normalize_name(name: Option<&str>) -> AppResult<Option<&str>> {
+    let name = name.map(str::trim).filter(|name| !name.is_empty());
+    if name.is_some_and(|name| name.chars().count() > MAX_NAME_LENGTH) {
+        return Err(AppError::BadRequest(format!(
+            "이름은 {MAX_NAME_LENGTH}자 이하여야 합니다"
+        )));
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trims_names_and_treats_blank_as_none() {
+        assert_eq!(normalize_name(Some("  Mom ")).unwrap(), Some("Mom"));
+        assert_eq!(normalize_name(Some("   ")).unwrap(), None);
+        assert_eq!(normalize_name(None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_rejects_names_longer_than_the_column() {
+        let name = "가".repeat(MAX_NAME_LENGTH);
+        assert!(normalize_name(Some(&name)).is_ok());
+
+        let name = "가".repeat(MAX_NAME_LENGTH + 1);
+        assert!(matches!(
+            normalize_name(Some(&name)),
+            Err(AppError::BadRequest(_))
+        ));
+    }
+}
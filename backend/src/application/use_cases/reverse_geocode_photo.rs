@@ -0,0 +1,34 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::place_repository::PlaceRepository;
+use crate::domain::services::reverse_geocoder::ReverseGeocoder;
+use crate::domain::value_objects::place::Place;
+use crate::error::AppResult;
+
+/// `reverse_geocode` 작업: 사진의 GPS 좌표로 위치명을 채움
+pub struct ReverseGeocodePhotoUseCase<'a, R: PlaceRepository, G: ReverseGeocoder> {
+    repository: &'a R,
+    geocoder: &'a G,
+}
+
+impl<'a, R: PlaceRepository, G: ReverseGeocoder> ReverseGeocodePhotoUseCase<'a, R, G> {
+    pub fn new(repository: &'a R, geocoder: &'a G) -> Self {
+        Self {
+            repository,
+            geocoder,
+        }
+    }
+
+    /// 좌표가 없거나 가까운 지명이 없으면 아무것도 저장하지 않고 `None`
+    pub async fn execute(&self, photo_id: Uuid) -> AppResult<Option<Place>> {
+        let Some(point) = self.repository.photo_location(photo_id).await? else {
+            return Ok(None);
+        };
+        let place = self.geocoder.reverse_geocode(point);
+
+        if let Some(place) = &place {
+            self.repository.save_place(photo_id, place).await?;
+        }
+        Ok(place)
+    }
+}
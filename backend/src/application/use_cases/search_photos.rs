@@ -0,0 +1,98 @@
+use uuid::Uuid;
+
+use crate::domain::entities::photo::Photo;
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::value_objects::photo_cursor::PhotoCursor;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::error::{AppError, AppResult};
+
+pub const DEFAULT_PAGE_SIZE: usize = 40;
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// 무한 스크롤 한 페이지 분량의 결과
+#[derive(Debug)]
+pub struct PhotoPage {
+    pub photos: Vec<Photo>,
+    pub next_cursor: Option<PhotoCursor>,
+}
+
+pub struct SearchPhotosUseCase<'a, R: PhotoRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PhotoRepository> SearchPhotosUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        filter: SearchFilter,
+        cursor: Option<PhotoCursor>,
+        limit: Option<usize>,
+    ) -> AppResult<PhotoPage> {
+        validate_filter(&filter)?;
+
+        let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+        let filter = normalize_filter(filter);
+
+        // 다음 페이지 존재 여부를 알기 위해 하나 더 조회
+        let mut photos = self
+            .repository
+            .search(owner_id, &filter, cursor, limit + 1)
+            .await?;
+        let has_more = photos.len() > limit;
+        photos.truncate(limit);
+
+        let next_cursor = if has_more {
+            photos.last().map(PhotoCursor::after)
+        } else {
+            None
+        };
+        Ok(PhotoPage {
+            photos,
+            next_cursor,
+        })
+    }
+}
+
+/// 날짜 범위, 지도 영역, 색상 허용 범위가 올바른지 확인
+pub fn validate_filter(filter: &SearchFilter) -> AppResult<()> {
+    if !filter.has_valid_date_range() {
+        return Err(AppError::BadRequest(
+            "date_from must not be after date_to".to_string(),
+        ));
+    }
+    if !filter.has_valid_area() {
+        return Err(AppError::BadRequest("invalid map area".to_string()));
+    }
+    if !filter.has_valid_color() {
+        return Err(AppError::BadRequest(
+            "color_distance must be between 0 and 100".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// 공백만 있는 문자열 조건은 조건이 없는 것으로 취급
+pub fn normalize_filter(filter: SearchFilter) -> SearchFilter {
+    let trimmed = |value: Option<String>| {
+        value
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    SearchFilter {
+        text: trimmed(filter.text),
+        camera: trimmed(filter.camera),
+        lens: trimmed(filter.lens),
+        place: trimmed(filter.place),
+        tags: filter
+            .tags
+            .into_iter()
+            .filter_map(|tag| trimmed(Some(tag)))
+            .collect(),
+        ..filter
+    }
+}
@@ -0,0 +1,21 @@
+use uuid::Uuid;
+
+use crate::application::use_cases::photo_batch::{MAX_PHOTO_BATCH, normalize_photo_ids};
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::error::AppResult;
+
+pub struct SetFavoritesUseCase<'a, R: PhotoRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PhotoRepository> SetFavoritesUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// 선택한 사진의 좋아요를 켜거나 끔, 바뀐 사진 수를 반환
+    pub async fn execute(&self, owner_id: Uuid, photo_ids: &[Uuid], liked: bool) -> AppResult<u64> {
+        let ids = normalize_photo_ids(photo_ids, MAX_PHOTO_BATCH)?;
+        self.repository.set_favorite(owner_id, &ids, liked).await
+    }
+}
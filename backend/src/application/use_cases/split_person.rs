@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+use crate::application::services::person_clustering::refresh_centroid;
+use crate::domain::repositories::person_repository::PersonRepository;
+use crate::error::{AppError, AppResult};
+
+pub struct SplitPersonUseCase<'a, R: PersonRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PersonRepository> SplitPersonUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// 선택한 얼굴을 새 인물로 분리하고 새 인물 id 반환 (두 인물의 중심 모두 재계산)
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        person_id: Uuid,
+        face_ids: &[Uuid],
+    ) -> AppResult<Uuid> {
+        if face_ids.is_empty() {
+            return Err(AppError::BadRequest("분리할 얼굴을 선택하세요".to_string()));
+        }
+
+        let new_person_id = self
+            .repository
+            .split(owner_id, person_id, face_ids)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        refresh_centroid(self.repository, person_id).await?;
+        refresh_centroid(self.repository, new_person_id).await?;
+        Ok(new_person_id)
+    }
+}
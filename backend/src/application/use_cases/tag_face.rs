@@ -0,0 +1,130 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::image_analysis::analyze_image;
+use crate::application::services::person_clustering::refresh_centroid;
+use crate::application::use_cases::detect_faces::ANALYSIS_SIZE;
+use crate::application::use_cases::rename_person::normalize_name;
+use crate::domain::repositories::person_repository::PersonRepository;
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::services::face_analyzer::FaceEmbedder;
+use crate::domain::services::image_store::ImageStore;
+use crate::domain::value_objects::face::{FaceAssignment, FaceBox};
+use crate::error::{AppError, AppResult};
+
+/// 미리보기에서 얼굴의 인물을 고치거나, 놓친 얼굴을 직접 추가/삭제
+///
+/// 변경된 인물의 군집 중심은 소속 얼굴로 다시 계산합니다.
+pub struct TagFaceUseCase<'a, R, P, S, E: ?Sized> {
+    people: &'a R,
+    photos: &'a P,
+    images: &'a S,
+    embedder: Arc<E>,
+}
+
+impl<'a, R, P, S, E> TagFaceUseCase<'a, R, P, S, E>
+where
+    R: PersonRepository,
+    P: PhotoRepository,
+    S: ImageStore + Clone + 'static,
+    E: FaceEmbedder + ?Sized + 'static,
+{
+    pub fn new(people: &'a R, photos: &'a P, images: &'a S, embedder: Arc<E>) -> Self {
+        Self {
+            people,
+            photos,
+            images,
+            embedder,
+        }
+    }
+
+    /// 얼굴을 다른 인물로 배정하고 배정된 인물 id 반환
+    pub async fn assign(
+        &self,
+        owner_id: Uuid,
+        face_id: Uuid,
+        target: &FaceAssignment,
+    ) -> AppResult<Uuid> {
+        let face = self
+            .people
+            .find_face(owner_id, face_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+        let person_id = self.resolve(owner_id, target).await?;
+
+        self.people.assign_face(face_id, person_id).await?;
+        self.retrain(&[face.person_id, Some(person_id)]).await?;
+        Ok(person_id)
+    }
+
+    /// 사용자가 그린 영역을 얼굴로 추가하고 `(얼굴 id, 인물 id)` 반환
+    pub async fn add(
+        &self,
+        owner_id: Uuid,
+        photo_id: Uuid,
+        bounds: FaceBox,
+        target: &FaceAssignment,
+    ) -> AppResult<(Uuid, Uuid)> {
+        if !bounds.is_valid() {
+            return Err(AppError::BadRequest(
+                "얼굴 영역이 올바르지 않습니다".to_string(),
+            ));
+        }
+        let source = self
+            .photos
+            .image_source(photo_id)
+            .await?
+            .filter(|source| source.owner_id == owner_id)
+            .ok_or(AppError::NotFound)?;
+
+        let embedder = Arc::clone(&self.embedder);
+        let embedding = analyze_image(self.images, &source.path, ANALYSIS_SIZE, move |image| {
+            Ok(embedder.embed(&image, bounds))
+        })
+        .await?;
+        let person_id = self.resolve(owner_id, target).await?;
+        let face_id = self
+            .people
+            .add_manual_face(photo_id, person_id, bounds, &embedding)
+            .await?;
+
+        refresh_centroid(self.people, person_id).await?;
+        Ok((face_id, person_id))
+    }
+
+    /// 얼굴이 아닌 검출 결과 삭제
+    pub async fn remove(&self, owner_id: Uuid, face_id: Uuid) -> AppResult<()> {
+        let face = self
+            .people
+            .find_face(owner_id, face_id)
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+        self.people.delete_face(face_id).await?;
+        self.retrain(&[face.person_id]).await
+    }
+
+    async fn resolve(&self, owner_id: Uuid, target: &FaceAssignment) -> AppResult<Uuid> {
+        match target {
+            FaceAssignment::Person(person_id) => {
+                match self.people.person_exists(owner_id, *person_id).await? {
+                    true => Ok(*person_id),
+                    false => Err(AppError::BadRequest("인물을 찾을 수 없습니다".to_string())),
+                }
+            }
+            FaceAssignment::NewPerson(name) => {
+                let name = normalize_name(name.as_deref())?;
+                self.people.create_person(owner_id, name, None).await
+            }
+        }
+    }
+
+    async fn retrain(&self, person_ids: &[Option<Uuid>]) -> AppResult<()> {
+        let mut person_ids: Vec<Uuid> = person_ids.iter().flatten().copied().collect();
+        person_ids.dedup();
+        for person_id in person_ids {
+            refresh_centroid(self.people, person_id).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use uuid::Uuid;
+
+use crate::application::use_cases::photo_batch::{MAX_PHOTO_BATCH, normalize_photo_ids};
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::error::{AppError, AppResult};
+
+/// 한 번에 붙일 수 있는 최대 태그 수
+const MAX_TAGS: usize = 20;
+/// 태그 이름 최대 길이 (`tags.name`)
+const MAX_TAG_LENGTH: usize = 100;
+
+pub struct TagPhotosUseCase<'a, R: TagRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: TagRepository> TagPhotosUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// 선택한 사진에 태그를 직접 붙임, 새로 붙은 수를 반환
+    pub async fn execute(
+        &self,
+        owner_id: Uuid,
+        photo_ids: &[Uuid],
+        tags: &[String],
+    ) -> AppResult<u64> {
+        let ids = normalize_photo_ids(photo_ids, MAX_PHOTO_BATCH)?;
+        let names = normalize_tags(tags)?;
+        self.repository.add_tags(owner_id, &ids, &names).await
+    }
+}
+
+/// 앞뒤 공백을 지우고 대소문자만 다른 태그는 하나로 (검색과 같은 기준)
+fn normalize_tags(tags: &[String]) -> AppResult<Vec<String>> {
+    let mut names: Vec<String> = Vec::new();
+    for tag in tags
+        .iter()
+        .map(|tag| tag.trim())
+        .filter(|tag| !tag.is_empty())
+    {
+        if tag.chars().count() > MAX_TAG_LENGTH {
+            return Err(AppError::BadRequest(format!(
+                "tags must be at most {MAX_TAG_LENGTH} characters"
+            )));
+        }
+        if !names
+            .iter()
+            .any(|name| name.to_lowercase() == tag.to_lowercase())
+        {
+            names.push(tag.to_string());
+        }
+    }
+    if names.is_empty() || names.len() > MAX_TAGS {
+        return Err(AppError::BadRequest(format!(
+            "tags must contain 1 to {MAX_TAGS} names"
+        )));
+    }
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_normalize_tags_trims_and_dedups_case_insensitively() {
+        let names = normalize_tags(&tags(&[" Beach ", "beach", "", "Sunset"])).unwrap();
+
+        assert_eq!(names, tags(&["Beach", "Sunset"]));
+    }
+
+    #[test]
+    fn test_normalize_tags_rejects_empty_and_long_names() {
+        assert!(normalize_tags(&tags(&["  "])).is_err());
+        assert!(normalize_tags(&["x".repeat(MAX_TAG_LENGTH + 1)]).is_err());
+    }
+}
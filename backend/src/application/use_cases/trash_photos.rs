@@ -0,0 +1,21 @@
+use uuid::Uuid;
+
+use crate::application::use_cases::photo_batch::{MAX_PHOTO_BATCH, normalize_photo_ids};
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::error::AppResult;
+
+pub struct TrashPhotosUseCase<'a, R: PhotoRepository> {
+    repository: &'a R,
+}
+
+impl<'a, R: PhotoRepository> TrashPhotosUseCase<'a, R> {
+    pub fn new(repository: &'a R) -> Self {
+        Self { repository }
+    }
+
+    /// 옮긴 사진 수를 반환 (이미 삭제된 사진은 제외)
+    pub async fn execute(&self, owner_id: Uuid, photo_ids: &[Uuid]) -> AppResult<u64> {
+        let ids = normalize_photo_ids(photo_ids, MAX_PHOTO_BATCH)?;
+        self.repository.trash(owner_id, &ids).await
+    }
+}
@@ -0,0 +1,95 @@
+use std::env;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+
+/// 자동 태그 신뢰도 기준 기본값
+const DEFAULT_MIN_CONFIDENCE: f32 = 0.5;
+
+/// 서버 실행 설정
+///
+/// `.env` 파일 또는 환경 변수에서 읽어옵니다. (`backend/.env.example` 참고)
+#[derive(Clone, Debug)]
+pub struct AppConfig {
+    pub database_url: String,
+    pub bind_addr: String,
+    /// 원본/썸네일/프리뷰 파일이 저장된 루트 디렉터리 (`/media` 로 서빙)
+    pub media_root: PathBuf,
+    /// 오프라인 지도 타일 디렉터리 (`/tiles/{z}/{x}/{y}.png` 로 서빙, 없으면 비활성)
+    pub tile_root: Option<PathBuf>,
+    /// 역지오코딩 지명 사전 (없으면 `backend/data/gazetteer.tsv` 내장본 사용)
+    pub gazetteer_path: Option<PathBuf>,
+    /// 자동 태그용 이미지 분류기 (없으면 `ai_tags` 작업 비활성)
+    pub classifier: Option<ClassifierConfig>,
+    /// 이 신뢰도 이상인 분류 결과만 태그로 저장 (0~1)
+    pub auto_tag_min_confidence: f32,
+    /// 인증 도입 전까지 모든 요청을 처리할 라이브러리 소유자
+    pub owner_id: Uuid,
+}
+
+impl AppConfig {
+    pub fn from_env() -> AppResult<Self> {
+        let _ = dotenvy::dotenv();
+
+        let owner_id = required("OWNER_ID")?
+            .parse::<Uuid>()
+            .map_err(|_| AppError::Config("OWNER_ID must be a UUID".to_string()))?;
+
+        Ok(Self {
+            database_url: required("DATABASE_URL")?,
+            bind_addr: env::var("BIND_ADDR").unwrap_or_else(|_| "127.0.0.1:8080".to_string()),
+            media_root: env::var("MEDIA_ROOT")
+                .unwrap_or_else(|_| "./storage".to_string())
+                .into(),
+            tile_root: env::var("TILE_ROOT").ok().map(PathBuf::from),
+            gazetteer_path: env::var("GAZETTEER_PATH").ok().map(PathBuf::from),
+            classifier: classifier_config()?,
+            auto_tag_min_confidence: min_confidence()?,
+            owner_id,
+        })
+    }
+}
+
+/// `CLASSIFIER` 로 고르는 이미지 분류기
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClassifierConfig {
+    /// 평균 밝기/색만 보는 결정적 분류기 (모델 없이 흐름 확인용)
+    Stub,
+    /// ONNX 분류 모델 (`onnx` 기능으로 빌드해야 사용 가능)
+    Onnx {
+        model_path: PathBuf,
+        labels_path: PathBuf,
+    },
+}
+
+fn classifier_config() -> AppResult<Option<ClassifierConfig>> {
+    match env::var("CLASSIFIER").ok().as_deref() {
+        None | Some("") | Some("none") => Ok(None),
+        Some("stub") => Ok(Some(ClassifierConfig::Stub)),
+        Some("onnx") => Ok(Some(ClassifierConfig::Onnx {
+            model_path: required("CLASSIFIER_MODEL_PATH")?.into(),
+            labels_path: required("CLASSIFIER_LABELS_PATH")?.into(),
+        })),
+        Some(other) => Err(AppError::Config(format!(
+            "CLASSIFIER must be none, stub or onnx (got {other})"
+        ))),
+    }
+}
+
+fn min_confidence() -> AppResult<f32> {
+    let Ok(value) = env::var("AUTO_TAG_MIN_CONFIDENCE") else {
+        return Ok(DEFAULT_MIN_CONFIDENCE);
+    };
+    value
+        .parse::<f32>()
+        .ok()
+        .filter(|v| (0.0..=1.0).contains(v))
+        .ok_or_else(|| {
+            AppError::Config("AUTO_TAG_MIN_CONFIDENCE must be between 0 and 1".to_string())
+        })
+}
+
+fn required(key: &str) -> AppResult<String> {
+    env::var(key).map_err(|_| AppError::Config(format!("{key} is not set")))
+}
@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::value_objects::color::Rgb;
+use crate::domain::value_objects::image_quality::ImageQuality;
+
+/// 라이브러리에 저장된 사진 (`photos` 테이블)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Photo {
+    // 필수 필드
+    pub id: Uuid,
+    pub user_id: Uuid,
+
+    // 파일 정보
+    pub original_filename: String,
+    pub storage_path: String,
+    pub thumbnail_path: Option<String>,
+    pub preview_path: Option<String>,
+
+    // 기본 EXIF 데이터
+    pub taken_at: Option<DateTime<Utc>>,
+    pub camera_make: Option<String>,
+    pub camera_model: Option<String>,
+    pub lens_model: Option<String>,
+    pub width: u32,
+    pub height: u32,
+
+    /// 대표 색 (많이 쓰인 순, 아직 추출 전이면 비어 있음)
+    pub dominant_colors: Vec<Rgb>,
+    /// 선명도/노출 점수 (아직 평가 전이면 `None`)
+    pub quality: Option<ImageQuality>,
+
+    // 사용자 상태
+    pub is_liked: bool,
+
+    // 타임스탬프
+    pub created_at: DateTime<Utc>,
+}
+
+impl Photo {
+    /// 타임라인 정렬 기준 시각 (촬영 시각이 없으면 업로드 시각)
+    pub fn sort_at(&self) -> DateTime<Utc> {
+        self.taken_at.unwrap_or(self.created_at)
+    }
+
+    /// 확장자를 제외한 파일명을 제목으로 사용
+    pub fn title(&self) -> &str {
+        self.original_filename
+            .rsplit_once('.')
+            .map_or(self.original_filename.as_str(), |(stem, _)| stem)
+    }
+}
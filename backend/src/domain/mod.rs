@@ -0,0 +1,4 @@
+pub mod entities;
+pub mod repositories;
+pub mod services;
+pub mod value_objects;
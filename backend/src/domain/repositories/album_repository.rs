@@ -0,0 +1,29 @@
+use std::future::Future;
+use uuid::Uuid;
+
+use crate::domain::value_objects::album::Album;
+use crate::error::AppResult;
+
+pub trait AlbumRepository {
+    /// 최근에 바뀐 앨범부터
+    fn list(&self, owner_id: Uuid) -> impl Future<Output = AppResult<Vec<Album>>> + Send;
+
+    /// 앨범 하나 (없으면 `None`)
+    fn find(
+        &self,
+        owner_id: Uuid,
+        album_id: Uuid,
+    ) -> impl Future<Output = AppResult<Option<Album>>> + Send;
+
+    fn create(&self, owner_id: Uuid, title: &str) -> impl Future<Output = AppResult<Album>> + Send;
+
+    /// 앨범 끝에 사진 추가 (이미 있는 사진은 건너뜀), 추가한 수를 반환
+    ///
+    /// 앨범이 없으면 `None`
+    fn add_photos(
+        &self,
+        owner_id: Uuid,
+        album_id: Uuid,
+        photo_ids: &[Uuid],
+    ) -> impl Future<Output = AppResult<Option<u64>>> + Send;
+}
@@ -0,0 +1,28 @@
+use std::future::Future;
+use uuid::Uuid;
+
+use crate::domain::value_objects::duplicate::{DuplicatePhoto, PerceptualHash};
+use crate::error::AppResult;
+
+pub trait DuplicateRepository {
+    fn save_perceptual_hash(
+        &self,
+        photo_id: Uuid,
+        hash: PerceptualHash,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// 지각 해시의 해밍 거리가 `max_distance` 이하인 사진 쌍 (가까운 순, 최대 `limit`개)
+    fn similar_pairs(
+        &self,
+        owner_id: Uuid,
+        max_distance: u32,
+        limit: usize,
+    ) -> impl Future<Output = AppResult<Vec<(Uuid, Uuid)>>> + Send;
+
+    /// 삭제되지 않은 사진의 상세 정보 (파일 크기 포함)
+    fn duplicate_photos(
+        &self,
+        owner_id: Uuid,
+        photo_ids: &[Uuid],
+    ) -> impl Future<Output = AppResult<Vec<DuplicatePhoto>>> + Send;
+}
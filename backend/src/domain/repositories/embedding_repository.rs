@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use std::future::Future;
+use uuid::Uuid;
+
+use crate::domain::entities::photo::Photo;
+use crate::domain::value_objects::image_embedding::{ImageEmbedding, StoredEmbedding};
+use crate::error::AppResult;
+
+pub trait EmbeddingRepository {
+    fn save_embedding(
+        &self,
+        photo_id: Uuid,
+        embedding: &ImageEmbedding,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// `since` 이후(포함)에 저장된 벡터 (삭제된 사진 제외, 저장 순)
+    fn embeddings_since(
+        &self,
+        owner_id: Uuid,
+        since: Option<DateTime<Utc>>,
+    ) -> impl Future<Output = AppResult<Vec<StoredEmbedding>>> + Send;
+
+    /// `photo_ids` 중 삭제되지 않은 사진 (순서는 보장하지 않음)
+    fn photos_by_ids(
+        &self,
+        owner_id: Uuid,
+        photo_ids: &[Uuid],
+    ) -> impl Future<Output = AppResult<Vec<Photo>>> + Send;
+}
@@ -0,0 +1,8 @@
+pub mod album_repository;
+pub mod duplicate_repository;
+pub mod embedding_repository;
+pub mod person_repository;
+pub mod photo_repository;
+pub mod place_repository;
+pub mod processing_queue_repository;
+pub mod tag_repository;
@@ -0,0 +1,134 @@
+use std::future::Future;
+use uuid::Uuid;
+
+use crate::domain::value_objects::face::{
+    FaceBox, FaceEmbedding, NewFace, Person, PersonCentroid, PersonFace, PhotoFace,
+};
+use crate::error::AppResult;
+
+/// 인물 군집(`people`)과 사진 속 얼굴(`photo_faces`)
+pub trait PersonRepository {
+    /// 라이브러리의 모든 인물 군집 중심 (얼굴 배정용)
+    fn person_centroids(
+        &self,
+        owner_id: Uuid,
+    ) -> impl Future<Output = AppResult<Vec<PersonCentroid>>> + Send;
+
+    fn create_person(
+        &self,
+        owner_id: Uuid,
+        name: Option<&str>,
+        centroid: Option<&FaceEmbedding>,
+    ) -> impl Future<Output = AppResult<Uuid>> + Send;
+
+    fn person_exists(
+        &self,
+        owner_id: Uuid,
+        person_id: Uuid,
+    ) -> impl Future<Output = AppResult<bool>> + Send;
+
+    /// 군집 중심 갱신 (`None` 이면 소속 얼굴이 없는 상태)
+    fn update_centroid(
+        &self,
+        person_id: Uuid,
+        centroid: Option<&FaceEmbedding>,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// 사진의 자동 검출 얼굴을 새 결과로 교체하고 검출 완료로 표시 (수동 얼굴은 유지)
+    ///
+    /// 지운 얼굴이 속해 있던 인물 id 를 반환
+    fn replace_faces(
+        &self,
+        photo_id: Uuid,
+        faces: &[NewFace],
+    ) -> impl Future<Output = AppResult<Vec<Uuid>>> + Send;
+
+    /// `person_ids` 중 얼굴이 하나도 남지 않은 인물을 삭제하고 삭제한 수 반환
+    fn delete_empty_people(
+        &self,
+        owner_id: Uuid,
+        person_ids: &[Uuid],
+    ) -> impl Future<Output = AppResult<u64>> + Send;
+
+    /// 얼굴이 하나 이상 있는 인물 (얼굴 많은 순)
+    fn list_people(&self, owner_id: Uuid) -> impl Future<Output = AppResult<Vec<Person>>> + Send;
+
+    /// 인물에 속한 얼굴 (신뢰도 높은 순)
+    fn person_faces(
+        &self,
+        owner_id: Uuid,
+        person_id: Uuid,
+    ) -> impl Future<Output = AppResult<Vec<PersonFace>>> + Send;
+
+    /// 중심 재계산용 소속 얼굴 벡터
+    fn face_embeddings(
+        &self,
+        person_id: Uuid,
+    ) -> impl Future<Output = AppResult<Vec<FaceEmbedding>>> + Send;
+
+    /// 이름 변경 (`None` 이면 이름 제거), 인물이 없으면 `false`
+    fn rename(
+        &self,
+        owner_id: Uuid,
+        person_id: Uuid,
+        name: Option<&str>,
+    ) -> impl Future<Output = AppResult<bool>> + Send;
+
+    /// `source_ids` 의 얼굴을 모두 `target_id` 로 옮기고 원래 인물은 삭제
+    ///
+    /// 옮긴 얼굴 수를 반환하며, `target_id` 가 없으면 `None`
+    fn merge(
+        &self,
+        owner_id: Uuid,
+        target_id: Uuid,
+        source_ids: &[Uuid],
+    ) -> impl Future<Output = AppResult<Option<u64>>> + Send;
+
+    /// `person_id` 의 얼굴 중 `face_ids` 를 새 인물로 옮기고 새 인물 id 반환
+    ///
+    /// 옮길 얼굴이 하나도 없으면 `None`
+    fn split(
+        &self,
+        owner_id: Uuid,
+        person_id: Uuid,
+        face_ids: &[Uuid],
+    ) -> impl Future<Output = AppResult<Option<Uuid>>> + Send;
+
+    /// 얼굴 썸네일을 만들 이미지 경로와 영역
+    fn face_source(
+        &self,
+        owner_id: Uuid,
+        face_id: Uuid,
+    ) -> impl Future<Output = AppResult<Option<(String, FaceBox)>>> + Send;
+
+    /// 사진에 나온 얼굴과 배정된 인물
+    fn photo_faces(
+        &self,
+        owner_id: Uuid,
+        photo_id: Uuid,
+    ) -> impl Future<Output = AppResult<Vec<PhotoFace>>> + Send;
+
+    fn find_face(
+        &self,
+        owner_id: Uuid,
+        face_id: Uuid,
+    ) -> impl Future<Output = AppResult<Option<PhotoFace>>> + Send;
+
+    /// 얼굴의 인물을 바꾸고 수동 얼굴로 표시
+    fn assign_face(
+        &self,
+        face_id: Uuid,
+        person_id: Uuid,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// 사용자가 그린 얼굴 영역 저장 (신뢰도 1.0), 새 얼굴 id 반환
+    fn add_manual_face(
+        &self,
+        photo_id: Uuid,
+        person_id: Uuid,
+        bounds: FaceBox,
+        embedding: &FaceEmbedding,
+    ) -> impl Future<Output = AppResult<Uuid>> + Send;
+
+    fn delete_face(&self, face_id: Uuid) -> impl Future<Output = AppResult<()>> + Send;
+}
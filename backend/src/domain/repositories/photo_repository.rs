@@ -0,0 +1,130 @@
+use std::future::Future;
+use uuid::Uuid;
+
+use crate::domain::entities::photo::Photo;
+use crate::domain::value_objects::color::PaletteColor;
+use crate::domain::value_objects::exif_filter::ExifField;
+use crate::domain::value_objects::geo_area::GeoCluster;
+use crate::domain::value_objects::image_quality::ImageQuality;
+use crate::domain::value_objects::photo_cursor::PhotoCursor;
+use crate::domain::value_objects::photo_file::PhotoFile;
+use crate::domain::value_objects::processing_task::ImageSource;
+use crate::domain::value_objects::search_facets::{RangeCount, ValueCount};
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::domain::value_objects::timeline::TimelineMonth;
+use crate::error::AppResult;
+
+pub trait PhotoRepository {
+    /// 조건에 맞는 사진을 타임라인 순서로 `cursor` 다음부터 최대 `limit`개 조회
+    fn search(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+        cursor: Option<PhotoCursor>,
+        limit: usize,
+    ) -> impl Future<Output = AppResult<Vec<Photo>>> + Send;
+
+    /// 사진 한 장 (없거나 삭제된 사진이면 `None`)
+    fn find(
+        &self,
+        owner_id: Uuid,
+        photo_id: Uuid,
+    ) -> impl Future<Output = AppResult<Option<Photo>>> + Send;
+
+    /// 조건에 맞는 사진 중 `cursor` 보다 최근 사진을 가까운 순으로 최대 `limit`개 조회
+    fn search_newer(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+        cursor: PhotoCursor,
+        limit: usize,
+    ) -> impl Future<Output = AppResult<Vec<Photo>>> + Send;
+
+    /// 렌즈 모델별 사진 수 (많은 순, 최대 `limit`개)
+    fn lens_facets(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+        limit: usize,
+    ) -> impl Future<Output = AppResult<Vec<ValueCount<String>>>> + Send;
+
+    /// `field.buckets()` 구간별 사진 수
+    fn range_facets(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+        field: ExifField,
+    ) -> impl Future<Output = AppResult<Vec<RangeCount>>> + Send;
+
+    /// 플래시 사용 여부별 사진 수
+    fn flash_facets(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+    ) -> impl Future<Output = AppResult<Vec<ValueCount<bool>>>> + Send;
+
+    /// 위치가 있는 사진을 `cell_degrees` 크기 격자로 묶은 클러스터 (사진 많은 순, 최대 `limit`개)
+    fn geo_clusters(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+        cell_degrees: f64,
+        limit: usize,
+    ) -> impl Future<Output = AppResult<Vec<GeoCluster>>> + Send;
+
+    /// 월별 사진 수 (최근 달부터)
+    fn month_counts(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+    ) -> impl Future<Output = AppResult<Vec<TimelineMonth>>> + Send;
+
+    /// 분석 작업에 사용할 이미지 파일 (삭제된 사진이면 `None`)
+    fn image_source(
+        &self,
+        photo_id: Uuid,
+    ) -> impl Future<Output = AppResult<Option<ImageSource>>> + Send;
+
+    /// 추출한 팔레트 저장 (`color_palette` 전체 + 많이 쓰인 순 `dominant_colors`)
+    fn save_palette(
+        &self,
+        photo_id: Uuid,
+        palette: &[PaletteColor],
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// 선명도/노출 점수 저장
+    fn save_quality(
+        &self,
+        photo_id: Uuid,
+        quality: &ImageQuality,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// 흐리거나 노출이 크게 어긋난 사진 (가장 나쁜 점수 순, 최대 `limit`개)
+    fn cleanup_candidates(
+        &self,
+        owner_id: Uuid,
+        limit: usize,
+    ) -> impl Future<Output = AppResult<Vec<Photo>>> + Send;
+
+    /// 휴지통으로 이동 (`deleted_at` 설정), 옮긴 사진 수를 반환
+    fn trash(
+        &self,
+        owner_id: Uuid,
+        photo_ids: &[Uuid],
+    ) -> impl Future<Output = AppResult<u64>> + Send;
+
+    /// 좋아요(`favorites`) 추가 또는 해제, 바뀐 사진 수를 반환
+    fn set_favorite(
+        &self,
+        owner_id: Uuid,
+        photo_ids: &[Uuid],
+        liked: bool,
+    ) -> impl Future<Output = AppResult<u64>> + Send;
+
+    /// 원본 파일 정보 (삭제된 사진 제외)
+    fn photo_files(
+        &self,
+        owner_id: Uuid,
+        photo_ids: &[Uuid],
+    ) -> impl Future<Output = AppResult<Vec<PhotoFile>>> + Send;
+}
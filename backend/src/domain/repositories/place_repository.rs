@@ -0,0 +1,27 @@
+use std::future::Future;
+use uuid::Uuid;
+
+use crate::domain::value_objects::geo_area::GeoPoint;
+use crate::domain::value_objects::place::{Place, PlaceCount};
+use crate::error::AppResult;
+
+pub trait PlaceRepository {
+    /// 사진의 GPS 좌표 (없거나 삭제된 사진이면 `None`)
+    fn photo_location(
+        &self,
+        photo_id: Uuid,
+    ) -> impl Future<Output = AppResult<Option<GeoPoint>>> + Send;
+
+    /// 역지오코딩 결과를 `location_name` 과 도시/지역/국가 컬럼에 저장
+    fn save_place(
+        &self,
+        photo_id: Uuid,
+        place: &Place,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// 도시별 사진 수 (국가명 → 사진 많은 순)
+    fn city_counts(
+        &self,
+        owner_id: Uuid,
+    ) -> impl Future<Output = AppResult<Vec<PlaceCount>>> + Send;
+}
@@ -0,0 +1,43 @@
+use std::future::Future;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::value_objects::processing_task::{ProcessingTask, TaskType};
+use crate::error::AppResult;
+
+/// `processing_queue` 작업 대기열
+pub trait ProcessingQueueRepository {
+    /// `task_type` 처리가 필요한 사진을 최대 `limit`장 대기열에 추가하고 추가된 수를 반환
+    fn enqueue_missing(
+        &self,
+        task_type: TaskType,
+        limit: usize,
+    ) -> impl Future<Output = AppResult<u64>> + Send;
+
+    /// `task_types` 중 우선순위가 가장 높은 대기 작업을 `processing` 상태로 바꾸어 가져옴
+    ///
+    /// 여러 워커가 동시에 실행되어도 같은 작업을 가져가지 않습니다.
+    fn claim_next(
+        &self,
+        task_types: &[TaskType],
+    ) -> impl Future<Output = AppResult<Option<ProcessingTask>>> + Send;
+
+    fn complete(&self, task_id: Uuid) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// 실패 기록 (재시도 횟수가 `max_retries`에 도달하면 `failed`, 아니면 다시 대기)
+    fn fail(
+        &self,
+        task_id: Uuid,
+        message: &str,
+        max_retries: i32,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// `processing` 상태로 `stale_after` 넘게 끝나지 않은 작업(작업자가 중단됨)을 실패로 기록하고 되살린 수를 반환
+    ///
+    /// 재시도 규칙은 `fail` 과 같아, `max_retries`에 도달하면 `failed` 로 두고 아니면 다시 대기합니다.
+    fn reclaim_stale(
+        &self,
+        stale_after: Duration,
+        max_retries: i32,
+    ) -> impl Future<Output = AppResult<u64>> + Send;
+}
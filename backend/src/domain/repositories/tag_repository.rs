@@ -0,0 +1,26 @@
+use std::future::Future;
+use uuid::Uuid;
+
+use crate::domain::value_objects::image_label::ImageLabel;
+use crate::error::AppResult;
+
+pub trait TagRepository {
+    /// 자동 태그 저장 (`ai_tags` 와 `photo_tags.confidence`)
+    ///
+    /// 이전 자동 태그 중 이번에 빠진 것은 지우고, 직접 붙인 태그는 그대로 둡니다.
+    fn save_ai_tags(
+        &self,
+        photo_id: Uuid,
+        labels: &[ImageLabel],
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// 직접 붙인 태그 추가 (없는 태그는 만들고, 같은 자동 태그는 직접 붙인 태그로 바꿈)
+    ///
+    /// 새로 붙거나 바뀐 (사진, 태그) 수를 반환
+    fn add_tags(
+        &self,
+        owner_id: Uuid,
+        photo_ids: &[Uuid],
+        names: &[String],
+    ) -> impl Future<Output = AppResult<u64>> + Send;
+}
@@ -0,0 +1,17 @@
+use image::RgbImage;
+
+use crate::domain::value_objects::face::{FaceBox, FaceCandidate, FaceEmbedding};
+
+/// 이미지에서 얼굴 영역을 찾는 CPU 검출기
+pub trait FaceDetector: Send + Sync {
+    /// 신뢰도 높은 순으로 반환
+    fn detect(&self, image: &RgbImage) -> Vec<FaceCandidate>;
+}
+
+/// 얼굴 영역을 특징 벡터로 바꾸는 CPU 임베더
+///
+/// 같은 사람의 얼굴일수록 코사인 유사도가 높아야 합니다.
+/// 구현을 바꾸면 기존 `people.face_encoding` 과 비교할 수 없으므로 재처리가 필요합니다.
+pub trait FaceEmbedder: Send + Sync {
+    fn embed(&self, image: &RgbImage, bounds: FaceBox) -> FaceEmbedding;
+}
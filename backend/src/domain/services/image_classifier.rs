@@ -0,0 +1,10 @@
+use image::RgbImage;
+
+use crate::domain::value_objects::image_label::ImageLabel;
+use crate::error::AppResult;
+
+/// 자동 태그용 이미지 분류기
+pub trait ImageClassifier: Send + Sync {
+    /// 신뢰도 높은 순 태그 (신뢰도 기준 적용 전)
+    fn classify(&self, image: &RgbImage) -> AppResult<Vec<ImageLabel>>;
+}
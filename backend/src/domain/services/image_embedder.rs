@@ -0,0 +1,11 @@
+use image::RgbImage;
+
+use crate::domain::value_objects::image_embedding::ImageEmbedding;
+
+/// 비슷한 사진 검색용 이미지 임베더
+///
+/// 구성이나 색이 비슷한 사진일수록 코사인 유사도가 높아야 합니다.
+/// 구현을 바꾸면 기존 `image_embedding` 과 비교할 수 없으므로 재처리가 필요합니다.
+pub trait ImageEmbedder: Send + Sync {
+    fn embed(&self, image: &RgbImage) -> ImageEmbedding;
+}
@@ -0,0 +1,10 @@
+use image::RgbImage;
+
+use crate::domain::value_objects::duplicate::PerceptualHash;
+
+/// 크기 변경/재압축에 강한 이미지 지각 해시
+///
+/// 구현을 바꾸면 기존 `perceptual_hash` 와 비교할 수 없으므로 재처리가 필요합니다.
+pub trait ImageHasher: Send + Sync {
+    fn hash(&self, image: &RgbImage) -> PerceptualHash;
+}
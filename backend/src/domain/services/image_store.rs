@@ -0,0 +1,11 @@
+use image::RgbImage;
+
+use crate::error::AppResult;
+
+/// `media_root` 아래 저장된 사진 파일 읽기/쓰기
+pub trait ImageStore: Send + Sync {
+    /// EXIF 방향을 보정하고, 긴 변이 `max_size` 보다 크면 줄여서 읽음
+    fn load_rgb(&self, path: &str, max_size: u32) -> AppResult<RgbImage>;
+
+    fn encode_jpeg(&self, image: &RgbImage) -> AppResult<Vec<u8>>;
+}
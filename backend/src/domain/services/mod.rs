@@ -0,0 +1,9 @@
+pub mod face_analyzer;
+pub mod image_classifier;
+pub mod image_embedder;
+pub mod image_hasher;
+pub mod image_store;
+pub mod palette_extractor;
+pub mod photo_archiver;
+pub mod quality_analyzer;
+pub mod reverse_geocoder;
@@ -0,0 +1,9 @@
+use image::RgbImage;
+
+use crate::domain::value_objects::color::PaletteColor;
+
+/// 이미지의 대표 색 추출기
+pub trait PaletteExtractor: Send + Sync {
+    /// 비율 높은 순으로 최대 `max_colors`개
+    fn extract(&self, image: &RgbImage, max_colors: usize) -> Vec<PaletteColor>;
+}
@@ -0,0 +1,13 @@
+use std::io::Write;
+
+use crate::domain::value_objects::photo_file::PhotoFile;
+use crate::error::AppResult;
+
+/// 여러 사진 원본을 압축 파일 하나로 묶음
+pub trait PhotoArchiver: Send + Sync {
+    /// 묶을 원본 파일 크기의 합 (bytes)
+    fn total_size(&self, files: &[PhotoFile]) -> AppResult<u64>;
+
+    /// 압축 파일을 `output` 에 앞에서부터 차례로 씀 (되돌아가 고쳐 쓰지 않으므로 바로 내보낼 수 있음)
+    fn archive(&self, files: &[PhotoFile], output: &mut dyn Write) -> AppResult<()>;
+}
@@ -0,0 +1,8 @@
+use image::RgbImage;
+
+use crate::domain::value_objects::image_quality::ImageQuality;
+
+/// 이미지 화질(선명도, 노출) 평가기
+pub trait QualityAnalyzer: Send + Sync {
+    fn assess(&self, image: &RgbImage) -> ImageQuality;
+}
@@ -0,0 +1,8 @@
+use crate::domain::value_objects::geo_area::GeoPoint;
+use crate::domain::value_objects::place::Place;
+
+/// 좌표 → 행정 구역 변환 (외부 서비스 없이 동작해야 함)
+pub trait ReverseGeocoder {
+    /// 가까운 지명이 없으면(바다 한가운데 등) `None`
+    fn reverse_geocode(&self, point: GeoPoint) -> Option<Place>;
+}
@@ -0,0 +1,9 @@
+use uuid::Uuid;
+
+/// 앨범 목록 항목 (휴지통 사진은 개수에서 제외)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Album {
+    pub id: Uuid,
+    pub title: String,
+    pub photo_count: i64,
+}
@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+
+/// sRGB 색상 (`#rrggbb` 문자열로 주고받음)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    /// `#rrggbb` 또는 `rrggbb`
+    pub fn parse_hex(value: &str) -> Option<Self> {
+        let hex = value.trim().trim_start_matches('#');
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+        Some(Self {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        })
+    }
+
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// D65 기준 CIELAB
+    pub fn to_lab(self) -> Lab {
+        let linear = |channel: u8| {
+            let c = channel as f32 / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let (r, g, b) = (linear(self.r), linear(self.g), linear(self.b));
+        let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.950_47;
+        let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.088_83;
+
+        let f = |t: f32| {
+            if t > 0.008_856 {
+                t.cbrt()
+            } else {
+                7.787 * t + 16.0 / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+
+impl TryFrom<String> for Rgb {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse_hex(&value).ok_or_else(|| format!("invalid color: {value}"))
+    }
+}
+
+impl From<Rgb> for String {
+    fn from(color: Rgb) -> Self {
+        color.to_hex()
+    }
+}
+
+/// CIELAB 색 공간의 한 점 (사람이 느끼는 색 차이가 거리에 비례)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Lab {
+    /// CIE76 색차 (ΔE, 2.3 정도면 겨우 구별되는 차이)
+    pub fn distance(&self, other: &Lab) -> f32 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+}
+
+/// 사진 팔레트의 색 하나
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PaletteColor {
+    pub color: Rgb,
+    pub lab: Lab,
+    /// 이 색에 가까운 픽셀 비율 (0~1)
+    pub ratio: f32,
+}
+
+/// 색상 검색 조건: 팔레트에 `color` 와 `max_distance` 이내의 색이 있는 사진
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorFilter {
+    pub color: Rgb,
+    /// 허용 색차 (ΔE)
+    pub max_distance: f32,
+}
+
+impl ColorFilter {
+    pub const DEFAULT_DISTANCE: f32 = 20.0;
+    /// 이보다 적게 쓰인 팔레트 색은 검색에서 무시
+    pub const MIN_RATIO: f32 = 0.05;
+
+    pub fn new(color: Rgb, max_distance: Option<f32>) -> Self {
+        Self {
+            color,
+            max_distance: max_distance.unwrap_or(Self::DEFAULT_DISTANCE),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.max_distance > 0.0 && self.max_distance <= 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_lab(color: Rgb, expected: (f32, f32, f32)) {
+        let lab = color.to_lab();
+        let expected = Lab {
+            l: expected.0,
+            a: expected.1,
+            b: expected.2,
+        };
+        assert!(lab.distance(&expected) < 0.5, "{color:?} -> {lab:?}");
+    }
+
+    #[test]
+    fn test_converts_reference_colors_to_lab() {
+        assert_lab(Rgb { r: 0, g: 0, b: 0 }, (0.0, 0.0, 0.0));
+        assert_lab(
+            Rgb {
+                r: 255,
+                g: 255,
+                b: 255,
+            },
+            (100.0, 0.0, 0.0),
+        );
+        assert_lab(Rgb { r: 255, g: 0, b: 0 }, (53.24, 80.09, 67.20));
+        assert_lab(Rgb { r: 0, g: 0, b: 255 }, (32.30, 79.19, -107.86));
+    }
+
+    #[test]
+    fn test_parses_hex_colors() {
+        let color = Rgb {
+            r: 255,
+            g: 136,
+            b: 0,
+        };
+        assert_eq!(Rgb::parse_hex("#ff8800"), Some(color));
+        assert_eq!(Rgb::parse_hex(" FF8800 "), Some(color));
+        assert_eq!(color.to_hex(), "#ff8800");
+        assert_eq!(Rgb::parse_hex("#ff88"), None);
+        assert_eq!(Rgb::parse_hex("#gg8800"), None);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::domain::entities::photo::Photo;
+
+/// 64비트 지각 해시 (비슷한 이미지일수록 서로 다른 비트 수가 적음)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PerceptualHash(pub u64);
+
+impl PerceptualHash {
+    /// 해밍 거리 (0~64)
+    pub fn distance(self, other: PerceptualHash) -> u32 {
+        (self.0 ^ other.0).count_ones()
+    }
+
+    /// `BIGINT` 컬럼 저장용 (비트를 그대로 재해석)
+    pub fn to_i64(self) -> i64 {
+        self.0 as i64
+    }
+
+    pub fn from_i64(value: i64) -> Self {
+        Self(value as u64)
+    }
+}
+
+/// 중복 그룹에 속한 사진 한 장
+#[derive(Clone, Debug)]
+pub struct DuplicatePhoto {
+    pub photo: Photo,
+    pub file_size_bytes: i64,
+}
+
+impl DuplicatePhoto {
+    /// 해상도가 높을수록, 같으면 파일이 클수록(덜 압축된 사본) 좋은 사본
+    fn quality_key(&self) -> (u64, i64) {
+        let pixels = u64::from(self.photo.width) * u64::from(self.photo.height);
+        (pixels, self.file_size_bytes)
+    }
+}
+
+/// 서로 거의 같은 사진 묶음 (가장 좋은 사본이 맨 앞)
+#[derive(Clone, Debug)]
+pub struct DuplicateGroup {
+    pub photos: Vec<DuplicatePhoto>,
+}
+
+impl DuplicateGroup {
+    pub fn new(mut photos: Vec<DuplicatePhoto>) -> Self {
+        photos.sort_by_key(|photo| std::cmp::Reverse(photo.quality_key()));
+        Self { photos }
+    }
+}
+
+/// 중복 검토 결과
+#[derive(Clone, Debug)]
+pub struct DuplicateReview {
+    /// 사진이 많은 그룹 순
+    pub groups: Vec<DuplicateGroup>,
+    /// 사진 쌍이 너무 많아 가까운 쌍까지만 묶었는지 (정리 후 다시 검토하면 나머지가 나옴)
+    pub truncated: bool,
+}
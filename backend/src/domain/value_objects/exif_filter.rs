@@ -0,0 +1,151 @@
+/// 반열린 구간 `[min, max)` 숫자 범위 (한쪽이 `None`이면 그 방향으로 제한 없음)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NumericRange {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl NumericRange {
+    pub const fn new(min: Option<f64>, max: Option<f64>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn is_unbounded(&self) -> bool {
+        self.min.is_none() && self.max.is_none()
+    }
+}
+
+/// 사진가용 EXIF 촬영 설정 필터
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExifFilter {
+    pub focal_length: NumericRange,
+    pub aperture: NumericRange,
+    /// 셔터 속도 (초)
+    pub shutter_speed: NumericRange,
+    pub iso: NumericRange,
+    pub flash: Option<bool>,
+}
+
+/// 범위로 필터링/집계하는 EXIF 숫자 필드
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExifField {
+    FocalLength,
+    Aperture,
+    ShutterSpeed,
+    Iso,
+}
+
+/// 패싯 집계 구간
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FacetBucket {
+    pub label: &'static str,
+    pub range: NumericRange,
+}
+
+const fn bucket(label: &'static str, min: Option<f64>, max: Option<f64>) -> FacetBucket {
+    FacetBucket {
+        label,
+        range: NumericRange::new(min, max),
+    }
+}
+
+const FOCAL_LENGTH_BUCKETS: &[FacetBucket] = &[
+    bucket("< 24mm", None, Some(24.0)),
+    bucket("24-35mm", Some(24.0), Some(35.0)),
+    bucket("35-70mm", Some(35.0), Some(70.0)),
+    bucket("70-135mm", Some(70.0), Some(135.0)),
+    bucket("135-300mm", Some(135.0), Some(300.0)),
+    bucket("300mm+", Some(300.0), None),
+];
+
+const APERTURE_BUCKETS: &[FacetBucket] = &[
+    bucket("< f/2", None, Some(2.0)),
+    bucket("f/2-f/4", Some(2.0), Some(4.0)),
+    bucket("f/4-f/8", Some(4.0), Some(8.0)),
+    bucket("f/8-f/16", Some(8.0), Some(16.0)),
+    bucket("f/16+", Some(16.0), None),
+];
+
+const SHUTTER_SPEED_BUCKETS: &[FacetBucket] = &[
+    bucket("< 1/1000s", None, Some(0.001)),
+    bucket("1/1000-1/250s", Some(0.001), Some(0.004)),
+    bucket("1/250-1/60s", Some(0.004), Some(1.0 / 60.0)),
+    bucket("1/60-1/15s", Some(1.0 / 60.0), Some(1.0 / 15.0)),
+    bucket("1/15s+", Some(1.0 / 15.0), None),
+];
+
+const ISO_BUCKETS: &[FacetBucket] = &[
+    bucket("< 200", None, Some(200.0)),
+    bucket("200-800", Some(200.0), Some(800.0)),
+    bucket("800-3200", Some(800.0), Some(3200.0)),
+    bucket("3200+", Some(3200.0), None),
+];
+
+impl ExifField {
+    pub const ALL: [ExifField; 4] = [
+        ExifField::FocalLength,
+        ExifField::Aperture,
+        ExifField::ShutterSpeed,
+        ExifField::Iso,
+    ];
+
+    pub fn buckets(self) -> &'static [FacetBucket] {
+        match self {
+            ExifField::FocalLength => FOCAL_LENGTH_BUCKETS,
+            ExifField::Aperture => APERTURE_BUCKETS,
+            ExifField::ShutterSpeed => SHUTTER_SPEED_BUCKETS,
+            ExifField::Iso => ISO_BUCKETS,
+        }
+    }
+
+    pub fn range(self, filter: &ExifFilter) -> NumericRange {
+        match self {
+            ExifField::FocalLength => filter.focal_length,
+            ExifField::Aperture => filter.aperture,
+            ExifField::ShutterSpeed => filter.shutter_speed,
+            ExifField::Iso => filter.iso,
+        }
+    }
+
+    pub fn range_mut(self, filter: &mut ExifFilter) -> &mut NumericRange {
+        match self {
+            ExifField::FocalLength => &mut filter.focal_length,
+            ExifField::Aperture => &mut filter.aperture,
+            ExifField::ShutterSpeed => &mut filter.shutter_speed,
+            ExifField::Iso => &mut filter.iso,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buckets_cover_all_values_without_gaps() {
+        for field in ExifField::ALL {
+            let buckets = field.buckets();
+            assert_eq!(buckets.first().unwrap().range.min, None, "{field:?}");
+            assert_eq!(buckets.last().unwrap().range.max, None, "{field:?}");
+            for pair in buckets.windows(2) {
+                let (lower, upper) = (pair[0].range, pair[1].range);
+                assert_eq!(lower.max, upper.min, "{field:?}");
+                assert!(lower.min.is_none_or(|min| min < lower.max.unwrap()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_range_mut_changes_only_its_field() {
+        let mut filter = ExifFilter::default();
+        for (index, field) in ExifField::ALL.into_iter().enumerate() {
+            *field.range_mut(&mut filter) = NumericRange::new(Some(index as f64), None);
+        }
+
+        for (index, field) in ExifField::ALL.into_iter().enumerate() {
+            assert_eq!(field.range(&filter).min, Some(index as f64));
+        }
+        assert!(NumericRange::default().is_unbounded());
+        assert!(!filter.iso.is_unbounded());
+    }
+}
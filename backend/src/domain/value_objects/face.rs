@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 사진 안의 얼굴 영역 (`photo_faces.face_rectangle`)
+///
+/// 방향 보정된 이미지 크기에 대한 0~1 비율이라 해상도가 달라도 그대로 쓸 수 있습니다.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FaceBox {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl FaceBox {
+    pub fn is_valid(&self) -> bool {
+        let inside = |start: f32, size: f32| start >= 0.0 && size > 0.0 && start + size <= 1.0;
+        inside(self.x, self.width) && inside(self.y, self.height)
+    }
+
+    /// 가로세로 `margin` 비율만큼 넓힌 픽셀 영역 `(x, y, width, height)` (이미지 밖은 잘라냄)
+    pub fn to_pixels(&self, width: u32, height: u32, margin: f32) -> (u32, u32, u32, u32) {
+        let expand = |start: f32, size: f32, total: u32| {
+            let total = total as f32;
+            let from = ((start - size * margin) * total).clamp(0.0, total - 1.0);
+            let to = ((start + size * (1.0 + margin)) * total).clamp(from + 1.0, total);
+            (from as u32, (to - from) as u32)
+        };
+        let (x, w) = expand(self.x, self.width, width);
+        let (y, h) = expand(self.y, self.height, height);
+        (x, y, w, h)
+    }
+}
+
+/// 얼굴 특징 벡터 (`people.face_encoding` 에 little-endian f32 배열로 저장)
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaceEmbedding(Vec<f32>);
+
+impl FaceEmbedding {
+    /// 길이 1로 정규화하여 생성
+    pub fn new(values: Vec<f32>) -> Self {
+        let norm = values.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm <= f32::EPSILON {
+            return Self(values);
+        }
+        Self(values.into_iter().map(|v| v / norm).collect())
+    }
+
+    /// 코사인 유사도 (-1~1, 차원이 다르면 비교 불가로 보고 -1)
+    pub fn similarity(&self, other: &FaceEmbedding) -> f32 {
+        if self.0.len() != other.0.len() {
+            return -1.0;
+        }
+        self.0.iter().zip(&other.0).map(|(a, b)| a * b).sum()
+    }
+
+    /// 여러 벡터의 평균 방향 (군집 중심)
+    pub fn centroid<'a>(embeddings: impl IntoIterator<Item = &'a FaceEmbedding>) -> Option<Self> {
+        let mut iter = embeddings.into_iter();
+        let mut sum = iter.next()?.0.clone();
+        let dimension = sum.len();
+        for embedding in iter.filter(|e| e.0.len() == dimension) {
+            sum.iter_mut().zip(&embedding.0).for_each(|(s, v)| *s += v);
+        }
+        Some(Self::new(sum))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.iter().flat_map(|v| v.to_le_bytes()).collect()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() || !bytes.len().is_multiple_of(4) {
+            return None;
+        }
+        let values = bytes
+            .chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
+        Some(Self(values))
+    }
+}
+
+/// 검출기가 찾은 얼굴 영역
+#[derive(Clone, Copy, Debug)]
+pub struct FaceCandidate {
+    pub bounds: FaceBox,
+    /// 0~1
+    pub confidence: f32,
+}
+
+/// 특징 벡터까지 추출한 얼굴
+#[derive(Clone, Debug)]
+pub struct DetectedFace {
+    pub bounds: FaceBox,
+    /// 0~1
+    pub confidence: f32,
+    pub embedding: FaceEmbedding,
+}
+
+/// 군집에 배정되어 저장할 얼굴
+#[derive(Clone, Debug)]
+pub struct NewFace {
+    pub person_id: Uuid,
+    pub face: DetectedFace,
+}
+
+/// 사용자 라이브러리의 인물 군집과 중심 벡터
+#[derive(Clone, Debug)]
+pub struct PersonCentroid {
+    pub id: Uuid,
+    pub centroid: FaceEmbedding,
+}
+
+/// People 화면의 인물 한 명
+#[derive(Clone, Debug)]
+pub struct Person {
+    pub id: Uuid,
+    pub name: Option<String>,
+    pub face_count: i64,
+    /// 대표 얼굴 (신뢰도가 가장 높은 얼굴)
+    pub cover_face_id: Option<Uuid>,
+}
+
+/// 인물에 속한 얼굴 하나 (분리할 얼굴 선택용)
+#[derive(Clone, Debug)]
+pub struct PersonFace {
+    pub id: Uuid,
+    pub photo_id: Uuid,
+    pub bounds: FaceBox,
+    pub confidence: f32,
+}
+
+/// 사진 미리보기에 겹쳐 그리는 얼굴 하나
+#[derive(Clone, Debug)]
+pub struct PhotoFace {
+    pub id: Uuid,
+    pub photo_id: Uuid,
+    pub person_id: Option<Uuid>,
+    pub person_name: Option<String>,
+    pub bounds: FaceBox,
+    pub confidence: f32,
+    /// 사용자가 직접 그리거나 인물을 바꾼 얼굴 (재검출 시에도 유지)
+    pub is_manual: bool,
+}
+
+/// 수동 태깅으로 얼굴을 배정할 대상
+#[derive(Clone, Debug, PartialEq)]
+pub enum FaceAssignment {
+    Person(Uuid),
+    /// 새 인물을 만들어 배정 (이름은 선택)
+    NewPerson(Option<String>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn face_box(x: f32, y: f32, width: f32, height: f32) -> FaceBox {
+        FaceBox {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_face_box_must_lie_inside_the_image() {
+        assert!(face_box(0.1, 0.2, 0.3, 0.4).is_valid());
+        assert!(face_box(0.0, 0.0, 1.0, 1.0).is_valid());
+        assert!(!face_box(0.8, 0.2, 0.3, 0.4).is_valid());
+        assert!(!face_box(-0.1, 0.2, 0.3, 0.4).is_valid());
+        assert!(!face_box(0.1, 0.2, 0.0, 0.4).is_valid());
+    }
+
+    #[test]
+    fn test_pixels_include_margin_and_stay_inside_the_image() {
+        let bounds = face_box(0.25, 0.5, 0.5, 0.25);
+
+        assert_eq!(bounds.to_pixels(200, 100, 0.0), (50, 50, 100, 25));
+        assert_eq!(bounds.to_pixels(200, 100, 0.5), (0, 37, 200, 50));
+    }
+
+    #[test]
+    fn test_embeddings_are_normalized_and_round_trip_as_bytes() {
+        let embedding = FaceEmbedding::new(vec![3.0, 4.0]);
+
+        assert!((embedding.similarity(&embedding) - 1.0).abs() < 1e-6);
+        assert_eq!(
+            FaceEmbedding::from_bytes(&embedding.to_bytes()),
+            Some(embedding)
+        );
+        assert_eq!(FaceEmbedding::from_bytes(&[1, 2, 3]), None);
+        assert_eq!(FaceEmbedding::from_bytes(&[]), None);
+    }
+
+    #[test]
+    fn test_centroid_is_the_mean_direction() {
+        let embeddings = [
+            FaceEmbedding::new(vec![1.0, 0.0]),
+            FaceEmbedding::new(vec![0.0, 1.0]),
+        ];
+
+        let centroid = FaceEmbedding::centroid(&embeddings).unwrap();
+
+        assert!((centroid.similarity(&FaceEmbedding::new(vec![1.0, 1.0])) - 1.0).abs() < 1e-6);
+        assert_eq!(FaceEmbedding::centroid(&[]), None);
+    }
+}
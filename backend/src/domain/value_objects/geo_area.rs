@@ -0,0 +1,156 @@
+use uuid::Uuid;
+
+/// WGS84 경위도 좌표
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeoPoint {
+    pub lat: f64,
+    pub lng: f64,
+}
+
+impl GeoPoint {
+    pub fn is_valid(&self) -> bool {
+        (-90.0..=90.0).contains(&self.lat) && (-180.0..=180.0).contains(&self.lng)
+    }
+}
+
+/// 경위도 사각 영역
+///
+/// `west > east` 이면 날짜 변경선(±180°)을 넘는 영역입니다.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+    pub west: f64,
+    pub south: f64,
+    pub east: f64,
+    pub north: f64,
+}
+
+impl BoundingBox {
+    pub fn is_valid(&self) -> bool {
+        let south_west = GeoPoint {
+            lat: self.south,
+            lng: self.west,
+        };
+        let north_east = GeoPoint {
+            lat: self.north,
+            lng: self.east,
+        };
+        south_west.is_valid() && north_east.is_valid() && self.south <= self.north
+    }
+
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    /// 날짜 변경선을 넘지 않는 영역들 (넘는 영역은 동서 두 개로 나눔)
+    pub fn split_at_antimeridian(&self) -> Vec<BoundingBox> {
+        if !self.crosses_antimeridian() {
+            return vec![*self];
+        }
+        vec![
+            BoundingBox {
+                east: 180.0,
+                ..*self
+            },
+            BoundingBox {
+                west: -180.0,
+                ..*self
+            },
+        ]
+    }
+}
+
+/// 지도 검색 영역
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GeoArea {
+    BoundingBox(BoundingBox),
+    /// 중심점에서 `meters` 이내
+    Radius {
+        center: GeoPoint,
+        meters: f64,
+    },
+}
+
+impl GeoArea {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            GeoArea::BoundingBox(bounds) => bounds.is_valid(),
+            GeoArea::Radius { center, meters } => center.is_valid() && *meters > 0.0,
+        }
+    }
+}
+
+/// 지도 한 지점에 모인 사진 묶음 (서버 측 격자 클러스터링 결과)
+#[derive(Clone, Debug)]
+pub struct GeoCluster {
+    /// 묶인 사진 위치의 중심
+    pub center: GeoPoint,
+    /// 묶인 사진 위치를 모두 포함하는 영역 (클러스터 클릭 시 검색 영역)
+    pub bounds: BoundingBox,
+    pub count: i64,
+    /// 가장 최근 사진 (마커 썸네일)
+    pub cover_photo_id: Uuid,
+    pub cover_path: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(west: f64, south: f64, east: f64, north: f64) -> BoundingBox {
+        BoundingBox {
+            west,
+            south,
+            east,
+            north,
+        }
+    }
+
+    #[test]
+    fn test_validates_bounds() {
+        assert!(bounds(126.0, 37.0, 127.5, 38.0).is_valid());
+        assert!(bounds(-180.0, -90.0, 180.0, 90.0).is_valid());
+        assert!(bounds(170.0, -20.0, -170.0, -10.0).is_valid());
+        assert!(!bounds(126.0, 38.0, 127.5, 37.0).is_valid());
+        assert!(!bounds(126.0, 37.0, 190.0, 38.0).is_valid());
+        assert!(!bounds(126.0, -91.0, 127.5, 38.0).is_valid());
+        assert!(!bounds(f64::NAN, 37.0, 127.5, 38.0).is_valid());
+    }
+
+    #[test]
+    fn test_splits_only_boxes_crossing_the_antimeridian() {
+        let plain = bounds(126.0, 37.0, 127.5, 38.0);
+        assert_eq!(plain.split_at_antimeridian(), [plain]);
+
+        let crossing = bounds(170.0, -20.0, -170.0, -10.0);
+        assert!(crossing.crosses_antimeridian());
+        assert_eq!(
+            crossing.split_at_antimeridian(),
+            [
+                bounds(170.0, -20.0, 180.0, -10.0),
+                bounds(-180.0, -20.0, -170.0, -10.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_radius_must_be_positive() {
+        let center = GeoPoint {
+            lat: 37.5,
+            lng: 127.0,
+        };
+        assert!(
+            GeoArea::Radius {
+                center,
+                meters: 500.0
+            }
+            .is_valid()
+        );
+        assert!(
+            !GeoArea::Radius {
+                center,
+                meters: 0.0
+            }
+            .is_valid()
+        );
+    }
+}
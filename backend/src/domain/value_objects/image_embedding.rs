@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::photo::Photo;
+
+/// 사진 전체의 특징 벡터 (`photos.image_embedding` 에 little-endian f32 배열로 저장)
+///
+/// 길이 1로 정규화되어 있어 내적이 곧 코사인 유사도입니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageEmbedding(Vec<f32>);
+
+impl ImageEmbedding {
+    pub fn new(values: Vec<f32>) -> Self {
+        let norm = values.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm <= f32::EPSILON {
+            return Self(values);
+        }
+        Self(values.into_iter().map(|v| v / norm).collect())
+    }
+
+    /// 코사인 유사도 (-1~1, 차원이 다르면 비교 불가로 보고 -1)
+    pub fn similarity(&self, other: &ImageEmbedding) -> f32 {
+        if self.0.len() != other.0.len() {
+            return -1.0;
+        }
+        self.0.iter().zip(&other.0).map(|(a, b)| a * b).sum()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.iter().flat_map(|v| v.to_le_bytes()).collect()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() || !bytes.len().is_multiple_of(4) {
+            return None;
+        }
+        let values = bytes
+            .chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
+        Some(Self(values))
+    }
+}
+
+/// 저장된 벡터 하나 (색인 동기화용)
+#[derive(Clone, Debug)]
+pub struct StoredEmbedding {
+    pub photo_id: Uuid,
+    pub embedded_at: DateTime<Utc>,
+    pub embedding: ImageEmbedding,
+}
+
+/// 비슷한 사진 검색 결과
+#[derive(Clone, Debug)]
+pub struct SimilarPhoto {
+    pub photo: Photo,
+    pub similarity: f32,
+}
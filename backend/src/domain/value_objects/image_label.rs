@@ -0,0 +1,20 @@
+/// 이미지 분류기가 붙인 태그 하나
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageLabel {
+    /// 태그 이름 (`tags.name`, 소문자)
+    pub tag: String,
+    /// 태그 분류 (`tags.category`, 예: "object", "scene", "color")
+    pub category: String,
+    /// 0~1 신뢰도
+    pub confidence: f32,
+}
+
+impl ImageLabel {
+    pub fn new(tag: &str, category: &str, confidence: f32) -> Self {
+        Self {
+            tag: tag.trim().to_lowercase(),
+            category: category.to_string(),
+            confidence: confidence.clamp(0.0, 1.0),
+        }
+    }
+}
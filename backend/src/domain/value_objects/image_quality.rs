@@ -0,0 +1,86 @@
+use crate::domain::value_objects::exif_filter::NumericRange;
+
+/// 화질 평가 결과 (모두 0~1)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ImageQuality {
+    /// 선명도 (라플라시안 분산 기반, 높을수록 선명)
+    pub sharpness: f32,
+    /// 노출 적정도 (밝기 히스토그램 기반, 높을수록 적정)
+    pub exposure: f32,
+    /// 평균 밝기 (노출 부족/과다 구분용)
+    pub brightness: f32,
+}
+
+impl ImageQuality {
+    /// 이보다 낮으면 흔들리거나 초점이 맞지 않은 사진으로 봄
+    pub const BLURRY_BELOW: f32 = 0.3;
+    /// 이보다 낮으면 노출이 크게 어긋난 사진으로 봄
+    pub const POOR_EXPOSURE_BELOW: f32 = 0.35;
+
+    pub fn issues(&self) -> Vec<QualityIssue> {
+        let mut issues = Vec::new();
+        if self.sharpness < Self::BLURRY_BELOW {
+            issues.push(QualityIssue::Blurry);
+        }
+        if self.exposure < Self::POOR_EXPOSURE_BELOW {
+            issues.push(if self.brightness < 0.5 {
+                QualityIssue::Underexposed
+            } else {
+                QualityIssue::Overexposed
+            });
+        }
+        issues
+    }
+}
+
+/// 정리 후보로 제안하는 이유
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QualityIssue {
+    Blurry,
+    Underexposed,
+    Overexposed,
+}
+
+impl QualityIssue {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            QualityIssue::Blurry => "blurry",
+            QualityIssue::Underexposed => "underexposed",
+            QualityIssue::Overexposed => "overexposed",
+        }
+    }
+}
+
+/// 화질 점수 범위 필터 (`[min, max)`, 평가 전인 사진은 범위가 있으면 제외)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct QualityFilter {
+    pub sharpness: NumericRange,
+    pub exposure: NumericRange,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quality(sharpness: f32, exposure: f32, brightness: f32) -> ImageQuality {
+        ImageQuality {
+            sharpness,
+            exposure,
+            brightness,
+        }
+    }
+
+    #[test]
+    fn test_reports_issues_below_thresholds() {
+        assert!(quality(0.8, 0.9, 0.5).issues().is_empty());
+        assert_eq!(quality(0.1, 0.9, 0.5).issues(), [QualityIssue::Blurry]);
+        assert_eq!(
+            quality(0.8, 0.1, 0.1).issues(),
+            [QualityIssue::Underexposed]
+        );
+        assert_eq!(
+            quality(0.1, 0.1, 0.9).issues(),
+            [QualityIssue::Blurry, QualityIssue::Overexposed]
+        );
+    }
+}
@@ -0,0 +1,16 @@
+pub mod album;
+pub mod color;
+pub mod duplicate;
+pub mod exif_filter;
+pub mod face;
+pub mod geo_area;
+pub mod image_embedding;
+pub mod image_label;
+pub mod image_quality;
+pub mod photo_cursor;
+pub mod photo_file;
+pub mod place;
+pub mod processing_task;
+pub mod search_facets;
+pub mod search_filter;
+pub mod timeline;
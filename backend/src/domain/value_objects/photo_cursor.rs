@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::photo::Photo;
+
+/// Keyset 페이지네이션 커서
+///
+/// 타임라인 정렬 순서 `(sort_at DESC, id DESC)` 에서 마지막으로 내려준 사진의 위치입니다.
+/// 외부에는 `"{unix_micros}:{uuid}"` 형태의 불투명 문자열로 노출합니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PhotoCursor {
+    pub sort_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl PhotoCursor {
+    pub fn after(photo: &Photo) -> Self {
+        Self {
+            sort_at: photo.sort_at(),
+            id: photo.id,
+        }
+    }
+
+    /// `at` 이전(미포함) 사진부터 시작하는 커서 (타임라인 날짜 이동)
+    pub fn before(at: DateTime<Utc>) -> Self {
+        Self {
+            sort_at: at,
+            id: Uuid::nil(),
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        format!("{}:{}", self.sort_at.timestamp_micros(), self.id)
+    }
+
+    pub fn decode(raw: &str) -> Option<Self> {
+        let (micros, id) = raw.split_once(':')?;
+        Some(Self {
+            sort_at: DateTime::from_timestamp_micros(micros.parse().ok()?)?,
+            id: id.parse().ok()?,
+        })
+    }
+}
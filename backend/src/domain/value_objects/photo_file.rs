@@ -0,0 +1,11 @@
+use uuid::Uuid;
+
+/// 내려받기에 쓰는 사진 원본 파일
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhotoFile {
+    pub photo_id: Uuid,
+    /// 업로드 시 파일 이름
+    pub filename: String,
+    /// `media_root` 기준 원본 경로
+    pub path: String,
+}
@@ -0,0 +1,40 @@
+/// 역지오코딩으로 얻은 행정 구역 (도시 / 지역 / 국가)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Place {
+    pub city: String,
+    pub region: String,
+    pub country: String,
+    /// ISO 3166-1 alpha-2
+    pub country_code: String,
+}
+
+impl Place {
+    /// `location_name` 에 저장하는 표시 이름 (예: "Jeju City, Jeju-do, South Korea")
+    ///
+    /// 지역명이 도시명과 같으면(특별시/광역시 등) 한 번만 씁니다.
+    pub fn display_name(&self) -> String {
+        let mut parts = vec![self.city.as_str()];
+        if !self.region.is_empty() && self.region != self.city {
+            parts.push(&self.region);
+        }
+        parts.push(&self.country);
+        parts.join(", ")
+    }
+}
+
+/// 도시별 사진 수와 대표 사진
+#[derive(Clone, Debug)]
+pub struct PlaceCount {
+    pub place: Place,
+    pub count: i64,
+    pub cover_path: String,
+}
+
+/// 국가별로 묶은 도시 목록 (Places 화면)
+#[derive(Clone, Debug)]
+pub struct CountryPlaces {
+    pub country: String,
+    pub country_code: String,
+    pub count: i64,
+    pub cities: Vec<PlaceCount>,
+}
@@ -0,0 +1,68 @@
+use uuid::Uuid;
+
+/// `processing_queue.task_type` 으로 저장되는 비동기 처리 작업 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TaskType {
+    /// GPS 좌표 → `location_name` (오프라인 지명 사전)
+    ReverseGeocode,
+    /// 얼굴 검출 → 인물 군집 배정 (`photo_faces`, `people`)
+    FaceDetection,
+    /// 대표 색 추출 (`color_palette`, `dominant_colors`)
+    ColorPalette,
+    /// 유사 중복 검출용 지각 해시 (`perceptual_hash`)
+    PerceptualHash,
+    /// 선명도/노출 평가 (`sharpness_score`, `exposure_score`)
+    QualityAssessment,
+    /// 이미지 분류 자동 태그 (`ai_tags`, `photo_tags.confidence`)
+    AiTags,
+    /// 비슷한 사진 검색용 특징 벡터 (`image_embedding`)
+    ImageEmbedding,
+}
+
+impl TaskType {
+    pub const ALL: [TaskType; 7] = [
+        TaskType::ReverseGeocode,
+        TaskType::FaceDetection,
+        TaskType::ColorPalette,
+        TaskType::PerceptualHash,
+        TaskType::QualityAssessment,
+        TaskType::AiTags,
+        TaskType::ImageEmbedding,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaskType::ReverseGeocode => "reverse_geocode",
+            TaskType::FaceDetection => "face_detection",
+            TaskType::ColorPalette => "color_palette",
+            TaskType::PerceptualHash => "perceptual_hash",
+            TaskType::QualityAssessment => "quality_assessment",
+            TaskType::AiTags => "ai_tags",
+            TaskType::ImageEmbedding => "image_embedding",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|task_type| task_type.as_str() == value)
+    }
+}
+
+/// 대기열에서 꺼낸 작업 하나
+#[derive(Clone, Debug)]
+pub struct ProcessingTask {
+    pub id: Uuid,
+    pub photo_id: Uuid,
+    pub task_type: TaskType,
+    pub retry_count: i32,
+}
+
+/// 이미지 분석 작업의 대상 파일
+#[derive(Clone, Debug)]
+pub struct ImageSource {
+    pub photo_id: Uuid,
+    pub owner_id: Uuid,
+    /// `media_root` 기준 상대 경로 (프리뷰가 있으면 프리뷰, 없으면 원본)
+    pub path: String,
+}
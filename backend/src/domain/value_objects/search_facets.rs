@@ -0,0 +1,29 @@
+use crate::domain::value_objects::exif_filter::NumericRange;
+
+/// 값별 사진 수 (예: 렌즈 모델)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueCount<T> {
+    pub value: T,
+    pub count: i64,
+}
+
+/// 구간별 사진 수
+#[derive(Clone, Debug, PartialEq)]
+pub struct RangeCount {
+    pub label: &'static str,
+    pub range: NumericRange,
+    pub count: i64,
+}
+
+/// 현재 검색 조건에서 선택 가능한 EXIF 값과 각 값의 사진 수
+///
+/// 각 패싯은 자기 자신의 조건을 제외한 나머지 조건으로 집계합니다.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchFacets {
+    pub lenses: Vec<ValueCount<String>>,
+    pub focal_length: Vec<RangeCount>,
+    pub aperture: Vec<RangeCount>,
+    pub shutter_speed: Vec<RangeCount>,
+    pub iso: Vec<RangeCount>,
+    pub flash: Vec<ValueCount<bool>>,
+}
@@ -0,0 +1,54 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::value_objects::color::ColorFilter;
+use crate::domain::value_objects::exif_filter::ExifFilter;
+use crate::domain::value_objects::geo_area::GeoArea;
+use crate::domain::value_objects::image_quality::QualityFilter;
+
+/// 통합 검색 조건
+///
+/// `text`는 제목/태그/앨범명에 대한 트라이그램 유사도 검색이며,
+/// 나머지 필드는 모두 AND 로 결합되는 구조화 필터입니다.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchFilter {
+    pub text: Option<String>,
+    pub date_from: Option<NaiveDate>,
+    pub date_to: Option<NaiveDate>,
+    pub camera: Option<String>,
+    pub lens: Option<String>,
+    /// 모두 포함해야 하는 태그 이름 (대소문자 무시)
+    pub tags: Vec<String>,
+    /// 역지오코딩된 위치명(`location_name`) 부분 일치
+    pub place: Option<String>,
+    pub is_liked: Option<bool>,
+    pub has_location: Option<bool>,
+    pub exif: ExifFilter,
+    /// 지도 영역 (`location` 이 없는 사진은 제외)
+    pub area: Option<GeoArea>,
+    /// 이 인물의 얼굴이 나온 사진만
+    pub person_id: Option<Uuid>,
+    /// 이 앨범에 담긴 사진만
+    pub album_id: Option<Uuid>,
+    /// 팔레트에 이 색과 비슷한 색이 있는 사진만
+    pub color: Option<ColorFilter>,
+    /// 선명도/노출 점수 범위
+    pub quality: QualityFilter,
+}
+
+impl SearchFilter {
+    pub fn has_valid_date_range(&self) -> bool {
+        match (self.date_from, self.date_to) {
+            (Some(from), Some(to)) => from <= to,
+            _ => true,
+        }
+    }
+
+    pub fn has_valid_area(&self) -> bool {
+        self.area.as_ref().is_none_or(GeoArea::is_valid)
+    }
+
+    pub fn has_valid_color(&self) -> bool {
+        self.color.as_ref().is_none_or(ColorFilter::is_valid)
+    }
+}
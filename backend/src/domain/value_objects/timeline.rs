@@ -0,0 +1,30 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::domain::value_objects::photo_cursor::PhotoCursor;
+
+/// 타임라인 스크러버의 한 달 구간 (정렬 시각의 UTC 기준 연/월)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimelineMonth {
+    pub year: i32,
+    /// 1~12
+    pub month: u32,
+    pub count: i64,
+}
+
+impl TimelineMonth {
+    /// 이 달의 가장 최근 사진부터 이어서 불러오는 커서
+    pub fn cursor(&self) -> PhotoCursor {
+        PhotoCursor::before(self.next_month_start())
+    }
+
+    fn next_month_start(&self) -> DateTime<Utc> {
+        let (year, month) = if self.month >= 12 {
+            (self.year + 1, 1)
+        } else {
+            (self.year, self.month + 1)
+        };
+        NaiveDate::from_ymd_opt(year, month, 1)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map_or(DateTime::<Utc>::MAX_UTC, |start| start.and_utc())
+    }
+}
@@ -0,0 +1,66 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AppError {
+    #[error("설정 오류: {0}")]
+    Config(String),
+
+    #[error("잘못된 요청: {0}")]
+    BadRequest(String),
+
+    #[error("리소스를 찾을 수 없음")]
+    NotFound,
+
+    #[error("데이터베이스 오류")]
+    Database(#[from] sqlx::Error),
+
+    #[error("이미지 처리 오류: {0}")]
+    Image(String),
+}
+
+pub type AppResult<T> = Result<T, AppError>;
+
+impl From<image::ImageError> for AppError {
+    fn from(err: image::ImageError) -> Self {
+        AppError::Image(err.to_string())
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        AppError::Image(err.to_string())
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::NotFound => StatusCode::NOT_FOUND,
+            AppError::Config(_) | AppError::Database(_) | AppError::Image(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        };
+
+        if let AppError::Database(err) = &self {
+            tracing::error!("database error: {err}");
+        }
+
+        (
+            status,
+            Json(ErrorBody {
+                error: self.to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
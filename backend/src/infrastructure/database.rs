@@ -0,0 +1,20 @@
+use sqlx::PgPool;
+use sqlx::postgres::PgPoolOptions;
+
+const MAX_CONNECTIONS: u32 = 10;
+
+pub async fn connect_pool(database_url: &str) -> Result<PgPool, sqlx::Error> {
+    PgPoolOptions::new()
+        .max_connections(MAX_CONNECTIONS)
+        .connect(database_url)
+        .await
+}
+
+/// `LIKE`/`ILIKE` 부분 일치 패턴 생성 (와일드카드 문자는 이스케이프)
+pub fn contains_pattern(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped}%")
+}
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::domain::services::reverse_geocoder::ReverseGeocoder;
+use crate::domain::value_objects::geo_area::GeoPoint;
+use crate::domain::value_objects::place::Place;
+use crate::error::{AppError, AppResult};
+
+/// 저장소에 포함된 기본 지명 사전 (`backend/data/gazetteer.tsv`)
+const BUNDLED_GAZETTEER: &str = include_str!("../../../data/gazetteer.tsv");
+
+/// 격자 한 칸의 크기 (위경도, 도 단위)
+const CELL_DEGREES: f64 = 1.0;
+/// 경도 방향 격자 칸 수 (±180° 에서 이어지도록 칸 번호를 이 수로 감쌈)
+const LNG_CELLS: i32 = (360.0 / CELL_DEGREES) as i32;
+/// 가장 가까운 도시가 이보다 멀면 위치명을 붙이지 않음
+const MAX_DISTANCE_KM: f64 = 150.0;
+/// `MAX_DISTANCE_KM` 을 덮는 주변 격자 칸 수
+const SEARCH_RING: i32 = 2;
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+struct GazetteerEntry {
+    place: Place,
+    point: GeoPoint,
+}
+
+/// 오프라인 지명 사전 기반 역지오코더
+///
+/// 탭 구분 파일(`city, region, country_code, country, latitude, longitude`)을 읽어
+/// 1도 격자로 색인하고, 주변 칸에서 가장 가까운 도시를 찾습니다.
+pub struct Gazetteer {
+    entries: Vec<GazetteerEntry>,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl Gazetteer {
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED_GAZETTEER)
+    }
+
+    pub fn from_file(path: &Path) -> AppResult<Self> {
+        let content = fs::read_to_string(path).map_err(|err| {
+            AppError::Config(format!("cannot read gazetteer {}: {err}", path.display()))
+        })?;
+        Ok(Self::parse(&content))
+    }
+
+    /// `#` 주석과 형식이 맞지 않는 줄(열 수, 숫자가 아니거나 범위를 벗어난 좌표)은 건너뜀
+    pub fn parse(content: &str) -> Self {
+        let entries: Vec<GazetteerEntry> = content
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .filter_map(parse_entry)
+            .collect();
+
+        let mut cells: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (index, entry) in entries.iter().enumerate() {
+            cells.entry(cell_of(entry.point)).or_default().push(index);
+        }
+        Self { entries, cells }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn candidates(&self, point: GeoPoint) -> impl Iterator<Item = &GazetteerEntry> {
+        let (lat_cell, lng_cell) = cell_of(point);
+        (-SEARCH_RING..=SEARCH_RING)
+            .flat_map(move |dy| {
+                (-SEARCH_RING..=SEARCH_RING)
+                    .map(move |dx| (lat_cell + dy, (lng_cell + dx).rem_euclid(LNG_CELLS)))
+            })
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .map(|&index| &self.entries[index])
+    }
+}
+
+impl ReverseGeocoder for Gazetteer {
+    fn reverse_geocode(&self, point: GeoPoint) -> Option<Place> {
+        self.candidates(point)
+            .map(|entry| (distance_km(point, entry.point), entry))
+            .filter(|(distance, _)| *distance <= MAX_DISTANCE_KM)
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, entry)| entry.place.clone())
+    }
+}
+
+fn parse_entry(line: &str) -> Option<GazetteerEntry> {
+    let fields: Vec<&str> = line.split('\t').map(str::trim).collect();
+    let [city, region, country_code, country, lat, lng] = fields.as_slice() else {
+        return None;
+    };
+    Some(GazetteerEntry {
+        place: Place {
+            city: city.to_string(),
+            region: region.to_string(),
+            country: country.to_string(),
+            country_code: country_code.to_string(),
+        },
+        point: GeoPoint {
+            lat: lat.parse().ok().filter(|lat: &f64| lat.abs() <= 90.0)?,
+            lng: lng.parse().ok().filter(|lng: &f64| lng.abs() <= 180.0)?,
+        },
+    })
+}
+
+fn cell_of(point: GeoPoint) -> (i32, i32) {
+    (
+        (point.lat / CELL_DEGREES).floor() as i32,
+        ((point.lng / CELL_DEGREES).floor() as i32).rem_euclid(LNG_CELLS),
+    )
+}
+
+/// 하버사인 대원 거리
+fn distance_km(a: GeoPoint, b: GeoPoint) -> f64 {
+    let (lat1, lat2) = (a.lat.to_radians(), b.lat.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lng = (b.lng - a.lng).to_radians();
+    let h = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lng / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * h.sqrt().asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+# city\tregion\tcountry_code\tcountry\tlatitude\tlongitude
+Seoul\tSeoul\tKR\tSouth Korea\t37.5665\t126.9780
+Incheon\tIncheon\tKR\tSouth Korea\t37.4563\t126.7052
+Busan\tBusan\tKR\tSouth Korea\t35.1796\t129.0756
+Labasa\tNorthern\tFJ\tFiji\t-16.4332\t179.3645
+";
+
+    fn point(lat: f64, lng: f64) -> GeoPoint {
+        GeoPoint { lat, lng }
+    }
+
+    fn city(gazetteer: &Gazetteer, lat: f64, lng: f64) -> Option<String> {
+        gazetteer
+            .reverse_geocode(point(lat, lng))
+            .map(|place| place.city)
+    }
+
+    #[test]
+    fn test_distance_between_known_cities() {
+        let seoul_busan = distance_km(point(37.5665, 126.9780), point(35.1796, 129.0756));
+        assert!((seoul_busan - 325.0).abs() < 5.0, "{seoul_busan}");
+
+        let london_paris = distance_km(point(51.5074, -0.1278), point(48.8566, 2.3522));
+        assert!((london_paris - 344.0).abs() < 5.0, "{london_paris}");
+    }
+
+    #[test]
+    fn test_picks_the_nearest_city() {
+        let gazetteer = Gazetteer::parse(SAMPLE);
+
+        assert_eq!(city(&gazetteer, 37.55, 126.95).as_deref(), Some("Seoul"));
+        assert_eq!(city(&gazetteer, 37.47, 126.65).as_deref(), Some("Incheon"));
+    }
+
+    #[test]
+    fn test_nothing_beyond_max_distance() {
+        let gazetteer = Gazetteer::parse(SAMPLE);
+
+        // 부산에서 동남쪽으로 약 140 km / 200 km
+        assert_eq!(city(&gazetteer, 34.3, 130.2).as_deref(), Some("Busan"));
+        assert_eq!(city(&gazetteer, 33.9, 130.6), None);
+    }
+
+    #[test]
+    fn test_finds_cities_across_the_antimeridian() {
+        let gazetteer = Gazetteer::parse(SAMPLE);
+
+        // 람바사에서 동쪽으로 약 80 km (경도 -179.9°)
+        assert_eq!(city(&gazetteer, -16.5, -179.9).as_deref(), Some("Labasa"));
+    }
+
+    #[test]
+    fn test_skips_malformed_rows() {
+        let gazetteer = Gazetteer::parse(
+            "Seoul\tSeoul\tKR\tSouth Korea\t37.5665\t126.9780\n\
+             \n\
+             Nowhere\tKR\tSouth Korea\t37.5\t127.0\n\
+             Nowhere\tNone\tKR\tSouth Korea\tnorth\t127.0\n\
+             Nowhere\tNone\tKR\tSouth Korea\t97.5\t127.0\n\
+             Nowhere\tNone\tKR\tSouth Korea\t37.5\t227.0\n",
+        );
+
+        assert_eq!(gazetteer.len(), 1);
+        assert_eq!(city(&gazetteer, 37.5, 127.0).as_deref(), Some("Seoul"));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod database;
+pub mod geocoding;
+pub mod processing;
+pub mod repositories;
+pub mod storage;
+pub mod vision;
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use crate::config::{AppConfig, ClassifierConfig};
+use crate::domain::services::face_analyzer::{FaceDetector, FaceEmbedder};
+use crate::domain::services::image_classifier::ImageClassifier;
+use crate::domain::services::image_embedder::ImageEmbedder;
+use crate::domain::services::image_hasher::ImageHasher;
+use crate::domain::services::palette_extractor::PaletteExtractor;
+use crate::domain::services::quality_analyzer::QualityAnalyzer;
+use crate::domain::value_objects::processing_task::TaskType;
+use crate::error::AppResult;
+use crate::infrastructure::geocoding::gazetteer::Gazetteer;
+use crate::infrastructure::vision::color_histogram_embedder::ColorHistogramEmbedder;
+use crate::infrastructure::vision::difference_hasher::DifferenceHasher;
+use crate::infrastructure::vision::kmeans_palette_extractor::KMeansPaletteExtractor;
+use crate::infrastructure::vision::laplacian_quality_analyzer::LaplacianQualityAnalyzer;
+use crate::infrastructure::vision::pixel_face_embedder::PixelFaceEmbedder;
+use crate::infrastructure::vision::skin_tone_face_detector::SkinToneFaceDetector;
+use crate::infrastructure::vision::stub_image_classifier::StubImageClassifier;
+
+/// 처리 작업에 사용하는 CPU 분석기 모음
+///
+/// 각 필드는 도메인 trait 객체라 더 정확한 구현으로 바꿔 끼울 수 있습니다.
+pub struct Analyzers {
+    pub gazetteer: Gazetteer,
+    pub face_detector: Arc<dyn FaceDetector>,
+    pub face_embedder: Arc<dyn FaceEmbedder>,
+    pub palette_extractor: Arc<dyn PaletteExtractor>,
+    pub image_hasher: Arc<dyn ImageHasher>,
+    pub quality_analyzer: Arc<dyn QualityAnalyzer>,
+    pub image_embedder: Arc<dyn ImageEmbedder>,
+    /// 설정하지 않으면 `ai_tags` 작업을 실행하지 않음
+    pub auto_tagger: Option<AutoTagger>,
+}
+
+/// 자동 태그 분류기와 신뢰도 기준
+pub struct AutoTagger {
+    pub classifier: Arc<dyn ImageClassifier>,
+    pub min_confidence: f32,
+}
+
+impl Analyzers {
+    /// 모델 파일 없이 동작하는 기본 구현
+    pub fn new(gazetteer: Gazetteer) -> Self {
+        Self {
+            gazetteer,
+            face_detector: Arc::new(SkinToneFaceDetector),
+            face_embedder: Arc::new(PixelFaceEmbedder),
+            palette_extractor: Arc::new(KMeansPaletteExtractor),
+            image_hasher: Arc::new(DifferenceHasher),
+            quality_analyzer: Arc::new(LaplacianQualityAnalyzer),
+            image_embedder: Arc::new(ColorHistogramEmbedder),
+            auto_tagger: None,
+        }
+    }
+
+    pub fn with_auto_tagger(self, auto_tagger: Option<AutoTagger>) -> Self {
+        Self {
+            auto_tagger,
+            ..self
+        }
+    }
+
+    /// 이 분석기 구성으로 처리할 수 있는 작업 종류
+    pub fn task_types(&self) -> Vec<TaskType> {
+        TaskType::ALL
+            .into_iter()
+            .filter(|task_type| *task_type != TaskType::AiTags || self.auto_tagger.is_some())
+            .collect()
+    }
+}
+
+impl AutoTagger {
+    /// `CLASSIFIER` 설정에 맞는 분류기 (설정이 없으면 `None`)
+    pub fn from_config(config: &AppConfig) -> AppResult<Option<Self>> {
+        let Some(classifier) = &config.classifier else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            classifier: load_classifier(classifier)?,
+            min_confidence: config.auto_tag_min_confidence,
+        }))
+    }
+}
+
+fn load_classifier(config: &ClassifierConfig) -> AppResult<Arc<dyn ImageClassifier>> {
+    match config {
+        ClassifierConfig::Stub => Ok(Arc::new(StubImageClassifier)),
+        #[cfg(feature = "onnx")]
+        ClassifierConfig::Onnx {
+            model_path,
+            labels_path,
+        } => Ok(Arc::new(
+            crate::infrastructure::vision::onnx_image_classifier::OnnxImageClassifier::load(
+                model_path,
+                labels_path,
+            )?,
+        )),
+        #[cfg(not(feature = "onnx"))]
+        ClassifierConfig::Onnx { .. } => Err(crate::error::AppError::Config(
+            "CLASSIFIER=onnx requires building the backend with --features onnx".to_string(),
+        )),
+    }
+}
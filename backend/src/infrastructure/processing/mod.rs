@@ -0,0 +1,2 @@
+pub mod analyzers;
+pub mod processing_worker;
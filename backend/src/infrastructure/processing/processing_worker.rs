@@ -0,0 +1,368 @@
+use sqlx::PgPool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use uuid::Uuid;
+
+use crate::application::use_cases::assess_quality::AssessQualityUseCase;
+use crate::application::use_cases::auto_tag_photo::AutoTagPhotoUseCase;
+use crate::application::use_cases::compute_embedding::ComputeEmbeddingUseCase;
+use crate::application::use_cases::compute_perceptual_hash::ComputePerceptualHashUseCase;
+use crate::application::use_cases::detect_faces::DetectFacesUseCase;
+use crate::application::use_cases::extract_palette::ExtractPaletteUseCase;
+use crate::application::use_cases::reverse_geocode_photo::ReverseGeocodePhotoUseCase;
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::repositories::processing_queue_repository::ProcessingQueueRepository;
+use crate::domain::value_objects::processing_task::{ProcessingTask, TaskType};
+use crate::error::AppResult;
+use crate::infrastructure::processing::analyzers::Analyzers;
+use crate::infrastructure::repositories::pg_duplicate_repository::PgDuplicateRepository;
+use crate::infrastructure::repositories::pg_embedding_repository::PgEmbeddingRepository;
+use crate::infrastructure::repositories::pg_person_repository::PgPersonRepository;
+use crate::infrastructure::repositories::pg_photo_repository::PgPhotoRepository;
+use crate::infrastructure::repositories::pg_place_repository::PgPlaceRepository;
+use crate::infrastructure::repositories::pg_processing_queue_repository::PgProcessingQueueRepository;
+use crate::infrastructure::repositories::pg_tag_repository::PgTagRepository;
+use crate::infrastructure::storage::fs_image_store::FsImageStore;
+
+/// 대기열이 비었을 때 다시 확인하기까지의 간격
+const IDLE_INTERVAL: Duration = Duration::from_secs(30);
+const MAX_RETRIES: i32 = 3;
+/// 한 번에 대기열에 추가하는 작업 수 (작업 종류별)
+const ENQUEUE_BATCH: usize = 500;
+/// 이 시간이 지나도 끝나지 않은 `processing` 작업은 작업자가 중단된 것으로 봄
+const STALE_TASK_AFTER: Duration = Duration::from_secs(15 * 60);
+
+/// `processing_queue` 를 순서대로 처리하는 백그라운드 작업자
+///
+/// 대기열이 비면 중단된 작업을 되살리고 처리가 필요한 사진을 찾아 작업을 채웁니다.
+#[derive(Clone)]
+pub struct ProcessingWorker {
+    pool: PgPool,
+    images: FsImageStore,
+    analyzers: Arc<Analyzers>,
+}
+
+impl ProcessingWorker {
+    pub fn new(pool: PgPool, images: FsImageStore, analyzers: Arc<Analyzers>) -> Self {
+        Self {
+            pool,
+            images,
+            analyzers,
+        }
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+
+    async fn run(self) {
+        loop {
+            match self.process_next().await {
+                Ok(true) => {}
+                Ok(false) => tokio::time::sleep(IDLE_INTERVAL).await,
+                Err(err) => {
+                    tracing::error!("processing worker error: {err}");
+                    tokio::time::sleep(IDLE_INTERVAL).await;
+                }
+            }
+        }
+    }
+
+    /// 작업 하나를 처리하고, 더 처리할 작업이 있을 수 있으면 `true`
+    async fn process_next(&self) -> AppResult<bool> {
+        let queue = PgProcessingQueueRepository::new(self.pool.clone());
+        let Some(task) = queue.claim_next(&self.analyzers.task_types()).await? else {
+            return Ok(refill(&queue, &self.analyzers.task_types()).await? > 0);
+        };
+
+        match self.execute(&task).await {
+            Ok(()) => queue.complete(task.id).await?,
+            Err(err) => {
+                tracing::warn!(
+                    "{} failed for photo {}: {err}",
+                    task.task_type.as_str(),
+                    task.photo_id
+                );
+                queue.fail(task.id, &err.to_string(), MAX_RETRIES).await?;
+            }
+        }
+        Ok(true)
+    }
+
+    async fn execute(&self, task: &ProcessingTask) -> AppResult<()> {
+        match task.task_type {
+            TaskType::ReverseGeocode => self.reverse_geocode(task.photo_id).await,
+            TaskType::FaceDetection => self.detect_faces(task.photo_id).await,
+            TaskType::ColorPalette => self.extract_palette(task.photo_id).await,
+            TaskType::PerceptualHash => self.compute_perceptual_hash(task.photo_id).await,
+            TaskType::QualityAssessment => self.assess_quality(task.photo_id).await,
+            TaskType::AiTags => self.auto_tag(task.photo_id).await,
+            TaskType::ImageEmbedding => self.compute_embedding(task.photo_id).await,
+        }
+    }
+
+    async fn reverse_geocode(&self, photo_id: Uuid) -> AppResult<()> {
+        let places = PgPlaceRepository::new(self.pool.clone());
+        ReverseGeocodePhotoUseCase::new(&places, &self.analyzers.gazetteer)
+            .execute(photo_id)
+            .await?;
+        Ok(())
+    }
+
+    async fn detect_faces(&self, photo_id: Uuid) -> AppResult<()> {
+        let photos = PgPhotoRepository::new(self.pool.clone());
+        let Some(source) = photos.image_source(photo_id).await? else {
+            return Ok(());
+        };
+
+        let people = PgPersonRepository::new(self.pool.clone());
+        let faces = DetectFacesUseCase::new(
+            &people,
+            &self.images,
+            self.analyzers.face_detector.clone(),
+            self.analyzers.face_embedder.clone(),
+        )
+        .execute(&source)
+        .await?;
+        tracing::debug!("detected {faces} faces in photo {photo_id}");
+        Ok(())
+    }
+
+    async fn extract_palette(&self, photo_id: Uuid) -> AppResult<()> {
+        let photos = PgPhotoRepository::new(self.pool.clone());
+        let Some(source) = photos.image_source(photo_id).await? else {
+            return Ok(());
+        };
+
+        let palette = ExtractPaletteUseCase::new(
+            &photos,
+            &self.images,
+            self.analyzers.palette_extractor.clone(),
+        )
+        .execute(&source)
+        .await?;
+        tracing::debug!("extracted {} colors from photo {photo_id}", palette.len());
+        Ok(())
+    }
+
+    async fn compute_perceptual_hash(&self, photo_id: Uuid) -> AppResult<()> {
+        let photos = PgPhotoRepository::new(self.pool.clone());
+        let Some(source) = photos.image_source(photo_id).await? else {
+            return Ok(());
+        };
+
+        let duplicates = PgDuplicateRepository::new(self.pool.clone());
+        ComputePerceptualHashUseCase::new(
+            &duplicates,
+            &self.images,
+            self.analyzers.image_hasher.clone(),
+        )
+        .execute(&source)
+        .await?;
+        Ok(())
+    }
+
+    async fn assess_quality(&self, photo_id: Uuid) -> AppResult<()> {
+        let photos = PgPhotoRepository::new(self.pool.clone());
+        let Some(source) = photos.image_source(photo_id).await? else {
+            return Ok(());
+        };
+
+        let quality = AssessQualityUseCase::new(
+            &photos,
+            &self.images,
+            self.analyzers.quality_analyzer.clone(),
+        )
+        .execute(&source)
+        .await?;
+        tracing::debug!(
+            "photo {photo_id} sharpness {:.2}, exposure {:.2}",
+            quality.sharpness,
+            quality.exposure
+        );
+        Ok(())
+    }
+
+    async fn auto_tag(&self, photo_id: Uuid) -> AppResult<()> {
+        let Some(auto_tagger) = &self.analyzers.auto_tagger else {
+            return Ok(());
+        };
+        let photos = PgPhotoRepository::new(self.pool.clone());
+        let Some(source) = photos.image_source(photo_id).await? else {
+            return Ok(());
+        };
+
+        let tags = PgTagRepository::new(self.pool.clone());
+        let labels = AutoTagPhotoUseCase::new(
+            &tags,
+            &self.images,
+            auto_tagger.classifier.clone(),
+            auto_tagger.min_confidence,
+        )
+        .execute(&source)
+        .await?;
+        tracing::debug!("tagged photo {photo_id} with {} labels", labels.len());
+        Ok(())
+    }
+
+    async fn compute_embedding(&self, photo_id: Uuid) -> AppResult<()> {
+        let photos = PgPhotoRepository::new(self.pool.clone());
+        let Some(source) = photos.image_source(photo_id).await? else {
+            return Ok(());
+        };
+
+        let embeddings = PgEmbeddingRepository::new(self.pool.clone());
+        ComputeEmbeddingUseCase::new(
+            &embeddings,
+            &self.images,
+            self.analyzers.image_embedder.clone(),
+        )
+        .execute(&source)
+        .await?;
+        Ok(())
+    }
+}
+
+/// 대기 작업이 없을 때 중단된 작업을 되살리고 빠진 작업을 채움 (새로 대기한 수)
+///
+/// 처리 중에 작업자가 멈춘 작업은 `processing` 으로 남아 `enqueue_missing` 도 건너뛰므로 여기서 되살립니다.
+async fn refill<Q: ProcessingQueueRepository>(
+    queue: &Q,
+    task_types: &[TaskType],
+) -> AppResult<u64> {
+    let mut queued = queue.reclaim_stale(STALE_TASK_AFTER, MAX_RETRIES).await?;
+    for task_type in task_types {
+        queued += queue.enqueue_missing(*task_type, ENQUEUE_BATCH).await?;
+    }
+    Ok(queued)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Status {
+        Pending,
+        /// 처리를 시작한 대기열 시각
+        Processing(Duration),
+        Failed,
+    }
+
+    /// 작업 상태만 흉내 내는 메모리 대기열 (처리가 필요한 사진은 없음, 시각은 직접 진행)
+    #[derive(Default)]
+    struct MemoryQueue {
+        rows: Mutex<Vec<(ProcessingTask, Status)>>,
+        now: Mutex<Duration>,
+    }
+
+    impl MemoryQueue {
+        fn with_task(task: ProcessingTask) -> Self {
+            Self {
+                rows: Mutex::new(vec![(task, Status::Pending)]),
+                ..Self::default()
+            }
+        }
+
+        fn advance(&self, elapsed: Duration) {
+            *self.now.lock().unwrap() += elapsed;
+        }
+
+        fn now(&self) -> Duration {
+            *self.now.lock().unwrap()
+        }
+
+        fn status(&self) -> Status {
+            self.rows.lock().unwrap()[0].1
+        }
+    }
+
+    impl ProcessingQueueRepository for MemoryQueue {
+        async fn enqueue_missing(&self, _task_type: TaskType, _limit: usize) -> AppResult<u64> {
+            Ok(0)
+        }
+
+        async fn claim_next(&self, _task_types: &[TaskType]) -> AppResult<Option<ProcessingTask>> {
+            let mut rows = self.rows.lock().unwrap();
+            let Some((task, status)) = rows.iter_mut().find(|(_, s)| *s == Status::Pending) else {
+                return Ok(None);
+            };
+            *status = Status::Processing(self.now());
+            Ok(Some(task.clone()))
+        }
+
+        async fn complete(&self, _task_id: Uuid) -> AppResult<()> {
+            Ok(())
+        }
+
+        async fn fail(&self, _task_id: Uuid, _message: &str, _max_retries: i32) -> AppResult<()> {
+            Ok(())
+        }
+
+        async fn reclaim_stale(&self, stale_after: Duration, max_retries: i32) -> AppResult<u64> {
+            let now = self.now();
+            let mut reclaimed = 0;
+            for (task, status) in self.rows.lock().unwrap().iter_mut() {
+                let Status::Processing(started_at) = *status else {
+                    continue;
+                };
+                if now - started_at < stale_after {
+                    continue;
+                }
+                task.retry_count += 1;
+                *status = if task.retry_count >= max_retries {
+                    Status::Failed
+                } else {
+                    reclaimed += 1;
+                    Status::Pending
+                };
+            }
+            Ok(reclaimed)
+        }
+    }
+
+    fn task() -> ProcessingTask {
+        ProcessingTask {
+            id: Uuid::new_v4(),
+            photo_id: Uuid::new_v4(),
+            task_type: TaskType::ColorPalette,
+            retry_count: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_task_left_processing_by_a_crash_is_claimed_again() {
+        let queue = MemoryQueue::with_task(task());
+        let types = [TaskType::ColorPalette];
+        let claimed = queue.claim_next(&types).await.unwrap().unwrap();
+
+        // 처리 중에 작업자가 멈춤: 아직 오래되지 않았으면 그대로 둠
+        assert_eq!(refill(&queue, &types).await.unwrap(), 0);
+        assert!(queue.claim_next(&types).await.unwrap().is_none());
+
+        queue.advance(STALE_TASK_AFTER);
+        assert_eq!(refill(&queue, &types).await.unwrap(), 1);
+        let reclaimed = queue.claim_next(&types).await.unwrap().unwrap();
+        assert_eq!(reclaimed.id, claimed.id);
+        assert_eq!(reclaimed.retry_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_task_that_keeps_crashing_the_worker_gives_up() {
+        let queue = MemoryQueue::with_task(task());
+        let types = [TaskType::ColorPalette];
+        for _ in 1..MAX_RETRIES {
+            queue.claim_next(&types).await.unwrap().unwrap();
+            queue.advance(STALE_TASK_AFTER);
+            assert_eq!(refill(&queue, &types).await.unwrap(), 1);
+        }
+
+        queue.claim_next(&types).await.unwrap().unwrap();
+        queue.advance(STALE_TASK_AFTER);
+        assert_eq!(refill(&queue, &types).await.unwrap(), 0);
+        assert_eq!(queue.status(), Status::Failed);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod pg_album_repository;
+pub mod pg_duplicate_repository;
+pub mod pg_embedding_repository;
+pub mod pg_person_repository;
+pub mod pg_photo_repository;
+pub mod pg_place_repository;
+pub mod pg_processing_queue_repository;
+pub mod pg_tag_repository;
@@ -0,0 +1,123 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::repositories::album_repository::AlbumRepository;
+use crate::domain::value_objects::album::Album;
+use crate::error::AppResult;
+
+#[derive(Clone)]
+pub struct PgAlbumRepository {
+    pool: PgPool,
+}
+
+impl PgAlbumRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl AlbumRepository for PgAlbumRepository {
+    async fn list(&self, owner_id: Uuid) -> AppResult<Vec<Album>> {
+        let rows: Vec<(Uuid, String, i64)> = sqlx::query_as(
+            "SELECT a.id, a.title, COUNT(p.id) FROM albums a \
+             LEFT JOIN album_photos ap ON ap.album_id = a.id \
+             LEFT JOIN photos p ON p.id = ap.photo_id AND p.deleted_at IS NULL \
+             WHERE a.user_id = $1 \
+             GROUP BY a.id ORDER BY a.updated_at DESC, a.id",
+        )
+        .bind(owner_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, title, photo_count)| Album {
+                id,
+                title,
+                photo_count,
+            })
+            .collect())
+    }
+
+    async fn find(&self, owner_id: Uuid, album_id: Uuid) -> AppResult<Option<Album>> {
+        let row: Option<(Uuid, String, i64)> = sqlx::query_as(
+            "SELECT a.id, a.title, COUNT(p.id) FROM albums a \
+             LEFT JOIN album_photos ap ON ap.album_id = a.id \
+             LEFT JOIN photos p ON p.id = ap.photo_id AND p.deleted_at IS NULL \
+             WHERE a.id = $1 AND a.user_id = $2 \
+             GROUP BY a.id",
+        )
+        .bind(album_id)
+        .bind(owner_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|(id, title, photo_count)| Album {
+            id,
+            title,
+            photo_count,
+        }))
+    }
+
+    async fn create(&self, owner_id: Uuid, title: &str) -> AppResult<Album> {
+        let (id, title): (Uuid, String) = sqlx::query_as(
+            "INSERT INTO albums (user_id, title) VALUES ($1, $2) RETURNING id, title",
+        )
+        .bind(owner_id)
+        .bind(title)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(Album {
+            id,
+            title,
+            photo_count: 0,
+        })
+    }
+
+    /// 앨범 행을 잠가 동시에 추가해도 순서(`position`)가 겹치지 않게 함
+    async fn add_photos(
+        &self,
+        owner_id: Uuid,
+        album_id: Uuid,
+        photo_ids: &[Uuid],
+    ) -> AppResult<Option<u64>> {
+        let mut tx = self.pool.begin().await?;
+        let album: Option<(Uuid,)> =
+            sqlx::query_as("SELECT id FROM albums WHERE id = $1 AND user_id = $2 FOR UPDATE")
+                .bind(album_id)
+                .bind(owner_id)
+                .fetch_optional(&mut *tx)
+                .await?;
+        if album.is_none() {
+            return Ok(None);
+        }
+
+        let result = sqlx::query(
+            "INSERT INTO album_photos (album_id, photo_id, position) \
+             SELECT $1, p.id, \
+                    (SELECT COALESCE(MAX(position), 0) FROM album_photos WHERE album_id = $1) \
+                    + ROW_NUMBER() OVER (ORDER BY COALESCE(p.taken_at, p.created_at), p.id) \
+             FROM photos p \
+             WHERE p.user_id = $2 AND p.id = ANY($3) AND p.deleted_at IS NULL \
+             ON CONFLICT (album_id, photo_id) DO NOTHING",
+        )
+        .bind(album_id)
+        .bind(owner_id)
+        .bind(photo_ids)
+        .execute(&mut *tx)
+        .await?;
+
+        // 표지가 없으면 추가한 사진 중 하나로 (updated_at 은 트리거가 갱신)
+        sqlx::query(
+            "UPDATE albums SET cover_photo_id = COALESCE(cover_photo_id, \
+             (SELECT photo_id FROM album_photos WHERE album_id = $1 ORDER BY position LIMIT 1)) \
+             WHERE id = $1",
+        )
+        .bind(album_id)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(Some(result.rows_affected()))
+    }
+}
@@ -0,0 +1,87 @@
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::photo::Photo;
+use crate::domain::repositories::duplicate_repository::DuplicateRepository;
+use crate::domain::value_objects::duplicate::{DuplicatePhoto, PerceptualHash};
+use crate::error::AppResult;
+use crate::infrastructure::repositories::pg_photo_repository::{PHOTO_COLUMNS_SQL, PhotoRow};
+
+#[derive(FromRow)]
+struct DuplicatePhotoRow {
+    #[sqlx(flatten)]
+    photo: PhotoRow,
+    file_size_bytes: i64,
+}
+
+impl From<DuplicatePhotoRow> for DuplicatePhoto {
+    fn from(row: DuplicatePhotoRow) -> Self {
+        Self {
+            photo: Photo::from(row.photo),
+            file_size_bytes: row.file_size_bytes,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PgDuplicateRepository {
+    pool: PgPool,
+}
+
+impl PgDuplicateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl DuplicateRepository for PgDuplicateRepository {
+    async fn save_perceptual_hash(&self, photo_id: Uuid, hash: PerceptualHash) -> AppResult<()> {
+        sqlx::query("UPDATE photos SET perceptual_hash = $2, updated_at = NOW() WHERE id = $1")
+            .bind(photo_id)
+            .bind(hash.to_i64())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// 구간 배열 겹침(`idx_photos_phash_bands`)으로 후보를 좁힌 뒤 정확한 거리로 거름
+    async fn similar_pairs(
+        &self,
+        owner_id: Uuid,
+        max_distance: u32,
+        limit: usize,
+    ) -> AppResult<Vec<(Uuid, Uuid)>> {
+        let rows: Vec<(Uuid, Uuid, i64)> = sqlx::query_as(
+            "SELECT a.id, b.id, bit_count((a.perceptual_hash # b.perceptual_hash)::bit(64)) AS distance \
+             FROM photos a \
+             JOIN photos b ON b.user_id = a.user_id AND b.id > a.id \
+             AND b.deleted_at IS NULL AND b.phash_bands && a.phash_bands \
+             WHERE a.user_id = $1 AND a.deleted_at IS NULL AND a.perceptual_hash IS NOT NULL \
+             AND bit_count((a.perceptual_hash # b.perceptual_hash)::bit(64)) <= $2 \
+             ORDER BY distance, a.id, b.id \
+             LIMIT $3",
+        )
+        .bind(owner_id)
+        .bind(max_distance as i64)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|(a, b, _)| (a, b)).collect())
+    }
+
+    async fn duplicate_photos(
+        &self,
+        owner_id: Uuid,
+        photo_ids: &[Uuid],
+    ) -> AppResult<Vec<DuplicatePhoto>> {
+        let rows: Vec<DuplicatePhotoRow> = sqlx::query_as(&format!(
+            "SELECT {PHOTO_COLUMNS_SQL}, p.file_size_bytes FROM photos p \
+             WHERE p.user_id = $1 AND p.id = ANY($2) AND p.deleted_at IS NULL"
+        ))
+        .bind(owner_id)
+        .bind(photo_ids)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(DuplicatePhoto::from).collect())
+    }
+}
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::photo::Photo;
+use crate::domain::repositories::embedding_repository::EmbeddingRepository;
+use crate::domain::value_objects::image_embedding::{ImageEmbedding, StoredEmbedding};
+use crate::error::AppResult;
+use crate::infrastructure::repositories::pg_photo_repository::{PHOTO_COLUMNS_SQL, PhotoRow};
+
+#[derive(Clone)]
+pub struct PgEmbeddingRepository {
+    pool: PgPool,
+}
+
+impl PgEmbeddingRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl EmbeddingRepository for PgEmbeddingRepository {
+    async fn save_embedding(&self, photo_id: Uuid, embedding: &ImageEmbedding) -> AppResult<()> {
+        sqlx::query(
+            "UPDATE photos SET image_embedding = $2, embedded_at = NOW(), updated_at = NOW() \
+             WHERE id = $1",
+        )
+        .bind(photo_id)
+        .bind(embedding.to_bytes())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn embeddings_since(
+        &self,
+        owner_id: Uuid,
+        since: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<StoredEmbedding>> {
+        let rows: Vec<(Uuid, DateTime<Utc>, Vec<u8>)> = sqlx::query_as(
+            "SELECT id, embedded_at, image_embedding FROM photos \
+             WHERE user_id = $1 AND deleted_at IS NULL AND image_embedding IS NOT NULL \
+             AND ($2::timestamptz IS NULL OR embedded_at >= $2) \
+             ORDER BY embedded_at",
+        )
+        .bind(owner_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(photo_id, embedded_at, bytes)| {
+                Some(StoredEmbedding {
+                    photo_id,
+                    embedded_at,
+                    embedding: ImageEmbedding::from_bytes(&bytes)?,
+                })
+            })
+            .collect())
+    }
+
+    async fn photos_by_ids(&self, owner_id: Uuid, photo_ids: &[Uuid]) -> AppResult<Vec<Photo>> {
+        let rows: Vec<PhotoRow> = sqlx::query_as(&format!(
+            "SELECT {PHOTO_COLUMNS_SQL} FROM photos p \
+             WHERE p.user_id = $1 AND p.id = ANY($2) AND p.deleted_at IS NULL"
+        ))
+        .bind(owner_id)
+        .bind(photo_ids)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(Photo::from).collect())
+    }
+}
@@ -0,0 +1,393 @@
+use sqlx::types::Json;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::repositories::person_repository::PersonRepository;
+use crate::domain::value_objects::face::{
+    FaceBox, FaceEmbedding, NewFace, Person, PersonCentroid, PersonFace, PhotoFace,
+};
+use crate::error::AppResult;
+
+#[derive(FromRow)]
+struct PersonRow {
+    id: Uuid,
+    name: Option<String>,
+    face_count: i64,
+    cover_face_id: Option<Uuid>,
+}
+
+impl From<PersonRow> for Person {
+    fn from(row: PersonRow) -> Self {
+        Self {
+            id: row.id,
+            name: row.name,
+            face_count: row.face_count,
+            cover_face_id: row.cover_face_id,
+        }
+    }
+}
+
+#[derive(FromRow)]
+struct PersonFaceRow {
+    id: Uuid,
+    photo_id: Uuid,
+    face_rectangle: Json<FaceBox>,
+    confidence: Option<f32>,
+}
+
+impl From<PersonFaceRow> for PersonFace {
+    fn from(row: PersonFaceRow) -> Self {
+        Self {
+            id: row.id,
+            photo_id: row.photo_id,
+            bounds: row.face_rectangle.0,
+            confidence: row.confidence.unwrap_or_default(),
+        }
+    }
+}
+
+/// `photo_faces` + 배정된 인물 이름
+const PHOTO_FACE_SQL: &str = "SELECT f.id, f.photo_id, f.person_id, pe.name AS person_name, \
+     f.face_rectangle, f.confidence::real AS confidence, f.is_manual \
+     FROM photo_faces f \
+     JOIN photos p ON p.id = f.photo_id AND p.deleted_at IS NULL \
+     LEFT JOIN people pe ON pe.id = f.person_id";
+
+#[derive(FromRow)]
+struct PhotoFaceRow {
+    id: Uuid,
+    photo_id: Uuid,
+    person_id: Option<Uuid>,
+    person_name: Option<String>,
+    face_rectangle: Json<FaceBox>,
+    confidence: Option<f32>,
+    is_manual: bool,
+}
+
+impl From<PhotoFaceRow> for PhotoFace {
+    fn from(row: PhotoFaceRow) -> Self {
+        Self {
+            id: row.id,
+            photo_id: row.photo_id,
+            person_id: row.person_id,
+            person_name: row.person_name,
+            bounds: row.face_rectangle.0,
+            confidence: row.confidence.unwrap_or_default(),
+            is_manual: row.is_manual,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PgPersonRepository {
+    pool: PgPool,
+}
+
+impl PgPersonRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl PersonRepository for PgPersonRepository {
+    async fn person_centroids(&self, owner_id: Uuid) -> AppResult<Vec<PersonCentroid>> {
+        let rows: Vec<(Uuid, Vec<u8>)> = sqlx::query_as(
+            "SELECT pe.id, pe.face_encoding FROM people pe \
+             WHERE pe.user_id = $1 AND pe.face_encoding IS NOT NULL \
+             AND EXISTS (SELECT 1 FROM photo_faces f WHERE f.person_id = pe.id)",
+        )
+        .bind(owner_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(id, encoding)| {
+                Some(PersonCentroid {
+                    id,
+                    centroid: FaceEmbedding::from_bytes(&encoding)?,
+                })
+            })
+            .collect())
+    }
+
+    async fn create_person(
+        &self,
+        owner_id: Uuid,
+        name: Option<&str>,
+        centroid: Option<&FaceEmbedding>,
+    ) -> AppResult<Uuid> {
+        let (id,): (Uuid,) = sqlx::query_as(
+            "INSERT INTO people (user_id, name, face_encoding) VALUES ($1, $2, $3) RETURNING id",
+        )
+        .bind(owner_id)
+        .bind(name)
+        .bind(centroid.map(FaceEmbedding::to_bytes))
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(id)
+    }
+
+    async fn person_exists(&self, owner_id: Uuid, person_id: Uuid) -> AppResult<bool> {
+        let found: Option<(Uuid,)> =
+            sqlx::query_as("SELECT id FROM people WHERE id = $1 AND user_id = $2")
+                .bind(person_id)
+                .bind(owner_id)
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(found.is_some())
+    }
+
+    async fn update_centroid(
+        &self,
+        person_id: Uuid,
+        centroid: Option<&FaceEmbedding>,
+    ) -> AppResult<()> {
+        sqlx::query("UPDATE people SET face_encoding = $2, updated_at = NOW() WHERE id = $1")
+            .bind(person_id)
+            .bind(centroid.map(FaceEmbedding::to_bytes))
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn replace_faces(&self, photo_id: Uuid, faces: &[NewFace]) -> AppResult<Vec<Uuid>> {
+        let mut tx = self.pool.begin().await?;
+        let removed: Vec<(Option<Uuid>,)> = sqlx::query_as(
+            "DELETE FROM photo_faces WHERE photo_id = $1 AND NOT is_manual RETURNING person_id",
+        )
+        .bind(photo_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for new_face in faces {
+            sqlx::query(
+                "INSERT INTO photo_faces (photo_id, person_id, face_rectangle, confidence, embedding) \
+                 VALUES ($1, $2, $3, ROUND($4::real::numeric, 2), $5)",
+            )
+            .bind(photo_id)
+            .bind(new_face.person_id)
+            .bind(Json(new_face.face.bounds))
+            .bind(new_face.face.confidence)
+            .bind(new_face.face.embedding.to_bytes())
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query("UPDATE photos SET faces_detected_at = NOW() WHERE id = $1")
+            .bind(photo_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        let mut person_ids: Vec<Uuid> = removed.into_iter().filter_map(|(id,)| id).collect();
+        person_ids.sort_unstable();
+        person_ids.dedup();
+        Ok(person_ids)
+    }
+
+    async fn delete_empty_people(&self, owner_id: Uuid, person_ids: &[Uuid]) -> AppResult<u64> {
+        let result = sqlx::query(
+            "DELETE FROM people pe WHERE pe.id = ANY($1) AND pe.user_id = $2 \
+             AND NOT EXISTS (SELECT 1 FROM photo_faces f WHERE f.person_id = pe.id)",
+        )
+        .bind(person_ids)
+        .bind(owner_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn list_people(&self, owner_id: Uuid) -> AppResult<Vec<Person>> {
+        let rows: Vec<PersonRow> = sqlx::query_as(
+            "SELECT pe.id, pe.name, COUNT(f.id) AS face_count, \
+             (ARRAY_AGG(f.id ORDER BY f.confidence DESC NULLS LAST, f.created_at))[1] AS cover_face_id \
+             FROM people pe \
+             JOIN photo_faces f ON f.person_id = pe.id \
+             JOIN photos p ON p.id = f.photo_id AND p.deleted_at IS NULL \
+             WHERE pe.user_id = $1 GROUP BY pe.id ORDER BY face_count DESC",
+        )
+        .bind(owner_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(Person::from).collect())
+    }
+
+    async fn person_faces(&self, owner_id: Uuid, person_id: Uuid) -> AppResult<Vec<PersonFace>> {
+        let rows: Vec<PersonFaceRow> = sqlx::query_as(
+            "SELECT f.id, f.photo_id, f.face_rectangle, f.confidence::real AS confidence \
+             FROM photo_faces f \
+             JOIN photos p ON p.id = f.photo_id AND p.deleted_at IS NULL \
+             WHERE p.user_id = $1 AND f.person_id = $2 \
+             ORDER BY f.confidence DESC NULLS LAST, f.created_at",
+        )
+        .bind(owner_id)
+        .bind(person_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(PersonFace::from).collect())
+    }
+
+    async fn face_embeddings(&self, person_id: Uuid) -> AppResult<Vec<FaceEmbedding>> {
+        let rows: Vec<(Vec<u8>,)> = sqlx::query_as(
+            "SELECT embedding FROM photo_faces WHERE person_id = $1 AND embedding IS NOT NULL",
+        )
+        .bind(person_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .iter()
+            .filter_map(|(bytes,)| FaceEmbedding::from_bytes(bytes))
+            .collect())
+    }
+
+    async fn rename(&self, owner_id: Uuid, person_id: Uuid, name: Option<&str>) -> AppResult<bool> {
+        let result = sqlx::query(
+            "UPDATE people SET name = $3, updated_at = NOW() WHERE id = $2 AND user_id = $1",
+        )
+        .bind(owner_id)
+        .bind(person_id)
+        .bind(name)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn merge(
+        &self,
+        owner_id: Uuid,
+        target_id: Uuid,
+        source_ids: &[Uuid],
+    ) -> AppResult<Option<u64>> {
+        if !self.person_exists(owner_id, target_id).await? {
+            return Ok(None);
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let moved = sqlx::query(
+            "UPDATE photo_faces SET person_id = $1 WHERE person_id IN \
+             (SELECT id FROM people WHERE id = ANY($2) AND user_id = $3)",
+        )
+        .bind(target_id)
+        .bind(source_ids)
+        .bind(owner_id)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM people WHERE id = ANY($1) AND user_id = $2")
+            .bind(source_ids)
+            .bind(owner_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(Some(moved.rows_affected()))
+    }
+
+    async fn split(
+        &self,
+        owner_id: Uuid,
+        person_id: Uuid,
+        face_ids: &[Uuid],
+    ) -> AppResult<Option<Uuid>> {
+        if !self.person_exists(owner_id, person_id).await? {
+            return Ok(None);
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let (new_person_id,): (Uuid,) =
+            sqlx::query_as("INSERT INTO people (user_id) VALUES ($1) RETURNING id")
+                .bind(owner_id)
+                .fetch_one(&mut *tx)
+                .await?;
+        let moved = sqlx::query(
+            "UPDATE photo_faces SET person_id = $1 WHERE person_id = $2 AND id = ANY($3)",
+        )
+        .bind(new_person_id)
+        .bind(person_id)
+        .bind(face_ids)
+        .execute(&mut *tx)
+        .await?;
+
+        if moved.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+        tx.commit().await?;
+        Ok(Some(new_person_id))
+    }
+
+    async fn face_source(
+        &self,
+        owner_id: Uuid,
+        face_id: Uuid,
+    ) -> AppResult<Option<(String, FaceBox)>> {
+        let row: Option<(String, Json<FaceBox>)> = sqlx::query_as(
+            "SELECT COALESCE(p.preview_path, p.storage_path), f.face_rectangle FROM photo_faces f \
+             JOIN photos p ON p.id = f.photo_id \
+             WHERE f.id = $1 AND p.user_id = $2 AND p.deleted_at IS NULL",
+        )
+        .bind(face_id)
+        .bind(owner_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(|(path, bounds)| (path, bounds.0)))
+    }
+
+    async fn photo_faces(&self, owner_id: Uuid, photo_id: Uuid) -> AppResult<Vec<PhotoFace>> {
+        let rows: Vec<PhotoFaceRow> = sqlx::query_as(&format!(
+            "{PHOTO_FACE_SQL} WHERE p.user_id = $1 AND f.photo_id = $2 ORDER BY f.created_at"
+        ))
+        .bind(owner_id)
+        .bind(photo_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(PhotoFace::from).collect())
+    }
+
+    async fn find_face(&self, owner_id: Uuid, face_id: Uuid) -> AppResult<Option<PhotoFace>> {
+        let row: Option<PhotoFaceRow> = sqlx::query_as(&format!(
+            "{PHOTO_FACE_SQL} WHERE p.user_id = $1 AND f.id = $2"
+        ))
+        .bind(owner_id)
+        .bind(face_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(PhotoFace::from))
+    }
+
+    async fn assign_face(&self, face_id: Uuid, person_id: Uuid) -> AppResult<()> {
+        sqlx::query("UPDATE photo_faces SET person_id = $2, is_manual = true WHERE id = $1")
+            .bind(face_id)
+            .bind(person_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn add_manual_face(
+        &self,
+        photo_id: Uuid,
+        person_id: Uuid,
+        bounds: FaceBox,
+        embedding: &FaceEmbedding,
+    ) -> AppResult<Uuid> {
+        let (id,): (Uuid,) = sqlx::query_as(
+            "INSERT INTO photo_faces (photo_id, person_id, face_rectangle, confidence, embedding, is_manual) \
+             VALUES ($1, $2, $3, 1.0, $4, true) RETURNING id",
+        )
+        .bind(photo_id)
+        .bind(person_id)
+        .bind(Json(bounds))
+        .bind(embedding.to_bytes())
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(id)
+    }
+
+    async fn delete_face(&self, face_id: Uuid) -> AppResult<()> {
+        sqlx::query("DELETE FROM photo_faces WHERE id = $1")
+            .bind(face_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,697 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use sqlx::types::Json;
+use sqlx::{FromRow, PgPool, Postgres, QueryBuilder, Row};
+use uuid::Uuid;
+
+use crate::domain::entities::photo::Photo;
+use crate::domain::repositories::photo_repository::PhotoRepository;
+use crate::domain::value_objects::color::{ColorFilter, PaletteColor, Rgb};
+use crate::domain::value_objects::exif_filter::{ExifField, ExifFilter, NumericRange};
+use crate::domain::value_objects::geo_area::{BoundingBox, GeoArea, GeoCluster, GeoPoint};
+use crate::domain::value_objects::image_quality::{ImageQuality, QualityFilter};
+use crate::domain::value_objects::photo_cursor::PhotoCursor;
+use crate::domain::value_objects::photo_file::PhotoFile;
+use crate::domain::value_objects::processing_task::ImageSource;
+use crate::domain::value_objects::search_facets::{RangeCount, ValueCount};
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::domain::value_objects::timeline::TimelineMonth;
+use crate::error::AppResult;
+use crate::infrastructure::database::contains_pattern;
+
+/// 타임라인 정렬 기준 (`Photo::sort_at` 과 동일한 규칙)
+pub const SORT_AT_SQL: &str = "COALESCE(p.taken_at, p.created_at, 'epoch'::timestamptz)";
+
+/// 카메라 제조사 + 모델명 (`idx_photos_camera_trgm` 과 동일한 식)
+pub const CAMERA_SQL: &str = "(COALESCE(p.camera_make, '') || ' ' || COALESCE(p.camera_model, ''))";
+
+/// 렌즈 모델 (컬럼이 비어 있으면 EXIF 원본의 `LensModel`, `idx_photos_exif_lens` 사용)
+pub const LENS_SQL: &str = "COALESCE(p.lens_model, p.exif_data->>'LensModel')";
+
+/// `PhotoRow` 로 읽는 컬럼 (`p` = `photos`)
+pub const PHOTO_COLUMNS_SQL: &str = "p.id, p.user_id, p.original_filename, p.storage_path, \
+     p.thumbnail_path, p.preview_path, p.taken_at, p.camera_make, p.camera_model, p.lens_model, \
+     p.width_pixels, p.height_pixels, p.dominant_colors, p.sharpness_score, p.exposure_score, \
+     p.brightness, p.created_at, \
+     EXISTS (SELECT 1 FROM favorites f WHERE f.photo_id = p.id AND f.user_id = p.user_id) AS is_liked";
+
+#[derive(FromRow)]
+pub struct PhotoRow {
+    id: Uuid,
+    user_id: Uuid,
+    original_filename: String,
+    storage_path: String,
+    thumbnail_path: Option<String>,
+    preview_path: Option<String>,
+    taken_at: Option<DateTime<Utc>>,
+    camera_make: Option<String>,
+    camera_model: Option<String>,
+    lens_model: Option<String>,
+    width_pixels: i32,
+    height_pixels: i32,
+    dominant_colors: Option<Vec<String>>,
+    sharpness_score: Option<f32>,
+    exposure_score: Option<f32>,
+    brightness: Option<f32>,
+    created_at: Option<DateTime<Utc>>,
+    is_liked: bool,
+}
+
+impl From<PhotoRow> for Photo {
+    fn from(row: PhotoRow) -> Self {
+        let quality = row.quality();
+        Self {
+            id: row.id,
+            user_id: row.user_id,
+            original_filename: row.original_filename,
+            storage_path: row.storage_path,
+            thumbnail_path: row.thumbnail_path,
+            preview_path: row.preview_path,
+            taken_at: row.taken_at,
+            camera_make: row.camera_make,
+            camera_model: row.camera_model,
+            lens_model: row.lens_model,
+            width: row.width_pixels.max(0) as u32,
+            height: row.height_pixels.max(0) as u32,
+            dominant_colors: row
+                .dominant_colors
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|hex| Rgb::parse_hex(hex))
+                .collect(),
+            quality,
+            is_liked: row.is_liked,
+            created_at: row.created_at.unwrap_or_default(),
+        }
+    }
+}
+
+impl PhotoRow {
+    fn quality(&self) -> Option<ImageQuality> {
+        Some(ImageQuality {
+            sharpness: self.sharpness_score?,
+            exposure: self.exposure_score?,
+            brightness: self.brightness.unwrap_or(0.5),
+        })
+    }
+}
+
+/// `color_palette` JSONB 원소 (`l`, `a`, `b` 는 색상 검색에서 직접 비교)
+#[derive(Serialize)]
+struct PaletteEntry {
+    hex: String,
+    ratio: f32,
+    l: f32,
+    a: f32,
+    b: f32,
+}
+
+impl From<&PaletteColor> for PaletteEntry {
+    fn from(color: &PaletteColor) -> Self {
+        Self {
+            hex: color.color.to_hex(),
+            ratio: color.ratio,
+            l: color.lab.l,
+            a: color.lab.a,
+            b: color.lab.b,
+        }
+    }
+}
+
+#[derive(FromRow)]
+struct GeoClusterRow {
+    count: i64,
+    lat: f64,
+    lng: f64,
+    west: f64,
+    south: f64,
+    east: f64,
+    north: f64,
+    cover_photo_id: Uuid,
+    cover_path: String,
+}
+
+impl From<GeoClusterRow> for GeoCluster {
+    fn from(row: GeoClusterRow) -> Self {
+        Self {
+            center: GeoPoint {
+                lat: row.lat,
+                lng: row.lng,
+            },
+            bounds: BoundingBox {
+                west: row.west,
+                south: row.south,
+                east: row.east,
+                north: row.north,
+            },
+            count: row.count,
+            cover_photo_id: row.cover_photo_id,
+            cover_path: row.cover_path,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PgPhotoRepository {
+    pool: PgPool,
+}
+
+impl PgPhotoRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl PhotoRepository for PgPhotoRepository {
+    async fn search(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+        cursor: Option<PhotoCursor>,
+        limit: usize,
+    ) -> AppResult<Vec<Photo>> {
+        let mut query =
+            QueryBuilder::<Postgres>::new(format!("SELECT {PHOTO_COLUMNS_SQL} FROM photos p"));
+        push_owner_scope(&mut query, owner_id);
+        push_search_filter(&mut query, filter);
+        push_cursor(&mut query, cursor);
+        push_timeline_order(&mut query, limit);
+
+        let rows = query
+            .build_query_as::<PhotoRow>()
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(Photo::from).collect())
+    }
+
+    async fn find(&self, owner_id: Uuid, photo_id: Uuid) -> AppResult<Option<Photo>> {
+        let mut query =
+            QueryBuilder::<Postgres>::new(format!("SELECT {PHOTO_COLUMNS_SQL} FROM photos p"));
+        push_owner_scope(&mut query, owner_id);
+        query.push(" AND p.id = ");
+        query.push_bind(photo_id);
+
+        let row = query
+            .build_query_as::<PhotoRow>()
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.map(Photo::from))
+    }
+
+    async fn search_newer(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+        cursor: PhotoCursor,
+        limit: usize,
+    ) -> AppResult<Vec<Photo>> {
+        let mut query =
+            QueryBuilder::<Postgres>::new(format!("SELECT {PHOTO_COLUMNS_SQL} FROM photos p"));
+        push_owner_scope(&mut query, owner_id);
+        push_search_filter(&mut query, filter);
+        // 타임라인 역방향: 커서 바로 앞(더 최근) 사진부터
+        query.push(format!(" AND ({SORT_AT_SQL}, p.id) > ("));
+        query.push_bind(cursor.sort_at);
+        query.push(", ");
+        query.push_bind(cursor.id);
+        query.push(format!(") ORDER BY {SORT_AT_SQL} ASC, p.id ASC LIMIT "));
+        query.push_bind(limit as i64);
+
+        let rows = query
+            .build_query_as::<PhotoRow>()
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(Photo::from).collect())
+    }
+
+    async fn lens_facets(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+        limit: usize,
+    ) -> AppResult<Vec<ValueCount<String>>> {
+        let mut query = QueryBuilder::<Postgres>::new(format!(
+            "SELECT {LENS_SQL} AS value, COUNT(*) AS count FROM photos p"
+        ));
+        push_owner_scope(&mut query, owner_id);
+        push_search_filter(&mut query, filter);
+        query.push(format!(
+            " AND {LENS_SQL} IS NOT NULL GROUP BY 1 ORDER BY count DESC, value LIMIT "
+        ));
+        query.push_bind(limit as i64);
+
+        let rows: Vec<(String, i64)> = query.build_query_as().fetch_all(&self.pool).await?;
+        Ok(rows
+            .into_iter()
+            .map(|(value, count)| ValueCount { value, count })
+            .collect())
+    }
+
+    async fn range_facets(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+        field: ExifField,
+    ) -> AppResult<Vec<RangeCount>> {
+        let column = exif_column(field);
+        let mut query = QueryBuilder::<Postgres>::new("SELECT ");
+        for (index, bucket) in field.buckets().iter().enumerate() {
+            if index > 0 {
+                query.push(", ");
+            }
+            query.push(format!("COUNT(*) FILTER (WHERE {column} IS NOT NULL"));
+            push_range(&mut query, column, bucket.range);
+            query.push(")");
+        }
+        query.push(" FROM photos p");
+        push_owner_scope(&mut query, owner_id);
+        push_search_filter(&mut query, filter);
+
+        let row = query.build().fetch_one(&self.pool).await?;
+        field
+            .buckets()
+            .iter()
+            .enumerate()
+            .map(|(index, bucket)| {
+                Ok(RangeCount {
+                    label: bucket.label,
+                    range: bucket.range,
+                    count: row.try_get(index)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn flash_facets(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+    ) -> AppResult<Vec<ValueCount<bool>>> {
+        let mut query = QueryBuilder::<Postgres>::new(
+            "SELECT p.flash_used AS value, COUNT(*) AS count FROM photos p",
+        );
+        push_owner_scope(&mut query, owner_id);
+        push_search_filter(&mut query, filter);
+        query.push(" AND p.flash_used IS NOT NULL GROUP BY 1 ORDER BY 1 DESC");
+
+        let rows: Vec<(bool, i64)> = query.build_query_as().fetch_all(&self.pool).await?;
+        Ok(rows
+            .into_iter()
+            .map(|(value, count)| ValueCount { value, count })
+            .collect())
+    }
+
+    async fn geo_clusters(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+        cell_degrees: f64,
+        limit: usize,
+    ) -> AppResult<Vec<GeoCluster>> {
+        let mut query = QueryBuilder::<Postgres>::new(format!(
+            "WITH located AS (SELECT p.id, p.location::geometry AS geom, {SORT_AT_SQL} AS sort_at, \
+             COALESCE(p.thumbnail_path, p.preview_path, p.storage_path) AS cover_path FROM photos p"
+        ));
+        push_owner_scope(&mut query, owner_id);
+        push_search_filter(&mut query, filter);
+        query.push(
+            " AND p.location IS NOT NULL) \
+             SELECT COUNT(*) AS count, \
+             ST_Y(ST_Centroid(ST_Collect(geom))) AS lat, ST_X(ST_Centroid(ST_Collect(geom))) AS lng, \
+             ST_XMin(ST_Extent(geom)) AS west, ST_YMin(ST_Extent(geom)) AS south, \
+             ST_XMax(ST_Extent(geom)) AS east, ST_YMax(ST_Extent(geom)) AS north, \
+             (ARRAY_AGG(id ORDER BY sort_at DESC))[1] AS cover_photo_id, \
+             (ARRAY_AGG(cover_path ORDER BY sort_at DESC))[1] AS cover_path \
+             FROM located GROUP BY ST_SnapToGrid(geom, ",
+        );
+        query.push_bind(cell_degrees);
+        query.push(") ORDER BY count DESC LIMIT ");
+        query.push_bind(limit as i64);
+
+        let rows = query
+            .build_query_as::<GeoClusterRow>()
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(GeoCluster::from).collect())
+    }
+
+    async fn month_counts(
+        &self,
+        owner_id: Uuid,
+        filter: &SearchFilter,
+    ) -> AppResult<Vec<TimelineMonth>> {
+        let mut query = QueryBuilder::<Postgres>::new(format!(
+            "SELECT EXTRACT(YEAR FROM {SORT_AT_SQL} AT TIME ZONE 'UTC')::INT AS year, \
+             EXTRACT(MONTH FROM {SORT_AT_SQL} AT TIME ZONE 'UTC')::INT AS month, \
+             COUNT(*) AS count FROM photos p"
+        ));
+        push_owner_scope(&mut query, owner_id);
+        push_search_filter(&mut query, filter);
+        query.push(" GROUP BY 1, 2 ORDER BY 1 DESC, 2 DESC");
+
+        let rows: Vec<(i32, i32, i64)> = query.build_query_as().fetch_all(&self.pool).await?;
+        Ok(rows
+            .into_iter()
+            .map(|(year, month, count)| TimelineMonth {
+                year,
+                month: month as u32,
+                count,
+            })
+            .collect())
+    }
+
+    async fn image_source(&self, photo_id: Uuid) -> AppResult<Option<ImageSource>> {
+        let row: Option<(Uuid, String)> = sqlx::query_as(
+            "SELECT user_id, COALESCE(preview_path, storage_path) FROM photos \
+             WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(photo_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|(owner_id, path)| ImageSource {
+            photo_id,
+            owner_id,
+            path,
+        }))
+    }
+
+    async fn save_palette(&self, photo_id: Uuid, palette: &[PaletteColor]) -> AppResult<()> {
+        let entries: Vec<PaletteEntry> = palette.iter().map(PaletteEntry::from).collect();
+        let hexes: Vec<String> = palette.iter().map(|c| c.color.to_hex()).collect();
+
+        sqlx::query(
+            "UPDATE photos SET color_palette = $2, dominant_colors = $3, updated_at = NOW() \
+             WHERE id = $1",
+        )
+        .bind(photo_id)
+        .bind(Json(entries))
+        .bind(hexes)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn save_quality(&self, photo_id: Uuid, quality: &ImageQuality) -> AppResult<()> {
+        sqlx::query(
+            "UPDATE photos SET sharpness_score = $2, exposure_score = $3, brightness = $4, \
+             updated_at = NOW() WHERE id = $1",
+        )
+        .bind(photo_id)
+        .bind(quality.sharpness)
+        .bind(quality.exposure)
+        .bind(quality.brightness)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn cleanup_candidates(&self, owner_id: Uuid, limit: usize) -> AppResult<Vec<Photo>> {
+        let mut query =
+            QueryBuilder::<Postgres>::new(format!("SELECT {PHOTO_COLUMNS_SQL} FROM photos p"));
+        push_owner_scope(&mut query, owner_id);
+        query.push(" AND (p.sharpness_score < ");
+        query.push_bind(ImageQuality::BLURRY_BELOW);
+        query.push(" OR p.exposure_score < ");
+        query.push_bind(ImageQuality::POOR_EXPOSURE_BELOW);
+        query.push(") ORDER BY LEAST(p.sharpness_score, p.exposure_score), p.id LIMIT ");
+        query.push_bind(limit as i64);
+
+        let rows: Vec<PhotoRow> = query.build_query_as().fetch_all(&self.pool).await?;
+        Ok(rows.into_iter().map(Photo::from).collect())
+    }
+
+    async fn trash(&self, owner_id: Uuid, photo_ids: &[Uuid]) -> AppResult<u64> {
+        let result = sqlx::query(
+            "UPDATE photos SET deleted_at = NOW(), updated_at = NOW() \
+             WHERE user_id = $1 AND id = ANY($2) AND deleted_at IS NULL",
+        )
+        .bind(owner_id)
+        .bind(photo_ids)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn set_favorite(
+        &self,
+        owner_id: Uuid,
+        photo_ids: &[Uuid],
+        liked: bool,
+    ) -> AppResult<u64> {
+        let sql = if liked {
+            "INSERT INTO favorites (user_id, photo_id) \
+             SELECT p.user_id, p.id FROM photos p \
+             WHERE p.user_id = $1 AND p.id = ANY($2) AND p.deleted_at IS NULL \
+             ON CONFLICT DO NOTHING"
+        } else {
+            "DELETE FROM favorites WHERE user_id = $1 AND photo_id = ANY($2)"
+        };
+        let result = sqlx::query(sql)
+            .bind(owner_id)
+            .bind(photo_ids)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn photo_files(&self, owner_id: Uuid, photo_ids: &[Uuid]) -> AppResult<Vec<PhotoFile>> {
+        let rows: Vec<(Uuid, String, String)> = sqlx::query_as(
+            "SELECT id, original_filename, storage_path FROM photos \
+             WHERE user_id = $1 AND id = ANY($2) AND deleted_at IS NULL \
+             ORDER BY COALESCE(taken_at, created_at) DESC, id DESC",
+        )
+        .bind(owner_id)
+        .bind(photo_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(photo_id, filename, path)| PhotoFile {
+                photo_id,
+                filename,
+                path,
+            })
+            .collect())
+    }
+}
+
+pub fn push_owner_scope(query: &mut QueryBuilder<'_, Postgres>, owner_id: Uuid) {
+    query.push(" WHERE p.deleted_at IS NULL AND p.user_id = ");
+    query.push_bind(owner_id);
+}
+
+pub fn push_search_filter(query: &mut QueryBuilder<'_, Postgres>, filter: &SearchFilter) {
+    if let Some(text) = &filter.text {
+        push_text_match(query, text);
+    }
+    push_date_range(query, filter.date_from, filter.date_to);
+    push_equipment_filter(query, filter);
+    push_tag_filter(query, &filter.tags);
+    if let Some(place) = &filter.place {
+        query.push(" AND p.location_name ILIKE ");
+        query.push_bind(contains_pattern(place));
+    }
+    push_flag_filter(query, filter);
+    push_exif_filter(query, &filter.exif);
+    if let Some(area) = &filter.area {
+        push_geo_area(query, area);
+    }
+    if let Some(person_id) = filter.person_id {
+        query.push(
+            " AND EXISTS (SELECT 1 FROM photo_faces pf WHERE pf.photo_id = p.id AND pf.person_id = ",
+        );
+        query.push_bind(person_id);
+        query.push(")");
+    }
+    if let Some(album_id) = filter.album_id {
+        query.push(
+            " AND EXISTS (SELECT 1 FROM album_photos ap WHERE ap.photo_id = p.id AND ap.album_id = ",
+        );
+        query.push_bind(album_id);
+        query.push(")");
+    }
+    if let Some(color) = &filter.color {
+        push_color_filter(query, color);
+    }
+    push_quality_filter(query, &filter.quality);
+}
+
+fn push_quality_filter(query: &mut QueryBuilder<'_, Postgres>, filter: &QualityFilter) {
+    push_range(query, "p.sharpness_score", filter.sharpness);
+    push_range(query, "p.exposure_score", filter.exposure);
+}
+
+/// 팔레트(`color_palette`) 중 일정 비율 이상 쓰인 색이 CIELAB 거리 안에 있는 사진만 통과
+fn push_color_filter(query: &mut QueryBuilder<'_, Postgres>, filter: &ColorFilter) {
+    let lab = filter.color.to_lab();
+    query.push(
+        " AND EXISTS (SELECT 1 FROM jsonb_array_elements(p.color_palette) c \
+         WHERE (c->>'ratio')::real >= ",
+    );
+    query.push_bind(ColorFilter::MIN_RATIO);
+    query.push(" AND sqrt(power((c->>'l')::real - ");
+    query.push_bind(lab.l);
+    query.push(", 2) + power((c->>'a')::real - ");
+    query.push_bind(lab.a);
+    query.push(", 2) + power((c->>'b')::real - ");
+    query.push_bind(lab.b);
+    query.push(", 2)) <= ");
+    query.push_bind(filter.max_distance);
+    query.push(")");
+}
+
+/// `location` 의 GIST 인덱스(`idx_photos_location_spatial`)를 사용하는 영역 조건
+///
+/// 날짜 변경선을 넘는 사각 영역은 동서 두 영역의 OR 로 검색합니다.
+fn push_geo_area(query: &mut QueryBuilder<'_, Postgres>, area: &GeoArea) {
+    match area {
+        GeoArea::BoundingBox(bounds) => {
+            query.push(" AND (");
+            for (index, part) in bounds.split_at_antimeridian().into_iter().enumerate() {
+                if index > 0 {
+                    query.push(" OR ");
+                }
+                query.push("p.location && ST_MakeEnvelope(");
+                let mut args = query.separated(", ");
+                for value in [part.west, part.south, part.east, part.north] {
+                    args.push_bind(value);
+                }
+                query.push(", 4326)::geography");
+            }
+            query.push(")");
+        }
+        GeoArea::Radius { center, meters } => {
+            query.push(" AND ST_DWithin(p.location, ST_SetSRID(ST_MakePoint(");
+            query.push_bind(center.lng);
+            query.push(", ");
+            query.push_bind(center.lat);
+            query.push("), 4326)::geography, ");
+            query.push_bind(*meters);
+            query.push(")");
+        }
+    }
+}
+
+fn push_equipment_filter(query: &mut QueryBuilder<'_, Postgres>, filter: &SearchFilter) {
+    if let Some(camera) = &filter.camera {
+        query.push(format!(" AND {CAMERA_SQL} ILIKE "));
+        query.push_bind(contains_pattern(camera));
+    }
+    if let Some(lens) = &filter.lens {
+        query.push(format!(" AND {LENS_SQL} ILIKE "));
+        query.push_bind(contains_pattern(lens));
+    }
+}
+
+fn exif_column(field: ExifField) -> &'static str {
+    match field {
+        ExifField::FocalLength => "p.focal_length_mm",
+        ExifField::Aperture => "p.aperture_value",
+        ExifField::ShutterSpeed => "p.shutter_speed_seconds",
+        ExifField::Iso => "p.iso_value",
+    }
+}
+
+fn push_exif_filter(query: &mut QueryBuilder<'_, Postgres>, exif: &ExifFilter) {
+    for field in ExifField::ALL {
+        push_range(query, exif_column(field), field.range(exif));
+    }
+    if let Some(flash) = exif.flash {
+        query.push(" AND p.flash_used = ");
+        query.push_bind(flash);
+    }
+}
+
+/// `[min, max)` 구간 조건
+fn push_range(query: &mut QueryBuilder<'_, Postgres>, column: &str, range: NumericRange) {
+    if let Some(min) = range.min {
+        query.push(format!(" AND {column} >= "));
+        query.push_bind(min);
+    }
+    if let Some(max) = range.max {
+        query.push(format!(" AND {column} < "));
+        query.push_bind(max);
+    }
+}
+
+/// 모든 태그를 포함하는 사진만 통과
+fn push_tag_filter(query: &mut QueryBuilder<'_, Postgres>, tags: &[String]) {
+    for tag in tags {
+        query.push(
+            " AND EXISTS (SELECT 1 FROM photo_tags pt JOIN tags t ON t.id = pt.tag_id \
+             WHERE pt.photo_id = p.id AND LOWER(t.name) = LOWER(",
+        );
+        query.push_bind(tag.clone());
+        query.push("))");
+    }
+}
+
+fn push_flag_filter(query: &mut QueryBuilder<'_, Postgres>, filter: &SearchFilter) {
+    if let Some(is_liked) = filter.is_liked {
+        query.push(if is_liked { " AND " } else { " AND NOT " });
+        query.push(
+            "EXISTS (SELECT 1 FROM favorites f WHERE f.photo_id = p.id AND f.user_id = p.user_id)",
+        );
+    }
+    if let Some(has_location) = filter.has_location {
+        query.push(if has_location {
+            " AND p.location IS NOT NULL"
+        } else {
+            " AND p.location IS NULL"
+        });
+    }
+}
+
+/// 제목(파일명)/위치명/태그/앨범명 중 하나라도 트라이그램 단어 유사도(`<%`)로 일치하면 통과
+fn push_text_match(query: &mut QueryBuilder<'_, Postgres>, text: &str) {
+    query.push(" AND (p.original_filename ILIKE ");
+    query.push_bind(contains_pattern(text));
+    query.push(" OR ");
+    query.push_bind(text.to_string());
+    query.push(" <% p.original_filename OR p.location_name ILIKE ");
+    query.push_bind(contains_pattern(text));
+    query.push(
+        " OR EXISTS (SELECT 1 FROM photo_tags pt JOIN tags t ON t.id = pt.tag_id \
+         WHERE pt.photo_id = p.id AND ",
+    );
+    query.push_bind(text.to_string());
+    query.push(
+        " <% t.name) OR EXISTS (SELECT 1 FROM album_photos ap JOIN albums a ON a.id = ap.album_id \
+         WHERE ap.photo_id = p.id AND ",
+    );
+    query.push_bind(text.to_string());
+    query.push(" <% a.title))");
+}
+
+/// `date_to` 는 해당 날짜를 포함하도록 다음 날 0시 미만으로 비교
+fn push_date_range(
+    query: &mut QueryBuilder<'_, Postgres>,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) {
+    if let Some(from) = from {
+        query.push(format!(" AND {SORT_AT_SQL} >= "));
+        query.push_bind(from);
+    }
+    if let Some(next_day) = to.and_then(|to| to.succ_opt()) {
+        query.push(format!(" AND {SORT_AT_SQL} < "));
+        query.push_bind(next_day);
+    }
+}
+
+pub fn push_cursor(query: &mut QueryBuilder<'_, Postgres>, cursor: Option<PhotoCursor>) {
+    if let Some(cursor) = cursor {
+        query.push(format!(" AND ({SORT_AT_SQL}, p.id) < ("));
+        query.push_bind(cursor.sort_at);
+        query.push(", ");
+        query.push_bind(cursor.id);
+        query.push(")");
+    }
+}
+
+pub fn push_timeline_order(query: &mut QueryBuilder<'_, Postgres>, limit: usize) {
+    query.push(format!(" ORDER BY {SORT_AT_SQL} DESC, p.id DESC LIMIT "));
+    query.push_bind(limit as i64);
+}
@@ -0,0 +1,91 @@
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::repositories::place_repository::PlaceRepository;
+use crate::domain::value_objects::geo_area::GeoPoint;
+use crate::domain::value_objects::place::{Place, PlaceCount};
+use crate::error::AppResult;
+
+#[derive(FromRow)]
+struct CityCountRow {
+    city: String,
+    region: Option<String>,
+    country: String,
+    country_code: String,
+    count: i64,
+    cover_path: String,
+}
+
+impl From<CityCountRow> for PlaceCount {
+    fn from(row: CityCountRow) -> Self {
+        Self {
+            place: Place {
+                city: row.city,
+                region: row.region.unwrap_or_default(),
+                country: row.country,
+                country_code: row.country_code,
+            },
+            count: row.count,
+            cover_path: row.cover_path,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PgPlaceRepository {
+    pool: PgPool,
+}
+
+impl PgPlaceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl PlaceRepository for PgPlaceRepository {
+    async fn photo_location(&self, photo_id: Uuid) -> AppResult<Option<GeoPoint>> {
+        let row: Option<(f64, f64)> = sqlx::query_as(
+            "SELECT ST_Y(location::geometry), ST_X(location::geometry) FROM photos \
+             WHERE id = $1 AND deleted_at IS NULL AND location IS NOT NULL",
+        )
+        .bind(photo_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|(lat, lng)| GeoPoint { lat, lng }))
+    }
+
+    async fn save_place(&self, photo_id: Uuid, place: &Place) -> AppResult<()> {
+        sqlx::query(
+            "UPDATE photos SET location_name = $2, location_city = $3, location_region = $4, \
+             location_country = $5, location_country_code = $6 WHERE id = $1",
+        )
+        .bind(photo_id)
+        .bind(place.display_name())
+        .bind(&place.city)
+        .bind(&place.region)
+        .bind(&place.country)
+        .bind(&place.country_code)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn city_counts(&self, owner_id: Uuid) -> AppResult<Vec<PlaceCount>> {
+        let rows = sqlx::query_as::<_, CityCountRow>(
+            "SELECT location_city AS city, MAX(location_region) AS region, \
+             location_country AS country, location_country_code AS country_code, COUNT(*) AS count, \
+             (ARRAY_AGG(COALESCE(thumbnail_path, preview_path, storage_path) \
+              ORDER BY COALESCE(taken_at, created_at, 'epoch'::timestamptz) DESC))[1] AS cover_path \
+             FROM photos \
+             WHERE user_id = $1 AND deleted_at IS NULL AND location_country IS NOT NULL \
+             GROUP BY location_country_code, location_country, location_city \
+             ORDER BY location_country_code, count DESC, city",
+        )
+        .bind(owner_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(PlaceCount::from).collect())
+    }
+}
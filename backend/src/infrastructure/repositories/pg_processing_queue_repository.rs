@@ -0,0 +1,113 @@
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::repositories::processing_queue_repository::ProcessingQueueRepository;
+use crate::domain::value_objects::processing_task::{ProcessingTask, TaskType};
+use crate::error::AppResult;
+
+/// 작업별로 처리가 필요한 사진 조건
+fn needs_task_sql(task_type: TaskType) -> &'static str {
+    match task_type {
+        TaskType::ReverseGeocode => "p.location IS NOT NULL AND p.location_name IS NULL",
+        TaskType::FaceDetection => "p.faces_detected_at IS NULL",
+        TaskType::ColorPalette => "p.dominant_colors IS NULL",
+        TaskType::PerceptualHash => "p.perceptual_hash IS NULL",
+        TaskType::QualityAssessment => "p.sharpness_score IS NULL",
+        TaskType::AiTags => "p.ai_tagged_at IS NULL",
+        TaskType::ImageEmbedding => "p.image_embedding IS NULL",
+    }
+}
+
+#[derive(Clone)]
+pub struct PgProcessingQueueRepository {
+    pool: PgPool,
+}
+
+impl PgProcessingQueueRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl ProcessingQueueRepository for PgProcessingQueueRepository {
+    async fn enqueue_missing(&self, task_type: TaskType, limit: usize) -> AppResult<u64> {
+        let sql = format!(
+            "INSERT INTO processing_queue (photo_id, task_type) \
+             SELECT p.id, $1 FROM photos p \
+             WHERE p.deleted_at IS NULL AND {} \
+             AND NOT EXISTS (SELECT 1 FROM processing_queue q WHERE q.photo_id = p.id AND q.task_type = $1) \
+             LIMIT $2 \
+             ON CONFLICT (photo_id, task_type) DO NOTHING",
+            needs_task_sql(task_type)
+        );
+        let result = sqlx::query(&sql)
+            .bind(task_type.as_str())
+            .bind(limit as i64)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn claim_next(&self, task_types: &[TaskType]) -> AppResult<Option<ProcessingTask>> {
+        let row: Option<(Uuid, Uuid, String, Option<i32>)> = sqlx::query_as(
+            "UPDATE processing_queue SET status = 'processing', started_at = NOW() \
+             WHERE id = (SELECT id FROM processing_queue \
+                         WHERE status = 'pending' AND task_type = ANY($1) \
+                         ORDER BY priority, created_at FOR UPDATE SKIP LOCKED LIMIT 1) \
+             RETURNING id, photo_id, task_type, retry_count",
+        )
+        .bind(task_types.iter().map(|t| t.as_str()).collect::<Vec<_>>())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        // 이 버전이 아는 작업 종류만 가져오므로 해석은 항상 성공
+        Ok(row.and_then(|(id, photo_id, task_type, retry_count)| {
+            Some(ProcessingTask {
+                id,
+                photo_id,
+                task_type: TaskType::parse(&task_type)?,
+                retry_count: retry_count.unwrap_or(0),
+            })
+        }))
+    }
+
+    async fn complete(&self, task_id: Uuid) -> AppResult<()> {
+        sqlx::query(
+            "UPDATE processing_queue SET status = 'completed', completed_at = NOW(), \
+             error_message = NULL WHERE id = $1",
+        )
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn fail(&self, task_id: Uuid, message: &str, max_retries: i32) -> AppResult<()> {
+        sqlx::query(
+            "UPDATE processing_queue SET retry_count = COALESCE(retry_count, 0) + 1, \
+             status = CASE WHEN COALESCE(retry_count, 0) + 1 >= $3 THEN 'failed' ELSE 'pending' END, \
+             error_message = $2 WHERE id = $1",
+        )
+        .bind(task_id)
+        .bind(message)
+        .bind(max_retries)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn reclaim_stale(&self, stale_after: Duration, max_retries: i32) -> AppResult<u64> {
+        let result = sqlx::query(
+            "UPDATE processing_queue SET retry_count = COALESCE(retry_count, 0) + 1, \
+             status = CASE WHEN COALESCE(retry_count, 0) + 1 >= $2 THEN 'failed' ELSE 'pending' END, \
+             error_message = 'interrupted while processing' \
+             WHERE status = 'processing' AND started_at < NOW() - make_interval(secs => $1)",
+        )
+        .bind(stale_after.as_secs_f64())
+        .bind(max_retries)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
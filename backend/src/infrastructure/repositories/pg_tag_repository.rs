@@ -0,0 +1,124 @@
+use serde::Serialize;
+use sqlx::PgPool;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::domain::value_objects::image_label::ImageLabel;
+use crate::error::AppResult;
+
+/// `ai_tags` JSONB 원소
+#[derive(Serialize)]
+struct AiTagEntry<'a> {
+    tag: &'a str,
+    category: &'a str,
+    confidence: f32,
+}
+
+impl<'a> From<&'a ImageLabel> for AiTagEntry<'a> {
+    fn from(label: &'a ImageLabel) -> Self {
+        Self {
+            tag: &label.tag,
+            category: &label.category,
+            confidence: label.confidence,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PgTagRepository {
+    pool: PgPool,
+}
+
+impl PgTagRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl TagRepository for PgTagRepository {
+    /// 자동 태그는 `confidence` 가 있는 `photo_tags` 행 (직접 붙인 태그는 `NULL`)
+    async fn save_ai_tags(&self, photo_id: Uuid, labels: &[ImageLabel]) -> AppResult<()> {
+        let names: Vec<&str> = labels.iter().map(|l| l.tag.as_str()).collect();
+        let categories: Vec<&str> = labels.iter().map(|l| l.category.as_str()).collect();
+        let confidences: Vec<f32> = labels.iter().map(|l| l.confidence).collect();
+        let entries: Vec<AiTagEntry> = labels.iter().map(AiTagEntry::from).collect();
+
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO tags (name, category) SELECT * FROM UNNEST($1::text[], $2::text[]) \
+             ON CONFLICT (name) DO NOTHING",
+        )
+        .bind(&names)
+        .bind(&categories)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "DELETE FROM photo_tags pt USING tags t \
+             WHERE pt.tag_id = t.id AND pt.photo_id = $1 AND pt.confidence IS NOT NULL \
+             AND NOT (t.name = ANY($2))",
+        )
+        .bind(photo_id)
+        .bind(&names)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO photo_tags (photo_id, tag_id, confidence) \
+             SELECT $1, t.id, ROUND(l.confidence::numeric, 2) \
+             FROM UNNEST($2::text[], $3::real[]) AS l(name, confidence) \
+             JOIN tags t ON t.name = l.name \
+             ON CONFLICT (photo_id, tag_id) DO UPDATE SET confidence = EXCLUDED.confidence \
+             WHERE photo_tags.confidence IS NOT NULL",
+        )
+        .bind(photo_id)
+        .bind(&names)
+        .bind(&confidences)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("UPDATE photos SET ai_tags = $2, ai_tagged_at = NOW() WHERE id = $1")
+            .bind(photo_id)
+            .bind(Json(entries))
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 대소문자만 다른 기존 태그가 있으면 그 태그를 사용
+    async fn add_tags(
+        &self,
+        owner_id: Uuid,
+        photo_ids: &[Uuid],
+        names: &[String],
+    ) -> AppResult<u64> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO tags (name) SELECT n FROM UNNEST($1::text[]) AS n \
+             WHERE NOT EXISTS (SELECT 1 FROM tags t WHERE LOWER(t.name) = LOWER(n)) \
+             ON CONFLICT (name) DO NOTHING",
+        )
+        .bind(names)
+        .execute(&mut *tx)
+        .await?;
+
+        let result = sqlx::query(
+            "INSERT INTO photo_tags (photo_id, tag_id, added_by) \
+             SELECT p.id, t.id, p.user_id FROM photos p \
+             JOIN tags t ON LOWER(t.name) IN (SELECT LOWER(n) FROM UNNEST($3::text[]) AS n) \
+             WHERE p.user_id = $1 AND p.id = ANY($2) AND p.deleted_at IS NULL \
+             ON CONFLICT (photo_id, tag_id) DO UPDATE \
+             SET confidence = NULL, added_by = EXCLUDED.added_by \
+             WHERE photo_tags.confidence IS NOT NULL",
+        )
+        .bind(owner_id)
+        .bind(photo_ids)
+        .bind(names)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,42 @@
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageDecoder, ImageReader, RgbImage};
+use std::path::PathBuf;
+
+use crate::domain::services::image_store::ImageStore;
+use crate::error::AppResult;
+
+const JPEG_QUALITY: u8 = 85;
+
+/// 로컬 파일 시스템(`media_root`)의 사진 파일
+#[derive(Clone)]
+pub struct FsImageStore {
+    media_root: PathBuf,
+}
+
+impl FsImageStore {
+    pub fn new(media_root: PathBuf) -> Self {
+        Self { media_root }
+    }
+}
+
+impl ImageStore for FsImageStore {
+    fn load_rgb(&self, path: &str, max_size: u32) -> AppResult<RgbImage> {
+        let mut decoder = ImageReader::open(self.media_root.join(path))?
+            .with_guessed_format()?
+            .into_decoder()?;
+        let orientation = decoder.orientation()?;
+        let mut image = DynamicImage::from_decoder(decoder)?;
+        image.apply_orientation(orientation);
+
+        if image.width().max(image.height()) > max_size {
+            image = image.thumbnail(max_size, max_size);
+        }
+        Ok(image.to_rgb8())
+    }
+
+    fn encode_jpeg(&self, image: &RgbImage) -> AppResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        JpegEncoder::new_with_quality(&mut bytes, JPEG_QUALITY).encode_image(image)?;
+        Ok(bytes)
+    }
+}
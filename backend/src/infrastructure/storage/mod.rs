@@ -0,0 +1,2 @@
+pub mod fs_image_store;
+pub mod zip_photo_archiver;
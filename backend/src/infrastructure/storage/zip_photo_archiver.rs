@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use zip::CompressionMethod;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use crate::domain::services::photo_archiver::PhotoArchiver;
+use crate::domain::value_objects::photo_file::PhotoFile;
+use crate::error::{AppError, AppResult};
+
+/// `media_root` 의 원본을 ZIP 으로 묶음 (사진은 이미 압축되어 있으므로 저장만 함)
+#[derive(Clone)]
+pub struct ZipPhotoArchiver {
+    media_root: PathBuf,
+}
+
+impl ZipPhotoArchiver {
+    pub fn new(media_root: PathBuf) -> Self {
+        Self { media_root }
+    }
+}
+
+impl PhotoArchiver for ZipPhotoArchiver {
+    fn total_size(&self, files: &[PhotoFile]) -> AppResult<u64> {
+        files.iter().try_fold(0, |total, file| {
+            Ok(total + std::fs::metadata(self.media_root.join(&file.path))?.len())
+        })
+    }
+
+    fn archive(&self, files: &[PhotoFile], output: &mut dyn Write) -> AppResult<()> {
+        // 스트림 모드: 크기와 CRC 는 각 항목 뒤의 data descriptor 에 기록
+        let mut writer = ZipWriter::new_stream(output);
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Stored)
+            .large_file(true);
+        let mut used = HashSet::new();
+
+        for file in files {
+            let mut source = std::fs::File::open(self.media_root.join(&file.path))?;
+            writer
+                .start_file(entry_name(&file.filename, &mut used), options)
+                .map_err(zip_error)?;
+            std::io::copy(&mut source, &mut writer)?;
+        }
+        writer.finish().map_err(zip_error)?;
+        Ok(())
+    }
+}
+
+fn zip_error(err: zip::result::ZipError) -> AppError {
+    AppError::Image(err.to_string())
+}
+
+/// 경로 구분자를 지우고, 이름이 겹치면 `name (2).jpg` 처럼 번호를 붙임
+fn entry_name(filename: &str, used: &mut HashSet<String>) -> String {
+    let base = filename.replace(['/', '\\'], "_");
+    let base = if base.trim().is_empty() {
+        "photo".to_string()
+    } else {
+        base
+    };
+    let path = Path::new(&base);
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or(&base);
+    let extension = path.extension().and_then(|e| e.to_str());
+
+    let mut name = base.clone();
+    let mut counter = 2;
+    while !used.insert(name.to_lowercase()) {
+        name = match extension {
+            Some(extension) => format!("{stem} ({counter}).{extension}"),
+            None => format!("{stem} ({counter})"),
+        };
+        counter += 1;
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Read};
+    use uuid::Uuid;
+    use zip::ZipArchive;
+
+    /// 테스트마다 따로 쓰는 임시 `media_root`
+    struct MediaRoot(PathBuf);
+
+    impl MediaRoot {
+        fn with_files(files: &[(&str, &[u8])]) -> Self {
+            let root = std::env::temp_dir().join(format!("photovault-zip-{}", Uuid::new_v4()));
+            std::fs::create_dir_all(&root).unwrap();
+            for (path, bytes) in files {
+                std::fs::write(root.join(path), bytes).unwrap();
+            }
+            Self(root)
+        }
+    }
+
+    impl Drop for MediaRoot {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn photo_file(filename: &str, path: &str) -> PhotoFile {
+        PhotoFile {
+            photo_id: Uuid::new_v4(),
+            filename: filename.to_string(),
+            path: path.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_archive_streams_originals_in_order() {
+        let root = MediaRoot::with_files(&[("a.jpg", b"first"), ("b.jpg", b"second photo")]);
+        let archiver = ZipPhotoArchiver::new(root.0.clone());
+        let files = [
+            photo_file("IMG.jpg", "a.jpg"),
+            photo_file("IMG.jpg", "b.jpg"),
+        ];
+
+        let mut output = Vec::new();
+        archiver.archive(&files, &mut output).unwrap();
+
+        let mut archive = ZipArchive::new(Cursor::new(output)).unwrap();
+        let mut entries = Vec::new();
+        for index in 0..archive.len() {
+            let mut entry = archive.by_index(index).unwrap();
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes).unwrap();
+            entries.push((entry.name().to_string(), bytes));
+        }
+        assert_eq!(
+            entries,
+            [
+                ("IMG.jpg".to_string(), b"first".to_vec()),
+                ("IMG (2).jpg".to_string(), b"second photo".to_vec()),
+            ]
+        );
+        assert_eq!(archiver.total_size(&files).unwrap(), 17);
+    }
+
+    #[test]
+    fn test_missing_original_is_an_error() {
+        let root = MediaRoot::with_files(&[]);
+        let archiver = ZipPhotoArchiver::new(root.0.clone());
+        let files = [photo_file("IMG.jpg", "missing.jpg")];
+
+        assert!(archiver.total_size(&files).is_err());
+        assert!(archiver.archive(&files, &mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_entry_name_numbers_duplicates() {
+        let mut used = HashSet::new();
+
+        assert_eq!(entry_name("IMG_1.jpg", &mut used), "IMG_1.jpg");
+        assert_eq!(entry_name("img_1.JPG", &mut used), "img_1 (2).JPG");
+        assert_eq!(entry_name("IMG_1.jpg", &mut used), "IMG_1 (3).jpg");
+        assert_eq!(entry_name("../etc/passwd", &mut used), ".._etc_passwd");
+        assert_eq!(entry_name("", &mut used), "photo");
+    }
+}
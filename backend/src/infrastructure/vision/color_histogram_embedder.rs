@@ -0,0 +1,129 @@
+use image::RgbImage;
+use image::imageops::{self, FilterType};
+
+use crate::domain::services::image_embedder::ImageEmbedder;
+use crate::domain::value_objects::image_embedding::ImageEmbedding;
+
+/// 계산에 사용하는 축소 이미지 크기
+const SAMPLE_SIZE: u32 = 64;
+/// 채널당 색 구간 수 (4×4×4 = 64 구간)
+const COLOR_BINS: usize = 4;
+/// 밝기 배치를 보는 격자 크기 (4×4)
+const LAYOUT_GRID: u32 = 4;
+/// 색 분포 대비 밝기 배치의 비중
+const LAYOUT_WEIGHT: f32 = 0.5;
+
+/// RGB 색 분포(64차원)와 4×4 밝기 배치(16차원)를 이어 붙인 임베더
+///
+/// 모델 없이 "색감이나 구도가 비슷한" 사진을 찾는 가벼운 기본 구현입니다.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ColorHistogramEmbedder;
+
+impl ImageEmbedder for ColorHistogramEmbedder {
+    fn embed(&self, image: &RgbImage) -> ImageEmbedding {
+        if image.width() == 0 || image.height() == 0 {
+            return ImageEmbedding::new(vec![
+                0.0;
+                COLOR_BINS.pow(3)
+                    + (LAYOUT_GRID * LAYOUT_GRID) as usize
+            ]);
+        }
+        let small = imageops::resize(image, SAMPLE_SIZE, SAMPLE_SIZE, FilterType::Triangle);
+
+        let mut values = color_histogram(&small);
+        values.extend(scaled(layout(&small), LAYOUT_WEIGHT));
+        ImageEmbedding::new(values)
+    }
+}
+
+/// 구간별 픽셀 비율의 제곱근 (길이 1, 큰 구간이 지나치게 지배하지 않도록)
+fn color_histogram(image: &RgbImage) -> Vec<f32> {
+    let bin = |value: u8| value as usize * COLOR_BINS / 256;
+    let mut counts = vec![0u32; COLOR_BINS.pow(3)];
+    for pixel in image.pixels() {
+        let [r, g, b] = pixel.0;
+        counts[(bin(r) * COLOR_BINS + bin(g)) * COLOR_BINS + bin(b)] += 1;
+    }
+
+    let total = image.pixels().len().max(1) as f32;
+    counts
+        .into_iter()
+        .map(|count| (count as f32 / total).sqrt())
+        .collect()
+}
+
+/// 격자 칸별 평균 밝기에서 전체 평균을 뺀 값 (밝은 곳/어두운 곳의 배치)
+fn layout(image: &RgbImage) -> Vec<f32> {
+    let gray = imageops::grayscale(image);
+    let cell = SAMPLE_SIZE / LAYOUT_GRID;
+    let mut means = Vec::with_capacity((LAYOUT_GRID * LAYOUT_GRID) as usize);
+    for gy in 0..LAYOUT_GRID {
+        for gx in 0..LAYOUT_GRID {
+            let cell_image = imageops::crop_imm(&gray, gx * cell, gy * cell, cell, cell).to_image();
+            let sum: u32 = cell_image.pixels().map(|p| u32::from(p.0[0])).sum();
+            means.push(sum as f32 / (cell * cell) as f32 / 255.0);
+        }
+    }
+
+    let overall = means.iter().sum::<f32>() / means.len() as f32;
+    means.into_iter().map(|mean| mean - overall).collect()
+}
+
+/// 길이를 `length` 로 맞춤 (모두 0이면 그대로)
+fn scaled(values: Vec<f32>, length: f32) -> Vec<f32> {
+    let norm = values.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm <= f32::EPSILON {
+        return values;
+    }
+    values.into_iter().map(|v| v / norm * length).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgb;
+
+    fn solid(color: [u8; 3]) -> RgbImage {
+        RgbImage::from_pixel(32, 24, Rgb(color))
+    }
+
+    /// 왼쪽 절반은 `left`, 오른쪽 절반은 `right`
+    fn split(left: [u8; 3], right: [u8; 3]) -> RgbImage {
+        RgbImage::from_fn(32, 32, |x, _| Rgb(if x < 16 { left } else { right }))
+    }
+
+    #[test]
+    fn test_identical_images_are_most_similar() {
+        let image = split([200, 40, 40], [20, 20, 60]);
+        let embedding = ColorHistogramEmbedder.embed(&image);
+
+        assert!((embedding.similarity(&ColorHistogramEmbedder.embed(&image)) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_similar_colors_are_closer_than_different_colors() {
+        let red = ColorHistogramEmbedder.embed(&solid([220, 30, 30]));
+        let dark_red = ColorHistogramEmbedder.embed(&solid([200, 20, 40]));
+        let blue = ColorHistogramEmbedder.embed(&solid([30, 30, 220]));
+
+        assert!(red.similarity(&dark_red) > red.similarity(&blue));
+    }
+
+    #[test]
+    fn test_layout_separates_mirrored_compositions() {
+        let (bright, dark) = ([240, 240, 240], [10, 10, 10]);
+        let original = ColorHistogramEmbedder.embed(&split(bright, dark));
+        let same = ColorHistogramEmbedder.embed(&split(bright, dark));
+        let mirrored = ColorHistogramEmbedder.embed(&split(dark, bright));
+
+        assert!(original.similarity(&same) > original.similarity(&mirrored));
+    }
+
+    #[test]
+    fn test_embedding_has_fixed_dimension() {
+        let a = ColorHistogramEmbedder.embed(&solid([1, 2, 3]));
+        let b = ColorHistogramEmbedder.embed(&RgbImage::new(0, 0));
+
+        assert_eq!(a.to_bytes().len(), b.to_bytes().len());
+    }
+}
@@ -0,0 +1,65 @@
+use image::RgbImage;
+use image::imageops::{self, FilterType};
+
+use crate::domain::services::image_hasher::ImageHasher;
+use crate::domain::value_objects::duplicate::PerceptualHash;
+
+/// 9×8 흑백으로 줄인 뒤 가로로 이웃한 픽셀의 밝기 비교 결과 64비트 (dHash)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DifferenceHasher;
+
+impl ImageHasher for DifferenceHasher {
+    fn hash(&self, image: &RgbImage) -> PerceptualHash {
+        let gray = imageops::grayscale(image);
+        let small = imageops::resize(&gray, 9, 8, FilterType::Triangle);
+
+        let mut bits = 0u64;
+        for y in 0..8 {
+            for x in 0..8 {
+                let brighter = small.get_pixel(x, y).0[0] > small.get_pixel(x + 1, y).0[0];
+                bits = (bits << 1) | u64::from(brighter);
+            }
+        }
+        PerceptualHash(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgb;
+
+    /// 왼쪽이 밝고 오른쪽으로 갈수록 어두워지는 이미지
+    fn gradient(width: u32, height: u32) -> RgbImage {
+        RgbImage::from_fn(width, height, |x, _| {
+            let value = 255 - (x * 255 / (width - 1)) as u8;
+            Rgb([value, value, value])
+        })
+    }
+
+    #[test]
+    fn test_solid_image_has_no_differences() {
+        let image = RgbImage::from_pixel(64, 64, Rgb([120, 80, 40]));
+
+        assert_eq!(DifferenceHasher.hash(&image), PerceptualHash(0));
+    }
+
+    #[test]
+    fn test_darkening_gradient_sets_every_bit() {
+        assert_eq!(
+            DifferenceHasher.hash(&gradient(90, 80)),
+            PerceptualHash(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_resized_copy_stays_close_and_mirror_is_far() {
+        let original = gradient(180, 160);
+        let resized = imageops::resize(&original, 90, 80, FilterType::Nearest);
+        let mirrored = imageops::flip_horizontal(&original);
+
+        let hash = DifferenceHasher.hash(&original);
+        assert!(hash.distance(DifferenceHasher.hash(&resized)) <= 2);
+        assert!(hash.distance(DifferenceHasher.hash(&mirrored)) > 32);
+    }
+}
@@ -0,0 +1,205 @@
+use image::RgbImage;
+
+use crate::domain::services::palette_extractor::PaletteExtractor;
+use crate::domain::value_objects::color::{Lab, PaletteColor, Rgb};
+
+/// 군집화에 사용하는 최대 픽셀 수 (큰 이미지는 일정 간격으로 건너뜀)
+const SAMPLE_LIMIT: usize = 4096;
+const ITERATIONS: usize = 10;
+/// 남은 픽셀이 모든 중심과 이보다 가까우면 더 나눌 색이 없는 것으로 봄 (ΔE)
+const MIN_SEPARATION: f32 = 4.0;
+
+/// CIELAB 공간에서 k-means 로 픽셀을 묶은 팔레트
+///
+/// 초기 중심은 평균색에서 시작해 가장 먼 픽셀을 차례로 고르므로 결과가 항상 같습니다.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KMeansPaletteExtractor;
+
+impl PaletteExtractor for KMeansPaletteExtractor {
+    fn extract(&self, image: &RgbImage, max_colors: usize) -> Vec<PaletteColor> {
+        let pixels = sample_pixels(image);
+        if pixels.is_empty() || max_colors == 0 {
+            return Vec::new();
+        }
+        let labs: Vec<Lab> = pixels.iter().map(|pixel| pixel.to_lab()).collect();
+
+        let mut centers = initial_centers(&labs, max_colors);
+        for _ in 0..ITERATIONS {
+            let labels = assign(&labs, &centers);
+            centers = recenter(&labs, &labels, &centers);
+        }
+        summarize(&pixels, &assign(&labs, &centers), centers.len())
+    }
+}
+
+fn sample_pixels(image: &RgbImage) -> Vec<Rgb> {
+    let total = (image.width() * image.height()) as usize;
+    let step = total.div_ceil(SAMPLE_LIMIT).max(1);
+    image
+        .pixels()
+        .step_by(step)
+        .map(|pixel| Rgb {
+            r: pixel.0[0],
+            g: pixel.0[1],
+            b: pixel.0[2],
+        })
+        .collect()
+}
+
+/// 평균색 + 기존 중심들과 가장 먼 픽셀을 차례로 추가
+fn initial_centers(labs: &[Lab], count: usize) -> Vec<Lab> {
+    let mut centers = vec![mean(labs.iter())];
+    while centers.len() < count {
+        let farthest = labs
+            .iter()
+            .map(|lab| (lab, nearest(lab, &centers).1))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        match farthest {
+            Some((lab, distance)) if distance > MIN_SEPARATION => centers.push(*lab),
+            _ => break,
+        }
+    }
+    centers
+}
+
+fn assign(labs: &[Lab], centers: &[Lab]) -> Vec<usize> {
+    labs.iter().map(|lab| nearest(lab, centers).0).collect()
+}
+
+/// 소속 픽셀의 평균으로 중심 이동 (빈 군집은 그대로 둠)
+fn recenter(labs: &[Lab], labels: &[usize], centers: &[Lab]) -> Vec<Lab> {
+    (0..centers.len())
+        .map(|index| {
+            let members: Vec<&Lab> = labs
+                .iter()
+                .zip(labels)
+                .filter(|(_, label)| **label == index)
+                .map(|(lab, _)| lab)
+                .collect();
+            if members.is_empty() {
+                centers[index]
+            } else {
+                mean(members.into_iter())
+            }
+        })
+        .collect()
+}
+
+/// 군집별 평균 RGB 와 비율 (비율 높은 순)
+fn summarize(pixels: &[Rgb], labels: &[usize], count: usize) -> Vec<PaletteColor> {
+    let mut sums = vec![([0u64; 3], 0u64); count];
+    for (pixel, label) in pixels.iter().zip(labels) {
+        let (sum, members) = &mut sums[*label];
+        sum[0] += u64::from(pixel.r);
+        sum[1] += u64::from(pixel.g);
+        sum[2] += u64::from(pixel.b);
+        *members += 1;
+    }
+
+    let mut palette: Vec<PaletteColor> = sums
+        .into_iter()
+        .filter(|(_, members)| *members > 0)
+        .map(|(sum, members)| {
+            let channel = |value: u64| (value / members) as u8;
+            let color = Rgb {
+                r: channel(sum[0]),
+                g: channel(sum[1]),
+                b: channel(sum[2]),
+            };
+            PaletteColor {
+                color,
+                lab: color.to_lab(),
+                ratio: members as f32 / pixels.len() as f32,
+            }
+        })
+        .collect();
+    palette.sort_by(|a, b| b.ratio.total_cmp(&a.ratio));
+    palette
+}
+
+/// 가장 가까운 중심의 위치와 거리
+fn nearest(lab: &Lab, centers: &[Lab]) -> (usize, f32) {
+    centers
+        .iter()
+        .map(|center| lab.distance(center))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap_or((0, f32::MAX))
+}
+
+fn mean<'a>(labs: impl Iterator<Item = &'a Lab>) -> Lab {
+    let (mut sum, mut count) = (Lab::default(), 0.0);
+    for lab in labs {
+        sum.l += lab.l;
+        sum.a += lab.a;
+        sum.b += lab.b;
+        count += 1.0;
+    }
+    if count == 0.0 {
+        return sum;
+    }
+    Lab {
+        l: sum.l / count,
+        a: sum.a / count,
+        b: sum.b / count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    /// 왼쪽 `left_columns` 열은 `left`, 나머지는 `right` 인 10×10 이미지
+    fn two_tone(left: [u8; 3], right: [u8; 3], left_columns: u32) -> RgbImage {
+        RgbImage::from_fn(10, 10, |x, _| {
+            image::Rgb(if x < left_columns { left } else { right })
+        })
+    }
+
+    #[test]
+    fn test_solid_image_is_a_single_color() {
+        let image = RgbImage::from_pixel(32, 32, image::Rgb([200, 40, 40]));
+
+        let palette = KMeansPaletteExtractor.extract(&image, 6);
+
+        assert_eq!(palette.len(), 1);
+        assert_eq!(palette[0].color, rgb(200, 40, 40));
+        assert_eq!(palette[0].ratio, 1.0);
+    }
+
+    #[test]
+    fn test_two_colors_are_split_by_share() {
+        let image = two_tone([0, 0, 255], [255, 255, 0], 7);
+
+        let palette = KMeansPaletteExtractor.extract(&image, 6);
+
+        let colors: Vec<(Rgb, f32)> = palette.iter().map(|c| (c.color, c.ratio)).collect();
+        assert_eq!(colors, [(rgb(0, 0, 255), 0.7), (rgb(255, 255, 0), 0.3)]);
+    }
+
+    #[test]
+    fn test_near_identical_shades_are_not_split() {
+        // ΔE 가 MIN_SEPARATION 보다 작은 두 회색
+        let image = two_tone([120, 120, 120], [122, 122, 122], 5);
+
+        let palette = KMeansPaletteExtractor.extract(&image, 6);
+
+        assert_eq!(palette.len(), 1);
+    }
+
+    #[test]
+    fn test_respects_max_colors() {
+        let image = RgbImage::from_fn(30, 1, |x, _| match x / 10 {
+            0 => image::Rgb([255, 0, 0]),
+            1 => image::Rgb([0, 255, 0]),
+            _ => image::Rgb([0, 0, 255]),
+        });
+
+        assert_eq!(KMeansPaletteExtractor.extract(&image, 2).len(), 2);
+        assert!(KMeansPaletteExtractor.extract(&image, 0).is_empty());
+    }
+}
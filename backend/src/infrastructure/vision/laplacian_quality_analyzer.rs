@@ -0,0 +1,131 @@
+use image::imageops;
+use image::{GrayImage, RgbImage};
+
+use crate::domain::services::quality_analyzer::QualityAnalyzer;
+use crate::domain::value_objects::image_quality::ImageQuality;
+
+/// 라플라시안 분산이 이 값일 때 선명도 0.5 (긴 변 512px 기준)
+const SHARPNESS_REFERENCE: f64 = 100.0;
+/// 이 밝기 이하/이상은 암부/명부가 뭉개진 픽셀로 봄
+const SHADOW_CLIP: u8 = 5;
+const HIGHLIGHT_CLIP: u8 = 250;
+
+/// 라플라시안 분산(선명도)과 밝기 히스토그램(노출)으로 화질 평가
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LaplacianQualityAnalyzer;
+
+impl QualityAnalyzer for LaplacianQualityAnalyzer {
+    fn assess(&self, image: &RgbImage) -> ImageQuality {
+        let gray = imageops::grayscale(image);
+        let variance = laplacian_variance(&gray);
+        let (brightness, clipped) = brightness_histogram(&gray);
+
+        ImageQuality {
+            sharpness: (variance / (variance + SHARPNESS_REFERENCE)) as f32,
+            exposure: ((1.0 - (brightness - 0.5).abs() * 2.0) * (1.0 - clipped)).clamp(0.0, 1.0)
+                as f32,
+            brightness: brightness as f32,
+        }
+    }
+}
+
+/// 4-이웃 라플라시안 응답의 분산 (가장자리 픽셀 제외)
+fn laplacian_variance(gray: &GrayImage) -> f64 {
+    let (width, height) = gray.dimensions();
+    if width < 3 || height < 3 {
+        return 0.0;
+    }
+
+    let at = |x: u32, y: u32| f64::from(gray.get_pixel(x, y).0[0]);
+    let (mut sum, mut sum_sq, mut count) = (0.0, 0.0, 0.0);
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            let response =
+                4.0 * at(x, y) - at(x - 1, y) - at(x + 1, y) - at(x, y - 1) - at(x, y + 1);
+            sum += response;
+            sum_sq += response * response;
+            count += 1.0;
+        }
+    }
+    let mean = sum / count;
+    (sum_sq / count - mean * mean).max(0.0)
+}
+
+/// 평균 밝기(0~1)와 암부/명부가 뭉개진 픽셀 비율
+fn brightness_histogram(gray: &GrayImage) -> (f64, f64) {
+    let mut histogram = [0u64; 256];
+    for pixel in gray.pixels() {
+        histogram[pixel.0[0] as usize] += 1;
+    }
+
+    let total = histogram.iter().sum::<u64>().max(1) as f64;
+    let weighted: f64 = histogram
+        .iter()
+        .enumerate()
+        .map(|(value, count)| value as f64 * *count as f64)
+        .sum();
+    let clipped: u64 = histogram[..=SHADOW_CLIP as usize].iter().sum::<u64>()
+        + histogram[HIGHLIGHT_CLIP as usize..].iter().sum::<u64>();
+
+    (weighted / total / 255.0, clipped as f64 / total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgb;
+    use image::imageops::FilterType;
+
+    fn solid(value: u8) -> RgbImage {
+        RgbImage::from_pixel(64, 64, Rgb([value, value, value]))
+    }
+
+    /// 8px 칸의 흑백 체커보드
+    fn checkerboard() -> RgbImage {
+        RgbImage::from_fn(64, 64, |x, y| {
+            let value = if (x / 8 + y / 8) % 2 == 0 { 40 } else { 215 };
+            Rgb([value, value, value])
+        })
+    }
+
+    /// 1/8 로 줄였다 다시 키운 흐린 체커보드
+    fn blurred_checkerboard() -> RgbImage {
+        let small = imageops::resize(&checkerboard(), 8, 8, FilterType::Triangle);
+        imageops::resize(&small, 64, 64, FilterType::Triangle)
+    }
+
+    #[test]
+    fn test_solid_colors_have_no_sharpness() {
+        let quality = LaplacianQualityAnalyzer.assess(&solid(128));
+
+        assert_eq!(quality.sharpness, 0.0);
+        assert!(quality.exposure > 0.95);
+        assert!((quality.brightness - 128.0 / 255.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_checkerboard_is_sharper_than_its_blurred_copy() {
+        let sharp = LaplacianQualityAnalyzer.assess(&checkerboard());
+        let blurred = LaplacianQualityAnalyzer.assess(&blurred_checkerboard());
+
+        assert!(sharp.sharpness > 0.9, "{sharp:?}");
+        assert!(blurred.sharpness < sharp.sharpness / 2.0, "{blurred:?}");
+    }
+
+    #[test]
+    fn test_clipped_black_and_white_are_poorly_exposed() {
+        let black = LaplacianQualityAnalyzer.assess(&solid(0));
+        let white = LaplacianQualityAnalyzer.assess(&solid(255));
+
+        assert_eq!((black.exposure, white.exposure), (0.0, 0.0));
+        assert_eq!((black.brightness, white.brightness), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_dark_but_not_clipped_is_partially_exposed() {
+        let quality = LaplacianQualityAnalyzer.assess(&solid(51));
+
+        // 평균 밝기 0.2 → 1 - |0.2 - 0.5| × 2 = 0.4
+        assert!((quality.exposure - 0.4).abs() < 1e-3, "{quality:?}");
+    }
+}
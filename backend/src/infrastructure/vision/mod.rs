@@ -0,0 +1,9 @@
+pub mod color_histogram_embedder;
+pub mod difference_hasher;
+pub mod kmeans_palette_extractor;
+pub mod laplacian_quality_analyzer;
+#[cfg(feature = "onnx")]
+pub mod onnx_image_classifier;
+pub mod pixel_face_embedder;
+pub mod skin_tone_face_detector;
+pub mod stub_image_classifier;
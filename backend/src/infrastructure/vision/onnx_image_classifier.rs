@@ -0,0 +1,108 @@
+use image::RgbImage;
+use image::imageops::{self, FilterType};
+use ort::session::Session;
+use ort::value::Tensor;
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::domain::services::image_classifier::ImageClassifier;
+use crate::domain::value_objects::image_label::ImageLabel;
+use crate::error::{AppError, AppResult};
+
+/// 모델 입력 크기 (정사각형)
+const INPUT_SIZE: u32 = 224;
+/// ImageNet 정규화 평균/표준편차 (RGB)
+const MEAN: [f32; 3] = [0.485, 0.456, 0.406];
+const STD: [f32; 3] = [0.229, 0.224, 0.225];
+/// 돌려줄 최대 태그 수 (확률 높은 순)
+const TOP_K: usize = 10;
+const DEFAULT_CATEGORY: &str = "object";
+
+/// ONNX Runtime(CPU)으로 실행하는 ImageNet 형식 분류 모델
+///
+/// 입력은 `[1, 3, 224, 224]` 정규화 RGB, 출력은 `[1, 클래스 수]` 로짓입니다.
+/// 레이블 파일은 한 줄에 하나씩 `태그` 또는 `태그<TAB>분류` 형식입니다.
+pub struct OnnxImageClassifier {
+    session: Mutex<Session>,
+    labels: Vec<(String, String)>,
+}
+
+impl OnnxImageClassifier {
+    pub fn load(model_path: &Path, labels_path: &Path) -> AppResult<Self> {
+        let session = Session::builder()
+            .and_then(|builder| builder.commit_from_file(model_path))
+            .map_err(|err| AppError::Config(format!("failed to load classifier model: {err}")))?;
+        let labels = std::fs::read_to_string(labels_path)
+            .map_err(|err| AppError::Config(format!("failed to read classifier labels: {err}")))?
+            .lines()
+            .map(parse_label)
+            .collect();
+
+        Ok(Self {
+            session: Mutex::new(session),
+            labels,
+        })
+    }
+}
+
+impl ImageClassifier for OnnxImageClassifier {
+    fn classify(&self, image: &RgbImage) -> AppResult<Vec<ImageLabel>> {
+        let input = Tensor::from_array((
+            [1usize, 3, INPUT_SIZE as usize, INPUT_SIZE as usize],
+            normalized_pixels(image),
+        ))
+        .map_err(model_error)?;
+
+        let mut session = self
+            .session
+            .lock()
+            .map_err(|_| AppError::Image("classifier session poisoned".to_string()))?;
+        let outputs = session.run(ort::inputs![input]).map_err(model_error)?;
+        let (_, logits) = outputs[0]
+            .try_extract_tensor::<f32>()
+            .map_err(model_error)?;
+
+        let mut scored: Vec<(usize, f32)> = softmax(logits).into_iter().enumerate().collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(scored
+            .into_iter()
+            .take(TOP_K)
+            .filter_map(|(index, probability)| {
+                let (tag, category) = self.labels.get(index)?;
+                Some(ImageLabel::new(tag, category, probability))
+            })
+            .collect())
+    }
+}
+
+fn parse_label(line: &str) -> (String, String) {
+    match line.split_once('\t') {
+        Some((tag, category)) => (tag.trim().to_string(), category.trim().to_string()),
+        None => (line.trim().to_string(), DEFAULT_CATEGORY.to_string()),
+    }
+}
+
+/// 입력 크기로 늘린 뒤 채널 우선(CHW) 순서로 정규화
+fn normalized_pixels(image: &RgbImage) -> Vec<f32> {
+    let resized = imageops::resize(image, INPUT_SIZE, INPUT_SIZE, FilterType::Triangle);
+    let plane = (INPUT_SIZE * INPUT_SIZE) as usize;
+    let mut data = vec![0.0; plane * 3];
+    for (index, pixel) in resized.pixels().enumerate() {
+        for channel in 0..3 {
+            let value = f32::from(pixel.0[channel]) / 255.0;
+            data[channel * plane + index] = (value - MEAN[channel]) / STD[channel];
+        }
+    }
+    data
+}
+
+fn softmax(logits: &[f32]) -> Vec<f32> {
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let exps: Vec<f32> = logits.iter().map(|v| (v - max).exp()).collect();
+    let sum: f32 = exps.iter().sum();
+    exps.into_iter().map(|v| v / sum).collect()
+}
+
+fn model_error(err: ort::Error) -> AppError {
+    AppError::Image(format!("classifier failed: {err}"))
+}
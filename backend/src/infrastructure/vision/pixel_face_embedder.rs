@@ -0,0 +1,27 @@
+use image::RgbImage;
+use image::imageops::{self, FilterType};
+
+use crate::domain::services::face_analyzer::FaceEmbedder;
+use crate::domain::value_objects::face::{FaceBox, FaceEmbedding};
+
+/// 임베딩 격자 한 변의 크기 (벡터 차원 = 한 변²)
+const GRID_SIDE: u32 = 16;
+
+/// 얼굴 영역을 16×16 흑백으로 줄이고 평균 밝기를 뺀 256차원 벡터
+///
+/// 모델 파일 없이 동작하는 기본 구현이라 조명/각도 변화에 약합니다.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PixelFaceEmbedder;
+
+impl FaceEmbedder for PixelFaceEmbedder {
+    fn embed(&self, image: &RgbImage, bounds: FaceBox) -> FaceEmbedding {
+        let (x, y, width, height) = bounds.to_pixels(image.width(), image.height(), 0.0);
+        let face = imageops::crop_imm(image, x, y, width, height).to_image();
+        let gray = imageops::grayscale(&face);
+        let grid = imageops::resize(&gray, GRID_SIDE, GRID_SIDE, FilterType::Triangle);
+
+        let values: Vec<f32> = grid.pixels().map(|pixel| f32::from(pixel.0[0])).collect();
+        let mean = values.iter().sum::<f32>() / values.len() as f32;
+        FaceEmbedding::new(values.into_iter().map(|value| value - mean).collect())
+    }
+}
@@ -0,0 +1,142 @@
+use image::imageops::{self, FilterType};
+use image::{Rgb, RgbImage};
+
+use crate::domain::services::face_analyzer::FaceDetector;
+use crate::domain::value_objects::face::{FaceBox, FaceCandidate};
+
+/// 검출에 사용하는 축소 이미지 크기 (긴 변)
+const WORK_SIZE: u32 = 160;
+/// 얼굴로 볼 최소 크기 (축소 이미지 긴 변 대비)
+const MIN_FACE_RATIO: f32 = 0.06;
+/// 영역 대비 피부색 픽셀 최소 비율
+const MIN_FILL: f32 = 0.45;
+const MAX_FACES: usize = 20;
+
+/// YCbCr 피부색 영역 중 얼굴 모양(세로로 약간 긴 타원)에 가까운 것을 찾는 검출기
+///
+/// 모델 파일 없이 동작하는 기본 구현이며, 정확도가 필요하면 `FaceDetector` 를 구현한 다른 검출기로 교체합니다.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SkinToneFaceDetector;
+
+impl FaceDetector for SkinToneFaceDetector {
+    fn detect(&self, image: &RgbImage) -> Vec<FaceCandidate> {
+        let small = downscale(image);
+        let (width, height) = small.dimensions();
+        let mask: Vec<bool> = small.pixels().map(is_skin).collect();
+
+        let mut faces: Vec<FaceCandidate> = skin_regions(&mask, width, height)
+            .iter()
+            .filter_map(|region| region.to_candidate(width, height))
+            .collect();
+        faces.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        faces.truncate(MAX_FACES);
+        faces
+    }
+}
+
+fn downscale(image: &RgbImage) -> RgbImage {
+    let (width, height) = image.dimensions();
+    let scale = WORK_SIZE as f32 / width.max(height) as f32;
+    if scale >= 1.0 {
+        return image.clone();
+    }
+    let size = |value: u32| ((value as f32 * scale) as u32).max(1);
+    imageops::resize(image, size(width), size(height), FilterType::Triangle)
+}
+
+fn is_skin(pixel: &Rgb<u8>) -> bool {
+    let [r, g, b] = pixel.0.map(f32::from);
+    let cb = 128.0 - 0.168_736 * r - 0.331_264 * g + 0.5 * b;
+    let cr = 128.0 + 0.5 * r - 0.418_688 * g - 0.081_312 * b;
+    (77.0..=127.0).contains(&cb) && (133.0..=173.0).contains(&cr)
+}
+
+/// 서로 이어진 피부색 픽셀 영역
+struct SkinRegion {
+    min_x: u32,
+    min_y: u32,
+    max_x: u32,
+    max_y: u32,
+    area: u32,
+}
+
+impl SkinRegion {
+    fn to_candidate(&self, width: u32, height: u32) -> Option<FaceCandidate> {
+        let (w, h) = (self.max_x - self.min_x + 1, self.max_y - self.min_y + 1);
+        let min_side = ((width.max(height) as f32 * MIN_FACE_RATIO) as u32).max(4);
+        let aspect = h as f32 / w as f32;
+        let fill = self.area as f32 / (w * h) as f32;
+        if w.min(h) < min_side || !(0.8..=2.0).contains(&aspect) || fill < MIN_FILL {
+            return None;
+        }
+
+        // 타원은 외접 사각형의 π/4 를 채우고, 얼굴은 대략 1:1.3 비율
+        let shape = 1.0 - ((aspect - 1.3).abs() / 0.7).min(1.0);
+        let roundness = 1.0 - ((fill - 0.785).abs() / 0.4).min(1.0);
+        let bounds = FaceBox {
+            x: self.min_x as f32 / width as f32,
+            y: self.min_y as f32 / height as f32,
+            width: w as f32 / width as f32,
+            height: h as f32 / height as f32,
+        };
+        Some(FaceCandidate {
+            bounds,
+            confidence: (0.4 + 0.3 * shape + 0.3 * roundness).min(0.99),
+        })
+    }
+}
+
+/// 4방향으로 이어진 피부색 영역 목록 (flood fill)
+fn skin_regions(mask: &[bool], width: u32, height: u32) -> Vec<SkinRegion> {
+    let mut visited = vec![false; mask.len()];
+    let mut regions = Vec::new();
+
+    for start in 0..mask.len() {
+        if mask[start] && !visited[start] {
+            regions.push(fill_region(mask, &mut visited, start, width, height));
+        }
+    }
+    regions
+}
+
+fn fill_region(
+    mask: &[bool],
+    visited: &mut [bool],
+    start: usize,
+    width: u32,
+    height: u32,
+) -> SkinRegion {
+    let (x, y) = (start as u32 % width, start as u32 / width);
+    let mut region = SkinRegion {
+        min_x: x,
+        min_y: y,
+        max_x: x,
+        max_y: y,
+        area: 0,
+    };
+    let mut stack = vec![start];
+    visited[start] = true;
+
+    while let Some(index) = stack.pop() {
+        let (x, y) = (index as u32 % width, index as u32 / width);
+        region.min_x = region.min_x.min(x);
+        region.max_x = region.max_x.max(x);
+        region.min_y = region.min_y.min(y);
+        region.max_y = region.max_y.max(y);
+        region.area += 1;
+
+        let neighbors = [
+            (x > 0).then(|| index - 1),
+            (x + 1 < width).then(|| index + 1),
+            (y > 0).then(|| index - width as usize),
+            (y + 1 < height).then(|| index + width as usize),
+        ];
+        for next in neighbors.into_iter().flatten() {
+            if mask[next] && !visited[next] {
+                visited[next] = true;
+                stack.push(next);
+            }
+        }
+    }
+    region
+}
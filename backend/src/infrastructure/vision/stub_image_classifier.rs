@@ -0,0 +1,88 @@
+use image::RgbImage;
+
+use crate::domain::services::image_classifier::ImageClassifier;
+use crate::domain::value_objects::image_label::ImageLabel;
+use crate::error::AppResult;
+
+/// 평균 밝기와 평균색만 보고 태그를 붙이는 결정적 분류기
+///
+/// 모델 없이 자동 태그 흐름을 확인하거나 테스트할 때 사용합니다. 같은 이미지에는 항상 같은 결과를 냅니다.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StubImageClassifier;
+
+impl ImageClassifier for StubImageClassifier {
+    fn classify(&self, image: &RgbImage) -> AppResult<Vec<ImageLabel>> {
+        let Some([r, g, b]) = mean_color(image) else {
+            return Ok(Vec::new());
+        };
+
+        let brightness = (0.299 * r + 0.587 * g + 0.114 * b) / 255.0;
+        let scene = if brightness >= 0.5 { "bright" } else { "dark" };
+        let total = (r + g + b).max(1.0);
+        let (color, share) = [("red", r), ("green", g), ("blue", b)]
+            .into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(name, value)| (name, value / total))
+            .unwrap_or(("red", 0.0));
+
+        let mut labels = vec![
+            ImageLabel::new(scene, "scene", ((brightness - 0.5).abs() * 2.0) as f32),
+            // 세 채널이 같으면 1/3, 한 채널만 있으면 1
+            ImageLabel::new(color, "color", ((share - 1.0 / 3.0) * 1.5) as f32),
+        ];
+        labels.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        Ok(labels)
+    }
+}
+
+fn mean_color(image: &RgbImage) -> Option<[f64; 3]> {
+    let count = image.pixels().len();
+    if count == 0 {
+        return None;
+    }
+    let mut sum = [0.0; 3];
+    for pixel in image.pixels() {
+        for (total, value) in sum.iter_mut().zip(pixel.0) {
+            *total += f64::from(value);
+        }
+    }
+    Some(sum.map(|total| total / count as f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgb;
+
+    fn solid(color: [u8; 3]) -> RgbImage {
+        RgbImage::from_pixel(8, 8, Rgb(color))
+    }
+
+    #[test]
+    fn test_labels_dominant_color_and_brightness() {
+        let labels = StubImageClassifier.classify(&solid([20, 30, 220])).unwrap();
+
+        let tags: Vec<&str> = labels.iter().map(|l| l.tag.as_str()).collect();
+        assert_eq!(tags, ["blue", "dark"]);
+        assert!(labels[0].confidence > labels[1].confidence);
+    }
+
+    #[test]
+    fn test_same_image_gives_same_labels() {
+        let image = solid([200, 180, 40]);
+        assert_eq!(
+            StubImageClassifier.classify(&image).unwrap(),
+            StubImageClassifier.classify(&image).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_empty_image_has_no_labels() {
+        assert!(
+            StubImageClassifier
+                .classify(&RgbImage::new(0, 0))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
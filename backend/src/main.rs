@@ -0,0 +1,48 @@
+use backend::config::AppConfig;
+use backend::infrastructure::database::connect_pool;
+use backend::infrastructure::geocoding::gazetteer::Gazetteer;
+use backend::infrastructure::processing::analyzers::{Analyzers, AutoTagger};
+use backend::infrastructure::processing::processing_worker::ProcessingWorker;
+use backend::infrastructure::storage::fs_image_store::FsImageStore;
+use backend::presentation::routes::build_router;
+use backend::presentation::state::AppState;
+use std::sync::Arc;
+use tracing_subscriber::EnvFilter;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()))
+        .init();
+
+    let config = AppConfig::from_env()?;
+    let pool = connect_pool(&config.database_url).await?;
+    let gazetteer = match &config.gazetteer_path {
+        Some(path) => Gazetteer::from_file(path)?,
+        None => Gazetteer::bundled(),
+    };
+    tracing::info!("gazetteer loaded: {} places", gazetteer.len());
+    let auto_tagger = AutoTagger::from_config(&config)?;
+    if let Some(auto_tagger) = &auto_tagger {
+        tracing::info!(
+            "auto tagging enabled (min confidence {})",
+            auto_tagger.min_confidence
+        );
+    }
+    let analyzers = Arc::new(Analyzers::new(gazetteer).with_auto_tagger(auto_tagger));
+    let images = FsImageStore::new(config.media_root.clone());
+    ProcessingWorker::new(pool.clone(), images, analyzers.clone()).spawn();
+    let state = AppState::new(pool, config.clone(), analyzers);
+
+    let listener = tokio::net::TcpListener::bind(&config.bind_addr).await?;
+    tracing::info!("PhotoVault API listening on {}", config.bind_addr);
+
+    axum::serve(listener, build_router(state))
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
+    Ok(())
+}
+
+async fn shutdown_signal() {
+    let _ = tokio::signal::ctrl_c().await;
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::album::Album;
+
+#[derive(Serialize, Debug)]
+pub struct AlbumDto {
+    pub id: Uuid,
+    pub title: String,
+    pub photo_count: i64,
+}
+
+impl From<&Album> for AlbumDto {
+    fn from(album: &Album) -> Self {
+        Self {
+            id: album.id,
+            title: album.title.clone(),
+            photo_count: album.photo_count,
+        }
+    }
+}
+
+/// `GET /api/albums` 응답
+#[derive(Serialize, Debug)]
+pub struct AlbumsResponse {
+    pub albums: Vec<AlbumDto>,
+}
+
+/// `POST /api/albums` 요청
+#[derive(Deserialize, Debug)]
+pub struct CreateAlbumRequest {
+    pub title: String,
+}
+
+/// `POST /api/albums/{id}/photos` 요청
+#[derive(Deserialize, Debug)]
+pub struct AddAlbumPhotosRequest {
+    pub photo_ids: Vec<Uuid>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct AddAlbumPhotosResponse {
+    pub added: u64,
+}
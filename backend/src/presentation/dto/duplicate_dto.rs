@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::duplicate::{DuplicateGroup, DuplicatePhoto};
+use crate::presentation::dto::photo_dto::PhotoDto;
+
+#[derive(Serialize, Debug)]
+pub struct DuplicatePhotoDto {
+    #[serde(flatten)]
+    pub photo: PhotoDto,
+    pub file_size_bytes: i64,
+}
+
+impl From<&DuplicatePhoto> for DuplicatePhotoDto {
+    fn from(photo: &DuplicatePhoto) -> Self {
+        Self {
+            photo: PhotoDto::from(&photo.photo),
+            file_size_bytes: photo.file_size_bytes,
+        }
+    }
+}
+
+/// 유사 중복 그룹 (`photos[0]` 이 남길 것을 권장하는 사본)
+#[derive(Serialize, Debug)]
+pub struct DuplicateGroupDto {
+    pub photos: Vec<DuplicatePhotoDto>,
+}
+
+impl From<&DuplicateGroup> for DuplicateGroupDto {
+    fn from(group: &DuplicateGroup) -> Self {
+        Self {
+            photos: group.photos.iter().map(DuplicatePhotoDto::from).collect(),
+        }
+    }
+}
+
+/// `GET /api/duplicates` 응답
+#[derive(Serialize, Debug)]
+pub struct DuplicatesResponse {
+    pub groups: Vec<DuplicateGroupDto>,
+    /// 사진 쌍이 너무 많아 가까운 쌍까지만 묶었는지
+    pub truncated: bool,
+}
+
+/// `POST /api/photos/trash` 요청
+#[derive(Deserialize, Debug)]
+pub struct TrashPhotosRequest {
+    pub photo_ids: Vec<Uuid>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TrashPhotosResponse {
+    pub trashed: u64,
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::face::{FaceAssignment, FaceBox, PhotoFace};
+
+#[derive(Serialize, Debug)]
+pub struct PhotoFaceDto {
+    pub id: Uuid,
+    pub person_id: Option<Uuid>,
+    pub person_name: Option<String>,
+    /// 방향 보정된 이미지 대비 0~1 비율
+    pub rectangle: FaceBox,
+    pub confidence: f32,
+    pub is_manual: bool,
+}
+
+impl From<&PhotoFace> for PhotoFaceDto {
+    fn from(face: &PhotoFace) -> Self {
+        Self {
+            id: face.id,
+            person_id: face.person_id,
+            person_name: face.person_name.clone(),
+            rectangle: face.bounds,
+            confidence: face.confidence,
+            is_manual: face.is_manual,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PhotoFacesResponse {
+    pub faces: Vec<PhotoFaceDto>,
+}
+
+/// 얼굴을 배정할 인물: `person_id` 가 있으면 기존 인물, 없으면 `new_person_name` 으로 새 인물
+#[derive(Deserialize, Debug)]
+pub struct FaceTargetDto {
+    pub person_id: Option<Uuid>,
+    pub new_person_name: Option<String>,
+}
+
+impl FaceTargetDto {
+    pub fn to_assignment(&self) -> FaceAssignment {
+        match self.person_id {
+            Some(person_id) => FaceAssignment::Person(person_id),
+            None => FaceAssignment::NewPerson(self.new_person_name.clone()),
+        }
+    }
+}
+
+/// `POST /api/photos/{id}/faces` 본문
+#[derive(Deserialize, Debug)]
+pub struct AddFaceRequest {
+    pub rectangle: FaceBox,
+    #[serde(flatten)]
+    pub target: FaceTargetDto,
+}
+
+#[derive(Serialize, Debug)]
+pub struct FaceTagResponse {
+    pub face_id: Uuid,
+    pub person_id: Uuid,
+}
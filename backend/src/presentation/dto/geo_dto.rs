@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::geo_area::GeoCluster;
+use crate::presentation::dto::photo_dto::media_url;
+
+/// `GET /api/geo/bbox`, `GET /api/geo/radius` 의 지도 배율 파라미터
+///
+/// 영역은 `SearchParams` 의 `west/south/east/north` 또는 `lat/lng/radius_m` 로 받습니다.
+#[derive(Deserialize, Debug)]
+pub struct ClusterParams {
+    pub zoom: u8,
+}
+
+#[derive(Serialize, Debug)]
+pub struct GeoClusterDto {
+    pub lat: f64,
+    pub lng: f64,
+    pub count: i64,
+    pub west: f64,
+    pub south: f64,
+    pub east: f64,
+    pub north: f64,
+    pub cover_photo_id: Uuid,
+    pub cover_thumbnail_url: String,
+}
+
+impl From<&GeoCluster> for GeoClusterDto {
+    fn from(cluster: &GeoCluster) -> Self {
+        Self {
+            lat: cluster.center.lat,
+            lng: cluster.center.lng,
+            count: cluster.count,
+            west: cluster.bounds.west,
+            south: cluster.bounds.south,
+            east: cluster.bounds.east,
+            north: cluster.bounds.north,
+            cover_photo_id: cluster.cover_photo_id,
+            cover_thumbnail_url: media_url(&cluster.cover_path),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct GeoClustersResponse {
+    pub clusters: Vec<GeoClusterDto>,
+}
@@ -0,0 +1,12 @@
+pub mod album_dto;
+pub mod duplicate_dto;
+pub mod face_dto;
+pub mod geo_dto;
+pub mod person_dto;
+pub mod photo_batch_dto;
+pub mod photo_dto;
+pub mod place_dto;
+pub mod quality_dto;
+pub mod search_dto;
+pub mod similar_dto;
+pub mod timeline_dto;
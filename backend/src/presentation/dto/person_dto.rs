@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::face::{FaceBox, Person, PersonFace};
+
+/// 얼굴 썸네일 URL (`GET /api/faces/{id}/crop`)
+pub fn face_crop_url(face_id: Uuid) -> String {
+    format!("/api/faces/{face_id}/crop")
+}
+
+#[derive(Serialize, Debug)]
+pub struct PersonDto {
+    pub id: Uuid,
+    pub name: Option<String>,
+    pub face_count: i64,
+    pub cover_url: Option<String>,
+}
+
+impl From<&Person> for PersonDto {
+    fn from(person: &Person) -> Self {
+        Self {
+            id: person.id,
+            name: person.name.clone(),
+            face_count: person.face_count,
+            cover_url: person.cover_face_id.map(face_crop_url),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PeopleResponse {
+    pub people: Vec<PersonDto>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PersonFaceDto {
+    pub id: Uuid,
+    pub photo_id: Uuid,
+    /// 방향 보정된 이미지 대비 0~1 비율
+    pub rectangle: FaceBox,
+    pub confidence: f32,
+    pub crop_url: String,
+}
+
+impl From<&PersonFace> for PersonFaceDto {
+    fn from(face: &PersonFace) -> Self {
+        Self {
+            id: face.id,
+            photo_id: face.photo_id,
+            rectangle: face.bounds,
+            confidence: face.confidence,
+            crop_url: face_crop_url(face.id),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PersonFacesResponse {
+    pub faces: Vec<PersonFaceDto>,
+}
+
+/// `PATCH /api/people/{id}` 본문 (`name` 이 없거나 비어 있으면 이름 제거)
+#[derive(Deserialize, Debug)]
+pub struct RenamePersonRequest {
+    pub name: Option<String>,
+}
+
+/// `POST /api/people/{id}/merge` 본문
+#[derive(Deserialize, Debug)]
+pub struct MergePeopleRequest {
+    pub person_ids: Vec<Uuid>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct MergePeopleResponse {
+    pub moved_faces: u64,
+}
+
+/// `POST /api/people/{id}/split` 본문
+#[derive(Deserialize, Debug)]
+pub struct SplitPersonRequest {
+    pub face_ids: Vec<Uuid>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SplitPersonResponse {
+    pub person_id: Uuid,
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// `POST /api/photos/favorites` 요청
+#[derive(Deserialize, Debug)]
+pub struct SetFavoritesRequest {
+    pub photo_ids: Vec<Uuid>,
+    pub liked: bool,
+}
+
+/// `POST /api/photos/tags` 요청
+#[derive(Deserialize, Debug)]
+pub struct TagPhotosRequest {
+    pub photo_ids: Vec<Uuid>,
+    pub tags: Vec<String>,
+}
+
+/// `POST /api/photos/download` 요청
+#[derive(Deserialize, Debug)]
+pub struct DownloadPhotosRequest {
+    pub photo_ids: Vec<Uuid>,
+}
+
+/// `POST /api/photos/download` 응답 (브라우저가 직접 받을 ZIP 주소, 한 번만 유효)
+#[derive(Serialize, Debug)]
+pub struct DownloadTicketResponse {
+    pub url: String,
+}
+
+/// 여러 사진 변경 결과 (실제로 바뀐 수)
+#[derive(Serialize, Debug)]
+pub struct PhotoBatchResponse {
+    pub updated: u64,
+}
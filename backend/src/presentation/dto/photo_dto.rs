@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::photo::Photo;
+
+/// UI `Photo` 엔티티에 대응하는 응답 모델
+///
+/// 파일 URL은 API 서버 기준 상대 경로(`/media/...`)입니다.
+#[derive(Serialize, Debug)]
+pub struct PhotoDto {
+    pub id: Uuid,
+    pub url: String,
+    pub thumbnail_url: String,
+    pub preview_url: String,
+    pub title: String,
+    pub taken_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub is_liked: bool,
+    pub width: u32,
+    pub height: u32,
+    /// `#rrggbb` 대표 색 (많이 쓰인 순)
+    pub dominant_colors: Vec<String>,
+    /// 선명도/노출 점수 0~1 (평가 전이면 `null`)
+    pub sharpness: Option<f32>,
+    pub exposure: Option<f32>,
+}
+
+impl From<&Photo> for PhotoDto {
+    fn from(photo: &Photo) -> Self {
+        let original = media_url(&photo.storage_path);
+        let preview = photo
+            .preview_path
+            .as_deref()
+            .map_or_else(|| original.clone(), media_url);
+        let thumbnail = photo
+            .thumbnail_path
+            .as_deref()
+            .map_or_else(|| preview.clone(), media_url);
+
+        Self {
+            id: photo.id,
+            url: original,
+            thumbnail_url: thumbnail,
+            preview_url: preview,
+            title: photo.title().to_string(),
+            taken_at: photo.taken_at,
+            created_at: photo.created_at,
+            is_liked: photo.is_liked,
+            width: photo.width,
+            height: photo.height,
+            dominant_colors: photo.dominant_colors.iter().map(|c| c.to_hex()).collect(),
+            sharpness: photo.quality.map(|q| q.sharpness),
+            exposure: photo.quality.map(|q| q.exposure),
+        }
+    }
+}
+
+/// 저장소 상대 경로 → `/media/...` URL
+pub fn media_url(path: &str) -> String {
+    format!("/media/{}", path.trim_start_matches('/'))
+}
@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+use crate::domain::value_objects::place::{CountryPlaces, PlaceCount};
+use crate::presentation::dto::photo_dto::media_url;
+
+#[derive(Serialize, Debug)]
+pub struct CityPlaceDto {
+    pub city: String,
+    pub region: String,
+    /// 검색 필터(`place`)에 그대로 사용할 수 있는 위치명
+    pub location_name: String,
+    pub count: i64,
+    pub cover_thumbnail_url: String,
+}
+
+impl From<&PlaceCount> for CityPlaceDto {
+    fn from(city: &PlaceCount) -> Self {
+        Self {
+            city: city.place.city.clone(),
+            region: city.place.region.clone(),
+            location_name: city.place.display_name(),
+            count: city.count,
+            cover_thumbnail_url: media_url(&city.cover_path),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct CountryPlacesDto {
+    pub country: String,
+    pub country_code: String,
+    pub count: i64,
+    pub cities: Vec<CityPlaceDto>,
+}
+
+impl From<&CountryPlaces> for CountryPlacesDto {
+    fn from(country: &CountryPlaces) -> Self {
+        Self {
+            country: country.country.clone(),
+            country_code: country.country_code.clone(),
+            count: country.count,
+            cities: country.cities.iter().map(CityPlaceDto::from).collect(),
+        }
+    }
+}
+
+/// `GET /api/places` 응답
+#[derive(Serialize, Debug)]
+pub struct PlacesResponse {
+    pub countries: Vec<CountryPlacesDto>,
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::application::use_cases::list_cleanup_suggestions::CleanupSuggestion;
+use crate::presentation::dto::photo_dto::PhotoDto;
+
+/// `GET /api/photos/cleanup` 쿼리 파라미터
+#[derive(Deserialize, Debug, Default)]
+pub struct CleanupParams {
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct CleanupSuggestionDto {
+    #[serde(flatten)]
+    pub photo: PhotoDto,
+    /// `blurry`, `underexposed`, `overexposed`
+    pub issues: Vec<&'static str>,
+}
+
+impl From<&CleanupSuggestion> for CleanupSuggestionDto {
+    fn from(suggestion: &CleanupSuggestion) -> Self {
+        Self {
+            photo: PhotoDto::from(&suggestion.photo),
+            issues: suggestion.issues.iter().map(|i| i.as_str()).collect(),
+        }
+    }
+}
+
+/// `GET /api/photos/cleanup` 응답 (가장 나쁜 점수 순)
+#[derive(Serialize, Debug)]
+pub struct CleanupResponse {
+    pub photos: Vec<CleanupSuggestionDto>,
+}
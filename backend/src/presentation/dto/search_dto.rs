@@ -0,0 +1,200 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::color::{ColorFilter, Rgb};
+use crate::domain::value_objects::exif_filter::{ExifFilter, NumericRange};
+use crate::domain::value_objects::geo_area::{BoundingBox, GeoArea, GeoPoint};
+use crate::domain::value_objects::image_quality::QualityFilter;
+use crate::domain::value_objects::search_facets::{RangeCount, SearchFacets, ValueCount};
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::presentation::dto::photo_dto::PhotoDto;
+
+/// `GET /api/search` 쿼리 파라미터
+#[derive(Deserialize, Debug, Default)]
+pub struct SearchParams {
+    pub q: Option<String>,
+    pub date_from: Option<NaiveDate>,
+    pub date_to: Option<NaiveDate>,
+    pub camera: Option<String>,
+    pub lens: Option<String>,
+    /// 쉼표로 구분된 태그 목록 (`tags=beach,sunset`)
+    pub tags: Option<String>,
+    pub place: Option<String>,
+    pub liked: Option<bool>,
+    pub has_location: Option<bool>,
+    // EXIF 범위 필터: [min, max)
+    pub focal_min: Option<f64>,
+    pub focal_max: Option<f64>,
+    pub aperture_min: Option<f64>,
+    pub aperture_max: Option<f64>,
+    pub shutter_min: Option<f64>,
+    pub shutter_max: Option<f64>,
+    pub iso_min: Option<f64>,
+    pub iso_max: Option<f64>,
+    pub flash: Option<bool>,
+    // 지도 영역: 사각 영역(west, south, east, north) 또는 반경(lat, lng, radius_m)
+    pub west: Option<f64>,
+    pub south: Option<f64>,
+    pub east: Option<f64>,
+    pub north: Option<f64>,
+    pub lat: Option<f64>,
+    pub lng: Option<f64>,
+    pub radius_m: Option<f64>,
+    /// 인물 군집 id (`people.id`)
+    pub person: Option<Uuid>,
+    /// 앨범 id (`albums.id`)
+    pub album: Option<Uuid>,
+    /// 팔레트 색상 (`#rrggbb` 또는 `rrggbb`) 과 허용 색차 (CIELAB ΔE, 기본 20)
+    pub color: Option<Rgb>,
+    pub color_distance: Option<f32>,
+    // 화질 점수 범위 필터 (0~1): [min, max)
+    pub sharpness_min: Option<f64>,
+    pub sharpness_max: Option<f64>,
+    pub exposure_min: Option<f64>,
+    pub exposure_max: Option<f64>,
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+}
+
+impl SearchParams {
+    pub fn to_filter(&self) -> SearchFilter {
+        SearchFilter {
+            text: self.q.clone(),
+            date_from: self.date_from,
+            date_to: self.date_to,
+            camera: self.camera.clone(),
+            lens: self.lens.clone(),
+            tags: self
+                .tags
+                .as_deref()
+                .map(|tags| tags.split(',').map(str::to_string).collect())
+                .unwrap_or_default(),
+            place: self.place.clone(),
+            is_liked: self.liked,
+            has_location: self.has_location,
+            exif: self.to_exif_filter(),
+            area: self.to_area(),
+            person_id: self.person,
+            album_id: self.album,
+            color: self
+                .color
+                .map(|color| ColorFilter::new(color, self.color_distance)),
+            quality: QualityFilter {
+                sharpness: NumericRange::new(self.sharpness_min, self.sharpness_max),
+                exposure: NumericRange::new(self.exposure_min, self.exposure_max),
+            },
+        }
+    }
+
+    /// 사각 영역이 있으면 사각 영역, 없으면 반경 영역
+    pub fn to_area(&self) -> Option<GeoArea> {
+        self.to_bounding_box().or_else(|| self.to_radius())
+    }
+
+    pub fn to_bounding_box(&self) -> Option<GeoArea> {
+        let (west, south, east, north) = (self.west?, self.south?, self.east?, self.north?);
+        Some(GeoArea::BoundingBox(BoundingBox {
+            west,
+            south,
+            east,
+            north,
+        }))
+    }
+
+    pub fn to_radius(&self) -> Option<GeoArea> {
+        Some(GeoArea::Radius {
+            center: GeoPoint {
+                lat: self.lat?,
+                lng: self.lng?,
+            },
+            meters: self.radius_m?,
+        })
+    }
+
+    fn to_exif_filter(&self) -> ExifFilter {
+        ExifFilter {
+            focal_length: NumericRange::new(self.focal_min, self.focal_max),
+            aperture: NumericRange::new(self.aperture_min, self.aperture_max),
+            shutter_speed: NumericRange::new(self.shutter_min, self.shutter_max),
+            iso: NumericRange::new(self.iso_min, self.iso_max),
+            flash: self.flash,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PhotoPageResponse {
+    pub photos: Vec<PhotoDto>,
+    pub next_cursor: Option<String>,
+}
+
+/// `GET /api/photos/{id}` 응답: 사진과 앞뒤 이웃 사진
+#[derive(Serialize, Debug)]
+pub struct PhotoContextResponse {
+    pub photo: PhotoDto,
+    /// 더 최근 사진 (타임라인 순서)
+    pub previous: Vec<PhotoDto>,
+    /// 이후 사진 (`next_cursor` 로 이어서 조회)
+    pub next: Vec<PhotoDto>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ValueFacetDto<T> {
+    pub value: T,
+    pub count: i64,
+}
+
+impl<T: Clone> From<&ValueCount<T>> for ValueFacetDto<T> {
+    fn from(facet: &ValueCount<T>) -> Self {
+        Self {
+            value: facet.value.clone(),
+            count: facet.count,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct RangeFacetDto {
+    pub label: String,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub count: i64,
+}
+
+impl From<&RangeCount> for RangeFacetDto {
+    fn from(facet: &RangeCount) -> Self {
+        Self {
+            label: facet.label.to_string(),
+            min: facet.range.min,
+            max: facet.range.max,
+            count: facet.count,
+        }
+    }
+}
+
+/// `GET /api/search/facets` 응답
+#[derive(Serialize, Debug)]
+pub struct SearchFacetsResponse {
+    pub lenses: Vec<ValueFacetDto<String>>,
+    pub focal_length: Vec<RangeFacetDto>,
+    pub aperture: Vec<RangeFacetDto>,
+    pub shutter_speed: Vec<RangeFacetDto>,
+    pub iso: Vec<RangeFacetDto>,
+    pub flash: Vec<ValueFacetDto<bool>>,
+}
+
+impl From<SearchFacets> for SearchFacetsResponse {
+    fn from(facets: SearchFacets) -> Self {
+        let ranges = |counts: &[RangeCount]| counts.iter().map(RangeFacetDto::from).collect();
+        Self {
+            lenses: facets.lenses.iter().map(ValueFacetDto::from).collect(),
+            focal_length: ranges(&facets.focal_length),
+            aperture: ranges(&facets.aperture),
+            shutter_speed: ranges(&facets.shutter_speed),
+            iso: ranges(&facets.iso),
+            flash: facets.flash.iter().map(ValueFacetDto::from).collect(),
+        }
+    }
+}
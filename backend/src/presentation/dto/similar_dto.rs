@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::image_embedding::SimilarPhoto;
+use crate::presentation::dto::photo_dto::PhotoDto;
+
+/// `GET /api/photos/{id}/similar` 쿼리 파라미터
+#[derive(Deserialize, Debug, Default)]
+pub struct SimilarParams {
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SimilarPhotoDto {
+    #[serde(flatten)]
+    pub photo: PhotoDto,
+    /// 코사인 유사도 (1에 가까울수록 비슷함)
+    pub similarity: f32,
+}
+
+impl From<&SimilarPhoto> for SimilarPhotoDto {
+    fn from(similar: &SimilarPhoto) -> Self {
+        Self {
+            photo: PhotoDto::from(&similar.photo),
+            similarity: similar.similarity,
+        }
+    }
+}
+
+/// `GET /api/photos/{id}/similar` 응답 (유사도 높은 순)
+#[derive(Serialize, Debug)]
+pub struct SimilarPhotosResponse {
+    pub photos: Vec<SimilarPhotoDto>,
+}
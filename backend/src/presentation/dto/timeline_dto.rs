@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+use crate::domain::value_objects::timeline::TimelineMonth;
+
+#[derive(Serialize, Debug)]
+pub struct TimelineMonthDto {
+    pub year: i32,
+    pub month: u32,
+    pub count: i64,
+    /// 이 달로 이동할 때 `GET /api/search` 에 넘기는 커서
+    pub cursor: String,
+}
+
+impl From<&TimelineMonth> for TimelineMonthDto {
+    fn from(month: &TimelineMonth) -> Self {
+        Self {
+            year: month.year,
+            month: month.month,
+            count: month.count,
+            cursor: month.cursor().encode(),
+        }
+    }
+}
+
+/// `GET /api/timeline` 응답 (최근 달부터)
+#[derive(Serialize, Debug)]
+pub struct TimelineResponse {
+    pub total: i64,
+    pub months: Vec<TimelineMonthDto>,
+}
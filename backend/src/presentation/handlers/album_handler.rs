@@ -0,0 +1,65 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+use crate::application::use_cases::add_photos_to_album::AddPhotosToAlbumUseCase;
+use crate::application::use_cases::create_album::CreateAlbumUseCase;
+use crate::application::use_cases::get_album::GetAlbumUseCase;
+use crate::application::use_cases::list_albums::ListAlbumsUseCase;
+use crate::error::AppResult;
+use crate::presentation::dto::album_dto::{
+    AddAlbumPhotosRequest, AddAlbumPhotosResponse, AlbumDto, AlbumsResponse, CreateAlbumRequest,
+};
+use crate::presentation::state::AppState;
+
+/// `GET /api/albums` - 앨범 목록 (최근에 바뀐 순)
+pub async fn list_albums(State(state): State<AppState>) -> AppResult<Json<AlbumsResponse>> {
+    let repository = state.album_repository();
+    let albums = ListAlbumsUseCase::new(&repository)
+        .execute(state.config.owner_id)
+        .await?;
+
+    Ok(Json(AlbumsResponse {
+        albums: albums.iter().map(AlbumDto::from).collect(),
+    }))
+}
+
+/// `GET /api/albums/{id}` - 앨범 하나 (앨범 화면 제목)
+pub async fn get_album(
+    State(state): State<AppState>,
+    Path(album_id): Path<Uuid>,
+) -> AppResult<Json<AlbumDto>> {
+    let repository = state.album_repository();
+    let album = GetAlbumUseCase::new(&repository)
+        .execute(state.config.owner_id, album_id)
+        .await?;
+
+    Ok(Json(AlbumDto::from(&album)))
+}
+
+/// `POST /api/albums` - 빈 앨범 만들기
+pub async fn create_album(
+    State(state): State<AppState>,
+    Json(request): Json<CreateAlbumRequest>,
+) -> AppResult<Json<AlbumDto>> {
+    let repository = state.album_repository();
+    let album = CreateAlbumUseCase::new(&repository)
+        .execute(state.config.owner_id, &request.title)
+        .await?;
+
+    Ok(Json(AlbumDto::from(&album)))
+}
+
+/// `POST /api/albums/{id}/photos` - 선택한 사진을 앨범에 추가
+pub async fn add_album_photos(
+    State(state): State<AppState>,
+    Path(album_id): Path<Uuid>,
+    Json(request): Json<AddAlbumPhotosRequest>,
+) -> AppResult<Json<AddAlbumPhotosResponse>> {
+    let repository = state.album_repository();
+    let added = AddPhotosToAlbumUseCase::new(&repository)
+        .execute(state.config.owner_id, album_id, &request.photo_ids)
+        .await?;
+
+    Ok(Json(AddAlbumPhotosResponse { added }))
+}
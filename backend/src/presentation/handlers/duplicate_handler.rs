@@ -0,0 +1,36 @@
+use axum::Json;
+use axum::extract::State;
+
+use crate::application::use_cases::find_duplicates::FindDuplicatesUseCase;
+use crate::application::use_cases::trash_photos::TrashPhotosUseCase;
+use crate::error::AppResult;
+use crate::presentation::dto::duplicate_dto::{
+    DuplicateGroupDto, DuplicatesResponse, TrashPhotosRequest, TrashPhotosResponse,
+};
+use crate::presentation::state::AppState;
+
+/// `GET /api/duplicates` - 지각 해시가 가까운 유사 중복 사진 그룹
+pub async fn list_duplicates(State(state): State<AppState>) -> AppResult<Json<DuplicatesResponse>> {
+    let repository = state.duplicate_repository();
+    let review = FindDuplicatesUseCase::new(&repository)
+        .execute(state.config.owner_id)
+        .await?;
+
+    Ok(Json(DuplicatesResponse {
+        groups: review.groups.iter().map(DuplicateGroupDto::from).collect(),
+        truncated: review.truncated,
+    }))
+}
+
+/// `POST /api/photos/trash` - 선택한 사진을 휴지통으로 이동
+pub async fn trash_photos(
+    State(state): State<AppState>,
+    Json(request): Json<TrashPhotosRequest>,
+) -> AppResult<Json<TrashPhotosResponse>> {
+    let repository = state.photo_repository();
+    let trashed = TrashPhotosUseCase::new(&repository)
+        .execute(state.config.owner_id, &request.photo_ids)
+        .await?;
+
+    Ok(Json(TrashPhotosResponse { trashed }))
+}
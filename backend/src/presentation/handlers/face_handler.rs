@@ -0,0 +1,83 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use uuid::Uuid;
+
+use crate::application::use_cases::tag_face::TagFaceUseCase;
+use crate::domain::repositories::person_repository::PersonRepository;
+use crate::error::AppResult;
+use crate::presentation::dto::face_dto::{
+    AddFaceRequest, FaceTagResponse, FaceTargetDto, PhotoFaceDto, PhotoFacesResponse,
+};
+use crate::presentation::state::AppState;
+
+/// `GET /api/photos/{id}/faces` - 사진의 얼굴 영역과 배정된 인물
+pub async fn list_photo_faces(
+    State(state): State<AppState>,
+    Path(photo_id): Path<Uuid>,
+) -> AppResult<Json<PhotoFacesResponse>> {
+    let faces = state
+        .person_repository()
+        .photo_faces(state.config.owner_id, photo_id)
+        .await?;
+
+    Ok(Json(PhotoFacesResponse {
+        faces: faces.iter().map(PhotoFaceDto::from).collect(),
+    }))
+}
+
+/// `POST /api/photos/{id}/faces` - 검출되지 않은 얼굴을 직접 추가
+pub async fn add_face(
+    State(state): State<AppState>,
+    Path(photo_id): Path<Uuid>,
+    Json(request): Json<AddFaceRequest>,
+) -> AppResult<Json<FaceTagResponse>> {
+    let (people, photos, images) = (
+        state.person_repository(),
+        state.photo_repository(),
+        state.image_store(),
+    );
+    let (face_id, person_id) =
+        TagFaceUseCase::new(&people, &photos, &images, state.face_embedder())
+            .add(
+                state.config.owner_id,
+                photo_id,
+                request.rectangle,
+                &request.target.to_assignment(),
+            )
+            .await?;
+    Ok(Json(FaceTagResponse { face_id, person_id }))
+}
+
+/// `PATCH /api/faces/{id}` - 얼굴의 인물 변경
+pub async fn assign_face(
+    State(state): State<AppState>,
+    Path(face_id): Path<Uuid>,
+    Json(target): Json<FaceTargetDto>,
+) -> AppResult<Json<FaceTagResponse>> {
+    let (people, photos, images) = (
+        state.person_repository(),
+        state.photo_repository(),
+        state.image_store(),
+    );
+    let person_id = TagFaceUseCase::new(&people, &photos, &images, state.face_embedder())
+        .assign(state.config.owner_id, face_id, &target.to_assignment())
+        .await?;
+    Ok(Json(FaceTagResponse { face_id, person_id }))
+}
+
+/// `DELETE /api/faces/{id}` - 얼굴이 아닌 검출 결과 삭제
+pub async fn remove_face(
+    State(state): State<AppState>,
+    Path(face_id): Path<Uuid>,
+) -> AppResult<StatusCode> {
+    let (people, photos, images) = (
+        state.person_repository(),
+        state.photo_repository(),
+        state.image_store(),
+    );
+    TagFaceUseCase::new(&people, &photos, &images, state.face_embedder())
+        .remove(state.config.owner_id, face_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,55 @@
+use axum::Json;
+use axum::extract::{Query, State};
+
+use crate::application::use_cases::get_geo_clusters::GetGeoClustersUseCase;
+use crate::domain::value_objects::geo_area::GeoArea;
+use crate::error::{AppError, AppResult};
+use crate::presentation::dto::geo_dto::{ClusterParams, GeoClusterDto, GeoClustersResponse};
+use crate::presentation::dto::search_dto::SearchParams;
+use crate::presentation::state::AppState;
+
+/// `GET /api/geo/bbox` - 사각 영역(`west, south, east, north`) 안의 사진 클러스터
+pub async fn clusters_in_bbox(
+    State(state): State<AppState>,
+    Query(params): Query<SearchParams>,
+    Query(cluster): Query<ClusterParams>,
+) -> AppResult<Json<GeoClustersResponse>> {
+    let area = params
+        .to_bounding_box()
+        .ok_or_else(|| missing("west, south, east, north"))?;
+    clusters(&state, &params, area, cluster.zoom).await
+}
+
+/// `GET /api/geo/radius` - 중심(`lat, lng`)에서 `radius_m` 이내의 사진 클러스터
+pub async fn clusters_in_radius(
+    State(state): State<AppState>,
+    Query(params): Query<SearchParams>,
+    Query(cluster): Query<ClusterParams>,
+) -> AppResult<Json<GeoClustersResponse>> {
+    let area = params
+        .to_radius()
+        .ok_or_else(|| missing("lat, lng, radius_m"))?;
+    clusters(&state, &params, area, cluster.zoom).await
+}
+
+/// 나머지 검색 조건(`SearchParams`)도 함께 적용하여 클러스터링
+async fn clusters(
+    state: &AppState,
+    params: &SearchParams,
+    area: GeoArea,
+    zoom: u8,
+) -> AppResult<Json<GeoClustersResponse>> {
+    let repository = state.photo_repository();
+
+    let clusters = GetGeoClustersUseCase::new(&repository)
+        .execute(state.config.owner_id, params.to_filter(), area, zoom)
+        .await?;
+
+    Ok(Json(GeoClustersResponse {
+        clusters: clusters.iter().map(GeoClusterDto::from).collect(),
+    }))
+}
+
+fn missing(names: &str) -> AppError {
+    AppError::BadRequest(format!("{names} are required"))
+}
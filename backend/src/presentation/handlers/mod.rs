@@ -0,0 +1,12 @@
+pub mod album_handler;
+pub mod duplicate_handler;
+pub mod face_handler;
+pub mod geo_handler;
+pub mod person_handler;
+pub mod photo_batch_handler;
+pub mod photo_handler;
+pub mod place_handler;
+pub mod quality_handler;
+pub mod search_handler;
+pub mod similar_handler;
+pub mod timeline_handler;
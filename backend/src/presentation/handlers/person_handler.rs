@@ -0,0 +1,105 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::{StatusCode, header};
+use axum::response::IntoResponse;
+use uuid::Uuid;
+
+use crate::application::use_cases::get_face_crop::GetFaceCropUseCase;
+use crate::application::use_cases::list_people::ListPeopleUseCase;
+use crate::application::use_cases::merge_people::MergePeopleUseCase;
+use crate::application::use_cases::rename_person::RenamePersonUseCase;
+use crate::application::use_cases::split_person::SplitPersonUseCase;
+use crate::error::AppResult;
+use crate::presentation::dto::person_dto::{
+    MergePeopleRequest, MergePeopleResponse, PeopleResponse, PersonDto, PersonFaceDto,
+    PersonFacesResponse, RenamePersonRequest, SplitPersonRequest, SplitPersonResponse,
+};
+use crate::presentation::state::AppState;
+
+/// `GET /api/people` - 얼굴 군집으로 묶인 인물 목록
+pub async fn list_people(State(state): State<AppState>) -> AppResult<Json<PeopleResponse>> {
+    let repository = state.person_repository();
+    let people = ListPeopleUseCase::new(&repository)
+        .execute(state.config.owner_id)
+        .await?;
+
+    Ok(Json(PeopleResponse {
+        people: people.iter().map(PersonDto::from).collect(),
+    }))
+}
+
+/// `GET /api/people/{id}/faces` - 인물에 속한 얼굴 목록
+pub async fn list_person_faces(
+    State(state): State<AppState>,
+    Path(person_id): Path<Uuid>,
+) -> AppResult<Json<PersonFacesResponse>> {
+    let repository = state.person_repository();
+    let faces = ListPeopleUseCase::new(&repository)
+        .faces(state.config.owner_id, person_id)
+        .await?;
+
+    Ok(Json(PersonFacesResponse {
+        faces: faces.iter().map(PersonFaceDto::from).collect(),
+    }))
+}
+
+/// `PATCH /api/people/{id}` - 이름 변경
+pub async fn rename_person(
+    State(state): State<AppState>,
+    Path(person_id): Path<Uuid>,
+    Json(request): Json<RenamePersonRequest>,
+) -> AppResult<StatusCode> {
+    let repository = state.person_repository();
+    RenamePersonUseCase::new(&repository)
+        .execute(state.config.owner_id, person_id, request.name.as_deref())
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `POST /api/people/{id}/merge` - 다른 인물들을 이 인물로 합침
+pub async fn merge_people(
+    State(state): State<AppState>,
+    Path(person_id): Path<Uuid>,
+    Json(request): Json<MergePeopleRequest>,
+) -> AppResult<Json<MergePeopleResponse>> {
+    let repository = state.person_repository();
+    let moved_faces = MergePeopleUseCase::new(&repository)
+        .execute(state.config.owner_id, person_id, &request.person_ids)
+        .await?;
+    Ok(Json(MergePeopleResponse { moved_faces }))
+}
+
+/// `POST /api/people/{id}/split` - 선택한 얼굴을 새 인물로 분리
+pub async fn split_person(
+    State(state): State<AppState>,
+    Path(person_id): Path<Uuid>,
+    Json(request): Json<SplitPersonRequest>,
+) -> AppResult<Json<SplitPersonResponse>> {
+    let repository = state.person_repository();
+    let new_person_id = SplitPersonUseCase::new(&repository)
+        .execute(state.config.owner_id, person_id, &request.face_ids)
+        .await?;
+    Ok(Json(SplitPersonResponse {
+        person_id: new_person_id,
+    }))
+}
+
+/// `GET /api/faces/{id}/crop` - 얼굴 영역 JPEG 썸네일
+pub async fn face_crop(
+    State(state): State<AppState>,
+    Path(face_id): Path<Uuid>,
+) -> AppResult<impl IntoResponse> {
+    let repository = state.person_repository();
+    let images = state.image_store();
+    let jpeg = GetFaceCropUseCase::new(&repository, &images)
+        .execute(state.config.owner_id, face_id)
+        .await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "image/jpeg"),
+            (header::CACHE_CONTROL, "private, max-age=86400"),
+        ],
+        jpeg,
+    ))
+}
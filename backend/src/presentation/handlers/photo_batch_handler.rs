@@ -0,0 +1,85 @@
+use axum::Json;
+use axum::body::Body;
+use axum::extract::{Path, State};
+use axum::http::header;
+use axum::response::IntoResponse;
+use tokio_stream::wrappers::ReceiverStream;
+use uuid::Uuid;
+
+use crate::application::use_cases::download_photos::DownloadPhotosUseCase;
+use crate::application::use_cases::set_favorites::SetFavoritesUseCase;
+use crate::application::use_cases::tag_photos::TagPhotosUseCase;
+use crate::error::{AppError, AppResult};
+use crate::presentation::dto::photo_batch_dto::{
+    DownloadPhotosRequest, DownloadTicketResponse, PhotoBatchResponse, SetFavoritesRequest,
+    TagPhotosRequest,
+};
+use crate::presentation::state::AppState;
+
+/// `POST /api/photos/favorites` - 선택한 사진의 좋아요 켜기/끄기
+pub async fn set_favorites(
+    State(state): State<AppState>,
+    Json(request): Json<SetFavoritesRequest>,
+) -> AppResult<Json<PhotoBatchResponse>> {
+    let repository = state.photo_repository();
+    let updated = SetFavoritesUseCase::new(&repository)
+        .execute(state.config.owner_id, &request.photo_ids, request.liked)
+        .await?;
+
+    Ok(Json(PhotoBatchResponse { updated }))
+}
+
+/// `POST /api/photos/tags` - 선택한 사진에 태그 붙이기
+pub async fn tag_photos(
+    State(state): State<AppState>,
+    Json(request): Json<TagPhotosRequest>,
+) -> AppResult<Json<PhotoBatchResponse>> {
+    let repository = state.tag_repository();
+    let updated = TagPhotosUseCase::new(&repository)
+        .execute(state.config.owner_id, &request.photo_ids, &request.tags)
+        .await?;
+
+    Ok(Json(PhotoBatchResponse { updated }))
+}
+
+/// `POST /api/photos/download` - 선택한 사진 원본 ZIP 다운로드 준비
+///
+/// 크기 제한까지 확인한 뒤, 브라우저가 GET 으로 받아 디스크에 바로 저장할 주소를 돌려줍니다.
+pub async fn prepare_download(
+    State(state): State<AppState>,
+    Json(request): Json<DownloadPhotosRequest>,
+) -> AppResult<Json<DownloadTicketResponse>> {
+    let (repository, archiver) = (state.photo_repository(), state.photo_archiver());
+    let files = DownloadPhotosUseCase::new(&repository, &archiver)
+        .prepare(state.config.owner_id, &request.photo_ids)
+        .await?;
+    let ticket = state.download_tickets.issue(files);
+
+    Ok(Json(DownloadTicketResponse {
+        url: format!("/api/photos/download/{ticket}"),
+    }))
+}
+
+/// `GET /api/photos/download/{ticket}` - 준비한 ZIP 내려받기 (만드는 대로 스트리밍, 한 번만 가능)
+pub async fn download_photos(
+    State(state): State<AppState>,
+    Path(ticket): Path<Uuid>,
+) -> AppResult<impl IntoResponse> {
+    let files = state
+        .download_tickets
+        .redeem(ticket)
+        .ok_or(AppError::NotFound)?;
+    let (repository, archiver) = (state.photo_repository(), state.photo_archiver());
+    let chunks = DownloadPhotosUseCase::new(&repository, &archiver).stream(files);
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/zip"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"photos.zip\"",
+            ),
+        ],
+        Body::from_stream(ReceiverStream::new(chunks)),
+    ))
+}
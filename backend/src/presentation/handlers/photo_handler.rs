@@ -0,0 +1,33 @@
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use uuid::Uuid;
+
+use crate::application::use_cases::get_photo_context::GetPhotoContextUseCase;
+use crate::error::AppResult;
+use crate::presentation::dto::photo_dto::PhotoDto;
+use crate::presentation::dto::search_dto::{PhotoContextResponse, SearchParams};
+use crate::presentation::state::AppState;
+
+/// `GET /api/photos/{id}` - 사진 한 장과 검색 조건 기준 앞뒤 이웃 사진 (`limit` 장씩)
+pub async fn photo_context(
+    State(state): State<AppState>,
+    Path(photo_id): Path<Uuid>,
+    Query(params): Query<SearchParams>,
+) -> AppResult<Json<PhotoContextResponse>> {
+    let repository = state.photo_repository();
+    let context = GetPhotoContextUseCase::new(&repository)
+        .execute(
+            state.config.owner_id,
+            photo_id,
+            params.to_filter(),
+            params.limit,
+        )
+        .await?;
+
+    Ok(Json(PhotoContextResponse {
+        photo: PhotoDto::from(&context.photo),
+        previous: context.previous.iter().map(PhotoDto::from).collect(),
+        next: context.next.photos.iter().map(PhotoDto::from).collect(),
+        next_cursor: context.next.next_cursor.map(|cursor| cursor.encode()),
+    }))
+}
@@ -0,0 +1,20 @@
+use axum::Json;
+use axum::extract::State;
+
+use crate::application::use_cases::list_places::ListPlacesUseCase;
+use crate::error::AppResult;
+use crate::presentation::dto::place_dto::{CountryPlacesDto, PlacesResponse};
+use crate::presentation::state::AppState;
+
+/// `GET /api/places` - 역지오코딩된 사진을 국가 → 도시별로 집계
+pub async fn list_places(State(state): State<AppState>) -> AppResult<Json<PlacesResponse>> {
+    let repository = state.place_repository();
+
+    let countries = ListPlacesUseCase::new(&repository)
+        .execute(state.config.owner_id)
+        .await?;
+
+    Ok(Json(PlacesResponse {
+        countries: countries.iter().map(CountryPlacesDto::from).collect(),
+    }))
+}
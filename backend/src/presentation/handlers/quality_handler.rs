@@ -0,0 +1,22 @@
+use axum::Json;
+use axum::extract::{Query, State};
+
+use crate::application::use_cases::list_cleanup_suggestions::ListCleanupSuggestionsUseCase;
+use crate::error::AppResult;
+use crate::presentation::dto::quality_dto::{CleanupParams, CleanupResponse, CleanupSuggestionDto};
+use crate::presentation::state::AppState;
+
+/// `GET /api/photos/cleanup` - 흐리거나 노출이 나쁜 정리 후보 사진
+pub async fn cleanup_suggestions(
+    State(state): State<AppState>,
+    Query(params): Query<CleanupParams>,
+) -> AppResult<Json<CleanupResponse>> {
+    let repository = state.photo_repository();
+    let suggestions = ListCleanupSuggestionsUseCase::new(&repository)
+        .execute(state.config.owner_id, params.limit)
+        .await?;
+
+    Ok(Json(CleanupResponse {
+        photos: suggestions.iter().map(CleanupSuggestionDto::from).collect(),
+    }))
+}
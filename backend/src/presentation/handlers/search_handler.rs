@@ -0,0 +1,60 @@
+use axum::Json;
+use axum::extract::{Query, State};
+
+use crate::application::use_cases::get_search_facets::GetSearchFacetsUseCase;
+use crate::application::use_cases::search_photos::{PhotoPage, SearchPhotosUseCase};
+use crate::domain::value_objects::photo_cursor::PhotoCursor;
+use crate::error::{AppError, AppResult};
+use crate::presentation::dto::photo_dto::PhotoDto;
+use crate::presentation::dto::search_dto::{PhotoPageResponse, SearchFacetsResponse, SearchParams};
+use crate::presentation::state::AppState;
+
+/// `GET /api/search` - 텍스트 유사도 + 구조화 필터 통합 검색
+pub async fn search_photos(
+    State(state): State<AppState>,
+    Query(params): Query<SearchParams>,
+) -> AppResult<Json<PhotoPageResponse>> {
+    let cursor = parse_cursor(params.cursor.as_deref())?;
+    let repository = state.photo_repository();
+
+    let page = SearchPhotosUseCase::new(&repository)
+        .execute(
+            state.config.owner_id,
+            params.to_filter(),
+            cursor,
+            params.limit,
+        )
+        .await?;
+
+    Ok(Json(page_response(page)))
+}
+
+/// `GET /api/search/facets` - 현재 검색 조건에서 EXIF 값별 사진 수 집계
+pub async fn search_facets(
+    State(state): State<AppState>,
+    Query(params): Query<SearchParams>,
+) -> AppResult<Json<SearchFacetsResponse>> {
+    let repository = state.photo_repository();
+
+    let facets = GetSearchFacetsUseCase::new(&repository)
+        .execute(state.config.owner_id, params.to_filter())
+        .await?;
+
+    Ok(Json(facets.into()))
+}
+
+pub fn parse_cursor(raw: Option<&str>) -> AppResult<Option<PhotoCursor>> {
+    match raw {
+        None | Some("") => Ok(None),
+        Some(raw) => PhotoCursor::decode(raw)
+            .map(Some)
+            .ok_or_else(|| AppError::BadRequest("invalid cursor".to_string())),
+    }
+}
+
+pub fn page_response(page: PhotoPage) -> PhotoPageResponse {
+    PhotoPageResponse {
+        photos: page.photos.iter().map(PhotoDto::from).collect(),
+        next_cursor: page.next_cursor.map(|cursor| cursor.encode()),
+    }
+}
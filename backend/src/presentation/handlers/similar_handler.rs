@@ -0,0 +1,26 @@
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use uuid::Uuid;
+
+use crate::application::use_cases::find_similar_photos::FindSimilarPhotosUseCase;
+use crate::error::AppResult;
+use crate::presentation::dto::similar_dto::{
+    SimilarParams, SimilarPhotoDto, SimilarPhotosResponse,
+};
+use crate::presentation::state::AppState;
+
+/// `GET /api/photos/{id}/similar` - 색감/구도가 비슷한 사진
+pub async fn similar_photos(
+    State(state): State<AppState>,
+    Path(photo_id): Path<Uuid>,
+    Query(params): Query<SimilarParams>,
+) -> AppResult<Json<SimilarPhotosResponse>> {
+    let repository = state.embedding_repository();
+    let photos = FindSimilarPhotosUseCase::new(&repository, &state.embedding_index)
+        .execute(state.config.owner_id, photo_id, params.limit)
+        .await?;
+
+    Ok(Json(SimilarPhotosResponse {
+        photos: photos.iter().map(SimilarPhotoDto::from).collect(),
+    }))
+}
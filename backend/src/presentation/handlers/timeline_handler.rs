@@ -0,0 +1,24 @@
+use axum::Json;
+use axum::extract::{Query, State};
+
+use crate::application::use_cases::get_timeline::GetTimelineUseCase;
+use crate::error::AppResult;
+use crate::presentation::dto::search_dto::SearchParams;
+use crate::presentation::dto::timeline_dto::{TimelineMonthDto, TimelineResponse};
+use crate::presentation::state::AppState;
+
+/// `GET /api/timeline` - 검색 조건에 맞는 사진의 월별 개수와 이동용 커서
+pub async fn timeline(
+    State(state): State<AppState>,
+    Query(params): Query<SearchParams>,
+) -> AppResult<Json<TimelineResponse>> {
+    let repository = state.photo_repository();
+    let months = GetTimelineUseCase::new(&repository)
+        .execute(state.config.owner_id, params.to_filter())
+        .await?;
+
+    Ok(Json(TimelineResponse {
+        total: months.iter().map(|month| month.count).sum(),
+        months: months.iter().map(TimelineMonthDto::from).collect(),
+    }))
+}
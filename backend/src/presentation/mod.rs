@@ -0,0 +1,4 @@
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+pub mod state;
@@ -0,0 +1,86 @@
+use axum::Router;
+use axum::routing::{get, patch, post};
+use tower_http::cors::CorsLayer;
+use tower_http::services::ServeDir;
+use tower_http::trace::TraceLayer;
+
+use crate::presentation::handlers::{
+    album_handler, duplicate_handler, face_handler, geo_handler, person_handler,
+    photo_batch_handler, photo_handler, place_handler, quality_handler, search_handler,
+    similar_handler, timeline_handler,
+};
+use crate::presentation::state::AppState;
+
+pub fn build_router(state: AppState) -> Router {
+    let media = ServeDir::new(&state.config.media_root);
+
+    let router = Router::new()
+        .route("/api/search", get(search_handler::search_photos))
+        .route("/api/search/facets", get(search_handler::search_facets))
+        .route("/api/timeline", get(timeline_handler::timeline))
+        .route("/api/geo/bbox", get(geo_handler::clusters_in_bbox))
+        .route("/api/geo/radius", get(geo_handler::clusters_in_radius))
+        .route("/api/places", get(place_handler::list_places))
+        .route("/api/people", get(person_handler::list_people))
+        .route("/api/people/{id}", patch(person_handler::rename_person))
+        .route(
+            "/api/people/{id}/faces",
+            get(person_handler::list_person_faces),
+        )
+        .route("/api/people/{id}/merge", post(person_handler::merge_people))
+        .route("/api/people/{id}/split", post(person_handler::split_person))
+        .route("/api/photos/{id}", get(photo_handler::photo_context))
+        .route(
+            "/api/photos/{id}/faces",
+            get(face_handler::list_photo_faces).post(face_handler::add_face),
+        )
+        .route(
+            "/api/faces/{id}",
+            patch(face_handler::assign_face).delete(face_handler::remove_face),
+        )
+        .route("/api/faces/{id}/crop", get(person_handler::face_crop))
+        .route("/api/duplicates", get(duplicate_handler::list_duplicates))
+        .route("/api/photos/trash", post(duplicate_handler::trash_photos))
+        .route(
+            "/api/photos/favorites",
+            post(photo_batch_handler::set_favorites),
+        )
+        .route("/api/photos/tags", post(photo_batch_handler::tag_photos))
+        .route(
+            "/api/photos/download",
+            post(photo_batch_handler::prepare_download),
+        )
+        .route(
+            "/api/photos/download/{ticket}",
+            get(photo_batch_handler::download_photos),
+        )
+        .route(
+            "/api/albums",
+            get(album_handler::list_albums).post(album_handler::create_album),
+        )
+        .route("/api/albums/{id}", get(album_handler::get_album))
+        .route(
+            "/api/albums/{id}/photos",
+            post(album_handler::add_album_photos),
+        )
+        .route(
+            "/api/photos/cleanup",
+            get(quality_handler::cleanup_suggestions),
+        )
+        .route(
+            "/api/photos/{id}/similar",
+            get(similar_handler::similar_photos),
+        )
+        .nest_service("/media", media);
+
+    // 미리 받아둔 지도 타일({z}/{x}/{y}.png)을 서빙하여 인터넷 없이도 지도 표시
+    let router = match &state.config.tile_root {
+        Some(tile_root) => router.nest_service("/tiles", ServeDir::new(tile_root)),
+        None => router,
+    };
+
+    router
+        .layer(CorsLayer::permissive())
+        .layer(TraceLayer::new_for_http())
+        .with_state(state)
+}
@@ -0,0 +1,81 @@
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use crate::application::services::download_tickets::DownloadTickets;
+use crate::application::services::embedding_index::EmbeddingIndex;
+use crate::config::AppConfig;
+use crate::domain::services::face_analyzer::FaceEmbedder;
+use crate::infrastructure::processing::analyzers::Analyzers;
+use crate::infrastructure::repositories::pg_album_repository::PgAlbumRepository;
+use crate::infrastructure::repositories::pg_duplicate_repository::PgDuplicateRepository;
+use crate::infrastructure::repositories::pg_embedding_repository::PgEmbeddingRepository;
+use crate::infrastructure::repositories::pg_person_repository::PgPersonRepository;
+use crate::infrastructure::repositories::pg_photo_repository::PgPhotoRepository;
+use crate::infrastructure::repositories::pg_place_repository::PgPlaceRepository;
+use crate::infrastructure::repositories::pg_tag_repository::PgTagRepository;
+use crate::infrastructure::storage::fs_image_store::FsImageStore;
+use crate::infrastructure::storage::zip_photo_archiver::ZipPhotoArchiver;
+
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: PgPool,
+    pub config: Arc<AppConfig>,
+    /// 처리 작업자와 같은 분석기 (수동 태깅 얼굴도 같은 임베더로 비교해야 함)
+    pub analyzers: Arc<Analyzers>,
+    /// 비슷한 사진 검색용 메모리 색인 (요청 시 새 벡터만 반영)
+    pub embedding_index: Arc<EmbeddingIndex>,
+    /// 확인을 마치고 브라우저가 받으러 오기를 기다리는 ZIP 다운로드
+    pub download_tickets: Arc<DownloadTickets>,
+}
+
+impl AppState {
+    pub fn new(pool: PgPool, config: AppConfig, analyzers: Arc<Analyzers>) -> Self {
+        Self {
+            pool,
+            config: Arc::new(config),
+            analyzers,
+            embedding_index: Arc::new(EmbeddingIndex::new()),
+            download_tickets: Arc::new(DownloadTickets::new()),
+        }
+    }
+
+    pub fn photo_repository(&self) -> PgPhotoRepository {
+        PgPhotoRepository::new(self.pool.clone())
+    }
+
+    pub fn place_repository(&self) -> PgPlaceRepository {
+        PgPlaceRepository::new(self.pool.clone())
+    }
+
+    pub fn person_repository(&self) -> PgPersonRepository {
+        PgPersonRepository::new(self.pool.clone())
+    }
+
+    pub fn duplicate_repository(&self) -> PgDuplicateRepository {
+        PgDuplicateRepository::new(self.pool.clone())
+    }
+
+    pub fn embedding_repository(&self) -> PgEmbeddingRepository {
+        PgEmbeddingRepository::new(self.pool.clone())
+    }
+
+    pub fn tag_repository(&self) -> PgTagRepository {
+        PgTagRepository::new(self.pool.clone())
+    }
+
+    pub fn album_repository(&self) -> PgAlbumRepository {
+        PgAlbumRepository::new(self.pool.clone())
+    }
+
+    pub fn image_store(&self) -> FsImageStore {
+        FsImageStore::new(self.config.media_root.clone())
+    }
+
+    pub fn photo_archiver(&self) -> ZipPhotoArchiver {
+        ZipPhotoArchiver::new(self.config.media_root.clone())
+    }
+
+    pub fn face_embedder(&self) -> Arc<dyn FaceEmbedder> {
+        self.analyzers.face_embedder.clone()
+    }
+}
@@ -0,0 +1,13 @@
+-- 통합 검색 (/api/search) 지원 인덱스
+
+-- 제목(파일명) 트라이그램 유사도 검색
+CREATE INDEX IF NOT EXISTS idx_photos_filename_trgm ON photos USING GIN (original_filename gin_trgm_ops);
+
+-- 타임라인 keyset 페이지네이션: ORDER BY COALESCE(taken_at, created_at, 'epoch') DESC, id DESC
+CREATE INDEX IF NOT EXISTS idx_photos_timeline ON photos
+    (user_id, (COALESCE(taken_at, created_at, 'epoch'::timestamptz)) DESC, id DESC)
+    WHERE deleted_at IS NULL;
+
+-- 카메라/렌즈 부분 일치 필터
+CREATE INDEX IF NOT EXISTS idx_photos_camera_trgm ON photos USING GIN ((COALESCE(camera_make, '') || ' ' || COALESCE(camera_model, '')) gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_photos_lens_trgm ON photos USING GIN (lens_model gin_trgm_ops);
@@ -0,0 +1,19 @@
+-- 오프라인 역지오코딩 결과 (processing_queue task_type = 'reverse_geocode')
+
+ALTER TABLE photos
+    ADD COLUMN IF NOT EXISTS location_city VARCHAR(200),
+    ADD COLUMN IF NOT EXISTS location_region VARCHAR(200),
+    ADD COLUMN IF NOT EXISTS location_country VARCHAR(100),
+    ADD COLUMN IF NOT EXISTS location_country_code CHAR(2);
+
+COMMENT ON COLUMN photos.location_name IS '역지오코딩된 위치명 ("도시, 지역, 국가")';
+
+-- Places 화면: 국가 → 도시별 사진 수
+CREATE INDEX IF NOT EXISTS idx_photos_places ON photos (user_id, location_country, location_city)
+    WHERE deleted_at IS NULL AND location_country IS NOT NULL;
+
+-- 통합 검색 텍스트 / 장소 필터
+CREATE INDEX IF NOT EXISTS idx_photos_location_name_trgm ON photos USING GIN (location_name gin_trgm_ops);
+
+-- 작업 대기열: 같은 사진에 같은 작업이 중복으로 쌓이지 않도록
+CREATE UNIQUE INDEX IF NOT EXISTS idx_processing_queue_photo_task ON processing_queue (photo_id, task_type);
@@ -0,0 +1,13 @@
+-- 얼굴 검출 / 인물 군집 (processing_queue task_type = 'face_detection')
+
+-- 얼굴별 특징 벡터: 병합/분리 후 인물 중심(people.face_encoding)을 다시 계산할 때 사용
+ALTER TABLE photo_faces
+    ADD COLUMN IF NOT EXISTS embedding BYTEA;
+
+COMMENT ON COLUMN photo_faces.face_rectangle IS '방향 보정된 이미지 대비 0~1 비율 {x, y, width, height}';
+COMMENT ON COLUMN photo_faces.embedding IS '얼굴 특징 벡터 (little-endian f32 배열)';
+COMMENT ON COLUMN people.face_encoding IS '인물 군집 중심 (소속 얼굴 embedding 평균, little-endian f32 배열)';
+
+-- 얼굴이 없는 사진도 다시 검출하지 않도록 완료 시각을 기록
+ALTER TABLE photos
+    ADD COLUMN IF NOT EXISTS faces_detected_at TIMESTAMPTZ;
@@ -0,0 +1,3 @@
+-- 미리보기에서 직접 그리거나 인물을 고친 얼굴 (face_detection 재실행 시에도 유지)
+ALTER TABLE photo_faces
+    ADD COLUMN IF NOT EXISTS is_manual BOOLEAN NOT NULL DEFAULT false;
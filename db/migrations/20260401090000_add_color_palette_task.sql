@@ -0,0 +1,6 @@
+-- 대표 색 추출 (processing_queue task_type = 'color_palette')
+
+COMMENT ON COLUMN photos.color_palette IS
+    '대표 색 팔레트 [{hex, ratio, l, a, b}] (비율 높은 순, l/a/b 는 CIELAB D65)';
+COMMENT ON COLUMN photos.dominant_colors IS
+    '대표 색 #rrggbb 배열 (비율 높은 순, NULL 이면 아직 추출 전)';
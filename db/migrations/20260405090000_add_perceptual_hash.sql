@@ -0,0 +1,26 @@
+-- 유사 중복 검출 (processing_queue task_type = 'perceptual_hash')
+
+-- 64비트 dHash: 크기 변경/재압축된 사본도 해밍 거리가 작음
+ALTER TABLE photos
+    ADD COLUMN IF NOT EXISTS perceptual_hash BIGINT;
+
+-- 해밍 거리 조회용 8비트 구간 8개 (구간 번호 * 256 + 값)
+-- 거리가 7 이하인 두 해시는 적어도 한 구간이 같으므로(비둘기집 원리) 배열 겹침(&&)으로 후보를 좁힘
+ALTER TABLE photos
+    ADD COLUMN IF NOT EXISTS phash_bands INTEGER[] GENERATED ALWAYS AS (
+        CASE WHEN perceptual_hash IS NULL THEN NULL ELSE ARRAY[
+            ((perceptual_hash >> 56) & 255)::int,
+            256 + ((perceptual_hash >> 48) & 255)::int,
+            512 + ((perceptual_hash >> 40) & 255)::int,
+            768 + ((perceptual_hash >> 32) & 255)::int,
+            1024 + ((perceptual_hash >> 24) & 255)::int,
+            1280 + ((perceptual_hash >> 16) & 255)::int,
+            1536 + ((perceptual_hash >> 8) & 255)::int,
+            1792 + (perceptual_hash & 255)::int
+        ] END
+    ) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_photos_phash_bands ON photos USING GIN (phash_bands)
+    WHERE deleted_at IS NULL;
+
+COMMENT ON COLUMN photos.perceptual_hash IS '64비트 dHash (9x8 흑백 축소 이미지의 가로 밝기 차이)';
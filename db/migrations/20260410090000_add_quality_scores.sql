@@ -0,0 +1,15 @@
+-- 화질 평가 (processing_queue task_type = 'quality_assessment')
+
+ALTER TABLE photos
+    ADD COLUMN IF NOT EXISTS sharpness_score REAL,
+    ADD COLUMN IF NOT EXISTS exposure_score REAL,
+    ADD COLUMN IF NOT EXISTS brightness REAL;
+
+-- 정리 후보 조회: 가장 나쁜 점수 순
+CREATE INDEX IF NOT EXISTS idx_photos_quality_worst
+    ON photos (user_id, LEAST(sharpness_score, exposure_score))
+    WHERE deleted_at IS NULL AND sharpness_score IS NOT NULL;
+
+COMMENT ON COLUMN photos.sharpness_score IS '선명도 0~1 (라플라시안 분산 v 에 대해 v / (v + 100), NULL 이면 평가 전)';
+COMMENT ON COLUMN photos.exposure_score IS '노출 적정도 0~1 (평균 밝기가 중간에서 먼 정도와 암부/명부 뭉개짐 비율)';
+COMMENT ON COLUMN photos.brightness IS '평균 밝기 0~1';
@@ -0,0 +1,9 @@
+-- 자동 태그 (processing_queue task_type = 'ai_tags')
+
+-- 분류기를 실행한 시각 (기준을 넘는 태그가 없어도 다시 실행하지 않도록)
+ALTER TABLE photos
+    ADD COLUMN IF NOT EXISTS ai_tagged_at TIMESTAMPTZ;
+
+COMMENT ON COLUMN photos.ai_tags IS
+    '자동 태그 [{tag, category, confidence}] (신뢰도 기준 이상, 높은 순)';
+COMMENT ON COLUMN photos.ai_tagged_at IS '이미지 분류기 실행 시각 (NULL 이면 아직 실행 전)';
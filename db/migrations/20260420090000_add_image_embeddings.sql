@@ -0,0 +1,13 @@
+-- 비슷한 사진 검색 (processing_queue task_type = 'image_embedding')
+
+ALTER TABLE photos
+    ADD COLUMN IF NOT EXISTS image_embedding BYTEA,
+    ADD COLUMN IF NOT EXISTS embedded_at TIMESTAMPTZ;
+
+-- API 서버의 메모리 색인이 새로 저장된 벡터만 읽어가도록
+CREATE INDEX IF NOT EXISTS idx_photos_embedded_at ON photos (user_id, embedded_at)
+    WHERE image_embedding IS NOT NULL AND deleted_at IS NULL;
+
+COMMENT ON COLUMN photos.image_embedding IS
+    '비슷한 사진 검색용 특징 벡터 (길이 1로 정규화한 little-endian f32 배열)';
+COMMENT ON COLUMN photos.embedded_at IS '특징 벡터 저장 시각 (메모리 색인 동기화 기준)';
@@ -0,0 +1,100 @@
+use crate::domain::value_objects::person::FaceRect;
+
+/// 직접 그린 얼굴로 인정하는 최소 크기 (이미지 대비 비율)
+const MIN_FACE_SIZE: f64 = 0.02;
+
+/// `object-contain` 으로 그려진 이미지가 실제로 차지하는 영역 (요소 기준 px)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ContainRect {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// `box_width × box_height` 요소 안에 `natural_width × natural_height` 이미지를
+/// 비율을 유지하며 가운데 맞춤했을 때의 영역
+pub fn contain_rect(
+    box_width: f64,
+    box_height: f64,
+    natural_width: f64,
+    natural_height: f64,
+) -> ContainRect {
+    if box_width <= 0.0 || box_height <= 0.0 || natural_width <= 0.0 || natural_height <= 0.0 {
+        return ContainRect::default();
+    }
+    let scale = (box_width / natural_width).min(box_height / natural_height);
+    let (width, height) = (natural_width * scale, natural_height * scale);
+
+    ContainRect {
+        left: (box_width - width) / 2.0,
+        top: (box_height - height) / 2.0,
+        width,
+        height,
+    }
+}
+
+/// 이미지 대비 0~1 좌표 두 점으로 만든 얼굴 영역 (이미지 밖은 잘라냄)
+pub fn rect_from_points(start: (f64, f64), end: (f64, f64)) -> FaceRect {
+    let clamp = |v: f64| v.clamp(0.0, 1.0);
+    let (x1, x2) = (clamp(start.0.min(end.0)), clamp(start.0.max(end.0)));
+    let (y1, y2) = (clamp(start.1.min(end.1)), clamp(start.1.max(end.1)));
+
+    FaceRect {
+        x: x1,
+        y: y1,
+        width: x2 - x1,
+        height: y2 - y1,
+    }
+}
+
+/// 실수로 누른 클릭과 구분할 만큼 큰 영역인지
+pub fn is_usable(rect: &FaceRect) -> bool {
+    rect.width >= MIN_FACE_SIZE && rect.height >= MIN_FACE_SIZE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wide_image_is_letterboxed() {
+        let rect = contain_rect(800.0, 600.0, 4000.0, 2000.0);
+
+        assert_eq!(
+            rect,
+            ContainRect {
+                left: 0.0,
+                top: 100.0,
+                width: 800.0,
+                height: 400.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_tall_image_is_pillarboxed_and_empty_sizes_give_nothing() {
+        let rect = contain_rect(800.0, 600.0, 1000.0, 1500.0);
+        assert_eq!((rect.left, rect.width, rect.height), (200.0, 400.0, 600.0));
+
+        assert_eq!(
+            contain_rect(800.0, 600.0, 0.0, 1500.0),
+            ContainRect::default()
+        );
+    }
+
+    #[test]
+    fn test_dragging_in_any_direction_gives_the_same_clamped_rect() {
+        let rect = rect_from_points((0.9, 1.2), (0.5, 0.4));
+
+        assert!((rect.x - 0.5).abs() < 1e-9 && (rect.y - 0.4).abs() < 1e-9);
+        assert!((rect.width - 0.4).abs() < 1e-9 && (rect.height - 0.6).abs() < 1e-9);
+        assert_eq!(rect, rect_from_points((0.5, 0.4), (0.9, 1.2)));
+    }
+
+    #[test]
+    fn test_tiny_rects_are_treated_as_clicks() {
+        assert!(is_usable(&rect_from_points((0.1, 0.1), (0.2, 0.2))));
+        assert!(!is_usable(&rect_from_points((0.1, 0.1), (0.105, 0.3))));
+    }
+}
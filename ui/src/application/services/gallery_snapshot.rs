@@ -0,0 +1,199 @@
+//! 갤러리 목록 복원용 스냅샷 (화면을 떠날 때 저장, 같은 조건으로 돌아오면 복원)
+//!
+//! 목록은 다음 페이지 방향으로만 이어 붙이므로 처음부터 끊김 없는 목록만 저장합니다.
+//! 너무 길면 저장하지 않고, 돌아왔을 때 처음부터 다시 불러옵니다.
+
+use crate::domain::entities::photo::Photo;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 복원용으로 저장할 최대 사진 수
+pub const MAX_SNAPSHOT_PHOTOS: usize = 1000;
+
+/// 보고 있던 사진과, 그 사진 위쪽이 스크롤 영역 위쪽 끝에서 떨어진 거리 (px)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScrollAnchor {
+    pub photo_id: Uuid,
+    pub offset: f64,
+}
+
+/// 갤러리를 다시 만들 때 복원할 목록과 스크롤 위치 (sessionStorage 에 저장)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GallerySnapshot {
+    pub filter: Option<SearchFilter>,
+    /// `photos` 마지막 사진 다음 페이지 커서
+    pub cursor: Option<String>,
+    pub has_more: bool,
+    pub photos: Vec<Photo>,
+    pub anchor: Option<ScrollAnchor>,
+}
+
+/// `len` 장짜리 목록을 통째로 저장할 수 있는지 (비었거나 너무 길면 저장하지 않음)
+pub fn fits(len: usize) -> bool {
+    (1..=MAX_SNAPSHOT_PHOTOS).contains(&len)
+}
+
+impl GallerySnapshot {
+    /// `filter` 조건으로 돌아왔을 때 복원할 수 있으면 그 스냅샷 (아니면 처음부터 다시 불러옴)
+    ///
+    /// 커서 없이 더 있다고 하면 다음 페이지가 처음부터 다시 붙으므로 버리고,
+    /// 목록에 없는 사진을 가리키는 스크롤 위치는 지웁니다.
+    pub fn restorable(self, filter: &Option<SearchFilter>) -> Option<Self> {
+        let is_valid = self.filter == *filter
+            && fits(self.photos.len())
+            && (self.cursor.is_some() || !self.has_more);
+        if !is_valid {
+            return None;
+        }
+        let anchor = self
+            .anchor
+            .filter(|anchor| self.photos.iter().any(|p| p.id == anchor.photo_id));
+        Some(Self { anchor, ..self })
+    }
+
+    /// 다른 화면에서 휴지통으로 보낸 사진을 뺀 스냅샷 (보던 사진이 빠지면 스크롤 위치도 지움)
+    pub fn without_photos(mut self, photo_ids: &[Uuid]) -> Self {
+        self.photos.retain(|photo| !photo_ids.contains(&photo.id));
+        self.anchor = self
+            .anchor
+            .filter(|anchor| !photo_ids.contains(&anchor.photo_id));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn photo() -> Photo {
+        Photo {
+            id: Uuid::new_v4(),
+            url: String::new(),
+            preview_url: String::new(),
+            original_url: String::new(),
+            title: String::new(),
+            taken_at: None,
+            created_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            is_liked: false,
+            width: 1,
+            height: 1,
+            dominant_colors: Vec::new(),
+            sharpness: None,
+            exposure: None,
+        }
+    }
+
+    fn beach() -> Option<SearchFilter> {
+        Some(SearchFilter {
+            text: Some("beach".to_string()),
+            ..SearchFilter::default()
+        })
+    }
+
+    fn snapshot(len: usize) -> GallerySnapshot {
+        let photos: Vec<Photo> = (0..len).map(|_| photo()).collect();
+        GallerySnapshot {
+            filter: beach(),
+            cursor: Some("1767225600000000:next".to_string()),
+            has_more: true,
+            anchor: photos.get(len / 2).map(|p| ScrollAnchor {
+                photo_id: p.id,
+                offset: 120.0,
+            }),
+            photos,
+        }
+    }
+
+    /// sessionStorage 를 거쳐 같은 조건으로 복원
+    fn round_trip(snapshot: &GallerySnapshot) -> Option<GallerySnapshot> {
+        let raw = serde_json::to_string(snapshot).unwrap();
+        serde_json::from_str::<GallerySnapshot>(&raw)
+            .unwrap()
+            .restorable(&beach())
+    }
+
+    #[test]
+    fn test_list_under_the_cap_round_trips_unchanged() {
+        for len in [1, 20, MAX_SNAPSHOT_PHOTOS] {
+            let saved = snapshot(len);
+            assert!(fits(len));
+            assert_eq!(round_trip(&saved), Some(saved));
+        }
+    }
+
+    #[test]
+    fn test_empty_list_is_neither_saved_nor_restored() {
+        assert!(!fits(0));
+        assert_eq!(round_trip(&snapshot(0)), None);
+    }
+
+    #[test]
+    fn test_list_over_the_cap_is_reloaded_from_the_top() {
+        assert!(!fits(MAX_SNAPSHOT_PHOTOS + 1));
+        assert_eq!(round_trip(&snapshot(MAX_SNAPSHOT_PHOTOS + 1)), None);
+    }
+
+    #[test]
+    fn test_snapshot_for_another_filter_is_ignored() {
+        let saved = snapshot(20);
+        assert_eq!(saved.clone().restorable(&None), None);
+        assert!(saved.restorable(&beach()).is_some());
+    }
+
+    #[test]
+    fn test_invalid_snapshot_is_not_restored() {
+        // 커서 없이 더 있다고 하면 이어 붙일 수 없음
+        let saved = GallerySnapshot {
+            cursor: None,
+            ..snapshot(20)
+        };
+        assert_eq!(round_trip(&saved), None);
+        // 마지막 페이지까지 받은 목록은 커서가 없어도 복원
+        let last_page = GallerySnapshot {
+            cursor: None,
+            has_more: false,
+            ..snapshot(20)
+        };
+        assert_eq!(round_trip(&last_page), Some(last_page));
+        // 형식이 맞지 않으면 읽지 않음
+        assert!(serde_json::from_str::<GallerySnapshot>(r#"{"photos":"oops"}"#).is_err());
+    }
+
+    #[test]
+    fn test_anchor_outside_the_list_is_dropped() {
+        let saved = GallerySnapshot {
+            anchor: Some(ScrollAnchor {
+                photo_id: Uuid::new_v4(),
+                offset: 0.0,
+            }),
+            ..snapshot(20)
+        };
+        let restored = round_trip(&saved).unwrap();
+        assert_eq!(restored.anchor, None);
+        assert_eq!(restored.photos, saved.photos);
+    }
+
+    #[test]
+    fn test_trashed_photos_are_removed_from_the_snapshot() {
+        let saved = snapshot(20);
+        let anchor = saved.anchor.unwrap().photo_id;
+        let trashed = vec![saved.photos[0].id, anchor];
+
+        let restored = round_trip(&saved.clone().without_photos(&trashed)).unwrap();
+
+        assert_eq!(restored.photos.len(), 18);
+        assert!(restored.photos.iter().all(|p| !trashed.contains(&p.id)));
+        assert_eq!(restored.anchor, None);
+        assert_eq!(restored.cursor, saved.cursor);
+    }
+
+    #[test]
+    fn test_snapshot_emptied_by_trash_is_not_restored() {
+        let saved = snapshot(2);
+        let trashed: Vec<Uuid> = saved.photos.iter().map(|p| p.id).collect();
+
+        assert_eq!(round_trip(&saved.without_photos(&trashed)), None);
+    }
+}
@@ -0,0 +1,502 @@
+/// 포인터 하나의 위치와 시각 (`PointerEvent` 의 `pointerId`, `clientX/Y`, `timeStamp`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointerSample {
+    pub id: i32,
+    pub x: f64,
+    pub y: f64,
+    /// ms
+    pub time: f64,
+}
+
+/// 이동이 고정된 방향
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// 인식한 제스처 (좌표는 client px, 속도는 px/ms)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GestureEvent {
+    /// 첫 포인터가 눌림
+    Press {
+        x: f64,
+        y: f64,
+    },
+    /// 처음 몇 px 움직인 방향으로 고정된 이동 시작
+    PanStart {
+        axis: Axis,
+    },
+    /// `offset` 은 시작점부터, `delta` 는 직전 이동부터의 거리
+    Pan {
+        axis: Axis,
+        offset: (f64, f64),
+        delta: (f64, f64),
+    },
+    /// 고정된 방향으로 빠르게 튕기고 놓음 (`PanEnd` 바로 앞에 옴)
+    Swipe {
+        direction: SwipeDirection,
+        velocity: f64,
+    },
+    PanEnd {
+        axis: Axis,
+        offset: (f64, f64),
+        velocity: (f64, f64),
+    },
+    PinchStart {
+        center: (f64, f64),
+    },
+    /// `scale` 은 핀치 시작 시 두 포인터 거리 대비 비율
+    Pinch {
+        scale: f64,
+        center: (f64, f64),
+    },
+    PinchEnd,
+    Tap {
+        x: f64,
+        y: f64,
+    },
+    DoubleTap {
+        x: f64,
+        y: f64,
+    },
+    LongPress {
+        x: f64,
+        y: f64,
+    },
+    /// 브라우저가 포인터를 가져감 (`pointercancel`): 진행 중인 표시를 되돌림
+    Cancel,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GestureConfig {
+    /// 이 거리(px)를 움직이면 탭이 아니라 이동으로 보고 방향을 고정
+    pub lock_distance: f64,
+    pub double_tap_ms: f64,
+    pub double_tap_distance: f64,
+    pub long_press_ms: u32,
+    /// 놓을 때 이보다 빠르면(px/ms) 스와이프
+    pub swipe_velocity: f64,
+}
+
+impl Default for GestureConfig {
+    fn default() -> Self {
+        Self {
+            lock_distance: 10.0,
+            double_tap_ms: 300.0,
+            double_tap_distance: 30.0,
+            long_press_ms: 450,
+            swipe_velocity: 0.3,
+        }
+    }
+}
+
+/// 마지막 이동 후 이 시간(ms) 넘게 멈췄다 놓으면 속도 0
+const VELOCITY_IDLE_MS: f64 = 100.0;
+
+/// 포인터 입력(누름/이동/뗌/취소)을 이동, 스와이프, 핀치, 탭, 두 번 탭, 길게 누르기로 바꾸는 상태 기계
+///
+/// 브라우저 이벤트와 타이머는 호출하는 쪽이 연결합니다 (길게 누르기는 `long_press_ms` 후 `long_press` 호출).
+#[derive(Clone, Debug, Default)]
+pub struct GestureRecognizer {
+    config: GestureConfig,
+    /// 누르고 있는 포인터 (최대 2개)
+    pointers: Vec<PointerSample>,
+    start: Option<PointerSample>,
+    last: Option<PointerSample>,
+    velocity: (f64, f64),
+    axis: Option<Axis>,
+    pinch_distance: Option<f64>,
+    /// 두 손가락을 썼거나 길게 눌렀으면 이번 누름은 탭이 아님
+    is_tap_cancelled: bool,
+    last_tap: Option<PointerSample>,
+}
+
+impl GestureRecognizer {
+    pub fn new(config: GestureConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    pub fn down(&mut self, p: PointerSample) -> Vec<GestureEvent> {
+        // 이미 누르고 있는 포인터가 다시 눌림: 요소 밖에서 뗀 up 을 놓쳤으므로 처음부터 다시 시작
+        let mut events = if self.pointers.iter().any(|q| q.id == p.id) {
+            self.cancel()
+        } else {
+            Vec::new()
+        };
+        if self.pointers.len() >= 2 {
+            return events;
+        }
+        self.pointers.push(p);
+        if self.pointers.len() == 1 {
+            self.begin_single(p, false);
+            events.push(GestureEvent::Press { x: p.x, y: p.y });
+            return events;
+        }
+
+        // 두 번째 손가락: 진행 중인 이동을 끝내고 핀치 시작
+        events.extend(self.finish_pan(false));
+        let (a, b) = (self.pointers[0], self.pointers[1]);
+        self.pinch_distance = Some(distance(a, b));
+        self.is_tap_cancelled = true;
+        events.push(GestureEvent::PinchStart {
+            center: midpoint(a, b),
+        });
+        events
+    }
+
+    pub fn move_to(&mut self, p: PointerSample) -> Vec<GestureEvent> {
+        let Some(index) = self.pointers.iter().position(|q| q.id == p.id) else {
+            return Vec::new();
+        };
+        self.pointers[index] = p;
+
+        if let (Some(start_distance), [a, b]) = (self.pinch_distance, self.pointers.as_slice()) {
+            return vec![GestureEvent::Pinch {
+                scale: distance(*a, *b) / start_distance.max(1.0),
+                center: midpoint(*a, *b),
+            }];
+        }
+        let (Some(start), Some(last)) = (self.start, self.last) else {
+            return Vec::new();
+        };
+        self.track_velocity(last, p);
+        self.last = Some(p);
+
+        let offset = (p.x - start.x, p.y - start.y);
+        match self.axis {
+            Some(axis) => vec![GestureEvent::Pan {
+                axis,
+                offset,
+                delta: (p.x - last.x, p.y - last.y),
+            }],
+            None if offset.0.hypot(offset.1) >= self.config.lock_distance => {
+                let axis = lock_axis(offset);
+                self.axis = Some(axis);
+                self.is_tap_cancelled = true;
+                vec![
+                    GestureEvent::PanStart { axis },
+                    GestureEvent::Pan {
+                        axis,
+                        offset,
+                        delta: offset,
+                    },
+                ]
+            }
+            None => Vec::new(),
+        }
+    }
+
+    pub fn up(&mut self, p: PointerSample) -> Vec<GestureEvent> {
+        let Some(index) = self.pointers.iter().position(|q| q.id == p.id) else {
+            return Vec::new();
+        };
+        self.pointers.remove(index);
+
+        if self.pinch_distance.take().is_some() {
+            // 남은 손가락은 새 시작점에서 이어서 이동 (탭은 아님)
+            if let Some(rest) = self.pointers.first().copied() {
+                self.begin_single(rest, true);
+            }
+            return vec![GestureEvent::PinchEnd];
+        }
+        if p.time - self.last.map_or(p.time, |l| l.time) > VELOCITY_IDLE_MS {
+            self.velocity = (0.0, 0.0);
+        }
+        if self.axis.is_some() {
+            let events = self.finish_pan(true);
+            self.start = None;
+            return events;
+        }
+        let events = if self.is_tap_cancelled {
+            Vec::new()
+        } else {
+            vec![self.tap(p)]
+        };
+        self.start = None;
+        events
+    }
+
+    pub fn cancel(&mut self) -> Vec<GestureEvent> {
+        let was_active = self.axis.is_some() || self.pinch_distance.is_some();
+        *self = Self::new(self.config);
+        if was_active {
+            vec![GestureEvent::Cancel]
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// 길게 누르기 타이머가 끝났을 때 (그동안 움직이거나 뗐으면 `None`)
+    pub fn long_press(&mut self) -> Option<GestureEvent> {
+        let start = self.start?;
+        if self.pointers.len() != 1 || self.axis.is_some() || self.is_tap_cancelled {
+            return None;
+        }
+        self.is_tap_cancelled = true;
+        Some(GestureEvent::LongPress {
+            x: start.x,
+            y: start.y,
+        })
+    }
+
+    fn begin_single(&mut self, p: PointerSample, is_tap_cancelled: bool) {
+        self.start = Some(p);
+        self.last = Some(p);
+        self.velocity = (0.0, 0.0);
+        self.axis = None;
+        self.is_tap_cancelled = is_tap_cancelled;
+    }
+
+    /// 이동 중이었으면 (스와이프와) 이동 끝
+    fn finish_pan(&mut self, allow_swipe: bool) -> Vec<GestureEvent> {
+        let (Some(axis), Some(start), Some(last)) = (self.axis.take(), self.start, self.last)
+        else {
+            return Vec::new();
+        };
+        let offset = (last.x - start.x, last.y - start.y);
+        let mut events = Vec::new();
+        if allow_swipe {
+            events.extend(self.swipe(axis, offset));
+        }
+        events.push(GestureEvent::PanEnd {
+            axis,
+            offset,
+            velocity: self.velocity,
+        });
+        events
+    }
+
+    /// 고정된 방향의 속도가 기준보다 빠르고 이동 방향과 같으면 스와이프
+    fn swipe(&self, axis: Axis, offset: (f64, f64)) -> Option<GestureEvent> {
+        let (speed, moved) = match axis {
+            Axis::Horizontal => (self.velocity.0, offset.0),
+            Axis::Vertical => (self.velocity.1, offset.1),
+        };
+        if speed.abs() < self.config.swipe_velocity || speed.signum() != moved.signum() {
+            return None;
+        }
+        let direction = match (axis, speed > 0.0) {
+            (Axis::Horizontal, true) => SwipeDirection::Right,
+            (Axis::Horizontal, false) => SwipeDirection::Left,
+            (Axis::Vertical, true) => SwipeDirection::Down,
+            (Axis::Vertical, false) => SwipeDirection::Up,
+        };
+        Some(GestureEvent::Swipe {
+            direction,
+            velocity: speed.abs(),
+        })
+    }
+
+    fn tap(&mut self, p: PointerSample) -> GestureEvent {
+        let is_double = self.last_tap.is_some_and(|t| {
+            p.time - t.time < self.config.double_tap_ms
+                && distance(t, p) < self.config.double_tap_distance
+        });
+        if is_double {
+            self.last_tap = None;
+            GestureEvent::DoubleTap { x: p.x, y: p.y }
+        } else {
+            self.last_tap = Some(p);
+            GestureEvent::Tap { x: p.x, y: p.y }
+        }
+    }
+
+    /// 최근 이동에 가중치를 둔 속도
+    fn track_velocity(&mut self, last: PointerSample, p: PointerSample) {
+        let dt = (p.time - last.time).max(1.0);
+        let current = ((p.x - last.x) / dt, (p.y - last.y) / dt);
+        self.velocity = (
+            current.0 * 0.8 + self.velocity.0 * 0.2,
+            current.1 * 0.8 + self.velocity.1 * 0.2,
+        );
+    }
+}
+
+fn lock_axis(offset: (f64, f64)) -> Axis {
+    if offset.0.abs() >= offset.1.abs() {
+        Axis::Horizontal
+    } else {
+        Axis::Vertical
+    }
+}
+
+fn distance(a: PointerSample, b: PointerSample) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+fn midpoint(a: PointerSample, b: PointerSample) -> (f64, f64) {
+    ((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(id: i32, x: f64, y: f64, time: f64) -> PointerSample {
+        PointerSample { id, x, y, time }
+    }
+
+    fn recognizer() -> GestureRecognizer {
+        GestureRecognizer::new(GestureConfig::default())
+    }
+
+    #[test]
+    fn test_pan_locks_axis_and_reports_offsets() {
+        let mut g = recognizer();
+        g.down(at(1, 0.0, 0.0, 0.0));
+        assert!(g.move_to(at(1, 4.0, 3.0, 10.0)).is_empty());
+        let events = g.move_to(at(1, 12.0, 20.0, 20.0));
+        assert_eq!(
+            events[0],
+            GestureEvent::PanStart {
+                axis: Axis::Vertical
+            }
+        );
+        let events = g.move_to(at(1, 30.0, 25.0, 30.0));
+        assert_eq!(
+            events,
+            vec![GestureEvent::Pan {
+                axis: Axis::Vertical,
+                offset: (30.0, 25.0),
+                delta: (18.0, 5.0),
+            }]
+        );
+        let events = g.up(at(1, 30.0, 25.0, 40.0));
+        assert!(matches!(
+            events.last(),
+            Some(GestureEvent::PanEnd {
+                axis: Axis::Vertical,
+                ..
+            })
+        ));
+        assert!(g.long_press().is_none());
+    }
+
+    #[test]
+    fn test_fast_release_is_a_swipe() {
+        let mut g = recognizer();
+        g.down(at(1, 200.0, 0.0, 0.0));
+        for step in 1..=5 {
+            g.move_to(at(1, 200.0 - step as f64 * 20.0, 0.0, step as f64 * 16.0));
+        }
+        let events = g.up(at(1, 100.0, 0.0, 90.0));
+        assert!(matches!(
+            events[0],
+            GestureEvent::Swipe { direction: SwipeDirection::Left, velocity } if velocity > 1.0
+        ));
+
+        // 멈췄다가 놓으면 스와이프 아님
+        g.down(at(1, 0.0, 0.0, 1000.0));
+        g.move_to(at(1, 0.0, 40.0, 1016.0));
+        let events = g.up(at(1, 0.0, 40.0, 1300.0));
+        assert_eq!(
+            events,
+            vec![GestureEvent::PanEnd {
+                axis: Axis::Vertical,
+                offset: (0.0, 40.0),
+                velocity: (0.0, 0.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_two_pointers_pinch_and_end_without_tap() {
+        let mut g = recognizer();
+        g.down(at(1, 100.0, 100.0, 0.0));
+        let events = g.down(at(2, 200.0, 100.0, 5.0));
+        assert_eq!(
+            events,
+            vec![GestureEvent::PinchStart {
+                center: (150.0, 100.0)
+            }]
+        );
+        let events = g.move_to(at(2, 300.0, 100.0, 20.0));
+        assert_eq!(
+            events,
+            vec![GestureEvent::Pinch {
+                scale: 2.0,
+                center: (200.0, 100.0),
+            }]
+        );
+        assert_eq!(
+            g.up(at(2, 300.0, 100.0, 30.0)),
+            vec![GestureEvent::PinchEnd]
+        );
+        assert!(g.up(at(1, 100.0, 100.0, 40.0)).is_empty());
+    }
+
+    #[test]
+    fn test_taps_double_taps_and_long_press() {
+        let mut g = recognizer();
+        g.down(at(1, 10.0, 10.0, 0.0));
+        assert_eq!(
+            g.up(at(1, 10.0, 10.0, 50.0)),
+            vec![GestureEvent::Tap { x: 10.0, y: 10.0 }]
+        );
+        g.down(at(1, 14.0, 12.0, 200.0));
+        assert_eq!(
+            g.up(at(1, 14.0, 12.0, 240.0)),
+            vec![GestureEvent::DoubleTap { x: 14.0, y: 12.0 }]
+        );
+
+        g.down(at(1, 50.0, 50.0, 1000.0));
+        assert_eq!(
+            g.long_press(),
+            Some(GestureEvent::LongPress { x: 50.0, y: 50.0 })
+        );
+        assert!(g.up(at(1, 50.0, 50.0, 1600.0)).is_empty());
+    }
+
+    #[test]
+    fn test_cancel_resets_active_gesture() {
+        let mut g = recognizer();
+        g.down(at(1, 0.0, 0.0, 0.0));
+        g.move_to(at(1, 0.0, 30.0, 16.0));
+        assert_eq!(g.cancel(), vec![GestureEvent::Cancel]);
+        assert!(g.up(at(1, 0.0, 30.0, 32.0)).is_empty());
+        assert!(g.cancel().is_empty());
+    }
+
+    #[test]
+    fn test_repeated_down_replaces_a_pointer_whose_up_was_missed() {
+        let mut g = recognizer();
+        // 마우스를 요소 밖에서 떼어 up 이 오지 않음
+        g.down(at(1, 0.0, 0.0, 0.0));
+        g.move_to(at(1, 40.0, 0.0, 16.0));
+        assert_eq!(
+            g.down(at(1, 100.0, 100.0, 500.0)),
+            vec![
+                GestureEvent::Cancel,
+                GestureEvent::Press { x: 100.0, y: 100.0 }
+            ]
+        );
+        // 새 누름은 처음부터 인식
+        assert_eq!(
+            g.up(at(1, 100.0, 100.0, 550.0)),
+            vec![GestureEvent::Tap { x: 100.0, y: 100.0 }]
+        );
+
+        // 움직이지 않고 남은 포인터는 조용히 교체
+        g.down(at(1, 0.0, 0.0, 2000.0));
+        assert_eq!(
+            g.down(at(1, 5.0, 5.0, 3000.0)),
+            vec![GestureEvent::Press { x: 5.0, y: 5.0 }]
+        );
+        assert_eq!(
+            g.long_press(),
+            Some(GestureEvent::LongPress { x: 5.0, y: 5.0 })
+        );
+    }
+}
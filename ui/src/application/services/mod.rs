@@ -0,0 +1,15 @@
+pub mod face_geometry;
+pub mod gallery_snapshot;
+pub mod gesture_recognizer;
+pub mod page_requests;
+pub mod photo_layout;
+pub mod photo_selection;
+pub mod route_parser;
+pub mod search_query_parser;
+pub mod search_vocabulary;
+pub mod subscription;
+pub mod swipe_gesture;
+pub mod timeline_layout;
+pub mod virtual_grid;
+pub mod web_mercator;
+pub mod zoom_transform;
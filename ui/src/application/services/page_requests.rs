@@ -0,0 +1,260 @@
+/// 요청을 보낼 때 받은 번호표 (응답을 반영해도 되는지 확인할 때 사용)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PageTicket {
+    generation: u64,
+}
+
+/// 도착한 응답을 목록에 반영하는 방법
+#[derive(Clone, Debug, PartialEq)]
+pub enum PageUpdate<T> {
+    /// 목록 뒤에 붙임 (다음 페이지)
+    Append(T),
+    /// 목록을 새로 시작하고 교체 (새로고침, 사진 앞뒤 구간)
+    Replace(T),
+    /// 오류 표시 (목록은 그대로)
+    Fail(String),
+    /// 그 사이 목록을 새로 시작해 버림
+    Discard,
+}
+
+/// 페이지 목록 요청의 세대 관리
+///
+/// 목록을 새로 시작하면(검색 조건 변경, 날짜 이동, 새로고침 반영) 세대가 바뀌고,
+/// 이전 세대에 보낸 요청의 응답은 도착해도 버립니다.
+/// 다음 페이지 요청은 한 번에 하나만 보냅니다.
+#[derive(Clone, Debug, Default)]
+pub struct PageRequests {
+    generation: u64,
+    is_loading_next: bool,
+}
+
+impl PageRequests {
+    /// 목록을 처음부터 다시 시작 (진행 중인 요청의 응답은 모두 버림)
+    pub fn restart(&mut self) {
+        self.generation += 1;
+        self.is_loading_next = false;
+    }
+
+    /// 다음 페이지 요청 시작 (더 없거나 이미 요청 중이면 `None`)
+    pub fn begin_next(&mut self, has_more: bool) -> Option<PageTicket> {
+        if !has_more || self.is_loading_next {
+            return None;
+        }
+        self.is_loading_next = true;
+        Some(self.ticket())
+    }
+
+    /// 다음 페이지 응답 반영 방법 (그 사이 목록을 새로 시작했으면 성공이든 실패든 버림)
+    pub fn finish_next<T>(
+        &mut self,
+        ticket: PageTicket,
+        result: Result<T, String>,
+    ) -> PageUpdate<T> {
+        if !self.is_current(ticket) {
+            return PageUpdate::Discard;
+        }
+        self.is_loading_next = false;
+        match result {
+            Ok(page) => PageUpdate::Append(page),
+            Err(message) => PageUpdate::Fail(message),
+        }
+    }
+
+    /// 기존 목록을 둔 채 첫 페이지를 다시 받는 요청 시작 (새로고침)
+    pub fn begin_reload(&self) -> PageTicket {
+        self.ticket()
+    }
+
+    /// 다시 받은 목록 반영 방법
+    ///
+    /// 교체할 때는 새 세대를 시작해, 진행 중이던 다음 페이지 요청의 응답을 버립니다.
+    /// 실패하면 기존 목록과 진행 중인 요청을 그대로 둡니다.
+    pub fn finish_reload<T>(
+        &mut self,
+        ticket: PageTicket,
+        result: Result<T, String>,
+    ) -> PageUpdate<T> {
+        if !self.is_current(ticket) {
+            return PageUpdate::Discard;
+        }
+        match result {
+            Ok(page) => {
+                self.restart();
+                PageUpdate::Replace(page)
+            }
+            Err(message) => PageUpdate::Fail(message),
+        }
+    }
+
+    fn is_current(&self, ticket: PageTicket) -> bool {
+        ticket.generation == self.generation
+    }
+
+    fn ticket(&self) -> PageTicket {
+        PageTicket {
+            generation: self.generation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 응답을 받은 그대로 반영하는 가짜 갤러리 목록
+    #[derive(Default)]
+    struct Gallery {
+        requests: PageRequests,
+        photos: Vec<u32>,
+        error: Option<String>,
+    }
+
+    impl Gallery {
+        fn apply(&mut self, update: PageUpdate<Vec<u32>>) -> bool {
+            match update {
+                PageUpdate::Append(page) => self.photos.extend(page),
+                PageUpdate::Replace(page) => self.photos = page,
+                PageUpdate::Fail(message) => self.error = Some(message),
+                PageUpdate::Discard => return false,
+            }
+            true
+        }
+
+        fn finish_next(&mut self, ticket: PageTicket, result: Result<Vec<u32>, String>) -> bool {
+            let update = self.requests.finish_next(ticket, result);
+            self.apply(update)
+        }
+
+        fn finish_reload(&mut self, ticket: PageTicket, result: Result<Vec<u32>, String>) -> bool {
+            let update = self.requests.finish_reload(ticket, result);
+            self.apply(update)
+        }
+    }
+
+    #[test]
+    fn test_only_one_next_page_request_at_a_time() {
+        let mut requests = PageRequests::default();
+        let load_more = requests.begin_next(true).unwrap();
+        // 불러오는 중 마지막 사진에서 다음으로 넘기면 중복 요청하지 않음
+        assert_eq!(requests.begin_next(true), None);
+        assert_eq!(
+            requests.finish_next(load_more, Ok(())),
+            PageUpdate::Append(())
+        );
+        assert!(requests.begin_next(true).is_some());
+    }
+
+    #[test]
+    fn test_no_next_page_request_after_the_last_page() {
+        let mut requests = PageRequests::default();
+        assert_eq!(requests.begin_next(false), None);
+        assert!(requests.begin_next(true).is_some());
+    }
+
+    #[test]
+    fn test_restart_drops_next_page_in_flight() {
+        let mut gallery = Gallery::default();
+        let old = gallery.requests.begin_next(true).unwrap();
+        // 검색 조건 변경: 새 첫 페이지 요청은 바로 보낼 수 있음
+        gallery.requests.restart();
+        let first = gallery.requests.begin_next(true).unwrap();
+        assert!(!gallery.finish_next(old, Ok(vec![1, 2])));
+        assert_eq!(gallery.requests.begin_next(true), None);
+        assert!(gallery.finish_next(first, Ok(vec![7, 8])));
+        assert_eq!(gallery.photos, vec![7, 8]);
+    }
+
+    #[test]
+    fn test_refresh_during_load_more_drops_the_stale_page() {
+        let mut gallery = Gallery {
+            photos: vec![1, 2],
+            ..Gallery::default()
+        };
+        let load_more = gallery.requests.begin_next(true).unwrap();
+        let reload = gallery.requests.begin_reload();
+        assert!(gallery.finish_reload(reload, Ok(vec![0, 1, 2])));
+        // 새로고침 뒤에 도착한 이전 목록의 다음 페이지는 붙이지 않음
+        assert!(!gallery.finish_next(load_more, Ok(vec![3, 4])));
+        assert_eq!(gallery.photos, vec![0, 1, 2]);
+        assert!(gallery.requests.begin_next(true).is_some());
+    }
+
+    #[test]
+    fn test_next_page_that_arrives_before_reload_is_kept_until_replaced() {
+        let mut gallery = Gallery {
+            photos: vec![1, 2],
+            ..Gallery::default()
+        };
+        let load_more = gallery.requests.begin_next(true).unwrap();
+        let reload = gallery.requests.begin_reload();
+        assert!(gallery.finish_next(load_more, Ok(vec![3, 4])));
+        assert_eq!(gallery.photos, vec![1, 2, 3, 4]);
+        assert!(gallery.finish_reload(reload, Ok(vec![0, 1])));
+        assert_eq!(gallery.photos, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_reload_is_dropped_when_list_restarts_meanwhile() {
+        let mut gallery = Gallery::default();
+        let reload = gallery.requests.begin_reload();
+        // 새로고침 중 날짜 이동
+        gallery.requests.restart();
+        let jump = gallery.requests.begin_next(true).unwrap();
+        assert!(!gallery.finish_reload(reload, Ok(vec![0, 1])));
+        assert!(gallery.finish_next(jump, Ok(vec![5, 6])));
+        assert_eq!(gallery.photos, vec![5, 6]);
+    }
+
+    #[test]
+    fn test_next_photo_racing_load_more_appends_once() {
+        let mut gallery = Gallery {
+            photos: vec![1, 2],
+            ..Gallery::default()
+        };
+        // 스크롤 끝에서 load_more, 곧바로 미리보기 마지막 사진에서 next_photo
+        let load_more = gallery.requests.begin_next(true);
+        let next_photo = gallery.requests.begin_next(true);
+        assert!(load_more.is_some());
+        assert_eq!(next_photo, None);
+        assert!(gallery.finish_next(load_more.unwrap(), Ok(vec![3, 4])));
+        assert_eq!(gallery.photos, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_superseded_error_does_not_overwrite_status() {
+        let mut gallery = Gallery::default();
+        let old = gallery.requests.begin_next(true).unwrap();
+        let reload = gallery.requests.begin_reload();
+        // 날짜 이동으로 목록을 새로 시작한 뒤 이전 요청들이 실패
+        gallery.requests.restart();
+        let jump = gallery.requests.begin_next(true).unwrap();
+        assert!(!gallery.finish_next(old, Err("timeout".to_string())));
+        assert!(!gallery.finish_reload(reload, Err("timeout".to_string())));
+        assert_eq!(gallery.error, None);
+        assert!(gallery.finish_next(jump, Ok(vec![5])));
+        assert_eq!(gallery.photos, vec![5]);
+    }
+
+    #[test]
+    fn test_failed_reload_leaves_list_and_load_more_untouched() {
+        let mut gallery = Gallery {
+            photos: vec![1, 2],
+            ..Gallery::default()
+        };
+        let load_more = gallery.requests.begin_next(true).unwrap();
+        let reload = gallery.requests.begin_reload();
+        assert!(gallery.finish_reload(reload, Err("offline".to_string())));
+        assert_eq!(gallery.error.as_deref(), Some("offline"));
+        assert!(gallery.finish_next(load_more, Ok(vec![3])));
+        assert_eq!(gallery.photos, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_failed_next_page_can_be_retried() {
+        let mut gallery = Gallery::default();
+        let first = gallery.requests.begin_next(true).unwrap();
+        assert!(gallery.finish_next(first, Err("offline".to_string())));
+        assert_eq!(gallery.error.as_deref(), Some("offline"));
+        assert!(gallery.requests.begin_next(true).is_some());
+    }
+}
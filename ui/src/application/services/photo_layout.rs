@@ -0,0 +1,265 @@
+//! 하루치 사진을 배치 방식에 따라 줄로 나누고 줄 안의 타일 위치를 계산
+//!
+//! 가상 그리드는 여기서 나눈 줄을 한 행으로 다루므로, 모든 방식이 줄 높이를 미리 알 수 있습니다.
+
+use crate::application::services::virtual_grid::{GridMetrics, TILE_HEIGHT};
+use crate::domain::value_objects::grid_mode::GridMode;
+use std::ops::Range;
+
+/// 행 맞춤 방식의 기준 줄 높이 (px, 좁은 화면에서는 너비의 절반까지 줄임)
+pub const JUSTIFIED_ROW_HEIGHT: f64 = 180.0;
+/// 배치에 쓰는 가로/세로 비율 범위 (아주 긴 파노라마가 줄 하나를 가늘게 만들지 않도록)
+const MIN_ASPECT: f64 = 0.25;
+const MAX_ASPECT: f64 = 4.0;
+
+/// 줄 시작 기준 타일 위치와 크기 (px)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TileBox {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// 배치된 한 줄
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlacedRow {
+    /// 입력 비율 목록에서의 위치
+    pub photos: Range<usize>,
+    /// 다음 줄 시작까지의 높이 (간격 포함)
+    pub height: f64,
+    pub tiles: Vec<TileBox>,
+}
+
+/// `aspects`(가로/세로 비율) 순서대로 사진을 배치
+pub fn place(mode: GridMode, aspects: &[f64], metrics: GridMetrics) -> Vec<PlacedRow> {
+    let aspects: Vec<f64> = aspects
+        .iter()
+        .map(|aspect| aspect.clamp(MIN_ASPECT, MAX_ASPECT))
+        .collect();
+    // 너비를 재기 전에는 비율 배치를 할 수 없으므로 같은 크기 타일로 둠
+    if metrics.width <= 0.0 {
+        return place_square(aspects.len(), metrics);
+    }
+    match mode {
+        GridMode::Square => place_square(aspects.len(), metrics),
+        GridMode::Justified => place_justified(&aspects, metrics),
+        GridMode::Masonry => place_masonry(&aspects, metrics),
+    }
+}
+
+fn place_square(count: usize, metrics: GridMetrics) -> Vec<PlacedRow> {
+    let columns = metrics.columns.max(1);
+    let width = metrics.column_width();
+    (0..count)
+        .step_by(columns)
+        .map(|start| {
+            let photos = start..(start + columns).min(count);
+            let tiles = (0..photos.len())
+                .map(|column| TileBox {
+                    left: column as f64 * (width + metrics.gap),
+                    top: 0.0,
+                    width,
+                    height: TILE_HEIGHT,
+                })
+                .collect();
+            PlacedRow {
+                photos,
+                height: TILE_HEIGHT + metrics.gap,
+                tiles,
+            }
+        })
+        .collect()
+}
+
+/// 기준 높이로 늘어놓다가 너비를 넘으면 그 줄을 너비에 딱 맞게 줄임 (마지막 줄은 기준 높이)
+fn place_justified(aspects: &[f64], metrics: GridMetrics) -> Vec<PlacedRow> {
+    let target = JUSTIFIED_ROW_HEIGHT.min(metrics.width / 2.0);
+    let mut rows = Vec::new();
+    let mut start = 0;
+    let mut aspect_sum = 0.0;
+    for (index, aspect) in aspects.iter().enumerate() {
+        aspect_sum += aspect;
+        let gaps = metrics.gap * (index - start) as f64;
+        if aspect_sum * target + gaps >= metrics.width {
+            let height = (metrics.width - gaps) / aspect_sum;
+            rows.push(justified_row(
+                aspects,
+                start..index + 1,
+                height,
+                metrics.gap,
+            ));
+            start = index + 1;
+            aspect_sum = 0.0;
+        }
+    }
+    if start < aspects.len() {
+        rows.push(justified_row(
+            aspects,
+            start..aspects.len(),
+            target,
+            metrics.gap,
+        ));
+    }
+    rows
+}
+
+fn justified_row(aspects: &[f64], photos: Range<usize>, height: f64, gap: f64) -> PlacedRow {
+    let mut left = 0.0;
+    let tiles = aspects[photos.clone()]
+        .iter()
+        .map(|aspect| {
+            let tile = TileBox {
+                left,
+                top: 0.0,
+                width: aspect * height,
+                height,
+            };
+            left += tile.width + gap;
+            tile
+        })
+        .collect();
+    PlacedRow {
+        photos,
+        height: height + gap,
+        tiles,
+    }
+}
+
+/// 가장 짧은 열에 차례로 붙인 뒤 `columns` 장씩 한 줄로 묶음
+///
+/// 새 타일은 항상 가장 짧은 열 끝에서 시작하므로 시작 위치가 순서대로 커지고,
+/// 줄 높이는 다음 줄 첫 타일까지의 거리가 됩니다. 줄 아래로 넘치는 타일은 앞뒤 여유 행이 덮습니다.
+fn place_masonry(aspects: &[f64], metrics: GridMetrics) -> Vec<PlacedRow> {
+    let columns = metrics.columns.max(1);
+    let width = metrics.column_width();
+    let mut bottoms = vec![0.0_f64; columns];
+    let placed: Vec<TileBox> = aspects
+        .iter()
+        .map(|aspect| {
+            let column = shortest_column(&bottoms);
+            let tile = TileBox {
+                left: column as f64 * (width + metrics.gap),
+                top: bottoms[column],
+                width,
+                height: width / aspect,
+            };
+            bottoms[column] += tile.height + metrics.gap;
+            tile
+        })
+        .collect();
+    let block_height = bottoms.iter().copied().fold(0.0, f64::max);
+
+    (0..placed.len())
+        .step_by(columns)
+        .map(|start| {
+            let photos = start..(start + columns).min(placed.len());
+            let top = placed[start].top;
+            let next_top = placed.get(photos.end).map_or(block_height, |tile| tile.top);
+            let tiles = placed[photos.clone()]
+                .iter()
+                .map(|tile| TileBox {
+                    top: tile.top - top,
+                    ..*tile
+                })
+                .collect();
+            PlacedRow {
+                photos,
+                height: next_top - top,
+                tiles,
+            }
+        })
+        .collect()
+}
+
+fn shortest_column(bottoms: &[f64]) -> usize {
+    bottoms
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(b.1))
+        .map_or(0, |(column, _)| column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics() -> GridMetrics {
+        GridMetrics {
+            width: 1000.0,
+            columns: 4,
+            gap: 10.0,
+        }
+    }
+
+    fn right_edge(row: &PlacedRow) -> f64 {
+        row.tiles.last().map_or(0.0, |tile| tile.left + tile.width)
+    }
+
+    #[test]
+    fn test_square_rows_follow_columns() {
+        let rows = place(GridMode::Square, &[1.0; 6], metrics());
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].photos, 4..6);
+        assert_eq!(rows[0].height, TILE_HEIGHT + 10.0);
+        assert_eq!(rows[0].tiles[1].left, 242.5 + 10.0);
+    }
+
+    #[test]
+    fn test_justified_rows_fill_width_and_keep_ratio() {
+        let aspects = [16.0 / 9.0, 2.0 / 3.0, 1.0, 16.0 / 9.0, 2.0 / 3.0, 1.0, 1.0];
+        let rows = place(GridMode::Justified, &aspects, metrics());
+
+        assert!(rows.len() >= 2);
+        for row in &rows[..rows.len() - 1] {
+            assert!((right_edge(row) - 1000.0).abs() < 1e-6);
+            for (tile, aspect) in row.tiles.iter().zip(&aspects[row.photos.clone()]) {
+                assert!((tile.width / tile.height - aspect).abs() < 1e-9);
+            }
+        }
+        // 마지막 줄은 늘리지 않음
+        let last = rows.last().unwrap();
+        assert_eq!(last.height, JUSTIFIED_ROW_HEIGHT + 10.0);
+        assert!(right_edge(last) <= 1000.0);
+        assert_eq!(
+            rows.iter().map(|r| r.photos.len()).sum::<usize>(),
+            aspects.len()
+        );
+    }
+
+    #[test]
+    fn test_masonry_fills_shortest_column_with_increasing_tops() {
+        let aspects = [0.5, 1.0, 2.0, 1.0, 1.0, 0.5, 1.0, 2.0, 1.0];
+        let rows = place(GridMode::Masonry, &aspects, metrics());
+
+        assert_eq!(rows.len(), 3);
+        // 다섯 번째 사진은 가장 짧은 열(세 번째, 가로 사진)에 붙음
+        let fifth = rows[1].tiles[0];
+        assert_eq!(fifth.left, rows[0].tiles[2].left);
+        // 줄 높이를 이어 붙이면 각 줄의 시작 위치가 됨
+        let mut row_top = 0.0;
+        let mut last_top = 0.0;
+        for row in &rows {
+            for tile in &row.tiles {
+                assert!(row_top + tile.top >= last_top);
+                last_top = row_top + tile.top;
+            }
+            assert!(row.height > 0.0);
+            row_top += row.height;
+        }
+    }
+
+    #[test]
+    fn test_unmeasured_width_falls_back_to_square() {
+        let unmeasured = GridMetrics {
+            width: 0.0,
+            ..metrics()
+        };
+
+        let rows = place(GridMode::Justified, &[1.0, 2.0], unmeasured);
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].height, TILE_HEIGHT + 10.0);
+    }
+}
@@ -0,0 +1,127 @@
+//! 갤러리 여러 장 선택 (하나씩 선택/해제, Shift 범위 선택)
+//!
+//! 사진 id 로 기억하므로 다음 페이지를 불러오거나 목록을 다시 받아도 선택이 유지됩니다.
+
+use std::collections::HashSet;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PhotoSelection {
+    ids: HashSet<Uuid>,
+    /// 범위 선택의 시작 (마지막으로 하나씩 선택/해제한 사진)
+    anchor: Option<Uuid>,
+}
+
+impl PhotoSelection {
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn contains(&self, photo_id: Uuid) -> bool {
+        self.ids.contains(&photo_id)
+    }
+
+    pub fn ids(&self) -> Vec<Uuid> {
+        self.ids.iter().copied().collect()
+    }
+
+    pub fn select(&mut self, photo_id: Uuid) {
+        self.ids.insert(photo_id);
+        self.anchor = Some(photo_id);
+    }
+
+    pub fn toggle(&mut self, photo_id: Uuid) {
+        if !self.ids.remove(&photo_id) {
+            self.ids.insert(photo_id);
+        }
+        self.anchor = Some(photo_id);
+    }
+
+    /// 시작 사진부터 `photo_id` 까지 `order`(목록 순서)에 있는 사진을 모두 선택
+    ///
+    /// 시작 사진이 없거나 목록에 없으면 `photo_id` 만 선택합니다.
+    pub fn select_range(&mut self, order: &[Uuid], photo_id: Uuid) {
+        let position = |id: Uuid| order.iter().position(|x| *x == id);
+        match (self.anchor.and_then(position), position(photo_id)) {
+            (Some(anchor), Some(target)) => {
+                let range = anchor.min(target)..=anchor.max(target);
+                self.ids.extend(order[range].iter().copied());
+            }
+            _ => self.select(photo_id),
+        }
+    }
+
+    /// 목록에서 사라진 사진 제외 (예: 휴지통으로 이동)
+    pub fn remove(&mut self, photo_ids: &[Uuid]) {
+        for photo_id in photo_ids {
+            self.ids.remove(photo_id);
+        }
+        if self.anchor.is_some_and(|anchor| photo_ids.contains(&anchor)) {
+            self.anchor = None;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(count: usize) -> Vec<Uuid> {
+        (0..count).map(|_| Uuid::new_v4()).collect()
+    }
+
+    #[test]
+    fn test_toggle_sets_range_anchor() {
+        let order = ids(6);
+        let mut selection = PhotoSelection::default();
+
+        selection.toggle(order[4]);
+        selection.select_range(&order, order[1]);
+
+        assert_eq!(selection.len(), 4);
+        assert!((1..=4).all(|i| selection.contains(order[i])));
+        assert!(!selection.contains(order[5]));
+
+        // 시작점은 그대로이므로 반대 방향으로 늘릴 수 있음
+        selection.select_range(&order, order[5]);
+        assert_eq!(selection.len(), 5);
+    }
+
+    #[test]
+    fn test_range_without_anchor_selects_single_photo() {
+        let order = ids(3);
+        let mut selection = PhotoSelection::default();
+
+        selection.select_range(&order, order[2]);
+        assert_eq!(selection.ids(), vec![order[2]]);
+
+        // 시작 사진이 목록에 없으면 (예: 다른 날짜로 이동) 하나만 선택
+        let mut selection = PhotoSelection::default();
+        selection.toggle(Uuid::new_v4());
+        selection.select_range(&order, order[0]);
+        assert_eq!(selection.len(), 2);
+    }
+
+    #[test]
+    fn test_remove_drops_ids_and_anchor() {
+        let order = ids(4);
+        let mut selection = PhotoSelection::default();
+        selection.toggle(order[0]);
+        selection.toggle(order[1]);
+
+        selection.remove(&[order[1]]);
+        selection.select_range(&order, order[3]);
+
+        assert!(!selection.contains(order[1]));
+        assert_eq!(selection.len(), 2);
+        assert!(!selection.contains(order[2]));
+    }
+}
@@ -0,0 +1,204 @@
+use uuid::Uuid;
+
+/// 주소창 경로로 표현되는 화면
+///
+/// 검색 조건은 경로가 아니라 쿼리(`?q=...`)로 표현하므로, 화면을 옮겨도 그대로 유지됩니다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Route {
+    /// `/` (검색 중이면 `/search`)
+    #[default]
+    Gallery,
+    /// `/photo/:id` - 사진 미리보기
+    Photo(Uuid),
+    /// `/albums/:id` - 앨범 사진만 보는 갤러리
+    Album(Uuid),
+    /// `/albums/:id/photo/:photo_id` - 앨범에서 연 사진 미리보기
+    AlbumPhoto(Uuid, Uuid),
+    Map,
+    Places,
+    People,
+    /// `/people/:id` - 인물 상세 패널을 연 인물 화면
+    Person(Uuid),
+    Duplicates,
+    Cleanup,
+}
+
+impl Route {
+    /// 경로 해석 (알 수 없는 경로는 갤러리)
+    pub fn parse(path: &str) -> Self {
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let id = |value: &str| Uuid::parse_str(value).ok();
+
+        match segments.as_slice() {
+            ["photo", photo_id] => id(photo_id).map(Route::Photo),
+            ["albums", album_id] => id(album_id).map(Route::Album),
+            ["albums", album_id, "photo", photo_id] => id(album_id)
+                .zip(id(photo_id))
+                .map(|(album_id, photo_id)| Route::AlbumPhoto(album_id, photo_id)),
+            ["people", person_id] => id(person_id).map(Route::Person),
+            ["map"] => Some(Route::Map),
+            ["places"] => Some(Route::Places),
+            ["people"] => Some(Route::People),
+            ["duplicates"] => Some(Route::Duplicates),
+            ["cleanup"] => Some(Route::Cleanup),
+            _ => None,
+        }
+        .unwrap_or_default()
+    }
+
+    /// 주소창 경로 (`has_query`: 검색 조건이 적용되어 있는지)
+    pub fn path(&self, has_query: bool) -> String {
+        match self {
+            Route::Gallery if has_query => "/search".to_string(),
+            Route::Gallery => "/".to_string(),
+            Route::Photo(id) => format!("/photo/{}", id),
+            Route::Album(id) => format!("/albums/{}", id),
+            Route::AlbumPhoto(album_id, photo_id) => {
+                format!("/albums/{}/photo/{}", album_id, photo_id)
+            }
+            Route::Map => "/map".to_string(),
+            Route::Places => "/places".to_string(),
+            Route::People => "/people".to_string(),
+            Route::Person(id) => format!("/people/{}", id),
+            Route::Duplicates => "/duplicates".to_string(),
+            Route::Cleanup => "/cleanup".to_string(),
+        }
+    }
+
+    /// 미리보기로 연 사진
+    pub fn photo(&self) -> Option<Uuid> {
+        match self {
+            Route::Photo(id) | Route::AlbumPhoto(_, id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// 보고 있는 앨범 (앨범에서 연 미리보기 포함)
+    pub fn album(&self) -> Option<Uuid> {
+        match self {
+            Route::Album(id) | Route::AlbumPhoto(id, _) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// 이 화면에서 사진을 열었을 때의 경로 (앨범에서 열면 앨범 안의 사진)
+    pub fn with_photo(&self, photo_id: Uuid) -> Route {
+        match self.album() {
+            Some(album_id) => Route::AlbumPhoto(album_id, photo_id),
+            None => Route::Photo(photo_id),
+        }
+    }
+
+    /// 미리보기를 닫으면 돌아갈 경로
+    pub fn without_photo(&self) -> Route {
+        match self {
+            Route::Photo(_) => Route::Gallery,
+            Route::AlbumPhoto(album_id, _) => Route::Album(*album_id),
+            route => *route,
+        }
+    }
+}
+
+/// 경로와 쿼리 문자열(`?` 제외)을 합친 주소
+pub fn route_url(route: Route, query: &str) -> String {
+    let query = query.trim_start_matches('?');
+    let path = route.path(!query.is_empty());
+    if query.is_empty() {
+        path
+    } else {
+        format!("{}?{}", path, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "6f1c2a8e-1d2b-4c3d-9e4f-5a6b7c8d9e0f";
+
+    fn id() -> Uuid {
+        Uuid::parse_str(ID).unwrap()
+    }
+
+    #[test]
+    fn test_parses_routes_with_ids() {
+        assert_eq!(Route::parse(&format!("/photo/{}", ID)), Route::Photo(id()));
+        assert_eq!(
+            Route::parse(&format!("/albums/{}/", ID)),
+            Route::Album(id())
+        );
+        assert_eq!(
+            Route::parse(&format!("/people/{}", ID)),
+            Route::Person(id())
+        );
+        assert_eq!(Route::parse("/people"), Route::People);
+    }
+
+    #[test]
+    fn test_parses_photo_opened_from_an_album() {
+        let photo_id = Uuid::new_v4();
+        let route = Route::parse(&format!("/albums/{}/photo/{}", ID, photo_id));
+        assert_eq!(route, Route::AlbumPhoto(id(), photo_id));
+        assert_eq!(route.photo(), Some(photo_id));
+        assert_eq!(route.album(), Some(id()));
+        assert_eq!(
+            Route::parse(&format!("/albums/{}/photo/not-a-uuid", ID)),
+            Route::Gallery
+        );
+    }
+
+    #[test]
+    fn test_photos_keep_the_album_they_were_opened_from() {
+        let photo_id = Uuid::new_v4();
+        let in_album = Route::Album(id()).with_photo(photo_id);
+        assert_eq!(in_album, Route::AlbumPhoto(id(), photo_id));
+        // 앨범 안에서 다음 사진으로 넘겨도 앨범 유지
+        let next = Uuid::new_v4();
+        assert_eq!(in_album.with_photo(next), Route::AlbumPhoto(id(), next));
+        assert_eq!(in_album.without_photo(), Route::Album(id()));
+
+        assert_eq!(Route::Gallery.with_photo(photo_id), Route::Photo(photo_id));
+        assert_eq!(Route::Photo(photo_id).without_photo(), Route::Gallery);
+        assert_eq!(Route::Map.without_photo(), Route::Map);
+    }
+
+    #[test]
+    fn test_unknown_or_malformed_paths_fall_back_to_gallery() {
+        assert_eq!(Route::parse("/"), Route::Gallery);
+        assert_eq!(Route::parse("/search"), Route::Gallery);
+        assert_eq!(Route::parse("/photo/not-a-uuid"), Route::Gallery);
+        assert_eq!(Route::parse("/nowhere/else"), Route::Gallery);
+    }
+
+    #[test]
+    fn test_gallery_url_depends_on_search_query() {
+        assert_eq!(route_url(Route::Gallery, ""), "/");
+        assert_eq!(route_url(Route::Gallery, "?q=beach"), "/search?q=beach");
+    }
+
+    #[test]
+    fn test_other_routes_keep_the_query() {
+        let url = route_url(Route::Photo(id()), "q=beach");
+        assert_eq!(url, format!("/photo/{}?q=beach", ID));
+        assert_eq!(route_url(Route::Map, ""), "/map");
+    }
+
+    #[test]
+    fn test_paths_round_trip() {
+        for route in [
+            Route::Gallery,
+            Route::Photo(id()),
+            Route::Album(id()),
+            Route::AlbumPhoto(id(), Uuid::new_v4()),
+            Route::Map,
+            Route::Places,
+            Route::People,
+            Route::Person(id()),
+            Route::Duplicates,
+            Route::Cleanup,
+        ] {
+            assert_eq!(Route::parse(&route.path(false)), route);
+            assert_eq!(Route::parse(&route.path(true)), route);
+        }
+    }
+}
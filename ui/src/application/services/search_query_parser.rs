@@ -0,0 +1,540 @@
+//! 자연어 검색어 파서
+//!
+//! "작년 여름 바다에서 찍은 사진", "sony photos from last summer in jeju" 처럼
+//! 한국어/영어 검색어를 규칙 기반으로 `SearchFilter`로 변환합니다.
+//! 상대 날짜는 인자로 받은 `today`를 기준으로 계산하므로 같은 입력에 대해 항상 같은 결과를 돌려줍니다.
+
+use crate::application::services::search_vocabulary::*;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use chrono::{Datelike, Duration, Months, NaiveDate};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Season {
+    Spring,
+    Summer,
+    Fall,
+    Winter,
+}
+
+impl Season {
+    fn from_word(word: &str) -> Option<Self> {
+        match word {
+            "봄" | "spring" => Some(Season::Spring),
+            "여름" | "summer" => Some(Season::Summer),
+            "가을" | "fall" | "autumn" => Some(Season::Fall),
+            "겨울" | "winter" => Some(Season::Winter),
+            _ => None,
+        }
+    }
+
+    /// `year`년에 시작하는 계절의 기간 (겨울은 다음 해 2월까지)
+    fn range(self, year: i32) -> Option<(NaiveDate, NaiveDate)> {
+        let (start_month, end_year, end_month) = match self {
+            Season::Spring => (3, year, 5),
+            Season::Summer => (6, year, 8),
+            Season::Fall => (9, year, 11),
+            Season::Winter => (12, year + 1, 2),
+        };
+        let start = NaiveDate::from_ymd_opt(year, start_month, 1)?;
+        Some((start, month_range(end_year, end_month)?.1))
+    }
+}
+
+/// 검색어에서 인식한 조건들 (날짜는 모든 단어를 읽은 뒤 한 번에 계산)
+#[derive(Default)]
+struct ParsedTerms {
+    year: Option<i32>,
+    month: Option<u32>,
+    season: Option<Season>,
+    // "지난 여름" 처럼 이미 끝난 계절을 가리키는지 여부
+    is_past_season: bool,
+    relative_range: Option<(NaiveDate, NaiveDate)>,
+    filter: SearchFilter,
+    // 인식하지 못한 단어 (원문 표기 유지)
+    words: Vec<String>,
+    // 직전 단어가 "in"/"at" 등 장소 전치사였는지 여부
+    expects_place: bool,
+}
+
+pub fn parse_search_query(input: &str, today: NaiveDate) -> SearchFilter {
+    let tokens: Vec<&str> = input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .collect();
+
+    let mut terms = ParsedTerms::default();
+    let mut index = 0;
+    while index < tokens.len() {
+        index += parse_token(&mut terms, &tokens[index..], today);
+    }
+
+    let (date_from, date_to) = resolve_date_range(&terms, today).unzip();
+    let text = (!terms.words.is_empty()).then(|| terms.words.join(" "));
+    SearchFilter { text, date_from, date_to, ..terms.filter }
+}
+
+/// `tokens[0]`부터 한 구문을 해석하고 소비한 단어 수를 반환
+fn parse_token(terms: &mut ParsedTerms, tokens: &[&str], today: NaiveDate) -> usize {
+    let words: Vec<String> = tokens.iter().take(3).map(|t| normalize(t)).collect();
+
+    if let Some(consumed) = parse_date_phrase(terms, &words, today) {
+        terms.expects_place = false;
+        return consumed;
+    }
+    if let Some(consumed) = parse_camera(terms, &words) {
+        return consumed;
+    }
+
+    parse_word(terms, tokens[0], &words[0]);
+    1
+}
+
+fn normalize(token: &str) -> String {
+    token
+        .trim_matches(|c: char| !c.is_alphanumeric() && c != '-')
+        .to_lowercase()
+}
+
+/// 단어 그대로, 또는 조사를 뗀 형태로 `find`를 시도
+fn find_with_particles<T>(word: &str, find: impl Fn(&str) -> Option<T>) -> Option<T> {
+    find(word).or_else(|| {
+        PARTICLES
+            .iter()
+            .filter_map(|particle| word.strip_suffix(particle))
+            .find_map(&find)
+    })
+}
+
+fn parse_word(terms: &mut ParsedTerms, original: &str, word: &str) {
+    if word.is_empty() || STOP_WORDS.contains(&word) {
+        return;
+    }
+    if PLACE_PREPOSITIONS.contains(&word) {
+        terms.expects_place = true;
+        return;
+    }
+    let expects_place = std::mem::take(&mut terms.expects_place);
+
+    if parse_keyword(terms, word) {
+        return;
+    }
+    if let Some(place) = word.strip_suffix(PLACE_PARTICLE).filter(|stem| !stem.is_empty()) {
+        terms.filter.place = Some(place.to_string());
+    } else if expects_place {
+        terms.filter.place = Some(original.trim_matches(|c: char| !c.is_alphanumeric()).to_string());
+    } else {
+        terms.words.push(original.to_string());
+    }
+}
+
+/// 사전에 등록된 태그/장소/렌즈/좋아요/위치정보 키워드 처리
+fn parse_keyword(terms: &mut ParsedTerms, word: &str) -> bool {
+    let filter = &mut terms.filter;
+
+    if let Some(tag) = find_with_particles(word, |w| lookup(TAG_KEYWORDS, w)) {
+        if !filter.tags.iter().any(|t| t == tag) {
+            filter.tags.push(tag.to_string());
+        }
+    } else if let Some(place) = find_with_particles(word, |w| lookup(PLACE_KEYWORDS, w)) {
+        filter.place = Some(place.to_string());
+    } else if let Some(lens) = find_with_particles(word, parse_focal_length) {
+        filter.lens = Some(lens);
+    } else if find_with_particles(word, |w| LIKED_KEYWORDS.contains(&w).then_some(())).is_some() {
+        filter.is_liked = Some(true);
+    } else if find_with_particles(word, |w| GEOTAGGED_KEYWORDS.contains(&w).then_some(())).is_some() {
+        filter.has_location = Some(true);
+    } else {
+        return false;
+    }
+    true
+}
+
+/// "35mm" 형태의 초점거리
+fn parse_focal_length(word: &str) -> Option<String> {
+    let digits = word.strip_suffix("mm")?;
+    digits.parse::<u32>().ok().map(|mm| format!("{}mm", mm))
+}
+
+/// 카메라 브랜드와 (있다면) 바로 뒤의 모델명: "sony a7iii" → "Sony a7iii"
+fn parse_camera(terms: &mut ParsedTerms, words: &[String]) -> Option<usize> {
+    let brand = find_with_particles(&words[0], |w| lookup(CAMERA_KEYWORDS, w))?;
+
+    let model = words.get(1).filter(|w| is_model_name(w));
+    terms.filter.camera = Some(match model {
+        Some(model) => format!("{} {}", brand, model),
+        None => brand.to_string(),
+    });
+    Some(if model.is_some() { 2 } else { 1 })
+}
+
+/// 숫자를 포함한 짧은 단어 (연도/초점거리 제외)를 모델명으로 간주
+fn is_model_name(word: &str) -> bool {
+    word.len() <= 8
+        && word.chars().any(|c| c.is_ascii_digit())
+        && word.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && parse_year(word).is_none()
+        && parse_focal_length(word).is_none()
+}
+
+/// 날짜 관련 구문 처리, 소비한 단어 수 반환
+fn parse_date_phrase(terms: &mut ParsedTerms, words: &[String], today: NaiveDate) -> Option<usize> {
+    if let Some((consumed, range)) = parse_relative_span(words, today) {
+        terms.relative_range = Some(range);
+        return Some(consumed);
+    }
+    if let Some(consumed) = parse_modified_period(terms, words, today) {
+        return Some(consumed);
+    }
+    parse_date_word(terms, &words[0], today).then_some(1)
+}
+
+/// "이번 주", "지난 달", "last summer", "this year" 처럼 수식어 + 기간
+fn parse_modified_period(terms: &mut ParsedTerms, words: &[String], today: NaiveDate) -> Option<usize> {
+    let offset: u32 = match words[0].as_str() {
+        "이번" | "올" | "this" => 0,
+        "지난" | "last" | "previous" => 1,
+        _ => return None,
+    };
+    let period = find_with_particles(words.get(1)?, |w| is_period_word(w).then(|| w.to_string()))?;
+
+    if let Some(season) = Season::from_word(&period) {
+        terms.season = Some(season);
+        terms.is_past_season = offset == 1;
+        if offset == 0 {
+            terms.year = Some(today.year());
+        }
+    } else if matches!(period.as_str(), "해" | "year") {
+        terms.year = Some(today.year() - offset as i32);
+    } else {
+        terms.relative_range = relative_period(&period, offset, today);
+    }
+    Some(2)
+}
+
+fn is_period_word(word: &str) -> bool {
+    Season::from_word(word).is_some()
+        || matches!(word, "주" | "달" | "해" | "week" | "month" | "year")
+}
+
+/// `offset` 만큼 이전의 주/달 기간 (0이면 이번 주/달, 오늘까지)
+fn relative_period(unit: &str, offset: u32, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+    match unit {
+        "주" | "week" => {
+            let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+            let start = monday - Duration::weeks(offset as i64);
+            let end = if offset == 0 { today } else { start + Duration::days(6) };
+            Some((start, end))
+        }
+        "달" | "month" => {
+            let month_start = today.with_day(1)? - Months::new(offset);
+            let (start, end) = month_range(month_start.year(), month_start.month())?;
+            Some((start, if offset == 0 { today } else { end }))
+        }
+        _ => None,
+    }
+}
+
+/// "최근 7일", "last 3 months", "past 2 weeks", "3년 전", "2 years ago"
+fn parse_relative_span(words: &[String], today: NaiveDate) -> Option<(usize, (NaiveDate, NaiveDate))> {
+    let first = words[0].as_str();
+    let second = words.get(1).map(String::as_str)?;
+
+    if first == "최근" {
+        let (amount, unit) = split_korean_amount(second)?;
+        return Some((2, (span_start(today, amount, unit)?, today)));
+    }
+    if matches!(first, "last" | "past") {
+        let amount = second.parse::<u32>().ok()?;
+        let unit = english_unit(words.get(2)?)?;
+        return Some((3, (span_start(today, amount, unit)?, today)));
+    }
+    parse_years_ago(words, today)
+}
+
+fn parse_years_ago(words: &[String], today: NaiveDate) -> Option<(usize, (NaiveDate, NaiveDate))> {
+    let (amount, consumed) = match words.get(1).map(String::as_str)? {
+        "전" | "전에" | "전의" => {
+            let amount = words[0].strip_suffix("년")?.parse::<i32>().ok()?;
+            (amount, 2)
+        }
+        "years" | "year" if words.get(2).is_some_and(|w| w == "ago") => (words[0].parse::<i32>().ok()?, 3),
+        _ => return None,
+    };
+    let year = today.year() - amount;
+    Some((consumed, (NaiveDate::from_ymd_opt(year, 1, 1)?, NaiveDate::from_ymd_opt(year, 12, 31)?)))
+}
+
+/// "7일" → (7, 'd'), "3개월" → (3, 'm'), "2주" → (2, 'w')
+fn split_korean_amount(word: &str) -> Option<(u32, char)> {
+    let (digits, unit) = [("개월", 'm'), ("달", 'm'), ("일", 'd'), ("주", 'w'), ("년", 'y')]
+        .iter()
+        .find_map(|(suffix, unit)| word.strip_suffix(suffix).map(|digits| (digits, *unit)))?;
+    Some((digits.parse().ok()?, unit))
+}
+
+fn english_unit(word: &str) -> Option<char> {
+    match word.trim_end_matches('s') {
+        "day" => Some('d'),
+        "week" => Some('w'),
+        "month" => Some('m'),
+        "year" => Some('y'),
+        _ => None,
+    }
+}
+
+/// 오늘을 포함하여 `amount` 단위만큼의 기간 시작일
+fn span_start(today: NaiveDate, amount: u32, unit: char) -> Option<NaiveDate> {
+    let amount = amount.max(1);
+    match unit {
+        'd' => Some(today - Duration::days(amount as i64 - 1)),
+        'w' => Some(today - Duration::weeks(amount as i64) + Duration::days(1)),
+        'm' => today.checked_sub_months(Months::new(amount))?.succ_opt(),
+        'y' => today.checked_sub_months(Months::new(amount * 12))?.succ_opt(),
+        _ => None,
+    }
+}
+
+/// 한 단어로 된 날짜 표현: 오늘/어제/작년/2023년/3월/여름 등
+fn parse_date_word(terms: &mut ParsedTerms, word: &str, today: NaiveDate) -> bool {
+    let date_word = find_with_particles(word, |w| match w {
+        "오늘" | "today" => Some(DateWord::Range(today, today)),
+        "어제" | "yesterday" => {
+            let yesterday = today.pred_opt()?;
+            Some(DateWord::Range(yesterday, yesterday))
+        }
+        "이번주" => relative_period("주", 0, today).map(|(s, e)| DateWord::Range(s, e)),
+        "지난주" => relative_period("주", 1, today).map(|(s, e)| DateWord::Range(s, e)),
+        "이번달" => relative_period("달", 0, today).map(|(s, e)| DateWord::Range(s, e)),
+        "지난달" => relative_period("달", 1, today).map(|(s, e)| DateWord::Range(s, e)),
+        "올해" => Some(DateWord::Year(today.year())),
+        "작년" | "지난해" => Some(DateWord::Year(today.year() - 1)),
+        "재작년" => Some(DateWord::Year(today.year() - 2)),
+        _ => parse_calendar_word(w),
+    });
+
+    match date_word {
+        Some(DateWord::Range(start, end)) => terms.relative_range = Some((start, end)),
+        Some(DateWord::Year(year)) => terms.year = Some(year),
+        Some(DateWord::Month(month)) => terms.month = Some(month),
+        Some(DateWord::Season(season)) => terms.season = Some(season),
+        None => return false,
+    }
+    true
+}
+
+enum DateWord {
+    Range(NaiveDate, NaiveDate),
+    Year(i32),
+    Month(u32),
+    Season(Season),
+}
+
+fn parse_calendar_word(word: &str) -> Option<DateWord> {
+    if let Some(year) = parse_year(word) {
+        return Some(DateWord::Year(year));
+    }
+    if let Some(month) = word.strip_suffix("월").and_then(|m| m.parse::<u32>().ok()) {
+        return (1..=12).contains(&month).then_some(DateWord::Month(month));
+    }
+    if let Some(position) = MONTH_NAMES.iter().position(|name| *name == word) {
+        return Some(DateWord::Month(position as u32 + 1));
+    }
+    Season::from_word(word).map(DateWord::Season)
+}
+
+/// "2023", "2023년", "2023년도"
+fn parse_year(word: &str) -> Option<i32> {
+    let digits = word.trim_end_matches("도").trim_end_matches("년");
+    if digits.len() != 4 {
+        return None;
+    }
+    digits.parse::<i32>().ok().filter(|year| (1900..=2100).contains(year))
+}
+
+/// 인식한 연도/계절/월/상대 기간을 하나의 날짜 범위로 결합
+fn resolve_date_range(terms: &ParsedTerms, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+    if let Some(season) = terms.season {
+        let year = terms
+            .year
+            .unwrap_or_else(|| latest_season_year(season, terms.is_past_season, today));
+        return season.range(year);
+    }
+    if let Some(month) = terms.month {
+        let year = terms.year.unwrap_or_else(|| {
+            if month <= today.month() { today.year() } else { today.year() - 1 }
+        });
+        return month_range(year, month);
+    }
+    if let Some(year) = terms.year {
+        return Some((NaiveDate::from_ymd_opt(year, 1, 1)?, NaiveDate::from_ymd_opt(year, 12, 31)?));
+    }
+    terms.relative_range
+}
+
+/// 연도 없이 계절만 주어졌을 때의 연도
+///
+/// 기본은 이미 시작된 가장 최근 계절, `is_past`이면 이미 끝난 가장 최근 계절입니다.
+fn latest_season_year(season: Season, is_past: bool, today: NaiveDate) -> i32 {
+    (today.year() - 2..=today.year())
+        .rev()
+        .find(|year| {
+            season.range(*year).is_some_and(|(start, end)| {
+                if is_past { end < today } else { start <= today }
+            })
+        })
+        .unwrap_or(today.year())
+}
+
+fn month_range(year: i32, month: u32) -> Option<(NaiveDate, NaiveDate)> {
+    let start = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let end = start.checked_add_months(Months::new(1))?.pred_opt()?;
+    Some((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn today() -> NaiveDate {
+        date(2026, 10, 18)
+    }
+
+    #[test]
+    fn test_headline_korean_query() {
+        let filter = parse_search_query("작년 여름 바다에서 찍은 사진", today());
+
+        assert_eq!(filter.date_from, Some(date(2025, 6, 1)));
+        assert_eq!(filter.date_to, Some(date(2025, 8, 31)));
+        assert_eq!(filter.tags, vec!["beach".to_string()]);
+        assert_eq!(filter.place, None);
+        assert_eq!(filter.text, None);
+    }
+
+    #[test]
+    fn test_english_last_summer_at_the_beach() {
+        let filter = parse_search_query("photos from last summer at the beach", today());
+
+        assert_eq!(filter.date_from, Some(date(2026, 6, 1)));
+        assert_eq!(filter.date_to, Some(date(2026, 8, 31)));
+        assert_eq!(filter.tags, vec!["beach".to_string()]);
+        assert_eq!(filter.text, None);
+    }
+
+    #[test]
+    fn test_season_without_year_uses_latest_started_season() {
+        let filter = parse_search_query("winter", date(2026, 1, 15));
+
+        assert_eq!(filter.date_from, Some(date(2025, 12, 1)));
+        assert_eq!(filter.date_to, Some(date(2026, 2, 28)));
+    }
+
+    #[test]
+    fn test_last_season_skips_current_season() {
+        let filter = parse_search_query("지난 겨울", date(2026, 1, 15));
+
+        assert_eq!(filter.date_from, Some(date(2024, 12, 1)));
+        assert_eq!(filter.date_to, Some(date(2025, 2, 28)));
+    }
+
+    #[test]
+    fn test_year_and_month_with_known_place() {
+        let filter = parse_search_query("2023년 3월 제주", today());
+
+        assert_eq!(filter.date_from, Some(date(2023, 3, 1)));
+        assert_eq!(filter.date_to, Some(date(2023, 3, 31)));
+        assert_eq!(filter.place.as_deref(), Some("Jeju"));
+    }
+
+    #[test]
+    fn test_month_without_year_is_most_recent() {
+        let filter = parse_search_query("december", today());
+
+        assert_eq!(filter.date_from, Some(date(2025, 12, 1)));
+        assert_eq!(filter.date_to, Some(date(2025, 12, 31)));
+    }
+
+    #[test]
+    fn test_camera_model_tag_and_english_place() {
+        let filter = parse_search_query("sony a7iii night photos in paris", today());
+
+        assert_eq!(filter.camera.as_deref(), Some("Sony a7iii"));
+        assert_eq!(filter.tags, vec!["night".to_string()]);
+        assert_eq!(filter.place.as_deref(), Some("Paris"));
+    }
+
+    #[test]
+    fn test_unknown_place_after_preposition_keeps_original_case() {
+        let filter = parse_search_query("dogs in Lisbon", today());
+
+        assert_eq!(filter.tags, vec!["dog".to_string()]);
+        assert_eq!(filter.place.as_deref(), Some("Lisbon"));
+    }
+
+    #[test]
+    fn test_unknown_korean_place_particle() {
+        let filter = parse_search_query("강릉역에서 35mm", today());
+
+        assert_eq!(filter.place.as_deref(), Some("강릉역"));
+        assert_eq!(filter.lens.as_deref(), Some("35mm"));
+    }
+
+    #[test]
+    fn test_relative_days_and_liked() {
+        let filter = parse_search_query("최근 7일 좋아요", today());
+
+        assert_eq!(filter.date_from, Some(date(2026, 10, 12)));
+        assert_eq!(filter.date_to, Some(today()));
+        assert_eq!(filter.is_liked, Some(true));
+    }
+
+    #[test]
+    fn test_last_month_korean_camera_brand() {
+        let filter = parse_search_query("지난달 캐논으로 찍은 고양이", today());
+
+        assert_eq!(filter.date_from, Some(date(2026, 9, 1)));
+        assert_eq!(filter.date_to, Some(date(2026, 9, 30)));
+        assert_eq!(filter.camera.as_deref(), Some("Canon"));
+        assert_eq!(filter.tags, vec!["cat".to_string()]);
+    }
+
+    #[test]
+    fn test_yesterday_and_this_week() {
+        let yesterday = parse_search_query("어제", today());
+        let this_week = parse_search_query("this week", today());
+
+        assert_eq!(yesterday.date_from, Some(date(2026, 10, 17)));
+        assert_eq!(yesterday.date_to, Some(date(2026, 10, 17)));
+        assert_eq!(this_week.date_from, Some(date(2026, 10, 12)));
+        assert_eq!(this_week.date_to, Some(today()));
+    }
+
+    #[test]
+    fn test_years_ago() {
+        let korean = parse_search_query("3년 전 꽃", today());
+        let english = parse_search_query("2 years ago", today());
+
+        assert_eq!(korean.date_from, Some(date(2023, 1, 1)));
+        assert_eq!(korean.tags, vec!["flower".to_string()]);
+        assert_eq!(english.date_to, Some(date(2024, 12, 31)));
+    }
+
+    #[test]
+    fn test_unrecognized_words_become_text() {
+        let filter = parse_search_query("Birthday cake 2024", today());
+
+        assert_eq!(filter.text.as_deref(), Some("Birthday cake"));
+        assert_eq!(filter.date_from, Some(date(2024, 1, 1)));
+        assert_eq!(filter.date_to, Some(date(2024, 12, 31)));
+    }
+
+    #[test]
+    fn test_parsing_is_deterministic() {
+        let query = "작년 여름 부산 바다 노을 sony 좋아요";
+
+        assert_eq!(parse_search_query(query, today()), parse_search_query(query, today()));
+    }
+}
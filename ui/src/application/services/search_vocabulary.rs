@@ -0,0 +1,156 @@
+//! 자연어 검색어 파서가 사용하는 한국어/영어 키워드 사전
+//!
+//! 모든 키는 소문자이며, 값은 서버에 저장된 표기(영문 태그명, 위치명, 카메라 제조사)입니다.
+
+/// 장면/사물 키워드 → 태그 이름 (`tags.name`)
+pub const TAG_KEYWORDS: &[(&str, &str)] = &[
+    ("바다", "beach"),
+    ("해변", "beach"),
+    ("해수욕장", "beach"),
+    ("beach", "beach"),
+    ("sea", "beach"),
+    ("ocean", "beach"),
+    ("산", "mountain"),
+    ("mountain", "mountain"),
+    ("mountains", "mountain"),
+    ("숲", "forest"),
+    ("forest", "forest"),
+    ("강아지", "dog"),
+    ("dog", "dog"),
+    ("dogs", "dog"),
+    ("고양이", "cat"),
+    ("cat", "cat"),
+    ("cats", "cat"),
+    ("꽃", "flower"),
+    ("flower", "flower"),
+    ("flowers", "flower"),
+    ("음식", "food"),
+    ("food", "food"),
+    ("노을", "sunset"),
+    ("일몰", "sunset"),
+    ("sunset", "sunset"),
+    ("sunsets", "sunset"),
+    ("야경", "night"),
+    ("밤", "night"),
+    ("night", "night"),
+    ("풍경", "landscape"),
+    ("landscape", "landscape"),
+    ("landscapes", "landscape"),
+    ("인물", "portrait"),
+    ("portrait", "portrait"),
+    ("portraits", "portrait"),
+    ("자연", "nature"),
+    ("nature", "nature"),
+    ("건물", "architecture"),
+    ("건축", "architecture"),
+    ("architecture", "architecture"),
+    ("building", "architecture"),
+    ("buildings", "architecture"),
+    ("거리", "street"),
+    ("street", "street"),
+    ("흑백", "black-white"),
+    ("black-white", "black-white"),
+    ("접사", "macro"),
+    ("macro", "macro"),
+];
+
+/// 지명 키워드 → 위치명 (`location_name`)
+pub const PLACE_KEYWORDS: &[(&str, &str)] = &[
+    ("서울", "Seoul"),
+    ("seoul", "Seoul"),
+    ("부산", "Busan"),
+    ("busan", "Busan"),
+    ("제주", "Jeju"),
+    ("제주도", "Jeju"),
+    ("jeju", "Jeju"),
+    ("인천", "Incheon"),
+    ("incheon", "Incheon"),
+    ("대구", "Daegu"),
+    ("daegu", "Daegu"),
+    ("대전", "Daejeon"),
+    ("daejeon", "Daejeon"),
+    ("광주", "Gwangju"),
+    ("gwangju", "Gwangju"),
+    ("강릉", "Gangneung"),
+    ("gangneung", "Gangneung"),
+    ("경주", "Gyeongju"),
+    ("gyeongju", "Gyeongju"),
+    ("속초", "Sokcho"),
+    ("sokcho", "Sokcho"),
+    ("여수", "Yeosu"),
+    ("yeosu", "Yeosu"),
+    ("도쿄", "Tokyo"),
+    ("tokyo", "Tokyo"),
+    ("오사카", "Osaka"),
+    ("osaka", "Osaka"),
+    ("파리", "Paris"),
+    ("paris", "Paris"),
+    ("런던", "London"),
+    ("london", "London"),
+    ("뉴욕", "New York"),
+    ("newyork", "New York"),
+];
+
+/// 카메라 브랜드 키워드 → EXIF 제조사/모델 표기
+pub const CAMERA_KEYWORDS: &[(&str, &str)] = &[
+    ("canon", "Canon"),
+    ("캐논", "Canon"),
+    ("nikon", "Nikon"),
+    ("니콘", "Nikon"),
+    ("sony", "Sony"),
+    ("소니", "Sony"),
+    ("fujifilm", "Fujifilm"),
+    ("fuji", "Fujifilm"),
+    ("후지", "Fujifilm"),
+    ("후지필름", "Fujifilm"),
+    ("leica", "Leica"),
+    ("라이카", "Leica"),
+    ("olympus", "Olympus"),
+    ("올림푸스", "Olympus"),
+    ("panasonic", "Panasonic"),
+    ("파나소닉", "Panasonic"),
+    ("ricoh", "Ricoh"),
+    ("리코", "Ricoh"),
+    ("pentax", "Pentax"),
+    ("펜탁스", "Pentax"),
+    ("iphone", "iPhone"),
+    ("아이폰", "iPhone"),
+    ("galaxy", "Galaxy"),
+    ("갤럭시", "Galaxy"),
+    ("pixel", "Pixel"),
+    ("픽셀", "Pixel"),
+];
+
+pub const LIKED_KEYWORDS: &[&str] = &[
+    "좋아요", "즐겨찾기", "liked", "favorite", "favorites", "favourite", "favourites",
+];
+
+pub const GEOTAGGED_KEYWORDS: &[&str] = &["위치정보", "geotagged", "gps"];
+
+/// 의미 없이 버리는 단어
+pub const STOP_WORDS: &[&str] = &[
+    "사진", "사진들", "찍은", "찍었던", "보여줘", "찾아줘", "모든", "photo", "photos", "picture",
+    "pictures", "pic", "pics", "image", "images", "of", "the", "a", "an", "from", "taken", "shot",
+    "with", "on", "during", "my", "me", "show", "find", "all",
+];
+
+/// 다음 단어가 장소임을 나타내는 전치사
+pub const PLACE_PREPOSITIONS: &[&str] = &["in", "at", "near"];
+
+/// 단어 끝에 붙는 조사 (긴 것부터 검사)
+pub const PARTICLES: &[&str] = &[
+    "에서의", "에서는", "에서", "으로", "에는", "이랑", "하고", "에", "의", "을", "를", "은", "는", "이",
+    "가", "로", "와", "과", "랑", "도", "만",
+];
+
+/// 장소를 나타내는 조사 ("강릉역에서" → 장소 "강릉역")
+pub const PLACE_PARTICLE: &str = "에서";
+
+pub const MONTH_NAMES: &[&str] = &[
+    "january", "february", "march", "april", "may", "june", "july", "august", "september",
+    "october", "november", "december",
+];
+
+pub fn lookup(table: &[(&str, &'static str)], key: &str) -> Option<&'static str> {
+    table.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+}
@@ -0,0 +1,68 @@
+/// 등록한 브라우저 리스너/observer 와 그 해제 함수
+///
+/// `unsubscribe` 나 drop 중 먼저 일어난 쪽에서 한 번만 해제합니다.
+pub struct Subscription<T> {
+    resource: Option<T>,
+    detach: fn(&T),
+}
+
+impl<T> Subscription<T> {
+    pub fn new(resource: T, detach: fn(&T)) -> Self {
+        Self {
+            resource: Some(resource),
+            detach,
+        }
+    }
+
+    pub fn unsubscribe(&mut self) {
+        if let Some(resource) = self.resource.take() {
+            (self.detach)(&resource);
+        }
+    }
+}
+
+impl<T> Drop for Subscription<T> {
+    fn drop(&mut self) {
+        self.unsubscribe();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// 등록된 리스너 이름 목록 (가짜 이벤트 대상)
+    type Target = Rc<RefCell<Vec<&'static str>>>;
+
+    fn listen(target: &Target, name: &'static str) -> Subscription<(Target, &'static str)> {
+        target.borrow_mut().push(name);
+        Subscription::new((target.clone(), name), |(target, name)| {
+            target.borrow_mut().retain(|n| n != name)
+        })
+    }
+
+    #[test]
+    fn test_unsubscribe_removes_listener_once() {
+        let target = Target::default();
+        let mut popstate = listen(&target, "popstate");
+        let _keydown = listen(&target, "keydown");
+        popstate.unsubscribe();
+        assert_eq!(*target.borrow(), vec!["keydown"]);
+        // 두 번째 해제는 다른 리스너에 영향 없음
+        target.borrow_mut().push("popstate");
+        popstate.unsubscribe();
+        assert_eq!(*target.borrow(), vec!["keydown", "popstate"]);
+    }
+
+    #[test]
+    fn test_drop_removes_listener() {
+        let target = Target::default();
+        {
+            let _resize = listen(&target, "resize");
+            assert_eq!(target.borrow().len(), 1);
+        }
+        assert!(target.borrow().is_empty());
+    }
+}
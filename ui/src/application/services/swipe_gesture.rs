@@ -0,0 +1,60 @@
+use crate::application::services::gesture_recognizer::SwipeDirection;
+
+/// 좌우로 넘기는 최소 거리(px)
+const PAGE_DISTANCE: f64 = 50.0;
+/// 아래로 끌어 닫는 최소 비율(화면 높이 대비)
+const DISMISS_RATIO: f64 = 0.25;
+/// 끝까지 끌었을 때 남는 배경 불투명도
+const MIN_BACKDROP_OPACITY: f64 = 0.2;
+
+/// 좌우 이동을 놓았을 때 넘길 방향 (-1: 이전, 1: 다음)
+///
+/// 충분히 끌었거나 좌우로 빠르게 튕기면(`swipe`) 넘깁니다.
+pub fn page_direction(dx: f64, swipe: Option<SwipeDirection>) -> Option<i32> {
+    match swipe {
+        Some(SwipeDirection::Right) => Some(-1),
+        Some(SwipeDirection::Left) => Some(1),
+        _ if dx.abs() < PAGE_DISTANCE => None,
+        _ => Some(if dx > 0.0 { -1 } else { 1 }),
+    }
+}
+
+/// 아래로 끌기를 놓았을 때 닫을지 (위로 튕기며 놓으면 닫지 않음)
+pub fn should_dismiss(dy: f64, swipe: Option<SwipeDirection>, viewport_height: f64) -> bool {
+    match swipe {
+        Some(SwipeDirection::Down) => dy > 0.0,
+        Some(SwipeDirection::Up) => false,
+        _ => dy > viewport_height * DISMISS_RATIO,
+    }
+}
+
+/// 아래로 끈 거리에 비례해 흐려지는 배경 불투명도 (1 → 0.2)
+pub fn backdrop_opacity(dy: f64, viewport_height: f64) -> f64 {
+    if viewport_height <= 0.0 {
+        return 1.0;
+    }
+    let progress = (dy.max(0.0) / (viewport_height / 2.0)).min(1.0);
+    1.0 - progress * (1.0 - MIN_BACKDROP_OPACITY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_by_distance_or_swipe() {
+        assert_eq!(page_direction(80.0, None), Some(-1));
+        assert_eq!(page_direction(-20.0, Some(SwipeDirection::Left)), Some(1));
+        assert_eq!(page_direction(20.0, None), None);
+    }
+
+    #[test]
+    fn test_dismiss_by_distance_or_swipe() {
+        assert!(should_dismiss(250.0, None, 800.0));
+        assert!(should_dismiss(40.0, Some(SwipeDirection::Down), 800.0));
+        assert!(!should_dismiss(40.0, None, 800.0));
+        assert!(!should_dismiss(300.0, Some(SwipeDirection::Up), 800.0));
+        assert_eq!(backdrop_opacity(0.0, 800.0), 1.0);
+        assert!((backdrop_opacity(400.0, 800.0) - MIN_BACKDROP_OPACITY).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,188 @@
+//! 타임라인 화면 구성
+//!
+//! 타임라인 순서(최근 먼저)로 받은 사진을 달/날짜 구간으로 묶고,
+//! 스크러버 위치(0~1)를 사진 수에 비례해 달로 변환합니다. 날짜는 백엔드 월별 집계와 같은 UTC 기준입니다.
+
+use crate::domain::entities::photo::Photo;
+use crate::domain::value_objects::timeline::Timeline;
+use chrono::{Datelike, NaiveDate};
+use std::ops::Range;
+
+/// 같은 날 찍은 사진 (원본 목록에서의 위치)
+#[derive(Clone, Debug, PartialEq)]
+pub struct DaySection {
+    pub date: NaiveDate,
+    pub photos: Range<usize>,
+}
+
+/// 같은 달 찍은 사진 (날짜 구간 목록)
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonthSection {
+    pub year: i32,
+    pub month: u32,
+    pub days: Vec<DaySection>,
+}
+
+impl MonthSection {
+    pub fn key(&self) -> (i32, u32) {
+        (self.year, self.month)
+    }
+}
+
+/// 이미 타임라인 순서인 사진을 연속 구간으로 묶음
+pub fn group_by_month_and_day<'a>(
+    photos: impl IntoIterator<Item = &'a Photo>,
+) -> Vec<MonthSection> {
+    let mut months: Vec<MonthSection> = Vec::new();
+    for (index, photo) in photos.into_iter().enumerate() {
+        let date = photo.sort_at().date_naive();
+        let month = match months.last_mut() {
+            Some(last) if last.key() == (date.year(), date.month()) => last,
+            _ => {
+                months.push(MonthSection {
+                    year: date.year(),
+                    month: date.month(),
+                    days: Vec::new(),
+                });
+                months.last_mut().expect("just pushed")
+            }
+        };
+        match month.days.last_mut() {
+            Some(day) if day.date == date => day.photos.end = index + 1,
+            _ => month.days.push(DaySection {
+                date,
+                photos: index..index + 1,
+            }),
+        }
+    }
+    months
+}
+
+/// `index` 번째 달이 스크러버에서 시작하는 위치 (0~1)
+pub fn month_offset(timeline: &Timeline, index: usize) -> f64 {
+    if timeline.total == 0 {
+        return 0.0;
+    }
+    let before: u64 = timeline.months.iter().take(index).map(|m| m.count).sum();
+    before as f64 / timeline.total as f64
+}
+
+/// 스크러버 위치(0~1)에 해당하는 달
+pub fn month_at(timeline: &Timeline, fraction: f64) -> Option<usize> {
+    if timeline.months.is_empty() {
+        return None;
+    }
+    let target = fraction.clamp(0.0, 1.0) * timeline.total as f64;
+    let mut before = 0u64;
+    for (index, month) in timeline.months.iter().enumerate() {
+        before += month.count;
+        if target < before as f64 {
+            return Some(index);
+        }
+    }
+    Some(timeline.months.len() - 1)
+}
+
+/// 각 해가 시작하는(가장 최근 달) 위치: 스크러버의 연도 표시
+pub fn year_marks(timeline: &Timeline) -> Vec<(i32, f64)> {
+    let mut marks: Vec<(i32, f64)> = Vec::new();
+    for (index, month) in timeline.months.iter().enumerate() {
+        if marks.last().is_none_or(|(year, _)| *year != month.year) {
+            marks.push((month.year, month_offset(timeline, index)));
+        }
+    }
+    marks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::timeline::TimelineMonth;
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    fn photo(year: i32, month: u32, day: u32, hour: u32) -> Photo {
+        let at = Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap();
+        Photo {
+            id: Uuid::new_v4(),
+            url: String::new(),
+            preview_url: String::new(),
+            original_url: String::new(),
+            title: String::new(),
+            taken_at: Some(at),
+            created_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            is_liked: false,
+            width: 1,
+            height: 1,
+            dominant_colors: Vec::new(),
+            sharpness: None,
+            exposure: None,
+        }
+    }
+
+    fn timeline(counts: &[(i32, u32, u64)]) -> Timeline {
+        let months: Vec<TimelineMonth> = counts
+            .iter()
+            .map(|&(year, month, count)| TimelineMonth {
+                year,
+                month,
+                count,
+                cursor: String::new(),
+            })
+            .collect();
+        Timeline {
+            total: months.iter().map(|m| m.count).sum(),
+            months,
+        }
+    }
+
+    #[test]
+    fn test_groups_consecutive_days_within_months() {
+        let photos = [
+            photo(2024, 3, 5, 18),
+            photo(2024, 3, 5, 9),
+            photo(2024, 3, 1, 12),
+            photo(2024, 2, 28, 23),
+        ];
+
+        let months = group_by_month_and_day(&photos);
+
+        assert_eq!(months.len(), 2);
+        assert_eq!(months[0].key(), (2024, 3));
+        assert_eq!(months[0].days.len(), 2);
+        assert_eq!(months[0].days[0].photos, 0..2);
+        assert_eq!(months[0].days[1].photos, 2..3);
+        assert_eq!(
+            months[1].days[0].date,
+            NaiveDate::from_ymd_opt(2024, 2, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_undated_photo_uses_upload_time() {
+        let mut undated = photo(2024, 3, 5, 0);
+        undated.taken_at = None;
+
+        let months = group_by_month_and_day(&[undated]);
+
+        assert_eq!(months[0].key(), (2026, 1));
+    }
+
+    #[test]
+    fn test_month_at_is_proportional_to_counts() {
+        let timeline = timeline(&[(2024, 3, 10), (2024, 2, 30), (2023, 12, 60)]);
+
+        assert_eq!(month_at(&timeline, 0.0), Some(0));
+        assert_eq!(month_at(&timeline, 0.2), Some(1));
+        assert_eq!(month_at(&timeline, 0.5), Some(2));
+        assert_eq!(month_at(&timeline, 1.0), Some(2));
+        assert_eq!(month_at(&Timeline::default(), 0.5), None);
+    }
+
+    #[test]
+    fn test_year_marks_start_at_first_month_of_each_year() {
+        let timeline = timeline(&[(2024, 3, 10), (2024, 2, 30), (2023, 12, 60)]);
+
+        assert_eq!(year_marks(&timeline), vec![(2024, 0.0), (2023, 0.4)]);
+    }
+}
@@ -0,0 +1,348 @@
+//! 가상 스크롤 사진 그리드 배치
+//!
+//! 달/날짜 구간을 높이가 정해진 행(머리글 또는 사진 한 줄)으로 펼쳐 각 행의 위치를 미리 계산합니다.
+//! 화면에는 보이는 범위의 행만 그리고, 전체 높이는 계산한 값으로 유지합니다.
+
+use crate::application::services::photo_layout::{place, TileBox};
+use crate::application::services::timeline_layout::MonthSection;
+use crate::domain::value_objects::grid_mode::GridMode;
+use chrono::NaiveDate;
+use std::ops::Range;
+
+/// 달 머리글 높이 (px, `h-11`)
+pub const MONTH_HEADER_HEIGHT: f64 = 44.0;
+/// 날짜 머리글 높이 (px, `h-7`)
+pub const DAY_HEADER_HEIGHT: f64 = 28.0;
+/// 같은 크기 타일(`GridMode::Square`)의 높이 (px)
+pub const TILE_HEIGHT: f64 = 192.0;
+/// 보이는 범위 앞뒤로 더 그려 둘 행 수
+pub const OVERSCAN_ROWS: usize = 4;
+
+/// 그리드 한 행
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GridRow {
+    Month {
+        year: i32,
+        month: u32,
+    },
+    Day(NaiveDate),
+    /// 한 줄에 놓인 사진의 원본 목록 위치
+    Photos(Range<usize>),
+}
+
+/// 그리드 너비에 따른 열 수와 간격 (Tailwind `sm`/`md`/`lg`/`xl` 과 같은 구간)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridMetrics {
+    pub width: f64,
+    pub columns: usize,
+    pub gap: f64,
+}
+
+impl GridMetrics {
+    pub fn for_width(width: f64) -> Self {
+        let columns = match width {
+            w if w < 640.0 => 2,
+            w if w < 768.0 => 3,
+            w if w < 1024.0 => 4,
+            w if w < 1280.0 => 5,
+            _ => 6,
+        };
+        let gap = if width < 768.0 { 8.0 } else { 16.0 };
+        Self {
+            width,
+            columns,
+            gap,
+        }
+    }
+
+    /// 열 하나의 너비 (간격 제외)
+    pub fn column_width(&self) -> f64 {
+        let columns = self.columns.max(1) as f64;
+        ((self.width - self.gap * (columns - 1.0)) / columns).max(0.0)
+    }
+}
+
+/// 모든 행과 각 행의 시작 위치, 사진별 타일 위치
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridLayout {
+    pub metrics: GridMetrics,
+    pub rows: Vec<GridRow>,
+    tops: Vec<f64>,
+    /// 원본 사진 목록 순서, 속한 행 기준 위치
+    tiles: Vec<TileBox>,
+    /// 원본 사진 목록 순서, 속한 행 번호
+    photo_rows: Vec<usize>,
+    pub total_height: f64,
+}
+
+impl GridLayout {
+    /// `aspects` 는 원본 사진 목록 순서의 가로/세로 비율
+    pub fn build(
+        sections: &[MonthSection],
+        aspects: &[f64],
+        metrics: GridMetrics,
+        mode: GridMode,
+    ) -> Self {
+        let mut layout = GridLayout {
+            metrics,
+            rows: Vec::new(),
+            tops: Vec::new(),
+            tiles: Vec::with_capacity(aspects.len()),
+            photo_rows: Vec::with_capacity(aspects.len()),
+            total_height: 0.0,
+        };
+        for month in sections {
+            layout.push(
+                GridRow::Month {
+                    year: month.year,
+                    month: month.month,
+                },
+                MONTH_HEADER_HEIGHT,
+            );
+            for day in &month.days {
+                layout.push(GridRow::Day(day.date), DAY_HEADER_HEIGHT);
+                let offset = day.photos.start;
+                for row in place(mode, &aspects[day.photos.clone()], metrics) {
+                    layout.tiles.extend(row.tiles);
+                    let index = layout.rows.len();
+                    layout.photo_rows.extend(row.photos.clone().map(|_| index));
+                    let photos = offset + row.photos.start..offset + row.photos.end;
+                    layout.push(GridRow::Photos(photos), row.height);
+                }
+            }
+        }
+        layout
+    }
+
+    fn push(&mut self, row: GridRow, height: f64) {
+        self.tops.push(self.total_height);
+        self.total_height += height;
+        self.rows.push(row);
+    }
+
+    pub fn top(&self, index: usize) -> f64 {
+        self.tops.get(index).copied().unwrap_or(self.total_height)
+    }
+
+    /// `photo` 번째 사진의 타일 (속한 행 기준)
+    pub fn tile(&self, photo: usize) -> TileBox {
+        self.tiles.get(photo).copied().unwrap_or_default()
+    }
+
+    /// `offset` 위치 행부터 처음 나오는 사진 (크기 변경 시 보던 위치 유지)
+    pub fn photo_at(&self, offset: f64) -> Option<usize> {
+        let current = self
+            .tops
+            .partition_point(|top| *top <= offset)
+            .saturating_sub(1);
+        self.rows.iter().skip(current).find_map(|row| match row {
+            GridRow::Photos(photos) => Some(photos.start),
+            _ => None,
+        })
+    }
+
+    /// `photo` 번째 사진 타일의 그리드 기준 위치
+    pub fn photo_top(&self, photo: usize) -> Option<f64> {
+        let index = *self.photo_rows.get(photo)?;
+        Some(self.top(index) + self.tile(photo).top)
+    }
+
+    /// `photo` 번째 사진 타일이 `[start, end)` 구간 안에 보이도록 스크롤할 거리 (이미 보이면 0)
+    ///
+    /// 위쪽은 고정 머리글(`MONTH_HEADER_HEIGHT`)에 가리지 않도록 비워 두고,
+    /// 타일이 구간보다 크면 윗부분이 보이게 맞춥니다.
+    pub fn reveal_delta(&self, photo: usize, start: f64, end: f64) -> Option<f64> {
+        let top = self.photo_top(photo)?;
+        let bottom = top + self.tile(photo).height;
+        let start = start + MONTH_HEADER_HEIGHT;
+        Some(if top < start {
+            top - start
+        } else if bottom > end {
+            (bottom - end).min(top - start)
+        } else {
+            0.0
+        })
+    }
+
+    /// 그리드 기준 `[start, end)` 구간과 겹치는 행 (앞뒤 `OVERSCAN_ROWS` 포함)
+    pub fn visible(&self, start: f64, end: f64) -> Range<usize> {
+        if self.rows.is_empty() || end <= start {
+            return 0..0;
+        }
+        // 시작 위치가 `start` 이하인 마지막 행부터
+        let first = self
+            .tops
+            .partition_point(|top| *top <= start)
+            .saturating_sub(1);
+        let last = self.tops.partition_point(|top| *top < end);
+        first.saturating_sub(OVERSCAN_ROWS)..(last + OVERSCAN_ROWS).min(self.rows.len())
+    }
+
+    /// `offset` 위치를 덮고 있는 달과 날짜 (고정 머리글 표시)
+    pub fn section_at(&self, offset: f64) -> (Option<(i32, u32)>, Option<NaiveDate>) {
+        let current = self.tops.partition_point(|top| *top <= offset);
+        let mut day = None;
+        for row in self.rows[..current].iter().rev() {
+            match row {
+                GridRow::Day(date) if day.is_none() => day = Some(*date),
+                GridRow::Month { year, month } => return (Some((*year, *month)), day),
+                _ => {}
+            }
+        }
+        (None, day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::timeline_layout::DaySection;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    /// 2024년 3월: 5일 7장, 4일 2장
+    fn sections() -> Vec<MonthSection> {
+        vec![MonthSection {
+            year: 2024,
+            month: 3,
+            days: vec![
+                DaySection {
+                    date: date(5),
+                    photos: 0..7,
+                },
+                DaySection {
+                    date: date(4),
+                    photos: 7..9,
+                },
+            ],
+        }]
+    }
+
+    fn metrics() -> GridMetrics {
+        GridMetrics {
+            width: 600.0,
+            columns: 3,
+            gap: 8.0,
+        }
+    }
+
+    #[test]
+    fn test_rows_split_days_by_columns() {
+        let layout = GridLayout::build(&sections(), &[1.0; 9], metrics(), GridMode::Square);
+
+        assert_eq!(
+            layout.rows,
+            vec![
+                GridRow::Month {
+                    year: 2024,
+                    month: 3
+                },
+                GridRow::Day(date(5)),
+                GridRow::Photos(0..3),
+                GridRow::Photos(3..6),
+                GridRow::Photos(6..7),
+                GridRow::Day(date(4)),
+                GridRow::Photos(7..9),
+            ]
+        );
+        let photo_row = TILE_HEIGHT + 8.0;
+        assert_eq!(
+            layout.total_height,
+            MONTH_HEADER_HEIGHT + 2.0 * DAY_HEADER_HEIGHT + 4.0 * photo_row
+        );
+        assert_eq!(layout.top(2), MONTH_HEADER_HEIGHT + DAY_HEADER_HEIGHT);
+    }
+
+    #[test]
+    fn test_visible_range_includes_overscan() {
+        let months: Vec<MonthSection> = (0..50)
+            .map(|i| MonthSection {
+                year: 2000 + i,
+                month: 1,
+                days: vec![DaySection {
+                    date: date(1),
+                    photos: (i as usize * 30)..(i as usize * 30 + 30),
+                }],
+            })
+            .collect();
+        let layout = GridLayout::build(&months, &[1.0; 1500], metrics(), GridMode::Square);
+        let row_at = |offset: f64| layout.tops.partition_point(|top| *top <= offset) - 1;
+
+        let range = layout.visible(5000.0, 5800.0);
+
+        assert_eq!(range.start, row_at(5000.0) - OVERSCAN_ROWS);
+        assert_eq!(range.end, row_at(5799.0) + 1 + OVERSCAN_ROWS);
+        assert!(range.len() < layout.rows.len() / 10);
+        assert_eq!(layout.visible(0.0, 0.0), 0..0);
+    }
+
+    #[test]
+    fn test_section_at_tracks_current_headers() {
+        let layout = GridLayout::build(&sections(), &[1.0; 9], metrics(), GridMode::Square);
+
+        assert_eq!(layout.section_at(0.0), (Some((2024, 3)), None));
+        assert_eq!(
+            layout.section_at(layout.top(3)),
+            (Some((2024, 3)), Some(date(5)))
+        );
+        assert_eq!(
+            layout.section_at(layout.total_height - 1.0),
+            (Some((2024, 3)), Some(date(4)))
+        );
+    }
+
+    #[test]
+    fn test_metrics_follow_breakpoints() {
+        assert_eq!(
+            GridMetrics::for_width(375.0),
+            GridMetrics {
+                width: 375.0,
+                columns: 2,
+                gap: 8.0
+            }
+        );
+        assert_eq!(
+            GridMetrics::for_width(800.0),
+            GridMetrics {
+                width: 800.0,
+                columns: 4,
+                gap: 16.0
+            }
+        );
+        assert_eq!(GridMetrics::for_width(1920.0).columns, 6);
+    }
+
+    #[test]
+    fn test_justified_rows_use_placed_heights() {
+        let aspects = [2.0, 2.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 0.5];
+        let layout = GridLayout::build(&sections(), &aspects, metrics(), GridMode::Justified);
+
+        // 첫 줄: 가로 사진 두 장이 너비를 채움 ((600 - 8) / 4 = 148)
+        assert_eq!(layout.rows[2], GridRow::Photos(0..2));
+        assert_eq!(layout.top(3) - layout.top(2), 148.0 + 8.0);
+        assert_eq!(layout.tile(1).left, 296.0 + 8.0);
+        assert_eq!(layout.photo_top(1), Some(layout.top(2)));
+        assert_eq!(layout.photo_at(layout.top(3) + 1.0), Some(2));
+    }
+
+    #[test]
+    fn test_reveal_delta_scrolls_just_enough() {
+        let layout = GridLayout::build(&sections(), &[1.0; 9], metrics(), GridMode::Square);
+        let top = layout.photo_top(7).unwrap();
+        let bottom = top + TILE_HEIGHT;
+
+        // 이미 보이는 사진은 그대로
+        assert_eq!(layout.reveal_delta(7, 0.0, bottom + 1.0), Some(0.0));
+        // 아래에 가려진 사진은 아래 끝에 맞춤
+        assert_eq!(layout.reveal_delta(7, 0.0, bottom - 50.0), Some(50.0));
+        // 위로 지나간 사진은 고정 머리글 바로 아래에 맞춤
+        let start = top + 10.0;
+        assert_eq!(
+            layout.reveal_delta(7, start, start + 400.0),
+            Some(-10.0 - MONTH_HEADER_HEIGHT)
+        );
+        assert_eq!(layout.reveal_delta(99, 0.0, 100.0), None);
+    }
+}
@@ -0,0 +1,159 @@
+use crate::domain::value_objects::geo_area::{BoundingBox, GeoPoint};
+use std::f64::consts::PI;
+
+pub const TILE_SIZE: f64 = 256.0;
+pub const MIN_ZOOM: u8 = 2;
+pub const MAX_ZOOM: u8 = 18;
+/// 웹 메르카토르로 표현 가능한 최대 위도
+const MAX_LATITUDE: f64 = 85.051_128_78;
+
+/// 배율 `zoom` 에서의 세계 픽셀 좌표 (좌상단 원점)
+pub fn project(point: GeoPoint, zoom: u8) -> (f64, f64) {
+    let scale = world_size(zoom);
+    let lat = point.lat.clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+    let x = (point.lng + 180.0) / 360.0 * scale;
+    let y = (1.0 - (lat.tan() + 1.0 / lat.cos()).ln() / PI) / 2.0 * scale;
+    (x, y)
+}
+
+pub fn unproject(x: f64, y: f64, zoom: u8) -> GeoPoint {
+    let scale = world_size(zoom);
+    let n = PI - 2.0 * PI * y / scale;
+    GeoPoint {
+        lat: n.sinh().atan().to_degrees(),
+        lng: x / scale * 360.0 - 180.0,
+    }
+}
+
+/// 경도를 -180~180 으로 (펼친 세계의 좌표를 실제 경도로)
+fn wrap_lng(lng: f64) -> f64 {
+    if (-180.0..=180.0).contains(&lng) {
+        lng
+    } else {
+        (lng + 180.0).rem_euclid(360.0) - 180.0
+    }
+}
+
+fn world_size(zoom: u8) -> f64 {
+    TILE_SIZE * f64::from(1u32 << zoom)
+}
+
+/// 타일 하나 (`x` 는 경도 방향으로 반복되는 세계를 펼친 인덱스)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TileCoord {
+    pub z: u8,
+    pub x: i64,
+    pub y: i64,
+}
+
+impl TileCoord {
+    /// 타일 서버에 요청할 실제 x 인덱스
+    pub fn wrapped_x(&self) -> i64 {
+        self.x.rem_euclid(1 << self.z)
+    }
+}
+
+/// 지도 화면 (중심, 배율, 화면 크기)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MapViewport {
+    pub center: GeoPoint,
+    pub zoom: u8,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl MapViewport {
+    /// 화면 좌상단의 세계 픽셀 좌표
+    pub fn top_left(&self) -> (f64, f64) {
+        let (x, y) = project(self.center, self.zoom);
+        (x - self.width / 2.0, y - self.height / 2.0)
+    }
+
+    /// 화면에 보이는 영역 (날짜 변경선에 걸치면 `west > east`)
+    pub fn bounds(&self) -> BoundingBox {
+        let (left, top) = self.top_left();
+        let north_west = unproject(left, top, self.zoom);
+        let south_east = unproject(left + self.width, top + self.height, self.zoom);
+        let (west, east) = if self.width >= world_size(self.zoom) {
+            (-180.0, 180.0)
+        } else {
+            (wrap_lng(north_west.lng), wrap_lng(south_east.lng))
+        };
+
+        BoundingBox {
+            west,
+            south: south_east.lat,
+            east,
+            north: north_west.lat,
+        }
+    }
+
+    /// 화면 픽셀만큼 지도를 끌어 이동
+    pub fn pan_by(&mut self, dx: f64, dy: f64) {
+        let (x, y) = project(self.center, self.zoom);
+        let center = unproject(x - dx, y - dy, self.zoom);
+        self.center = GeoPoint {
+            lat: center.lat.clamp(-MAX_LATITUDE, MAX_LATITUDE),
+            lng: wrap_lng(center.lng),
+        };
+    }
+
+    /// 화면 좌표 `anchor` 아래의 지점을 고정한 채로 배율 변경
+    pub fn zoom_at(&mut self, zoom: u8, anchor: (f64, f64)) {
+        let zoom = zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+        let (left, top) = self.top_left();
+        let fixed = unproject(left + anchor.0, top + anchor.1, self.zoom);
+        let (x, y) = project(fixed, zoom);
+
+        self.zoom = zoom;
+        self.center = unproject(
+            x - anchor.0 + self.width / 2.0,
+            y - anchor.1 + self.height / 2.0,
+            zoom,
+        );
+    }
+
+    pub fn visible_tiles(&self) -> Vec<TileCoord> {
+        let (left, top) = self.top_left();
+        let last = (1i64 << self.zoom) - 1;
+        let index = |pixel: f64| (pixel / TILE_SIZE).floor() as i64;
+        let (x0, x1) = (index(left), index(left + self.width));
+        let (y0, y1) = (index(top).max(0), index(top + self.height).min(last));
+
+        (y0..=y1)
+            .flat_map(|y| (x0..=x1).map(move |x| TileCoord { z: self.zoom, x, y }))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewport(lng: f64, zoom: u8, width: f64) -> MapViewport {
+        MapViewport {
+            center: GeoPoint { lat: 0.0, lng },
+            zoom,
+            width,
+            height: 256.0,
+        }
+    }
+
+    #[test]
+    fn test_bounds_cross_the_antimeridian_when_the_view_does() {
+        // 배율 4 에서 512px = 45°
+        let bounds = viewport(179.0, 4, 512.0).bounds();
+
+        assert!((bounds.west - 156.5).abs() < 1e-9);
+        assert!((bounds.east - -158.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bounds_stay_ordered_elsewhere_and_cover_the_world_when_zoomed_out() {
+        let bounds = viewport(127.0, 4, 512.0).bounds();
+        assert!(bounds.west < bounds.east);
+
+        let world = viewport(179.0, 2, 2048.0).bounds();
+        assert_eq!((world.west, world.east), (-180.0, 180.0));
+    }
+}
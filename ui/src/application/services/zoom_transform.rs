@@ -0,0 +1,177 @@
+/// 확대 배율 범위
+pub const MIN_SCALE: f64 = 1.0;
+pub const MAX_SCALE: f64 = 8.0;
+/// 두 번 탭했을 때의 배율
+pub const DOUBLE_TAP_SCALE: f64 = 2.0;
+
+/// 프레임(16ms)마다 남는 관성 속도 비율
+const FRICTION_PER_FRAME: f64 = 0.92;
+/// 이보다 느리면(px/ms) 관성 이동 종료
+const MIN_SPEED: f64 = 0.02;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Size {
+    pub width: f64,
+    pub height: f64,
+}
+
+/// 미리보기 이미지의 확대/이동 (`translate(x, y) scale(scale)`, 이미지 중심 기준)
+///
+/// 좌표는 모두 화면(뷰포트) 중심에서의 px 입니다.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoomTransform {
+    pub scale: f64,
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Default for ZoomTransform {
+    fn default() -> Self {
+        Self {
+            scale: MIN_SCALE,
+            x: 0.0,
+            y: 0.0,
+        }
+    }
+}
+
+impl ZoomTransform {
+    pub fn is_zoomed(&self) -> bool {
+        self.scale > MIN_SCALE + 1e-3
+    }
+
+    /// `focal` 아래의 이미지 지점이 화면에서 제자리에 있도록 배율 변경
+    pub fn zoom_at(self, scale: f64, focal: (f64, f64), content: Size, viewport: Size) -> Self {
+        let scale = scale.clamp(MIN_SCALE, MAX_SCALE);
+        let ratio = scale / self.scale;
+        Self {
+            scale,
+            x: focal.0 - (focal.0 - self.x) * ratio,
+            y: focal.1 - (focal.1 - self.y) * ratio,
+        }
+        .clamped(content, viewport)
+    }
+
+    pub fn pan_by(self, dx: f64, dy: f64, content: Size, viewport: Size) -> Self {
+        Self {
+            x: self.x + dx,
+            y: self.y + dy,
+            ..self
+        }
+        .clamped(content, viewport)
+    }
+
+    /// 두 번 탭: 확대 중이면 원래대로, 아니면 탭한 곳을 중심으로 2배
+    pub fn toggled_at(self, focal: (f64, f64), content: Size, viewport: Size) -> Self {
+        if self.is_zoomed() {
+            return Self::default();
+        }
+        self.zoom_at(DOUBLE_TAP_SCALE, focal, content, viewport)
+    }
+
+    /// 확대한 이미지 가장자리가 화면 안쪽으로 들어오지 않도록 이동 범위 제한
+    pub fn clamped(self, content: Size, viewport: Size) -> Self {
+        let max_x = ((content.width * self.scale - viewport.width) / 2.0).max(0.0);
+        let max_y = ((content.height * self.scale - viewport.height) / 2.0).max(0.0);
+        Self {
+            x: self.x.clamp(-max_x, max_x),
+            y: self.y.clamp(-max_y, max_y),
+            ..self
+        }
+    }
+
+    /// 화면에 그려지는 크기가 불러온 이미지 해상도를 넘는지 (원본으로 바꿀 시점)
+    pub fn exceeds_resolution(
+        &self,
+        rendered_width: f64,
+        natural_width: f64,
+        pixel_ratio: f64,
+    ) -> bool {
+        natural_width > 0.0 && rendered_width * self.scale * pixel_ratio > natural_width
+    }
+}
+
+/// 손을 뗀 뒤 이어지는 이동 (속도 px/ms, 프레임마다 감속)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Inertia {
+    pub vx: f64,
+    pub vy: f64,
+}
+
+impl Inertia {
+    /// `dt` ms 동안의 이동량을 돌려주고 속도를 줄임
+    pub fn step(&mut self, dt: f64) -> (f64, f64) {
+        let delta = (self.vx * dt, self.vy * dt);
+        let decay = FRICTION_PER_FRAME.powf(dt / 16.0);
+        self.vx *= decay;
+        self.vy *= decay;
+        delta
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.vx.hypot(self.vy) < MIN_SPEED
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: Size = Size {
+        width: 400.0,
+        height: 300.0,
+    };
+    const VIEWPORT: Size = Size {
+        width: 400.0,
+        height: 600.0,
+    };
+
+    #[test]
+    fn test_zoom_keeps_focal_point_in_place() {
+        let zoomed = ZoomTransform::default().zoom_at(2.0, (100.0, 50.0), CONTENT, VIEWPORT);
+        // 확대 전 (100, 50) 에 있던 이미지 지점은 확대 후에도 같은 화면 위치
+        let image_point = (100.0, 50.0);
+        assert_eq!(zoomed.x + image_point.0 * zoomed.scale, 100.0);
+        // 세로는 확대해도 화면보다 작으므로 가운데 고정
+        assert_eq!(zoomed.y, 0.0);
+    }
+
+    #[test]
+    fn test_pan_is_bounded_by_image_edges() {
+        let zoomed = ZoomTransform::default().zoom_at(3.0, (0.0, 0.0), CONTENT, VIEWPORT);
+        let panned = zoomed.pan_by(10_000.0, -10_000.0, CONTENT, VIEWPORT);
+        assert_eq!(panned.x, 400.0);
+        assert_eq!(panned.y, -150.0);
+        assert_eq!(
+            ZoomTransform::default().pan_by(50.0, 50.0, CONTENT, VIEWPORT),
+            ZoomTransform::default()
+        );
+    }
+
+    #[test]
+    fn test_double_tap_toggles_and_scale_is_clamped() {
+        let zoomed = ZoomTransform::default().toggled_at((0.0, 0.0), CONTENT, VIEWPORT);
+        assert_eq!(zoomed.scale, DOUBLE_TAP_SCALE);
+        assert_eq!(
+            zoomed.toggled_at((0.0, 0.0), CONTENT, VIEWPORT),
+            ZoomTransform::default()
+        );
+        let max = zoomed.zoom_at(100.0, (0.0, 0.0), CONTENT, VIEWPORT);
+        assert_eq!(max.scale, MAX_SCALE);
+        assert!(max.exceeds_resolution(400.0, 1600.0, 1.0));
+        assert!(!zoomed.exceeds_resolution(400.0, 1600.0, 1.0));
+    }
+
+    #[test]
+    fn test_inertia_decays_until_stopped() {
+        let mut inertia = Inertia { vx: 1.0, vy: 0.0 };
+        let (dx, _) = inertia.step(16.0);
+        assert_eq!(dx, 16.0);
+        let mut frames = 0;
+        while !inertia.is_stopped() {
+            inertia.step(16.0);
+            frames += 1;
+        }
+        assert!(frames > 10 && frames < 100);
+    }
+}
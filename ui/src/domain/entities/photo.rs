@@ -1,38 +1,69 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Photo {
     pub id: Uuid,
     pub url: String,
     pub preview_url: String,
+    /// 원본 파일 (미리보기에서 확대했을 때 사용)
+    pub original_url: String,
     pub title: String,
+    /// EXIF 촬영 시각 (없으면 `None`)
+    pub taken_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub is_liked: bool,
     pub width: u32,
     pub height: u32,
+    /// `#rrggbb` 대표 색 (많이 쓰인 순)
+    pub dominant_colors: Vec<String>,
+    /// 선명도/노출 점수 0~1 (평가 전이면 `None`)
+    pub sharpness: Option<f32>,
+    pub exposure: Option<f32>,
 }
 
 impl Photo {
+    /// 백엔드 없이 볼 때 쓰는 가짜 사진 (`mock-photos` feature, 이미지는 picsum.photos)
+    #[cfg(feature = "mock-photos")]
     pub fn new_mock(id: usize) -> Self {
         let image_id = id % 1000;
-        
-        // Mocking varied aspect ratios based on ID
+        // id 에 따라 가로, 세로, 정사각형을 번갈아 사용
         let (width, height) = match image_id % 3 {
-            0 => (1600, 900),   // 16:9 Landscape
-            1 => (800, 1200),   // 2:3 Portrait
-            _ => (1200, 1200),  // 1:1 Square
+            0 => (1600, 900),
+            1 => (800, 1200),
+            _ => (1200, 1200),
         };
+        let preview_url = format!("https://picsum.photos/id/{}/{}/{}", image_id, width, height);
 
         Self {
             id: Uuid::new_v4(),
-            url: format!("https://picsum.photos/id/{}/400/400", image_id), // Thumbnail (Square crop)
-            preview_url: format!("https://picsum.photos/id/{}/{}/{}", image_id, width, height), // Preview (Original ratio)
+            url: format!("https://picsum.photos/id/{}/400/400", image_id),
+            original_url: preview_url.clone(),
+            preview_url,
             title: format!("Photo #{}", id),
+            // 타임라인이 여러 날짜로 나뉘도록 최신순으로 몇 시간씩 간격을 둠
+            taken_at: Some(Utc::now() - chrono::Duration::hours(id as i64 * 7)),
             created_at: Utc::now(),
             is_liked: false,
             width,
             height,
+            dominant_colors: Vec::new(),
+            sharpness: None,
+            exposure: None,
         }
     }
+
+    /// 타임라인 정렬/묶음 기준 시각 (촬영 시각이 없으면 업로드 시각)
+    pub fn sort_at(&self) -> DateTime<Utc> {
+        self.taken_at.unwrap_or(self.created_at)
+    }
+
+    /// 가로/세로 비율 (크기를 모르면 1)
+    pub fn aspect_ratio(&self) -> f64 {
+        if self.width == 0 || self.height == 0 {
+            return 1.0;
+        }
+        self.width as f64 / self.height as f64
+    }
 }
@@ -0,0 +1,9 @@
+use uuid::Uuid;
+
+/// 앨범 목록 항목
+#[derive(Clone, Debug, PartialEq)]
+pub struct Album {
+    pub id: Uuid,
+    pub title: String,
+    pub photo_count: i64,
+}
@@ -0,0 +1,36 @@
+use crate::domain::entities::photo::Photo;
+
+/// 정리 후보로 제안된 이유
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QualityIssue {
+    Blurry,
+    Underexposed,
+    Overexposed,
+}
+
+impl QualityIssue {
+    /// API 값 (`blurry`, `underexposed`, `overexposed`)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "blurry" => Some(QualityIssue::Blurry),
+            "underexposed" => Some(QualityIssue::Underexposed),
+            "overexposed" => Some(QualityIssue::Overexposed),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            QualityIssue::Blurry => "Blurry",
+            QualityIssue::Underexposed => "Too dark",
+            QualityIssue::Overexposed => "Too bright",
+        }
+    }
+}
+
+/// 흐리거나 노출이 나쁜 정리 후보 사진
+#[derive(Clone, Debug, PartialEq)]
+pub struct CleanupSuggestion {
+    pub photo: Photo,
+    pub issues: Vec<QualityIssue>,
+}
@@ -0,0 +1,6 @@
+/// `#rrggbb` / `rrggbb` 를 소문자 `#rrggbb` 로 정리 (형식이 다르면 `None`)
+pub fn normalize_hex(value: &str) -> Option<String> {
+    let hex = value.trim().trim_start_matches('#');
+    let is_hex = hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit());
+    is_hex.then(|| format!("#{}", hex.to_ascii_lowercase()))
+}
@@ -0,0 +1,44 @@
+use crate::domain::entities::photo::Photo;
+
+/// 유사 중복 그룹의 사진 한 장
+#[derive(Clone, Debug, PartialEq)]
+pub struct DuplicatePhoto {
+    pub photo: Photo,
+    pub file_size_bytes: i64,
+}
+
+impl DuplicatePhoto {
+    pub fn resolution_label(&self) -> String {
+        format!("{} × {}", self.photo.width, self.photo.height)
+    }
+
+    /// 사람이 읽기 쉬운 파일 크기 (예: "2.4 MB")
+    pub fn size_label(&self) -> String {
+        const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+        let mut size = self.file_size_bytes.max(0) as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        if unit == 0 {
+            format!("{} B", size)
+        } else {
+            format!("{:.1} {}", size, UNITS[unit])
+        }
+    }
+}
+
+/// 서로 거의 같은 사진 묶음 (첫 번째가 남기기를 권장하는 사본)
+#[derive(Clone, Debug, PartialEq)]
+pub struct DuplicateGroup {
+    pub photos: Vec<DuplicatePhoto>,
+}
+
+/// 중복 검토 결과
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DuplicateReview {
+    pub groups: Vec<DuplicateGroup>,
+    /// 사진 쌍이 너무 많아 가장 비슷한 사진들만 묶었는지
+    pub truncated: bool,
+}
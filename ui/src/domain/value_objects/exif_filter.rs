@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+/// 반열린 구간 `[min, max)` 숫자 범위 (한쪽이 `None`이면 그 방향으로 제한 없음)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NumericRange {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl NumericRange {
+    pub fn is_unbounded(&self) -> bool {
+        self.min.is_none() && self.max.is_none()
+    }
+}
+
+/// 사진가용 EXIF 촬영 설정 필터
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExifFilter {
+    pub focal_length: NumericRange,
+    pub aperture: NumericRange,
+    /// 셔터 속도 (초)
+    pub shutter_speed: NumericRange,
+    pub iso: NumericRange,
+    pub flash: Option<bool>,
+}
+
+/// 범위로 필터링하는 EXIF 숫자 필드
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExifField {
+    FocalLength,
+    Aperture,
+    ShutterSpeed,
+    Iso,
+}
+
+impl ExifField {
+    pub const ALL: [ExifField; 4] = [
+        ExifField::FocalLength,
+        ExifField::Aperture,
+        ExifField::ShutterSpeed,
+        ExifField::Iso,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            ExifField::FocalLength => "Focal length",
+            ExifField::Aperture => "Aperture",
+            ExifField::ShutterSpeed => "Shutter speed",
+            ExifField::Iso => "ISO",
+        }
+    }
+
+    pub fn range(self, filter: &ExifFilter) -> NumericRange {
+        match self {
+            ExifField::FocalLength => filter.focal_length,
+            ExifField::Aperture => filter.aperture,
+            ExifField::ShutterSpeed => filter.shutter_speed,
+            ExifField::Iso => filter.iso,
+        }
+    }
+
+    pub fn range_mut(self, filter: &mut ExifFilter) -> &mut NumericRange {
+        match self {
+            ExifField::FocalLength => &mut filter.focal_length,
+            ExifField::Aperture => &mut filter.aperture,
+            ExifField::ShutterSpeed => &mut filter.shutter_speed,
+            ExifField::Iso => &mut filter.iso,
+        }
+    }
+
+    /// 칩 표시용 범위 문자열 (예: "24-35mm", "f/2-f/4", "1/250s-1/60s", "ISO 800+")
+    pub fn format_range(self, range: NumericRange) -> String {
+        let value = |v: f64| self.format_value(v);
+        match (range.min, range.max) {
+            (Some(min), Some(max)) => format!("{}-{}", value(min), value(max)),
+            (None, Some(max)) => format!("< {}", value(max)),
+            (Some(min), None) => format!("{}+", value(min)),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn format_value(self, value: f64) -> String {
+        match self {
+            ExifField::FocalLength => format!("{}mm", value),
+            ExifField::Aperture => format!("f/{}", value),
+            ExifField::ShutterSpeed if value < 1.0 => format!("1/{}s", (1.0 / value).round()),
+            ExifField::ShutterSpeed => format!("{}s", value),
+            ExifField::Iso => format!("ISO {}", value),
+        }
+    }
+}
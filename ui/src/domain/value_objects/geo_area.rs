@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// WGS84 경위도 좌표
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GeoPoint {
+    pub lat: f64,
+    pub lng: f64,
+}
+
+/// 경위도 사각 영역 (`west > east` 이면 날짜 변경선을 넘는 영역)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BoundingBox {
+    pub west: f64,
+    pub south: f64,
+    pub east: f64,
+    pub north: f64,
+}
+
+impl BoundingBox {
+    /// 사방으로 `degrees` 만큼 넓힌 영역 (한 지점뿐인 영역도 검색되도록)
+    pub fn padded(&self, degrees: f64) -> Self {
+        Self {
+            west: (self.west - degrees).max(-180.0),
+            south: (self.south - degrees).max(-90.0),
+            east: (self.east + degrees).min(180.0),
+            north: (self.north + degrees).min(90.0),
+        }
+    }
+}
+
+/// 지도 검색 영역
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GeoArea {
+    BoundingBox(BoundingBox),
+    /// 중심점에서 `meters` 이내
+    Radius { center: GeoPoint, meters: f64 },
+}
+
+impl GeoArea {
+    pub fn label(&self) -> String {
+        match self {
+            GeoArea::BoundingBox(_) => "🗺 Map area".to_string(),
+            GeoArea::Radius { meters, .. } => format!("🗺 Within {:.1} km", meters / 1000.0),
+        }
+    }
+}
+
+/// 지도 한 지점에 모인 사진 묶음
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeoCluster {
+    pub center: GeoPoint,
+    /// 묶인 사진 위치를 모두 포함하는 영역
+    pub bounds: BoundingBox,
+    pub count: i64,
+    pub cover_photo_id: Uuid,
+    pub cover_thumbnail_url: String,
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// 갤러리 그리드 배치 방식
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GridMode {
+    /// 같은 크기 타일 (가운데를 잘라 채움)
+    #[default]
+    Square,
+    /// 행마다 높이를 맞춰 너비를 꽉 채움 (원본 비율 유지)
+    Justified,
+    /// 열 너비를 맞추고 가장 짧은 열에 이어 붙임 (원본 비율 유지)
+    Masonry,
+}
+
+impl GridMode {
+    pub const ALL: [GridMode; 3] = [GridMode::Square, GridMode::Justified, GridMode::Masonry];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GridMode::Square => "Grid",
+            GridMode::Justified => "Rows",
+            GridMode::Masonry => "Masonry",
+        }
+    }
+
+    pub fn icon(self) -> &'static str {
+        match self {
+            GridMode::Square => "▦",
+            GridMode::Justified => "☰",
+            GridMode::Masonry => "▥",
+        }
+    }
+}
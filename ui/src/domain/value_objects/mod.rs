@@ -0,0 +1,15 @@
+pub mod album;
+pub mod cleanup;
+pub mod color;
+pub mod duplicate;
+pub mod exif_filter;
+pub mod geo_area;
+pub mod grid_mode;
+pub mod person;
+pub mod photo_page;
+pub mod place;
+pub mod quality_filter;
+pub mod search_facets;
+pub mod search_filter;
+pub mod similar;
+pub mod timeline;
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 얼굴 영역 (이미지 크기 대비 0~1 비율)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FaceRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// 얼굴 군집으로 묶인 인물
+#[derive(Clone, Debug, PartialEq)]
+pub struct Person {
+    pub id: Uuid,
+    pub name: Option<String>,
+    pub face_count: i64,
+    /// 대표 얼굴 썸네일
+    pub cover_url: Option<String>,
+}
+
+impl Person {
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| "Unnamed".to_string())
+    }
+
+    pub fn to_ref(&self) -> PersonRef {
+        PersonRef {
+            id: self.id,
+            name: self.display_name(),
+        }
+    }
+}
+
+/// 인물에 속한 얼굴 하나
+#[derive(Clone, Debug, PartialEq)]
+pub struct PersonFace {
+    pub id: Uuid,
+    pub photo_id: Uuid,
+    pub rectangle: FaceRect,
+    pub confidence: f64,
+    pub crop_url: String,
+}
+
+/// 검색 조건에 담는 인물 (이름은 칩 표시용)
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PersonRef {
+    pub id: Uuid,
+    pub name: String,
+}
+
+/// 미리보기에 겹쳐 그리는 얼굴 하나
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhotoFace {
+    pub id: Uuid,
+    /// 아직 인물이 배정되지 않았으면 `None`
+    pub person: Option<PersonRef>,
+    pub rectangle: FaceRect,
+    pub confidence: f64,
+    /// 사용자가 직접 그리거나 고친 얼굴
+    pub is_manual: bool,
+}
+
+/// 얼굴을 배정할 인물
+#[derive(Clone, Debug, PartialEq)]
+pub enum FaceAssignment {
+    Person(Uuid),
+    /// 새 인물을 만들어 배정 (이름은 선택)
+    NewPerson(Option<String>),
+}
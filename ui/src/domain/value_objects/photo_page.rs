@@ -0,0 +1,20 @@
+use crate::domain::entities::photo::Photo;
+
+/// 무한 스크롤 한 페이지 분량의 결과
+///
+/// `next_cursor`가 `None`이면 더 불러올 사진이 없습니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhotoPage {
+    pub photos: Vec<Photo>,
+    pub next_cursor: Option<String>,
+}
+
+/// 링크로 바로 연 사진과 타임라인에서 앞뒤로 이웃한 사진
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhotoContext {
+    pub photo: Photo,
+    /// 더 최근 사진 (타임라인 순서)
+    pub previous: Vec<Photo>,
+    /// 이후 사진 (`next.next_cursor` 로 이어서 조회)
+    pub next: PhotoPage,
+}
@@ -0,0 +1,31 @@
+/// 도시별 사진 수와 대표 사진
+#[derive(Clone, Debug, PartialEq)]
+pub struct CityPlace {
+    pub city: String,
+    pub region: String,
+    /// 장소 검색 조건으로 사용하는 전체 위치명 (예: "Jeju City, Jeju-do, South Korea")
+    pub location_name: String,
+    pub count: i64,
+    pub cover_thumbnail_url: String,
+}
+
+/// 국가별로 묶은 도시 목록
+#[derive(Clone, Debug, PartialEq)]
+pub struct CountryPlaces {
+    pub country: String,
+    pub country_code: String,
+    pub count: i64,
+    pub cities: Vec<CityPlace>,
+}
+
+impl CountryPlaces {
+    /// 국가 코드의 국기 이모지 (예: "KR" → 🇰🇷)
+    pub fn flag(&self) -> String {
+        self.country_code
+            .to_ascii_uppercase()
+            .chars()
+            .filter(char::is_ascii_uppercase)
+            .filter_map(|c| char::from_u32(0x1F1E6 + (c as u32 - 'A' as u32)))
+            .collect()
+    }
+}
@@ -0,0 +1,84 @@
+use crate::domain::entities::photo::Photo;
+use crate::domain::value_objects::exif_filter::NumericRange;
+use serde::{Deserialize, Serialize};
+
+/// 화질 점수(0~1) 범위 필터
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct QualityFilter {
+    pub sharpness: NumericRange,
+    pub exposure: NumericRange,
+}
+
+/// 범위로 필터링하는 화질 점수
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QualityField {
+    Sharpness,
+    Exposure,
+}
+
+impl QualityField {
+    pub const ALL: [QualityField; 2] = [QualityField::Sharpness, QualityField::Exposure];
+
+    /// 이보다 낮으면 정리 후보 (백엔드 `ImageQuality` 기준과 동일)
+    pub fn threshold(self) -> f64 {
+        match self {
+            QualityField::Sharpness => 0.3,
+            QualityField::Exposure => 0.35,
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            QualityField::Sharpness => "Sharpness",
+            QualityField::Exposure => "Exposure",
+        }
+    }
+
+    /// 빠른 선택 (좋은 사진만, 나쁜 사진만)
+    pub fn presets(self) -> [(&'static str, NumericRange); 2] {
+        let good = NumericRange {
+            min: Some(self.threshold()),
+            max: None,
+        };
+        let bad = NumericRange {
+            min: None,
+            max: Some(self.threshold()),
+        };
+        match self {
+            QualityField::Sharpness => [("Sharp", good), ("Blurry", bad)],
+            QualityField::Exposure => [("Well exposed", good), ("Poorly exposed", bad)],
+        }
+    }
+
+    pub fn score(self, photo: &Photo) -> Option<f32> {
+        match self {
+            QualityField::Sharpness => photo.sharpness,
+            QualityField::Exposure => photo.exposure,
+        }
+    }
+
+    pub fn range(self, filter: &QualityFilter) -> NumericRange {
+        match self {
+            QualityField::Sharpness => filter.sharpness,
+            QualityField::Exposure => filter.exposure,
+        }
+    }
+
+    pub fn range_mut(self, filter: &mut QualityFilter) -> &mut NumericRange {
+        match self {
+            QualityField::Sharpness => &mut filter.sharpness,
+            QualityField::Exposure => &mut filter.exposure,
+        }
+    }
+
+    /// 칩 표시용 문자열 (예: "Sharpness ≥ 30%", "Exposure < 35%")
+    pub fn format_range(self, range: NumericRange) -> String {
+        let percent = |v: f64| format!("{}%", (v * 100.0).round());
+        match (range.min, range.max) {
+            (Some(min), Some(max)) => format!("{} {}-{}", self.title(), percent(min), percent(max)),
+            (None, Some(max)) => format!("{} < {}", self.title(), percent(max)),
+            (Some(min), None) => format!("{} ≥ {}", self.title(), percent(min)),
+            (None, None) => String::new(),
+        }
+    }
+}
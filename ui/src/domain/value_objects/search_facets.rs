@@ -0,0 +1,38 @@
+use crate::domain::value_objects::exif_filter::{ExifField, NumericRange};
+
+/// 값별 사진 수 (예: 렌즈 모델)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueFacet<T> {
+    pub value: T,
+    pub count: i64,
+}
+
+/// 구간별 사진 수
+#[derive(Clone, Debug, PartialEq)]
+pub struct RangeFacet {
+    pub label: String,
+    pub range: NumericRange,
+    pub count: i64,
+}
+
+/// 현재 검색 조건에서 선택 가능한 EXIF 값과 각 값의 사진 수
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchFacets {
+    pub lenses: Vec<ValueFacet<String>>,
+    pub focal_length: Vec<RangeFacet>,
+    pub aperture: Vec<RangeFacet>,
+    pub shutter_speed: Vec<RangeFacet>,
+    pub iso: Vec<RangeFacet>,
+    pub flash: Vec<ValueFacet<bool>>,
+}
+
+impl SearchFacets {
+    pub fn ranges(&self, field: ExifField) -> &[RangeFacet] {
+        match field {
+            ExifField::FocalLength => &self.focal_length,
+            ExifField::Aperture => &self.aperture,
+            ExifField::ShutterSpeed => &self.shutter_speed,
+            ExifField::Iso => &self.iso,
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::domain::value_objects::exif_filter::{ExifField, ExifFilter, NumericRange};
+use crate::domain::value_objects::geo_area::GeoArea;
+use crate::domain::value_objects::person::PersonRef;
+use crate::domain::value_objects::quality_filter::{QualityField, QualityFilter};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 통합 검색 조건
+///
+/// `text`는 제목/태그/앨범명 유사도 검색, 나머지는 모두 AND 로 결합되는 구조화 필터입니다.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchFilter {
+    pub text: Option<String>,
+    pub date_from: Option<NaiveDate>,
+    pub date_to: Option<NaiveDate>,
+    pub camera: Option<String>,
+    pub lens: Option<String>,
+    /// 모두 포함해야 하는 태그 (예: "beach", "sunset")
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// 위치명 부분 일치 (예: "Jeju")
+    #[serde(default)]
+    pub place: Option<String>,
+    pub is_liked: Option<bool>,
+    pub has_location: Option<bool>,
+    #[serde(default)]
+    pub exif: ExifFilter,
+    /// 지도에서 선택한 영역
+    #[serde(default)]
+    pub area: Option<GeoArea>,
+    /// 이 인물의 얼굴이 나온 사진만
+    #[serde(default)]
+    pub person: Option<PersonRef>,
+    /// 팔레트에 이 색(`#rrggbb`)과 비슷한 색이 있는 사진만
+    #[serde(default)]
+    pub color: Option<String>,
+    /// 선명도/노출 점수 범위
+    #[serde(default)]
+    pub quality: QualityFilter,
+    /// 이 앨범에 담긴 사진만 (앨범 경로에서 채움, 칩으로 표시하지 않음)
+    #[serde(default)]
+    pub album_id: Option<Uuid>,
+}
+
+impl SearchFilter {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// 조건 하나하나를 칩 단위로 분해
+    pub fn chips(&self) -> Vec<FilterChip> {
+        let mut chips = Vec::new();
+
+        chips.extend(self.text.clone().map(FilterChip::Text));
+        if self.date_from.is_some() || self.date_to.is_some() {
+            chips.push(FilterChip::DateRange(self.date_from, self.date_to));
+        }
+        chips.extend(self.camera.clone().map(FilterChip::Camera));
+        chips.extend(self.lens.clone().map(FilterChip::Lens));
+        chips.extend(self.tags.iter().cloned().map(FilterChip::Tag));
+        chips.extend(self.place.clone().map(FilterChip::Place));
+        chips.extend(self.is_liked.map(FilterChip::Liked));
+        chips.extend(self.has_location.map(FilterChip::HasLocation));
+        for field in ExifField::ALL {
+            let range = field.range(&self.exif);
+            if !range.is_unbounded() {
+                chips.push(FilterChip::ExifRange(field, range));
+            }
+        }
+        chips.extend(self.exif.flash.map(FilterChip::Flash));
+        chips.extend(self.area.map(FilterChip::Area));
+        chips.extend(self.person.clone().map(FilterChip::Person));
+        chips.extend(self.color.clone().map(FilterChip::Color));
+        for field in QualityField::ALL {
+            let range = field.range(&self.quality);
+            if !range.is_unbounded() {
+                chips.push(FilterChip::Quality(field, range));
+            }
+        }
+
+        chips
+    }
+
+    /// 칩 하나에 해당하는 조건을 제거한 필터
+    pub fn without(&self, chip: &FilterChip) -> Self {
+        let mut filter = self.clone();
+        match chip {
+            FilterChip::Text(_) => filter.text = None,
+            FilterChip::DateRange(_, _) => (filter.date_from, filter.date_to) = (None, None),
+            FilterChip::Camera(_) => filter.camera = None,
+            FilterChip::Lens(_) => filter.lens = None,
+            FilterChip::Tag(tag) => filter.tags.retain(|t| t != tag),
+            FilterChip::Place(_) => filter.place = None,
+            FilterChip::Liked(_) => filter.is_liked = None,
+            FilterChip::HasLocation(_) => filter.has_location = None,
+            FilterChip::ExifRange(field, _) => {
+                *field.range_mut(&mut filter.exif) = NumericRange::default()
+            }
+            FilterChip::Flash(_) => filter.exif.flash = None,
+            FilterChip::Area(_) => filter.area = None,
+            FilterChip::Person(_) => filter.person = None,
+            FilterChip::Color(_) => filter.color = None,
+            FilterChip::Quality(field, _) => {
+                *field.range_mut(&mut filter.quality) = NumericRange::default()
+            }
+        }
+        filter
+    }
+
+    /// 비어 있는 조건을 `fallback`의 값으로 채움 (태그는 합집합)
+    pub fn or(self, fallback: SearchFilter) -> Self {
+        let has_dates = self.date_from.is_some() || self.date_to.is_some();
+        let mut tags = self.tags;
+        for tag in fallback.tags {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+
+        Self {
+            text: self.text.or(fallback.text),
+            date_from: if has_dates { self.date_from } else { fallback.date_from },
+            date_to: if has_dates { self.date_to } else { fallback.date_to },
+            camera: self.camera.or(fallback.camera),
+            lens: self.lens.or(fallback.lens),
+            tags,
+            place: self.place.or(fallback.place),
+            is_liked: self.is_liked.or(fallback.is_liked),
+            has_location: self.has_location.or(fallback.has_location),
+            exif: self.exif,
+            area: self.area.or(fallback.area),
+            person: self.person.or(fallback.person),
+            color: self.color.or(fallback.color),
+            quality: self.quality,
+            album_id: self.album_id.or(fallback.album_id),
+        }
+    }
+
+    /// 최근 검색 목록 등에 표시할 한 줄 요약
+    pub fn label(&self) -> String {
+        self.chips()
+            .iter()
+            .map(FilterChip::label)
+            .collect::<Vec<_>>()
+            .join(" · ")
+    }
+}
+
+/// 검색 조건 한 개 (UI에서 개별 편집/삭제 가능한 칩 단위)
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterChip {
+    Text(String),
+    DateRange(Option<NaiveDate>, Option<NaiveDate>),
+    Camera(String),
+    Lens(String),
+    Tag(String),
+    Place(String),
+    Liked(bool),
+    HasLocation(bool),
+    ExifRange(ExifField, NumericRange),
+    Flash(bool),
+    Area(GeoArea),
+    Person(PersonRef),
+    Color(String),
+    Quality(QualityField, NumericRange),
+}
+
+impl FilterChip {
+    pub fn label(&self) -> String {
+        let date = |d: &Option<NaiveDate>| d.map(|d| d.to_string()).unwrap_or_default();
+
+        match self {
+            FilterChip::Text(text) => format!("\"{}\"", text),
+            FilterChip::DateRange(from, to) => format!("{} ~ {}", date(from), date(to)),
+            FilterChip::Camera(camera) => format!("📷 {}", camera),
+            FilterChip::Lens(lens) => format!("🔭 {}", lens),
+            FilterChip::Tag(tag) => format!("#{}", tag),
+            FilterChip::Place(place) => format!("📍 {}", place),
+            FilterChip::Liked(true) => "❤️ Liked".to_string(),
+            FilterChip::Liked(false) => "🤍 Not liked".to_string(),
+            FilterChip::HasLocation(true) => "Geotagged".to_string(),
+            FilterChip::HasLocation(false) => "No location".to_string(),
+            FilterChip::ExifRange(field, range) => field.format_range(*range),
+            FilterChip::Flash(true) => "⚡ Flash".to_string(),
+            FilterChip::Flash(false) => "No flash".to_string(),
+            FilterChip::Area(area) => area.label(),
+            FilterChip::Person(person) => format!("👤 {}", person.name),
+            FilterChip::Color(color) => format!("🎨 {}", color),
+            FilterChip::Quality(field, range) => field.format_range(*range),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::domain::entities::photo::Photo;
+
+/// 비슷한 사진 검색 결과 하나
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimilarPhoto {
+    pub photo: Photo,
+    /// 코사인 유사도 (1에 가까울수록 비슷함)
+    pub similarity: f32,
+}
@@ -0,0 +1,47 @@
+use chrono::{Month, NaiveDate};
+
+/// 스크러버의 한 달 구간 (UTC 기준)
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimelineMonth {
+    pub year: i32,
+    /// 1~12
+    pub month: u32,
+    pub count: u64,
+    /// 이 달의 가장 최근 사진부터 불러오는 검색 커서
+    pub cursor: String,
+}
+
+impl TimelineMonth {
+    /// 예: "Mar 2024"
+    pub fn label(&self) -> String {
+        format!("{} {}", short_month_name(self.month), self.year)
+    }
+}
+
+/// 라이브러리(또는 검색 결과) 전체의 월별 사진 수 (최근 달부터)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Timeline {
+    pub total: u64,
+    pub months: Vec<TimelineMonth>,
+}
+
+/// 예: "March 2024"
+pub fn month_title(year: i32, month: u32) -> String {
+    let name = month_of(month).map_or("", |m| m.name());
+    format!("{} {}", name, year)
+}
+
+/// 예: "Mon, Mar 4"
+pub fn day_title(date: NaiveDate) -> String {
+    date.format("%a, %b %-d").to_string()
+}
+
+fn short_month_name(month: u32) -> &'static str {
+    month_of(month).map_or("", |m| &m.name()[..3])
+}
+
+fn month_of(month: u32) -> Option<Month> {
+    u8::try_from(month)
+        .ok()
+        .and_then(|m| Month::try_from(m).ok())
+}
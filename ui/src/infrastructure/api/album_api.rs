@@ -0,0 +1,39 @@
+use crate::domain::value_objects::album::Album;
+use crate::infrastructure::api::album_dto::{
+    AddAlbumPhotosDto, AddAlbumPhotosRequest, AlbumDto, AlbumsDto, CreateAlbumRequest,
+};
+use crate::infrastructure::api::api_client::{get_json, send_json, ApiResult};
+use gloo_net::http::Method;
+use uuid::Uuid;
+
+/// `GET /api/albums` - 앨범 목록 (최근에 바뀐 순)
+pub async fn fetch_albums() -> ApiResult<Vec<Album>> {
+    get_json::<AlbumsDto>("/api/albums", &[])
+        .await
+        .map(|dto| dto.albums.into_iter().map(Album::from).collect())
+}
+
+/// `GET /api/albums/{id}` - 앨범 하나
+pub async fn fetch_album(album_id: Uuid) -> ApiResult<Album> {
+    get_json::<AlbumDto>(&format!("/api/albums/{}", album_id), &[])
+        .await
+        .map(Album::from)
+}
+
+/// `POST /api/albums` - 빈 앨범 만들기
+pub async fn create_album(title: &str) -> ApiResult<Album> {
+    send_json::<AlbumDto>(Method::POST, "/api/albums", &CreateAlbumRequest { title })
+        .await
+        .map(Album::from)
+}
+
+/// `POST /api/albums/{id}/photos` - 앨범에 사진 추가, 새로 추가된 수 반환
+pub async fn add_photos_to_album(album_id: Uuid, photo_ids: Vec<Uuid>) -> ApiResult<u64> {
+    send_json::<AddAlbumPhotosDto>(
+        Method::POST,
+        &format!("/api/albums/{}/photos", album_id),
+        &AddAlbumPhotosRequest { photo_ids },
+    )
+    .await
+    .map(|dto| dto.added)
+}
@@ -0,0 +1,43 @@
+use crate::domain::value_objects::album::Album;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug)]
+pub struct AlbumDto {
+    pub id: Uuid,
+    pub title: String,
+    pub photo_count: i64,
+}
+
+impl From<AlbumDto> for Album {
+    fn from(dto: AlbumDto) -> Self {
+        Self {
+            id: dto.id,
+            title: dto.title,
+            photo_count: dto.photo_count,
+        }
+    }
+}
+
+/// `GET /api/albums` 응답
+#[derive(Deserialize, Debug)]
+pub struct AlbumsDto {
+    pub albums: Vec<AlbumDto>,
+}
+
+/// `POST /api/albums` 요청
+#[derive(Serialize, Debug)]
+pub struct CreateAlbumRequest<'a> {
+    pub title: &'a str,
+}
+
+/// `POST /api/albums/{id}/photos` 요청
+#[derive(Serialize, Debug)]
+pub struct AddAlbumPhotosRequest {
+    pub photo_ids: Vec<Uuid>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AddAlbumPhotosDto {
+    pub added: u64,
+}
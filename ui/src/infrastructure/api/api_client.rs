@@ -0,0 +1,127 @@
+use gloo_net::http::{Method, Request, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+
+/// API 서버 주소 (빌드 시 `PHOTOVAULT_API_URL` 환경 변수로 변경 가능)
+pub const API_BASE_URL: &str = match option_env!("PHOTOVAULT_API_URL") {
+    Some(url) => url,
+    None => "http://127.0.0.1:8080",
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ApiError {
+    Network(String),
+    Status(u16),
+    Decode(String),
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Network(message) => write!(f, "서버에 연결할 수 없습니다: {}", message),
+            ApiError::Status(status) => write!(f, "요청이 실패했습니다 (HTTP {})", status),
+            ApiError::Decode(message) => write!(f, "응답을 해석할 수 없습니다: {}", message),
+        }
+    }
+}
+
+pub type ApiResult<T> = Result<T, ApiError>;
+
+/// `GET {API_BASE_URL}{path}?{query}` 요청 후 JSON 응답을 역직렬화
+pub async fn get_json<T: DeserializeOwned>(path: &str, query: &[(&str, String)]) -> ApiResult<T> {
+    let url = format!("{}{}", API_BASE_URL, path);
+    let response = Request::get(&url)
+        .query(query.iter().map(|(key, value)| (*key, value.as_str())))
+        .send()
+        .await
+        .map_err(|e| ApiError::Network(e.to_string()))?;
+
+    if !response.ok() {
+        return Err(ApiError::Status(response.status()));
+    }
+
+    response
+        .json::<T>()
+        .await
+        .map_err(|e| ApiError::Decode(e.to_string()))
+}
+
+/// `{method} {API_BASE_URL}{path}` 로 JSON 본문을 보내고 응답 JSON 을 역직렬화
+pub async fn send_json<T: DeserializeOwned>(
+    method: Method,
+    path: &str,
+    body: &impl Serialize,
+) -> ApiResult<T> {
+    send(method, path, body)
+        .await?
+        .json::<T>()
+        .await
+        .map_err(|e| ApiError::Decode(e.to_string()))
+}
+
+/// 응답 본문이 없는 요청 (`204 No Content`)
+pub async fn send_json_no_content(method: Method, path: &str, body: &impl Serialize) -> ApiResult<()> {
+    send(method, path, body).await.map(|_| ())
+}
+
+/// 파일 주소(`resolve_url` 결과)의 내용과 `Content-Type` (예: 공유할 미리보기 이미지)
+pub async fn get_bytes(url: &str) -> ApiResult<(Vec<u8>, String)> {
+    let response = Request::get(url)
+        .send()
+        .await
+        .map_err(|e| ApiError::Network(e.to_string()))?;
+
+    if !response.ok() {
+        return Err(ApiError::Status(response.status()));
+    }
+
+    let mime_type = response
+        .headers()
+        .get("content-type")
+        .unwrap_or_else(|| "application/octet-stream".to_string());
+    let bytes = response
+        .binary()
+        .await
+        .map_err(|e| ApiError::Decode(e.to_string()))?;
+    Ok((bytes, mime_type))
+}
+
+/// `DELETE {API_BASE_URL}{path}`
+pub async fn delete(path: &str) -> ApiResult<()> {
+    let url = format!("{}{}", API_BASE_URL, path);
+    let response = Request::delete(&url)
+        .send()
+        .await
+        .map_err(|e| ApiError::Network(e.to_string()))?;
+
+    match response.ok() {
+        true => Ok(()),
+        false => Err(ApiError::Status(response.status())),
+    }
+}
+
+async fn send(method: Method, path: &str, body: &impl Serialize) -> ApiResult<Response> {
+    let url = format!("{}{}", API_BASE_URL, path);
+    let response = RequestBuilder::new(&url)
+        .method(method)
+        .json(body)
+        .map_err(|e| ApiError::Network(e.to_string()))?
+        .send()
+        .await
+        .map_err(|e| ApiError::Network(e.to_string()))?;
+
+    if !response.ok() {
+        return Err(ApiError::Status(response.status()));
+    }
+    Ok(response)
+}
+
+/// 서버가 내려준 상대 경로(`/media/...`)를 절대 URL로 변환
+pub fn resolve_url(path: &str) -> String {
+    if path.starts_with("http://") || path.starts_with("https://") {
+        path.to_string()
+    } else {
+        format!("{}{}", API_BASE_URL, path)
+    }
+}
@@ -0,0 +1,15 @@
+use crate::domain::value_objects::cleanup::CleanupSuggestion;
+use crate::infrastructure::api::api_client::{get_json, ApiResult};
+use crate::infrastructure::api::cleanup_dto::CleanupDto;
+
+/// `GET /api/photos/cleanup` - 흐리거나 노출이 나쁜 사진 (가장 나쁜 순)
+pub async fn fetch_cleanup_suggestions() -> ApiResult<Vec<CleanupSuggestion>> {
+    get_json::<CleanupDto>("/api/photos/cleanup", &[])
+        .await
+        .map(|dto| {
+            dto.photos
+                .into_iter()
+                .map(CleanupSuggestion::from)
+                .collect()
+        })
+}
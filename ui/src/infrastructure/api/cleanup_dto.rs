@@ -0,0 +1,32 @@
+use crate::domain::entities::photo::Photo;
+use crate::domain::value_objects::cleanup::{CleanupSuggestion, QualityIssue};
+use crate::infrastructure::api::photo_dto::PhotoDto;
+use serde::Deserialize;
+
+/// 백엔드 `CleanupSuggestionDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct CleanupSuggestionDto {
+    #[serde(flatten)]
+    pub photo: PhotoDto,
+    #[serde(default)]
+    pub issues: Vec<String>,
+}
+
+impl From<CleanupSuggestionDto> for CleanupSuggestion {
+    fn from(dto: CleanupSuggestionDto) -> Self {
+        Self {
+            photo: Photo::from(dto.photo),
+            issues: dto
+                .issues
+                .iter()
+                .filter_map(|issue| QualityIssue::parse(issue))
+                .collect(),
+        }
+    }
+}
+
+/// 백엔드 `CleanupResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct CleanupDto {
+    pub photos: Vec<CleanupSuggestionDto>,
+}
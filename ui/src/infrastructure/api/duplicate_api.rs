@@ -0,0 +1,25 @@
+use crate::domain::value_objects::duplicate::DuplicateReview;
+use crate::infrastructure::api::api_client::{get_json, send_json, ApiResult};
+use crate::infrastructure::api::duplicate_dto::{
+    DuplicatesDto, TrashPhotosDto, TrashPhotosRequest,
+};
+use gloo_net::http::Method;
+use uuid::Uuid;
+
+/// `GET /api/duplicates` - 유사 중복 사진 그룹
+pub async fn fetch_duplicates() -> ApiResult<DuplicateReview> {
+    get_json::<DuplicatesDto>("/api/duplicates", &[])
+        .await
+        .map(DuplicateReview::from)
+}
+
+/// `POST /api/photos/trash` - 휴지통으로 옮기고 옮긴 사진 수 반환
+pub async fn trash_photos(photo_ids: Vec<Uuid>) -> ApiResult<u64> {
+    send_json::<TrashPhotosDto>(
+        Method::POST,
+        "/api/photos/trash",
+        &TrashPhotosRequest { photo_ids },
+    )
+    .await
+    .map(|dto| dto.trashed)
+}
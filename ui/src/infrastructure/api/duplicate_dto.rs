@@ -0,0 +1,63 @@
+use crate::domain::entities::photo::Photo;
+use crate::domain::value_objects::duplicate::{DuplicateGroup, DuplicatePhoto, DuplicateReview};
+use crate::infrastructure::api::photo_dto::PhotoDto;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 백엔드 `DuplicatePhotoDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct DuplicatePhotoDto {
+    #[serde(flatten)]
+    pub photo: PhotoDto,
+    pub file_size_bytes: i64,
+}
+
+impl From<DuplicatePhotoDto> for DuplicatePhoto {
+    fn from(dto: DuplicatePhotoDto) -> Self {
+        Self {
+            photo: Photo::from(dto.photo),
+            file_size_bytes: dto.file_size_bytes,
+        }
+    }
+}
+
+/// 백엔드 `DuplicatesResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct DuplicatesDto {
+    pub groups: Vec<DuplicateGroupDto>,
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+impl From<DuplicatesDto> for DuplicateReview {
+    fn from(dto: DuplicatesDto) -> Self {
+        Self {
+            groups: dto.groups.into_iter().map(DuplicateGroup::from).collect(),
+            truncated: dto.truncated,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DuplicateGroupDto {
+    pub photos: Vec<DuplicatePhotoDto>,
+}
+
+impl From<DuplicateGroupDto> for DuplicateGroup {
+    fn from(dto: DuplicateGroupDto) -> Self {
+        Self {
+            photos: dto.photos.into_iter().map(DuplicatePhoto::from).collect(),
+        }
+    }
+}
+
+/// `POST /api/photos/trash` 요청 본문
+#[derive(Serialize, Debug)]
+pub struct TrashPhotosRequest {
+    pub photo_ids: Vec<Uuid>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TrashPhotosDto {
+    pub trashed: u64,
+}
@@ -0,0 +1,24 @@
+use crate::domain::value_objects::geo_area::{BoundingBox, GeoArea, GeoCluster};
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::infrastructure::api::api_client::{get_json, ApiResult};
+use crate::infrastructure::api::geo_dto::GeoClustersDto;
+use crate::infrastructure::api::search_query::{area_to_query, filter_to_query};
+
+/// `GET /api/geo/bbox` - 지도 화면 영역 안의 사진 클러스터 (나머지 검색 조건 포함)
+pub async fn fetch_clusters_in_bbox(
+    bounds: BoundingBox,
+    zoom: u8,
+    filter: &SearchFilter,
+) -> ApiResult<Vec<GeoCluster>> {
+    let filter = SearchFilter {
+        area: None,
+        ..filter.clone()
+    };
+    let mut query = filter_to_query(&filter);
+    query.extend(area_to_query(&GeoArea::BoundingBox(bounds)));
+    query.push(("zoom", zoom.to_string()));
+
+    get_json::<GeoClustersDto>("/api/geo/bbox", &query)
+        .await
+        .map(|dto| dto.clusters.into_iter().map(GeoCluster::from).collect())
+}
@@ -0,0 +1,44 @@
+use crate::domain::value_objects::geo_area::{BoundingBox, GeoCluster, GeoPoint};
+use crate::infrastructure::api::api_client::resolve_url;
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// 백엔드 `GeoClusterDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct GeoClusterDto {
+    pub lat: f64,
+    pub lng: f64,
+    pub count: i64,
+    pub west: f64,
+    pub south: f64,
+    pub east: f64,
+    pub north: f64,
+    pub cover_photo_id: Uuid,
+    pub cover_thumbnail_url: String,
+}
+
+impl From<GeoClusterDto> for GeoCluster {
+    fn from(dto: GeoClusterDto) -> Self {
+        Self {
+            center: GeoPoint {
+                lat: dto.lat,
+                lng: dto.lng,
+            },
+            bounds: BoundingBox {
+                west: dto.west,
+                south: dto.south,
+                east: dto.east,
+                north: dto.north,
+            },
+            count: dto.count,
+            cover_photo_id: dto.cover_photo_id,
+            cover_thumbnail_url: resolve_url(&dto.cover_thumbnail_url),
+        }
+    }
+}
+
+/// 백엔드 `GeoClustersResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct GeoClustersDto {
+    pub clusters: Vec<GeoClusterDto>,
+}
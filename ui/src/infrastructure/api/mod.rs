@@ -0,0 +1,25 @@
+pub mod album_api;
+pub mod album_dto;
+pub mod api_client;
+pub mod cleanup_api;
+pub mod cleanup_dto;
+pub mod duplicate_api;
+pub mod duplicate_dto;
+pub mod geo_api;
+pub mod geo_dto;
+pub mod person_api;
+pub mod person_dto;
+pub mod photo_api;
+pub mod photo_batch_api;
+pub mod photo_batch_dto;
+pub mod photo_dto;
+pub mod place_api;
+pub mod place_dto;
+pub mod search_api;
+pub mod search_facets_dto;
+pub mod search_query;
+pub mod similar_api;
+pub mod similar_dto;
+pub mod tile_source;
+pub mod timeline_api;
+pub mod timeline_dto;
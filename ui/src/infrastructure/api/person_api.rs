@@ -0,0 +1,80 @@
+use crate::domain::value_objects::person::{
+    FaceAssignment, FaceRect, Person, PersonFace, PhotoFace,
+};
+use crate::infrastructure::api::api_client::{
+    delete, get_json, send_json, send_json_no_content, ApiResult,
+};
+use crate::infrastructure::api::person_dto::{
+    AddFaceRequest, FaceTargetRequest, MergePeopleDto, MergePeopleRequest, PeopleDto,
+    PersonFacesDto, PhotoFacesDto, RenamePersonRequest, SplitPersonDto, SplitPersonRequest,
+};
+use gloo_net::http::Method;
+use uuid::Uuid;
+
+/// `GET /api/people` - 얼굴 군집으로 묶인 인물 목록
+pub async fn fetch_people() -> ApiResult<Vec<Person>> {
+    get_json::<PeopleDto>("/api/people", &[])
+        .await
+        .map(|dto| dto.people.into_iter().map(Person::from).collect())
+}
+
+/// `GET /api/people/{id}/faces`
+pub async fn fetch_person_faces(person_id: Uuid) -> ApiResult<Vec<PersonFace>> {
+    get_json::<PersonFacesDto>(&format!("/api/people/{}/faces", person_id), &[])
+        .await
+        .map(|dto| dto.faces.into_iter().map(PersonFace::from).collect())
+}
+
+/// `PATCH /api/people/{id}` - `None` 이면 이름 제거
+pub async fn rename_person(person_id: Uuid, name: Option<String>) -> ApiResult<()> {
+    let path = format!("/api/people/{}", person_id);
+    send_json_no_content(Method::PATCH, &path, &RenamePersonRequest { name }).await
+}
+
+/// `POST /api/people/{id}/merge` - `person_ids` 를 `target_id` 로 합치고 옮긴 얼굴 수 반환
+pub async fn merge_people(target_id: Uuid, person_ids: Vec<Uuid>) -> ApiResult<u64> {
+    let path = format!("/api/people/{}/merge", target_id);
+    send_json::<MergePeopleDto>(Method::POST, &path, &MergePeopleRequest { person_ids })
+        .await
+        .map(|dto| dto.moved_faces)
+}
+
+/// `POST /api/people/{id}/split` - 선택한 얼굴을 새 인물로 분리하고 새 인물 id 반환
+pub async fn split_person(person_id: Uuid, face_ids: Vec<Uuid>) -> ApiResult<Uuid> {
+    let path = format!("/api/people/{}/split", person_id);
+    send_json::<SplitPersonDto>(Method::POST, &path, &SplitPersonRequest { face_ids })
+        .await
+        .map(|dto| dto.person_id)
+}
+
+/// `GET /api/photos/{id}/faces` - 사진의 얼굴 영역과 배정된 인물
+pub async fn fetch_photo_faces(photo_id: Uuid) -> ApiResult<Vec<PhotoFace>> {
+    get_json::<PhotoFacesDto>(&format!("/api/photos/{}/faces", photo_id), &[])
+        .await
+        .map(|dto| dto.faces.into_iter().map(PhotoFace::from).collect())
+}
+
+/// `POST /api/photos/{id}/faces` - 직접 그린 얼굴 추가
+pub async fn add_face(
+    photo_id: Uuid,
+    rectangle: FaceRect,
+    assignment: FaceAssignment,
+) -> ApiResult<()> {
+    let path = format!("/api/photos/{}/faces", photo_id);
+    let request = AddFaceRequest {
+        rectangle,
+        target: FaceTargetRequest::from(assignment),
+    };
+    send_json_no_content(Method::POST, &path, &request).await
+}
+
+/// `PATCH /api/faces/{id}` - 얼굴의 인물 변경
+pub async fn assign_face(face_id: Uuid, assignment: FaceAssignment) -> ApiResult<()> {
+    let path = format!("/api/faces/{}", face_id);
+    send_json_no_content(Method::PATCH, &path, &FaceTargetRequest::from(assignment)).await
+}
+
+/// `DELETE /api/faces/{id}` - 얼굴이 아닌 검출 결과 삭제
+pub async fn remove_face(face_id: Uuid) -> ApiResult<()> {
+    delete(&format!("/api/faces/{}", face_id)).await
+}
@@ -0,0 +1,148 @@
+use crate::domain::value_objects::person::{
+    FaceAssignment, FaceRect, Person, PersonFace, PersonRef, PhotoFace,
+};
+use crate::infrastructure::api::api_client::resolve_url;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 백엔드 `PersonDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct PersonDto {
+    pub id: Uuid,
+    pub name: Option<String>,
+    pub face_count: i64,
+    pub cover_url: Option<String>,
+}
+
+impl From<PersonDto> for Person {
+    fn from(dto: PersonDto) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            face_count: dto.face_count,
+            cover_url: dto.cover_url.as_deref().map(resolve_url),
+        }
+    }
+}
+
+/// 백엔드 `PeopleResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct PeopleDto {
+    pub people: Vec<PersonDto>,
+}
+
+/// 백엔드 `PersonFaceDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct PersonFaceDto {
+    pub id: Uuid,
+    pub photo_id: Uuid,
+    pub rectangle: FaceRect,
+    pub confidence: f64,
+    pub crop_url: String,
+}
+
+impl From<PersonFaceDto> for PersonFace {
+    fn from(dto: PersonFaceDto) -> Self {
+        Self {
+            id: dto.id,
+            photo_id: dto.photo_id,
+            rectangle: dto.rectangle,
+            confidence: dto.confidence,
+            crop_url: resolve_url(&dto.crop_url),
+        }
+    }
+}
+
+/// 백엔드 `PersonFacesResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct PersonFacesDto {
+    pub faces: Vec<PersonFaceDto>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct RenamePersonRequest {
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct MergePeopleRequest {
+    pub person_ids: Vec<Uuid>,
+}
+
+/// 백엔드 `MergePeopleResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct MergePeopleDto {
+    pub moved_faces: u64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SplitPersonRequest {
+    pub face_ids: Vec<Uuid>,
+}
+
+/// 백엔드 `SplitPersonResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct SplitPersonDto {
+    pub person_id: Uuid,
+}
+
+/// 백엔드 `PhotoFaceDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct PhotoFaceDto {
+    pub id: Uuid,
+    pub person_id: Option<Uuid>,
+    pub person_name: Option<String>,
+    pub rectangle: FaceRect,
+    pub confidence: f64,
+    pub is_manual: bool,
+}
+
+impl From<PhotoFaceDto> for PhotoFace {
+    fn from(dto: PhotoFaceDto) -> Self {
+        Self {
+            id: dto.id,
+            person: dto.person_id.map(|id| PersonRef {
+                id,
+                name: dto.person_name.unwrap_or_else(|| "Unnamed".to_string()),
+            }),
+            rectangle: dto.rectangle,
+            confidence: dto.confidence,
+            is_manual: dto.is_manual,
+        }
+    }
+}
+
+/// 백엔드 `PhotoFacesResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct PhotoFacesDto {
+    pub faces: Vec<PhotoFaceDto>,
+}
+
+/// 배정 대상: `person_id` 가 없으면 `new_person_name` 으로 새 인물 생성
+#[derive(Serialize, Debug)]
+pub struct FaceTargetRequest {
+    pub person_id: Option<Uuid>,
+    pub new_person_name: Option<String>,
+}
+
+impl From<FaceAssignment> for FaceTargetRequest {
+    fn from(assignment: FaceAssignment) -> Self {
+        match assignment {
+            FaceAssignment::Person(person_id) => Self {
+                person_id: Some(person_id),
+                new_person_name: None,
+            },
+            FaceAssignment::NewPerson(name) => Self {
+                person_id: None,
+                new_person_name: name,
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct AddFaceRequest {
+    pub rectangle: FaceRect,
+    #[serde(flatten)]
+    pub target: FaceTargetRequest,
+}
@@ -0,0 +1,20 @@
+use crate::domain::value_objects::photo_page::PhotoContext;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::infrastructure::api::api_client::{get_json, ApiResult};
+use crate::infrastructure::api::photo_dto::PhotoContextDto;
+use crate::infrastructure::api::search_query::filter_to_query;
+use uuid::Uuid;
+
+/// `GET /api/photos/{id}` - 사진 한 장과 검색 조건 기준 앞뒤 이웃 사진 (`limit` 장씩)
+pub async fn fetch_photo_context(
+    photo_id: Uuid,
+    filter: &SearchFilter,
+    limit: usize,
+) -> ApiResult<PhotoContext> {
+    let mut query = filter_to_query(filter);
+    query.push(("limit", limit.to_string()));
+
+    get_json::<PhotoContextDto>(&format!("/api/photos/{}", photo_id), &query)
+        .await
+        .map(PhotoContext::from)
+}
@@ -0,0 +1,39 @@
+use crate::infrastructure::api::api_client::{resolve_url, send_json, ApiResult};
+use crate::infrastructure::api::photo_batch_dto::{
+    DownloadPhotosRequest, DownloadTicketDto, PhotoBatchDto, SetFavoritesRequest, TagPhotosRequest,
+};
+use gloo_net::http::Method;
+use uuid::Uuid;
+
+/// `POST /api/photos/favorites` - 좋아요 켜기/끄기, 바뀐 사진 수 반환
+pub async fn set_favorites(photo_ids: Vec<Uuid>, liked: bool) -> ApiResult<u64> {
+    send_json::<PhotoBatchDto>(
+        Method::POST,
+        "/api/photos/favorites",
+        &SetFavoritesRequest { photo_ids, liked },
+    )
+    .await
+    .map(|dto| dto.updated)
+}
+
+/// `POST /api/photos/tags` - 태그 붙이기, 새로 붙은 수 반환
+pub async fn tag_photos(photo_ids: Vec<Uuid>, tags: Vec<String>) -> ApiResult<u64> {
+    send_json::<PhotoBatchDto>(
+        Method::POST,
+        "/api/photos/tags",
+        &TagPhotosRequest { photo_ids, tags },
+    )
+    .await
+    .map(|dto| dto.updated)
+}
+
+/// `POST /api/photos/download` - 원본 ZIP 을 준비하고 브라우저가 직접 받을 주소 반환 (한 번만 유효)
+pub async fn download_photos(photo_ids: Vec<Uuid>) -> ApiResult<String> {
+    send_json::<DownloadTicketDto>(
+        Method::POST,
+        "/api/photos/download",
+        &DownloadPhotosRequest { photo_ids },
+    )
+    .await
+    .map(|dto| resolve_url(&dto.url))
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// `POST /api/photos/favorites` 요청
+#[derive(Serialize, Debug)]
+pub struct SetFavoritesRequest {
+    pub photo_ids: Vec<Uuid>,
+    pub liked: bool,
+}
+
+/// `POST /api/photos/tags` 요청
+#[derive(Serialize, Debug)]
+pub struct TagPhotosRequest {
+    pub photo_ids: Vec<Uuid>,
+    pub tags: Vec<String>,
+}
+
+/// `POST /api/photos/download` 요청
+#[derive(Serialize, Debug)]
+pub struct DownloadPhotosRequest {
+    pub photo_ids: Vec<Uuid>,
+}
+
+/// `POST /api/photos/download` 응답 (ZIP 을 받을 주소)
+#[derive(Deserialize, Debug)]
+pub struct DownloadTicketDto {
+    pub url: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PhotoBatchDto {
+    pub updated: u64,
+}
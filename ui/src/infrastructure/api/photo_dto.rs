@@ -0,0 +1,86 @@
+use crate::domain::entities::photo::Photo;
+use crate::domain::value_objects::photo_page::{PhotoContext, PhotoPage};
+use crate::infrastructure::api::api_client::resolve_url;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// 백엔드 `PhotoDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct PhotoDto {
+    pub id: Uuid,
+    pub url: String,
+    pub thumbnail_url: String,
+    pub preview_url: String,
+    pub title: String,
+    pub taken_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub is_liked: bool,
+    pub width: u32,
+    pub height: u32,
+    #[serde(default)]
+    pub dominant_colors: Vec<String>,
+    #[serde(default)]
+    pub sharpness: Option<f32>,
+    #[serde(default)]
+    pub exposure: Option<f32>,
+}
+
+impl From<PhotoDto> for Photo {
+    fn from(dto: PhotoDto) -> Self {
+        Self {
+            id: dto.id,
+            // 그리드에서는 썸네일을 사용 (mock 데이터와 동일한 규칙)
+            url: resolve_url(&dto.thumbnail_url),
+            preview_url: resolve_url(&dto.preview_url),
+            original_url: resolve_url(&dto.url),
+            title: dto.title,
+            taken_at: dto.taken_at,
+            created_at: dto.created_at,
+            is_liked: dto.is_liked,
+            width: dto.width,
+            height: dto.height,
+            dominant_colors: dto.dominant_colors,
+            sharpness: dto.sharpness,
+            exposure: dto.exposure,
+        }
+    }
+}
+
+/// 백엔드 `PhotoPageResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct PhotoPageDto {
+    pub photos: Vec<PhotoDto>,
+    pub next_cursor: Option<String>,
+}
+
+impl From<PhotoPageDto> for PhotoPage {
+    fn from(dto: PhotoPageDto) -> Self {
+        Self {
+            photos: dto.photos.into_iter().map(Photo::from).collect(),
+            next_cursor: dto.next_cursor,
+        }
+    }
+}
+
+/// 백엔드 `PhotoContextResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct PhotoContextDto {
+    pub photo: PhotoDto,
+    pub previous: Vec<PhotoDto>,
+    pub next: Vec<PhotoDto>,
+    pub next_cursor: Option<String>,
+}
+
+impl From<PhotoContextDto> for PhotoContext {
+    fn from(dto: PhotoContextDto) -> Self {
+        Self {
+            photo: Photo::from(dto.photo),
+            previous: dto.previous.into_iter().map(Photo::from).collect(),
+            next: PhotoPage {
+                photos: dto.next.into_iter().map(Photo::from).collect(),
+                next_cursor: dto.next_cursor,
+            },
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::domain::value_objects::place::CountryPlaces;
+use crate::infrastructure::api::api_client::{get_json, ApiResult};
+use crate::infrastructure::api::place_dto::PlacesDto;
+
+/// `GET /api/places` - 국가 → 도시별 사진 수
+pub async fn fetch_places() -> ApiResult<Vec<CountryPlaces>> {
+    get_json::<PlacesDto>("/api/places", &[])
+        .await
+        .map(|dto| dto.countries.into_iter().map(CountryPlaces::from).collect())
+}
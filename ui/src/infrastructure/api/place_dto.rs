@@ -0,0 +1,51 @@
+use crate::domain::value_objects::place::{CityPlace, CountryPlaces};
+use crate::infrastructure::api::api_client::resolve_url;
+use serde::Deserialize;
+
+/// 백엔드 `CityPlaceDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct CityPlaceDto {
+    pub city: String,
+    pub region: String,
+    pub location_name: String,
+    pub count: i64,
+    pub cover_thumbnail_url: String,
+}
+
+impl From<CityPlaceDto> for CityPlace {
+    fn from(dto: CityPlaceDto) -> Self {
+        Self {
+            city: dto.city,
+            region: dto.region,
+            location_name: dto.location_name,
+            count: dto.count,
+            cover_thumbnail_url: resolve_url(&dto.cover_thumbnail_url),
+        }
+    }
+}
+
+/// 백엔드 `CountryPlacesDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct CountryPlacesDto {
+    pub country: String,
+    pub country_code: String,
+    pub count: i64,
+    pub cities: Vec<CityPlaceDto>,
+}
+
+impl From<CountryPlacesDto> for CountryPlaces {
+    fn from(dto: CountryPlacesDto) -> Self {
+        Self {
+            country: dto.country,
+            country_code: dto.country_code,
+            count: dto.count,
+            cities: dto.cities.into_iter().map(CityPlace::from).collect(),
+        }
+    }
+}
+
+/// 백엔드 `PlacesResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct PlacesDto {
+    pub countries: Vec<CountryPlacesDto>,
+}
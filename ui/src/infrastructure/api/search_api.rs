@@ -0,0 +1,31 @@
+use crate::domain::value_objects::photo_page::PhotoPage;
+use crate::domain::value_objects::search_facets::SearchFacets;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::infrastructure::api::api_client::{get_json, ApiResult};
+use crate::infrastructure::api::photo_dto::PhotoPageDto;
+use crate::infrastructure::api::search_facets_dto::SearchFacetsDto;
+use crate::infrastructure::api::search_query::filter_to_query;
+
+/// `GET /api/search` - 검색 결과 한 페이지 조회
+pub async fn search_photos(
+    filter: &SearchFilter,
+    cursor: Option<&str>,
+    limit: usize,
+) -> ApiResult<PhotoPage> {
+    let mut query = filter_to_query(filter);
+    query.push(("limit", limit.to_string()));
+    if let Some(cursor) = cursor {
+        query.push(("cursor", cursor.to_string()));
+    }
+
+    get_json::<PhotoPageDto>("/api/search", &query)
+        .await
+        .map(PhotoPage::from)
+}
+
+/// `GET /api/search/facets` - 현재 조건에서 선택 가능한 EXIF 값별 사진 수
+pub async fn fetch_search_facets(filter: &SearchFilter) -> ApiResult<SearchFacets> {
+    get_json::<SearchFacetsDto>("/api/search/facets", &filter_to_query(filter))
+        .await
+        .map(SearchFacets::from)
+}
@@ -0,0 +1,69 @@
+use crate::domain::value_objects::exif_filter::NumericRange;
+use crate::domain::value_objects::search_facets::{RangeFacet, SearchFacets, ValueFacet};
+use serde::Deserialize;
+
+/// 백엔드 `ValueFacetDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct ValueFacetDto<T> {
+    pub value: T,
+    pub count: i64,
+}
+
+impl<T> From<ValueFacetDto<T>> for ValueFacet<T> {
+    fn from(dto: ValueFacetDto<T>) -> Self {
+        Self {
+            value: dto.value,
+            count: dto.count,
+        }
+    }
+}
+
+/// 백엔드 `RangeFacetDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct RangeFacetDto {
+    pub label: String,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub count: i64,
+}
+
+impl From<RangeFacetDto> for RangeFacet {
+    fn from(dto: RangeFacetDto) -> Self {
+        Self {
+            label: dto.label,
+            range: NumericRange {
+                min: dto.min,
+                max: dto.max,
+            },
+            count: dto.count,
+        }
+    }
+}
+
+/// 백엔드 `SearchFacetsResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct SearchFacetsDto {
+    pub lenses: Vec<ValueFacetDto<String>>,
+    pub focal_length: Vec<RangeFacetDto>,
+    pub aperture: Vec<RangeFacetDto>,
+    pub shutter_speed: Vec<RangeFacetDto>,
+    pub iso: Vec<RangeFacetDto>,
+    pub flash: Vec<ValueFacetDto<bool>>,
+}
+
+impl From<SearchFacetsDto> for SearchFacets {
+    fn from(dto: SearchFacetsDto) -> Self {
+        fn convert<D, T: From<D>>(items: Vec<D>) -> Vec<T> {
+            items.into_iter().map(T::from).collect()
+        }
+
+        Self {
+            lenses: convert(dto.lenses),
+            focal_length: convert(dto.focal_length),
+            aperture: convert(dto.aperture),
+            shutter_speed: convert(dto.shutter_speed),
+            iso: convert(dto.iso),
+            flash: convert(dto.flash),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::domain::value_objects::color::normalize_hex;
+use crate::domain::value_objects::exif_filter::{ExifField, NumericRange};
+use crate::domain::value_objects::geo_area::{BoundingBox, GeoArea, GeoPoint};
+use crate::domain::value_objects::person::PersonRef;
+use crate::domain::value_objects::quality_filter::QualityField;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use std::str::FromStr;
+
+/// EXIF 범위 필드의 쿼리 파라미터 이름 (`min`, `max`)
+fn range_params(field: ExifField) -> (&'static str, &'static str) {
+    match field {
+        ExifField::FocalLength => ("focal_min", "focal_max"),
+        ExifField::Aperture => ("aperture_min", "aperture_max"),
+        ExifField::ShutterSpeed => ("shutter_min", "shutter_max"),
+        ExifField::Iso => ("iso_min", "iso_max"),
+    }
+}
+
+/// 화질 점수 필드의 쿼리 파라미터 이름 (`min`, `max`)
+fn quality_params(field: QualityField) -> (&'static str, &'static str) {
+    match field {
+        QualityField::Sharpness => ("sharpness_min", "sharpness_max"),
+        QualityField::Exposure => ("exposure_min", "exposure_max"),
+    }
+}
+
+/// 검색 조건 → 쿼리 파라미터 (API 요청과 주소창 URL 에서 공통 사용)
+pub fn filter_to_query(filter: &SearchFilter) -> Vec<(&'static str, String)> {
+    let mut query = Vec::new();
+    let mut push = |name: &'static str, value: Option<String>| {
+        if let Some(value) = value {
+            query.push((name, value));
+        }
+    };
+
+    push("q", filter.text.clone());
+    push("date_from", filter.date_from.map(|d| d.to_string()));
+    push("date_to", filter.date_to.map(|d| d.to_string()));
+    push("camera", filter.camera.clone());
+    push("lens", filter.lens.clone());
+    push(
+        "tags",
+        Some(filter.tags.join(",")).filter(|tags| !tags.is_empty()),
+    );
+    push("place", filter.place.clone());
+    push("liked", filter.is_liked.map(|v| v.to_string()));
+    push("has_location", filter.has_location.map(|v| v.to_string()));
+    for field in ExifField::ALL {
+        let range = field.range(&filter.exif);
+        let (min, max) = range_params(field);
+        push(min, range.min.map(|v| v.to_string()));
+        push(max, range.max.map(|v| v.to_string()));
+    }
+    push("flash", filter.exif.flash.map(|v| v.to_string()));
+    for field in QualityField::ALL {
+        let range = field.range(&filter.quality);
+        let (min, max) = quality_params(field);
+        push(min, range.min.map(|v| v.to_string()));
+        push(max, range.max.map(|v| v.to_string()));
+    }
+    // `#` 은 주소창에서 인코딩이 필요하므로 16진수만 전달
+    push(
+        "color",
+        filter.color.as_ref().map(|c| c.trim_start_matches('#').to_string()),
+    );
+    push("album", filter.album_id.map(|id| id.to_string()));
+    query.extend(filter.area.as_ref().map(area_to_query).unwrap_or_default());
+    if let Some(person) = &filter.person {
+        // 이름은 주소창에서 칩을 다시 그리기 위한 값 (서버는 id 만 사용)
+        query.push(("person", person.id.to_string()));
+        query.push(("person_name", person.name.clone()));
+    }
+
+    query
+}
+
+/// 지도 영역 → `west/south/east/north` 또는 `lat/lng/radius_m`
+pub fn area_to_query(area: &GeoArea) -> Vec<(&'static str, String)> {
+    match area {
+        GeoArea::BoundingBox(bounds) => vec![
+            ("west", bounds.west.to_string()),
+            ("south", bounds.south.to_string()),
+            ("east", bounds.east.to_string()),
+            ("north", bounds.north.to_string()),
+        ],
+        GeoArea::Radius { center, meters } => vec![
+            ("lat", center.lat.to_string()),
+            ("lng", center.lng.to_string()),
+            ("radius_m", meters.to_string()),
+        ],
+    }
+}
+
+/// 쿼리 파라미터 → 검색 조건 (형식이 맞지 않는 값은 무시)
+pub fn filter_from_query(get: impl Fn(&str) -> Option<String>) -> SearchFilter {
+    let text = |name: &str| {
+        get(name)
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    let mut filter = SearchFilter {
+        text: text("q"),
+        date_from: parse(text("date_from")),
+        date_to: parse(text("date_to")),
+        camera: text("camera"),
+        lens: text("lens"),
+        tags: text("tags")
+            .map(|tags| split_tags(&tags))
+            .unwrap_or_default(),
+        place: text("place"),
+        is_liked: parse(text("liked")),
+        has_location: parse(text("has_location")),
+        ..SearchFilter::default()
+    };
+    for field in ExifField::ALL {
+        let (min, max) = range_params(field);
+        *field.range_mut(&mut filter.exif) = NumericRange {
+            min: parse(text(min)),
+            max: parse(text(max)),
+        };
+    }
+    filter.exif.flash = parse(text("flash"));
+    for field in QualityField::ALL {
+        let (min, max) = quality_params(field);
+        *field.range_mut(&mut filter.quality) = NumericRange {
+            min: parse(text(min)),
+            max: parse(text(max)),
+        };
+    }
+    filter.area = area_from_query(|name| parse(text(name)));
+    filter.color = text("color").and_then(|color| normalize_hex(&color));
+    filter.person = parse(text("person")).map(|id| PersonRef {
+        id,
+        name: text("person_name").unwrap_or_else(|| "Person".to_string()),
+    });
+    filter.album_id = parse(text("album"));
+
+    filter
+}
+
+fn area_from_query(get: impl Fn(&str) -> Option<f64>) -> Option<GeoArea> {
+    let bounds = || {
+        Some(BoundingBox {
+            west: get("west")?,
+            south: get("south")?,
+            east: get("east")?,
+            north: get("north")?,
+        })
+    };
+    let radius = || {
+        Some(GeoArea::Radius {
+            center: GeoPoint {
+                lat: get("lat")?,
+                lng: get("lng")?,
+            },
+            meters: get("radius_m")?,
+        })
+    };
+
+    bounds().map(GeoArea::BoundingBox).or_else(radius)
+}
+
+fn parse<T: FromStr>(value: Option<String>) -> Option<T> {
+    value.and_then(|v| v.parse().ok())
+}
+
+fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(',')
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
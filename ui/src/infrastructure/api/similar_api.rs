@@ -0,0 +1,12 @@
+use crate::domain::value_objects::similar::SimilarPhoto;
+use crate::infrastructure::api::api_client::{get_json, ApiResult};
+use crate::infrastructure::api::similar_dto::SimilarPhotosDto;
+use uuid::Uuid;
+
+/// `GET /api/photos/{id}/similar` - 색감/구도가 비슷한 사진 (유사도 높은 순)
+pub async fn fetch_similar_photos(photo_id: Uuid, limit: usize) -> ApiResult<Vec<SimilarPhoto>> {
+    let path = format!("/api/photos/{}/similar", photo_id);
+    get_json::<SimilarPhotosDto>(&path, &[("limit", limit.to_string())])
+        .await
+        .map(|dto| dto.photos.into_iter().map(SimilarPhoto::from).collect())
+}
@@ -0,0 +1,27 @@
+use crate::domain::entities::photo::Photo;
+use crate::domain::value_objects::similar::SimilarPhoto;
+use crate::infrastructure::api::photo_dto::PhotoDto;
+use serde::Deserialize;
+
+/// 백엔드 `SimilarPhotoDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct SimilarPhotoDto {
+    #[serde(flatten)]
+    pub photo: PhotoDto,
+    pub similarity: f32,
+}
+
+impl From<SimilarPhotoDto> for SimilarPhoto {
+    fn from(dto: SimilarPhotoDto) -> Self {
+        Self {
+            photo: Photo::from(dto.photo),
+            similarity: dto.similarity,
+        }
+    }
+}
+
+/// 백엔드 `SimilarPhotosResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct SimilarPhotosDto {
+    pub photos: Vec<SimilarPhotoDto>,
+}
@@ -0,0 +1,16 @@
+use crate::infrastructure::api::api_client::API_BASE_URL;
+
+/// 지도 타일 URL 템플릿 (빌드 시 `PHOTOVAULT_TILE_URL` 환경 변수로 변경 가능)
+///
+/// 기본값은 API 서버가 `TILE_ROOT` 에서 서빙하는 로컬 타일이라 인터넷 연결 없이도 동작합니다.
+const TILE_URL_TEMPLATE: Option<&str> = option_env!("PHOTOVAULT_TILE_URL");
+
+pub fn tile_url(z: u8, x: i64, y: i64) -> String {
+    match TILE_URL_TEMPLATE {
+        Some(template) => template
+            .replace("{z}", &z.to_string())
+            .replace("{x}", &x.to_string())
+            .replace("{y}", &y.to_string()),
+        None => format!("{}/tiles/{}/{}/{}.png", API_BASE_URL, z, x, y),
+    }
+}
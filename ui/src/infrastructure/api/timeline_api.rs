@@ -0,0 +1,12 @@
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::domain::value_objects::timeline::Timeline;
+use crate::infrastructure::api::api_client::{get_json, ApiResult};
+use crate::infrastructure::api::search_query::filter_to_query;
+use crate::infrastructure::api::timeline_dto::TimelineDto;
+
+/// `GET /api/timeline` - 검색 조건에 맞는 사진의 월별 개수와 이동용 커서
+pub async fn fetch_timeline(filter: &SearchFilter) -> ApiResult<Timeline> {
+    get_json::<TimelineDto>("/api/timeline", &filter_to_query(filter))
+        .await
+        .map(Timeline::from)
+}
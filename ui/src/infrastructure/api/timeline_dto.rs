@@ -0,0 +1,38 @@
+use crate::domain::value_objects::timeline::{Timeline, TimelineMonth};
+use serde::Deserialize;
+
+/// 백엔드 `TimelineMonthDto` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct TimelineMonthDto {
+    pub year: i32,
+    pub month: u32,
+    pub count: u64,
+    pub cursor: String,
+}
+
+impl From<TimelineMonthDto> for TimelineMonth {
+    fn from(dto: TimelineMonthDto) -> Self {
+        Self {
+            year: dto.year,
+            month: dto.month,
+            count: dto.count,
+            cursor: dto.cursor,
+        }
+    }
+}
+
+/// 백엔드 `TimelineResponse` 응답 모델
+#[derive(Deserialize, Debug)]
+pub struct TimelineDto {
+    pub total: u64,
+    pub months: Vec<TimelineMonthDto>,
+}
+
+impl From<TimelineDto> for Timeline {
+    fn from(dto: TimelineDto) -> Self {
+        Self {
+            total: dto.total,
+            months: dto.months.into_iter().map(TimelineMonth::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::HtmlAnchorElement;
+
+/// 서버 주소의 파일을 브라우저 다운로드로 저장 (`<a download>` 사용)
+///
+/// 브라우저가 직접 받아 디스크에 바로 쓰므로 큰 ZIP 도 메모리에 올리지 않습니다.
+pub fn download_url(url: &str) -> Result<(), String> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or("document is not available")?;
+    let anchor: HtmlAnchorElement = document
+        .create_element("a")
+        .map_err(js_error)?
+        .unchecked_into();
+    anchor.set_href(url);
+    // 파일 이름은 서버의 Content-Disposition 을 따름
+    anchor.set_download("");
+    anchor.click();
+    Ok(())
+}
+
+fn js_error(value: JsValue) -> String {
+    value.as_string().unwrap_or_else(|| format!("{:?}", value))
+}
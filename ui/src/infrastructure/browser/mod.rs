@@ -0,0 +1,3 @@
+pub mod file_download;
+pub mod scroll_container;
+pub mod web_share;
@@ -0,0 +1,20 @@
+use web_sys::Element;
+
+/// `el` 을 감싸는 가장 가까운 세로 스크롤 영역 (없으면 문서의 스크롤 요소)
+pub fn nearest_scroll_container(el: &Element) -> Option<Element> {
+    let window = web_sys::window()?;
+    let mut current = el.parent_element();
+    while let Some(node) = current {
+        let overflow_y = window
+            .get_computed_style(&node)
+            .ok()
+            .flatten()
+            .and_then(|style| style.get_property_value("overflow-y").ok())
+            .unwrap_or_default();
+        if matches!(overflow_y.as_str(), "auto" | "scroll" | "overlay") {
+            return Some(node);
+        }
+        current = node.parent_element();
+    }
+    window.document()?.scrolling_element()
+}
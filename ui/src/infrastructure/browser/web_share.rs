@@ -0,0 +1,59 @@
+use wasm_bindgen::JsValue;
+use web_sys::{File, FilePropertyBag, Navigator, ShareData};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShareOutcome {
+    /// 기기 공유 시트를 열었음
+    Shared,
+    /// 파일 공유(Web Share Level 2)를 지원하지 않음
+    Unsupported,
+}
+
+/// 공유할 파일 하나 (이름, MIME 타입, 내용)
+pub struct SharedFile {
+    pub name: String,
+    pub mime_type: String,
+    pub bytes: Vec<u8>,
+}
+
+/// 파일 자체를 기기 공유 시트(Web Share)로 보냄
+///
+/// 서버 주소는 받는 사람이 열 수 없으므로 링크가 아니라 파일을 보냅니다.
+pub fn share_files(title: &str, files: &[SharedFile]) -> Result<ShareOutcome, String> {
+    let Some(navigator) = web_sys::window().map(|window| window.navigator()) else {
+        return Ok(ShareOutcome::Unsupported);
+    };
+    if !has_api(&navigator, "canShare") {
+        return Ok(ShareOutcome::Unsupported);
+    }
+
+    let parts = js_sys::Array::new();
+    for file in files {
+        let file = to_js_file(file)?;
+        parts.push(&file);
+    }
+    let data = ShareData::new();
+    data.set_title(title);
+    data.set_files(&parts);
+    if !navigator.can_share_with_data(&data) {
+        return Ok(ShareOutcome::Unsupported);
+    }
+    let _ = navigator.share_with_data(&data);
+    Ok(ShareOutcome::Shared)
+}
+
+fn to_js_file(file: &SharedFile) -> Result<File, String> {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(file.bytes.as_slice()));
+    let options = FilePropertyBag::new();
+    options.set_type(&file.mime_type);
+    File::new_with_u8_array_sequence_and_options(&parts, &file.name, &options).map_err(js_error)
+}
+
+fn has_api(navigator: &Navigator, name: &str) -> bool {
+    js_sys::Reflect::get(navigator, &JsValue::from_str(name))
+        .is_ok_and(|value| !value.is_undefined())
+}
+
+fn js_error(value: JsValue) -> String {
+    value.as_string().unwrap_or_else(|| format!("{:?}", value))
+}
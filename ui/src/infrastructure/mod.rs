@@ -1 +1,4 @@
+pub mod api;
+pub mod browser;
 pub mod repositories;
+pub mod storage;
@@ -1,12 +1,16 @@
 use crate::domain::entities::photo::Photo;
 use gloo_timers::future::TimeoutFuture;
 
-pub async fn fetch_mock_photos(page: usize, limit: usize) -> Vec<Photo> {
+/// 가짜 목록의 전체 사진 수
+const MOCK_PHOTO_COUNT: usize = 200;
+
+/// `cursor`(앞서 받은 사진 수) 다음 `limit`장과 다음 커서 (마지막이면 `None`)
+pub async fn fetch_mock_photos(cursor: Option<&str>, limit: usize) -> (Vec<Photo>, Option<String>) {
     // 네트워크 지연 시뮬레이션
     TimeoutFuture::new(800).await;
 
-    let start = (page - 1) * limit;
-    (start..start + limit)
-        .map(Photo::new_mock)
-        .collect()
+    let start = cursor.and_then(|c| c.parse().ok()).unwrap_or(0);
+    let end = (start + limit).min(MOCK_PHOTO_COUNT);
+    let photos = (start..end).map(Photo::new_mock).collect();
+    (photos, (end < MOCK_PHOTO_COUNT).then(|| end.to_string()))
 }
@@ -1 +1,2 @@
+#[cfg(feature = "mock-photos")]
 pub mod mock_photo_repository;
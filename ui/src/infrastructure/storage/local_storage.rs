@@ -0,0 +1,20 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use web_sys::Storage;
+
+fn storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// localStorage 에 JSON 으로 저장된 값을 읽음 (없거나 형식이 맞지 않으면 `None`)
+pub fn load_json<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let raw = storage()?.get_item(key).ok()??;
+    serde_json::from_str(&raw).ok()
+}
+
+/// 값을 JSON 으로 직렬화하여 localStorage 에 저장 (실패는 무시)
+pub fn save_json<T: Serialize>(key: &str, value: &T) {
+    if let (Some(storage), Ok(raw)) = (storage(), serde_json::to_string(value)) {
+        let _ = storage.set_item(key, &raw);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod local_storage;
+pub mod session_storage;
+pub mod url_query;
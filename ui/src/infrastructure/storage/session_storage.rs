@@ -0,0 +1,29 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use web_sys::Storage;
+
+fn storage() -> Option<Storage> {
+    web_sys::window()?.session_storage().ok()?
+}
+
+/// sessionStorage 에 JSON 으로 저장된 값을 읽음 (없거나 형식이 맞지 않으면 `None`)
+///
+/// 탭을 닫으면 지워지므로 화면 복원처럼 그 탭에서만 의미 있는 값에 사용합니다.
+pub fn load_json<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let raw = storage()?.get_item(key).ok()??;
+    serde_json::from_str(&raw).ok()
+}
+
+/// 저장된 값 삭제
+pub fn remove(key: &str) {
+    if let Some(storage) = storage() {
+        let _ = storage.remove_item(key);
+    }
+}
+
+/// 값을 JSON 으로 직렬화하여 sessionStorage 에 저장 (용량 초과 등 실패는 무시)
+pub fn save_json<T: Serialize>(key: &str, value: &T) {
+    if let (Some(storage), Ok(raw)) = (storage(), serde_json::to_string(value)) {
+        let _ = storage.set_item(key, &raw);
+    }
+}
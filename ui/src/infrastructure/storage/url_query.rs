@@ -0,0 +1,68 @@
+use wasm_bindgen::JsValue;
+use web_sys::UrlSearchParams;
+
+/// 현재 주소의 쿼리 파라미터 (`?a=1&b=2`)
+pub fn current_query() -> Option<UrlSearchParams> {
+    let search = web_sys::window()?.location().search().ok()?;
+    UrlSearchParams::new_with_str(&search).ok()
+}
+
+/// 현재 주소의 경로 (`/photo/...`)
+pub fn current_path() -> String {
+    web_sys::window()
+        .and_then(|window| window.location().pathname().ok())
+        .unwrap_or_default()
+}
+
+/// 현재 주소의 쿼리 문자열 (`?` 포함, 없으면 빈 문자열)
+pub fn current_search() -> String {
+    web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .unwrap_or_default()
+}
+
+/// 히스토리 항목을 추가하며 주소 변경 (`popstate` 는 발생하지 않음)
+pub fn push_url(url: &str) {
+    if let Some(history) = web_sys::window().and_then(|window| window.history().ok()) {
+        let _ = history.push_state_with_url(&JsValue::NULL, "", Some(url));
+    }
+}
+
+/// 현재 히스토리 항목의 주소만 교체
+pub fn replace_url(url: &str) {
+    if let Some(history) = web_sys::window().and_then(|window| window.history().ok()) {
+        let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(url));
+    }
+}
+
+/// 브라우저 뒤로가기 (완료되면 `popstate` 발생)
+pub fn go_back() {
+    if let Some(history) = web_sys::window().and_then(|window| window.history().ok()) {
+        let _ = history.back();
+    }
+}
+
+/// 히스토리 항목을 추가하지 않고 주소의 쿼리만 교체 (경로와 `#hash` 는 유지)
+pub fn replace_query(params: &[(&str, String)]) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Ok(search) = UrlSearchParams::new() else {
+        return;
+    };
+    for (name, value) in params {
+        search.append(name, value);
+    }
+
+    let location = window.location();
+    let path = location.pathname().unwrap_or_default();
+    let hash = location.hash().unwrap_or_default();
+    let query: String = search.to_string().into();
+    let url = if query.is_empty() {
+        format!("{}{}", path, hash)
+    } else {
+        format!("{}?{}{}", path, query, hash)
+    };
+
+    replace_url(&url);
+}
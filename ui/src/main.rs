@@ -1,19 +1,48 @@
+use leptos::ev;
 use leptos::prelude::*;
 
+mod application;
 mod domain;
 mod infrastructure;
 mod presentation;
 
+use crate::presentation::hooks::use_event_listener::use_window_event_listener;
 use crate::presentation::layouts::main_layout::MainLayout;
+use crate::presentation::pages::cleanup::CleanupPage;
+use crate::presentation::pages::duplicates::DuplicatesPage;
 use crate::presentation::pages::gallery::GalleryPage;
+use crate::presentation::pages::map::MapPage;
+use crate::presentation::pages::people::PeoplePage;
+use crate::presentation::pages::places::PlacesPage;
+use crate::presentation::view_models::navigation_vm::{AppPage, NavigationViewModel};
+use crate::presentation::view_models::search_vm::SearchViewModel;
 
 fn main() {
     console_error_panic_hook::set_once();
 
     leptos::mount::mount_to_body(|| {
+        // 전역 상태 (헤더 검색창과 페이지가 공유)
+        let navigation = NavigationViewModel::new();
+        provide_context(navigation);
+        let search_vm = SearchViewModel::new(navigation);
+        provide_context(search_vm);
+
+        // 뒤로/앞으로가기로 바뀐 주소의 경로와 검색 조건 반영
+        use_window_event_listener(ev::popstate, move |_| {
+            navigation.sync_on_popstate();
+            search_vm.sync_on_popstate();
+        });
+
         view! {
             <MainLayout>
-                <GalleryPage />
+                {move || match navigation.page.get() {
+                    AppPage::Gallery => view! { <GalleryPage /> }.into_any(),
+                    AppPage::Map => view! { <MapPage /> }.into_any(),
+                    AppPage::Places => view! { <PlacesPage /> }.into_any(),
+                    AppPage::People => view! { <PeoplePage /> }.into_any(),
+                    AppPage::Duplicates => view! { <DuplicatesPage /> }.into_any(),
+                    AppPage::Cleanup => view! { <CleanupPage /> }.into_any(),
+                }}
             </MainLayout>
         }
     })
@@ -1 +1,2 @@
+pub mod nav_tabs;
 pub mod pull_to_refresh;
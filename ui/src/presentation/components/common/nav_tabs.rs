@@ -0,0 +1,28 @@
+use crate::presentation::view_models::navigation_vm::{AppPage, NavigationViewModel};
+use leptos::prelude::*;
+
+/// 헤더의 화면 전환 탭
+#[component]
+pub fn NavTabs() -> impl IntoView {
+    let navigation = use_context::<NavigationViewModel>().expect("NavigationViewModel required");
+
+    view! {
+        <nav class="flex items-center gap-1">
+            {AppPage::ALL.into_iter().map(|page| {
+                let is_current = move || navigation.page.get() == page;
+                view! {
+                    <button
+                        class="px-3 py-1 rounded-full text-sm transition"
+                        class=("bg-gray-900", is_current)
+                        class=("text-white", is_current)
+                        class=("text-gray-600", move || !is_current())
+                        class=("hover:bg-gray-100", move || !is_current())
+                        on:click=move |_| navigation.navigate(page)
+                    >
+                        {page.title()}
+                    </button>
+                }
+            }).collect_view()}
+        </nav>
+    }
+}
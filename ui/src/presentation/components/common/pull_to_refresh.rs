@@ -1,144 +1,231 @@
-use leptos::prelude::*;
+use crate::application::services::gesture_recognizer::{Axis, GestureEvent};
+use crate::infrastructure::browser::scroll_container::nearest_scroll_container;
+use crate::presentation::hooks::use_event_listener::{
+    use_event_listener, use_window_event_listener,
+};
+use crate::presentation::hooks::use_gesture::{use_gesture, GestureOptions};
+use crate::presentation::hooks::use_media_query::use_media_query;
+use futures::future::{abortable, AbortHandle};
+use gloo_timers::callback::Timeout;
 use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+
+/// 휠 입력이 이 시간(ms) 끊기면 한 번의 휠 동작이 끝난 것으로 봄
+const WHEEL_IDLE_MS: u32 = 200;
+/// 휠 delta 대비 당김 거리
+const WHEEL_RATIO: f64 = 0.5;
 
+/// 맨 위에서 아래로 당겨 새로고침
+///
+/// 터치/마우스/펜으로 끌어 당기고, 데스크톱에서는 맨 위에서 휠을 위로 더 굴리거나 새로고침 버튼을 누릅니다.
+/// 스크롤 위치는 가장 가까운 스크롤 영역에서 읽으며, 새로고침 중 표시기를 누르거나 Esc 로 취소합니다.
 #[component]
 pub fn PullToRefresh<F, Fut>(
     on_refresh: F,
+    /// 놓았을 때 새로고침하는 당김 거리 (px)
+    #[prop(default = 80.0)]
+    threshold: f64,
+    /// 최대 당김 거리 (px)
+    #[prop(default = 120.0)]
+    max_pull: f64,
+    /// 끈 거리 대비 당겨지는 비율
+    #[prop(default = 0.5)]
+    resistance: f64,
     children: Children,
 ) -> impl IntoView
 where
     F: Fn() -> Fut + 'static + Send + Sync + Clone,
     Fut: std::future::Future<Output = ()> + 'static,
 {
+    let on_refresh = StoredValue::new(on_refresh);
     let (pull_y, set_pull_y) = signal(0.0);
     let (is_refreshing, set_is_refreshing) = signal(false);
-    let (start_y, set_start_y) = signal::<Option<f64>>(None);
-    
-    // Threshold for triggering refresh
-    const THRESHOLD: f64 = 80.0;
-    const MAX_PULL: f64 = 120.0;
-
-    let on_touch_start = move |e: ev::TouchEvent| {
-        // Only start if scrollTop is 0 (at the top)
-        if let Some(window) = web_sys::window() {
-             if window.scroll_y().unwrap_or(0.0) <= 0.0 {
-                 if let Some(touch) = e.touches().item(0) {
-                    set_start_y.set(Some(touch.client_y() as f64));
-                }
-             }
-        }
-    };
+    let (is_pulling, set_is_pulling) = signal(false);
+    // 맨 위에서 누른 경우에만 당기기 시작
+    let is_armed = StoredValue::new(false);
+    let abort = StoredValue::new(None::<AbortHandle>);
 
-    let on_touch_move = move |e: ev::TouchEvent| {
-        if is_refreshing.get() {
+    // 가장 가까운 스크롤 영역이 맨 위인지 (스크롤할 때마다 갱신)
+    let root = NodeRef::<html::Div>::new();
+    let (is_at_top, set_is_at_top) = signal(true);
+    Effect::new(move |_| {
+        let Some(container) = root.get().and_then(|el| nearest_scroll_container(&el)) else {
             return;
-        }
+        };
+        set_is_at_top.set(container.scroll_top() <= 0);
+        use_event_listener(scroll_event_target(&container), ev::scroll, move |_| {
+            set_is_at_top.set(container.scroll_top() <= 0)
+        });
+    });
 
-        if let Some(start) = start_y.get() {
-            if let Some(touch) = e.touches().item(0) {
-                let current_y = touch.client_y() as f64;
-                let diff = current_y - start;
-
-                if diff > 0.0 {
-                    // Prevent default scrolling when pulling down
-                    // Note: This might interfere with normal scrolling if not handled carefully.
-                    // Usually we only prevent default if we are sure it's a pull action.
-                    // But prevent_default in passive listener is not allowed in modern browsers.
-                    // CSS 'overscroll-behavior-y: contain' on body helps.
-                    
-                    // Logarithmic resistance
-                    let resistance = 0.5;
-                    let move_y = (diff * resistance).min(MAX_PULL);
-                    set_pull_y.set(move_y);
-                }
-            }
-        }
+    let reset = move || {
+        is_armed.set_value(false);
+        set_is_pulling.set(false);
+        set_pull_y.set(0.0);
     };
 
-    let on_touch_end = move |_| {
-        if is_refreshing.get() {
+    let refresh = move || {
+        if is_refreshing.get_untracked() {
             return;
         }
-        
-        let current_pull = pull_y.get();
-        if current_pull >= THRESHOLD {
-            set_is_refreshing.set(true);
-            set_pull_y.set(THRESHOLD); // Snap to threshold position
-            
-            let on_refresh = on_refresh.clone();
-            
-            leptos::task::spawn_local(async move {
-                on_refresh().await;
+        set_is_refreshing.set(true);
+        set_pull_y.set(threshold); // Snap to threshold position
+
+        let (task, handle) = abortable(on_refresh.with_value(|f| f()));
+        abort.set_value(Some(handle));
+        leptos::task::spawn_local(async move {
+            if task.await.is_ok() {
                 // Wait a bit to show completion
                 gloo_timers::future::TimeoutFuture::new(500).await;
-                set_is_refreshing.set(false);
-                set_pull_y.set(0.0);
-                set_start_y.set(None);
-            });
+            }
+            abort.set_value(None);
+            set_is_refreshing.set(false);
+            reset();
+        });
+    };
+
+    let cancel = move || {
+        abort.with_value(|handle| {
+            if let Some(handle) = handle {
+                handle.abort();
+            }
+        });
+    };
+
+    let release = move || {
+        set_is_pulling.set(false);
+        if pull_y.get_untracked() >= threshold {
+            refresh();
         } else {
             // Cancel pull
-            set_pull_y.set(0.0);
-            set_start_y.set(None);
+            reset();
         }
     };
 
+    let gesture = use_gesture(GestureOptions::default(), move |event| match event {
+        GestureEvent::Press { .. } => {
+            is_armed.set_value(!is_refreshing.get_untracked() && is_at_top.get_untracked());
+        }
+        GestureEvent::Pan {
+            axis: Axis::Vertical,
+            offset,
+            ..
+        } if is_armed.get_value() => {
+            set_is_pulling.set(true);
+            set_pull_y.set((offset.1 * resistance).clamp(0.0, max_pull));
+        }
+        GestureEvent::PanEnd { .. } if is_armed.get_value() => release(),
+        GestureEvent::PinchStart { .. } | GestureEvent::Cancel
+            if !is_refreshing.get_untracked() =>
+        {
+            reset()
+        }
+        _ => {}
+    });
+
+    // 데스크톱: 맨 위에서 시작한 휠 동작이 위로 더 굴리면 당김으로 처리 (관성 스크롤로 맨 위에 닿은 경우 제외)
+    let last_wheel_at = StoredValue::new(0.0);
+    let wheel_timer = StoredValue::new_local(None::<Timeout>);
+    let on_wheel = move |e: ev::WheelEvent| {
+        if is_refreshing.get_untracked() {
+            return;
+        }
+        let now = js_sys::Date::now();
+        if now - last_wheel_at.get_value() > WHEEL_IDLE_MS as f64 {
+            is_armed.set_value(is_at_top.get_untracked());
+        }
+        last_wheel_at.set_value(now);
+        if !is_armed.get_value() {
+            return;
+        }
+        if e.delta_y() > 0.0 {
+            wheel_timer.set_value(None);
+            reset();
+            return;
+        }
+        set_is_pulling.set(true);
+        set_pull_y
+            .update(|y| *y = (*y - e.delta_y() * WHEEL_RATIO * resistance).clamp(0.0, max_pull));
+        wheel_timer.set_value(Some(Timeout::new(WHEEL_IDLE_MS, release)));
+    };
+
+    use_window_event_listener(ev::keydown, move |e| {
+        if e.key() == "Escape" && is_refreshing.get_untracked() {
+            cancel();
+        }
+    });
+    let has_fine_pointer = use_media_query("(pointer: fine)");
+
     view! {
-        <div 
+        <div
+            node_ref=root
             class="relative w-full h-full"
-            on:touchstart=on_touch_start
-            on:touchmove=on_touch_move
-            on:touchend=on_touch_end
+            // 맨 위에서는 아래로 당기는 동작을 브라우저 스크롤 대신 직접 처리
+            style:touch-action=move || if is_at_top.get() { "pan-x pan-down" } else { "auto" }
+            on:pointerdown=move |e| gesture.down.run(e)
+            on:pointermove=move |e| gesture.moved.run(e)
+            on:pointerup=move |e| gesture.up.run(e)
+            on:pointercancel=move |e| gesture.cancel.run(e)
+            on:wheel=on_wheel
         >
-            // Refresh Indicator
-            <div 
+            // 마우스 환경의 새로고침 버튼
+            <Show when=move || has_fine_pointer.get()>
+                <button
+                    class="absolute top-3 right-10 md:right-14 z-20 flex items-center justify-center w-8 h-8 rounded-full bg-white text-blue-600 shadow border border-gray-100 hover:bg-gray-50 disabled:opacity-50"
+                    title="Refresh"
+                    disabled=is_refreshing
+                    on:click=move |_| refresh()
+                >
+                    <RefreshIcon rotate=Signal::derive(|| 0) />
+                </button>
+            </Show>
+
+            // Refresh Indicator (새로고침 중에는 눌러서 취소)
+            <div
                 class="absolute left-0 right-0 flex justify-center items-center pointer-events-none z-20"
                 style=move || {
                     let y = pull_y.get();
                     // 당기는 거리에 따라 서서히 나타남 (임계값의 20% 지점부터 나타나기 시작)
-                    let opacity = ((y - 20.0) / (THRESHOLD - 20.0)).max(0.0).min(1.0);
-                    
+                    let opacity = ((y - 20.0) / (threshold - 20.0)).clamp(0.0, 1.0);
+
                     // 시작 위치를 약간 더 아래로 조정 (top: 10px)
                     format!(
-                        "top: 10px; transform: translateY({}px); opacity: {}; transition: transform {}ms, opacity 200ms;", 
+                        "top: 10px; transform: translateY({}px); opacity: {}; transition: transform {}ms, opacity 200ms;",
                         y - 50.0, // -50px 지점에서 시작하여 내려옴
                         opacity,
-                        if start_y.get().is_none() { 300 } else { 0 }
+                        if is_pulling.get() { 0 } else { 300 }
                     )
                 }
             >
-                <div class="bg-white rounded-full p-2 shadow-lg border border-gray-100">
-                     {move || {
+                <button
+                    class="relative bg-white rounded-full p-2 shadow-lg border border-gray-100"
+                    class=("pointer-events-auto", is_refreshing)
+                    title=move || if is_refreshing.get() { "Cancel refresh" } else { "" }
+                    on:click=move |_| cancel()
+                >
+                    {move || {
                         if is_refreshing.get() {
                             view! {
                                 <div class="animate-spin rounded-full h-6 w-6 border-b-2 border-blue-600"></div>
+                                <span class="absolute inset-0 flex items-center justify-center text-xs text-blue-600">"✕"</span>
                             }.into_any()
                         } else {
                             let rotate = (pull_y.get() * 3.0) as i32;
-                            view! {
-                                <svg 
-                                    xmlns="http://www.w3.org/2000/svg" 
-                                    fill="none" 
-                                    viewBox="0 0 24 24" 
-                                    stroke-width="2" 
-                                    stroke="currentColor" 
-                                    class="w-6 h-6 text-blue-600"
-                                    style=format!("transform: rotate({}deg)", rotate)
-                                >
-                                    <path stroke-linecap="round" stroke-linejoin="round" d="M16.023 9.348h4.992v-.001M2.985 19.644v-4.992m0 0h4.992m-4.993 0l3.181 3.183a8.25 8.25 0 0013.803-3.7M4.031 9.865a8.25 8.25 0 0113.803-3.7l3.181 3.182m0-4.991v4.99" />
-                                </svg>
-                            }.into_any()
+                            view! { <RefreshIcon rotate=Signal::derive(move || rotate) /> }.into_any()
                         }
-                     }}
-                </div>
+                    }}
+                </button>
             </div>
 
-            // Content
+            // Content (당기지 않을 때는 transform 을 없애 안쪽 fixed 요소가 화면 기준으로 배치되도록)
             <div
                 style=move || {
+                    let y = pull_y.get();
                     format!(
-                        "transform: translateY({}px); transition: transform {}ms;", 
-                        pull_y.get(),
-                        if start_y.get().is_none() { 300 } else { 0 }
+                        "transform: {}; transition: transform {}ms;",
+                        if y > 0.0 { format!("translateY({}px)", y) } else { "none".to_string() },
+                        if is_pulling.get() { 0 } else { 300 }
                     )
                 }
             >
@@ -147,3 +234,34 @@ where
         </div>
     }
 }
+
+#[component]
+fn RefreshIcon(rotate: Signal<i32>) -> impl IntoView {
+    view! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            fill="none"
+            viewBox="0 0 24 24"
+            stroke-width="2"
+            stroke="currentColor"
+            class="w-6 h-6 text-blue-600"
+            style=move || format!("transform: rotate({}deg)", rotate.get())
+        >
+            <path stroke-linecap="round" stroke-linejoin="round" d="M16.023 9.348h4.992v-.001M2.985 19.644v-4.992m0 0h4.992m-4.993 0l3.181 3.183a8.25 8.25 0 0013.803-3.7M4.031 9.865a8.25 8.25 0 0113.803-3.7l3.181 3.182m0-4.991v4.99" />
+        </svg>
+    }
+}
+
+/// 문서 전체가 스크롤되는 경우 scroll 이벤트는 요소가 아니라 window 에서 발생
+fn scroll_event_target(container: &web_sys::Element) -> web_sys::EventTarget {
+    let window = web_sys::window().expect("window");
+    let is_document = window
+        .document()
+        .and_then(|document| document.scrolling_element())
+        .is_some_and(|scrolling| &scrolling == container);
+    if is_document {
+        window.into()
+    } else {
+        container.clone().into()
+    }
+}
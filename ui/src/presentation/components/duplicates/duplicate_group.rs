@@ -0,0 +1,79 @@
+use crate::domain::value_objects::duplicate::{DuplicateGroup, DuplicatePhoto};
+use crate::presentation::view_models::duplicates_vm::DuplicatesViewModel;
+use leptos::prelude::*;
+
+/// 유사 중복 그룹 한 줄 (사본을 나란히 비교)
+///
+/// 사본마다 남기기/버리기를 고르고 "Trash" 로 선택한 사본을 휴지통으로 보냅니다.
+#[component]
+pub fn DuplicateGroupRow(group: DuplicateGroup, index: usize) -> impl IntoView {
+    let vm = use_context::<DuplicatesViewModel>().expect("DuplicatesViewModel required");
+    let marked_group = group.clone();
+    let marked_count = Memo::new(move |_| vm.state.with(|s| s.marked_in(&marked_group).len()));
+
+    view! {
+        <section class="p-3 md:p-4 rounded-xl border border-gray-200 bg-white">
+            <div class="flex gap-2 md:gap-4 overflow-x-auto">
+                {group
+                    .photos
+                    .into_iter()
+                    .enumerate()
+                    .map(|(position, photo)| view! { <DuplicateCard photo=photo index=index is_best=position == 0 /> })
+                    .collect_view()}
+            </div>
+            <div class="mt-3 flex items-center justify-end gap-2 text-sm">
+                <button
+                    class="px-3 py-1 rounded-full border border-gray-300 hover:bg-gray-100"
+                    on:click=move |_| vm.dismiss_group(index)
+                >
+                    "Not duplicates"
+                </button>
+                <button
+                    class="px-3 py-1 rounded-full bg-red-600 text-white disabled:opacity-40"
+                    disabled=move || marked_count.get() == 0 || vm.state.get().is_saving
+                    on:click=move |_| vm.resolve_group(index)
+                >
+                    {move || format!("Trash {}", marked_count.get())}
+                </button>
+            </div>
+        </section>
+    }
+}
+
+#[component]
+fn DuplicateCard(photo: DuplicatePhoto, index: usize, is_best: bool) -> impl IntoView {
+    let vm = use_context::<DuplicatesViewModel>().expect("DuplicatesViewModel required");
+    let photo_id = photo.photo.id;
+    let is_marked = move || vm.state.with(|s| s.is_marked(photo_id));
+
+    view! {
+        <div class="flex-none w-40 md:w-56">
+            <div
+                class="relative aspect-square rounded-lg overflow-hidden bg-gray-100 ring-offset-2"
+                class=("ring-4", is_marked)
+                class=("ring-red-500", is_marked)
+            >
+                <img src=photo.photo.preview_url.clone() class="w-full h-full object-contain" loading="lazy" />
+                {is_best.then(|| view! {
+                    <span class="absolute left-2 top-2 px-2 py-0.5 rounded-full bg-green-600 text-white text-xs">"Best"</span>
+                })}
+                <Show when=is_marked>
+                    <div class="absolute inset-0 flex items-center justify-center bg-red-500/30 text-2xl text-white">"🗑"</div>
+                </Show>
+            </div>
+            <div class="mt-1 flex items-center justify-between text-xs text-gray-500">
+                <span>{photo.resolution_label()}</span>
+                <span>{photo.size_label()}</span>
+            </div>
+            <button
+                class="mt-1 w-full px-2 py-1 rounded text-sm border"
+                class=("border-red-300", is_marked)
+                class=("text-red-600", is_marked)
+                class=("border-gray-300", move || !is_marked())
+                on:click=move |_| vm.toggle_mark(index, photo_id)
+            >
+                {move || if is_marked() { "Trash" } else { "Keep" }}
+            </button>
+        </div>
+    }
+}
@@ -0,0 +1 @@
+pub mod duplicate_group;
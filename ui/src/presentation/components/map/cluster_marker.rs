@@ -0,0 +1,44 @@
+use crate::domain::value_objects::geo_area::GeoCluster;
+use leptos::prelude::*;
+
+/// 클러스터 마커 (대표 사진 썸네일 + 사진 수)
+///
+/// `x`, `y` 는 지도 세계 픽셀 좌표이며 마커 중심이 그 위치에 놓입니다.
+#[component]
+pub fn ClusterMarker(
+    cluster: GeoCluster,
+    x: f64,
+    y: f64,
+    on_click: Callback<GeoCluster>,
+) -> impl IntoView {
+    let size = format!("{}px", marker_size(cluster.count));
+    let count = cluster.count;
+    let thumbnail_url = cluster.cover_thumbnail_url.clone();
+
+    view! {
+        <div
+            class="absolute -translate-x-1/2 -translate-y-1/2"
+            style:left=format!("{}px", x)
+            style:top=format!("{}px", y)
+        >
+            <button
+                class="block rounded-lg border-2 border-white shadow-lg overflow-hidden bg-gray-300 hover:scale-110 transition-transform"
+                style:width=size.clone()
+                style:height=size
+                // 마커를 누를 때는 지도 드래그를 시작하지 않음
+                on:pointerdown=|ev| ev.stop_propagation()
+                on:click=move |_| on_click.run(cluster.clone())
+            >
+                <img src=thumbnail_url class="w-full h-full object-cover" draggable="false" />
+            </button>
+            <span class="absolute -top-2 -right-2 min-w-5 px-1 rounded-full bg-blue-600 text-white text-xs text-center font-semibold pointer-events-none">
+                {count}
+            </span>
+        </div>
+    }
+}
+
+/// 사진이 많을수록 조금씩 커지는 마커 크기 (40 ~ 64px)
+fn marker_size(count: i64) -> f64 {
+    40.0 + (count.max(1) as f64).log2().min(6.0) * 4.0
+}
@@ -0,0 +1,2 @@
+pub mod cluster_marker;
+pub mod slippy_map;
@@ -0,0 +1,155 @@
+use crate::application::services::web_mercator::{project, TILE_SIZE};
+use crate::domain::value_objects::geo_area::GeoCluster;
+use crate::infrastructure::api::tile_source::tile_url;
+use crate::presentation::components::map::cluster_marker::ClusterMarker;
+use crate::presentation::hooks::use_resize_observer::use_resize_observer;
+use crate::presentation::view_models::map_vm::MapViewModel;
+use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+
+/// 휠 한 단계로 취급할 누적 스크롤 양 (트랙패드의 잦은 작은 이벤트 흡수)
+const WHEEL_STEP: f64 = 100.0;
+
+/// 타일 지도 + 클러스터 마커
+///
+/// 드래그로 이동, 휠/버튼으로 확대·축소하며, 이동이 끝날 때마다 `on_settle` 을 호출합니다.
+#[component]
+pub fn SlippyMap(on_settle: Callback<()>, on_cluster_click: Callback<GeoCluster>) -> impl IntoView {
+    let vm = use_context::<MapViewModel>().expect("MapViewModel required");
+    let container = NodeRef::<html::Div>::new();
+    let drag_from = StoredValue::new(None::<(f64, f64)>);
+    let dragged = StoredValue::new(false);
+    let wheel_delta = StoredValue::new(0.0);
+
+    // 화면 크기 측정 (마운트 시 + 크기가 바뀔 때마다)
+    let measure = move || {
+        if let Some(el) = container.get_untracked() {
+            vm.resize(el.client_width() as f64, el.client_height() as f64);
+            on_settle.run(());
+        }
+    };
+    Effect::new(move |_| {
+        if let Some(el) = container.get() {
+            use_resize_observer(&el, measure);
+        }
+    });
+
+    let on_pointer_down = move |ev: ev::PointerEvent| {
+        drag_from.set_value(Some((ev.client_x() as f64, ev.client_y() as f64)));
+        dragged.set_value(false);
+    };
+    let on_pointer_move = move |ev: ev::PointerEvent| {
+        let Some((x0, y0)) = drag_from.get_value() else {
+            return;
+        };
+        let (x, y) = (ev.client_x() as f64, ev.client_y() as f64);
+        vm.pan_by(x - x0, y - y0);
+        drag_from.set_value(Some((x, y)));
+        dragged.set_value(true);
+    };
+    let end_drag = move || {
+        drag_from.set_value(None);
+        if dragged.get_value() {
+            dragged.set_value(false);
+            on_settle.run(());
+        }
+    };
+
+    let on_wheel = move |ev: ev::WheelEvent| {
+        ev.prevent_default();
+        let delta = wheel_delta.get_value() + ev.delta_y();
+        if delta.abs() < WHEEL_STEP {
+            wheel_delta.set_value(delta);
+            return;
+        }
+        wheel_delta.set_value(0.0);
+        let anchor = container.get_untracked().map(|el| {
+            let rect = el.get_bounding_client_rect();
+            (ev.client_x() as f64 - rect.left(), ev.client_y() as f64 - rect.top())
+        });
+        vm.zoom_by(if delta < 0.0 { 1 } else { -1 }, anchor);
+        on_settle.run(());
+    };
+
+    // 세계 픽셀 좌표로 배치한 타일/마커 레이어를 화면 위치만큼 이동
+    let transform = move || {
+        let (left, top) = vm.state.with(|s| s.viewport.top_left());
+        format!("translate({}px, {}px)", -left, -top)
+    };
+
+    view! {
+        <div
+            node_ref=container
+            class="relative w-full h-full overflow-hidden bg-gray-200 touch-none select-none cursor-grab active:cursor-grabbing"
+            on:pointerdown=on_pointer_down
+            on:pointermove=on_pointer_move
+            on:pointerup=move |_| end_drag()
+            on:pointerleave=move |_| end_drag()
+            on:pointercancel=move |_| end_drag()
+            on:wheel=on_wheel
+        >
+            <div class="absolute left-0 top-0" style:transform=transform>
+                <TileLayer />
+                <ClusterLayer on_click=on_cluster_click />
+            </div>
+            <ZoomControls on_zoom=Callback::new(move |steps: i8| {
+                vm.zoom_by(steps, None);
+                on_settle.run(());
+            }) />
+        </div>
+    }
+}
+
+#[component]
+fn TileLayer() -> impl IntoView {
+    let vm = use_context::<MapViewModel>().expect("MapViewModel required");
+    let tiles = Memo::new(move |_| vm.state.with(|s| s.viewport.visible_tiles()));
+    let px = |value: i64| format!("{}px", value as f64 * TILE_SIZE);
+
+    view! {
+        <For each=move || tiles.get() key=|tile| *tile let:tile>
+            <img
+                src=tile_url(tile.z, tile.wrapped_x(), tile.y)
+                class="absolute max-w-none pointer-events-none"
+                style:left=px(tile.x)
+                style:top=px(tile.y)
+                style:width=format!("{}px", TILE_SIZE)
+                style:height=format!("{}px", TILE_SIZE)
+                draggable="false"
+            />
+        </For>
+    }
+}
+
+#[component]
+fn ClusterLayer(on_click: Callback<GeoCluster>) -> impl IntoView {
+    let vm = use_context::<MapViewModel>().expect("MapViewModel required");
+    let clusters = Memo::new(move |_| vm.state.with(|s| (s.clusters.clone(), s.viewport.zoom)));
+
+    move || {
+        let (clusters, zoom) = clusters.get();
+        clusters
+            .into_iter()
+            .map(|cluster| {
+                let (x, y) = project(cluster.center, zoom);
+                view! { <ClusterMarker cluster=cluster x=x y=y on_click=on_click /> }
+            })
+            .collect_view()
+    }
+}
+
+#[component]
+fn ZoomControls(on_zoom: Callback<i8>) -> impl IntoView {
+    let button_class = "w-9 h-9 bg-white text-lg text-gray-700 hover:bg-gray-100";
+
+    view! {
+        <div
+            class="absolute right-3 bottom-3 flex flex-col rounded-lg shadow-lg overflow-hidden divide-y divide-gray-200"
+            on:pointerdown=|ev| ev.stop_propagation()
+        >
+            <button class=button_class on:click=move |_| on_zoom.run(1)>"+"</button>
+            <button class=button_class on:click=move |_| on_zoom.run(-1)>"−"</button>
+        </div>
+    }
+}
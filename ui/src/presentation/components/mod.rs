@@ -1,2 +1,7 @@
 pub mod common;
+pub mod duplicates;
+pub mod map;
+pub mod people;
 pub mod photo;
+pub mod search;
+pub mod timeline;
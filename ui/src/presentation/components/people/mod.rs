@@ -0,0 +1,2 @@
+pub mod person_card;
+pub mod person_detail;
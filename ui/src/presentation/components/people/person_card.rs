@@ -0,0 +1,42 @@
+use crate::domain::value_objects::person::Person;
+use leptos::prelude::*;
+
+/// 대표 얼굴과 이름, 사진 수를 보여주는 인물 카드
+///
+/// 선택 모드에서는 누를 때마다 병합 대상으로 선택/해제됩니다.
+#[component]
+pub fn PersonCard(
+    person: Person,
+    #[prop(into)] is_selected: Signal<bool>,
+    #[prop(into)] is_open: Signal<bool>,
+    on_click: Callback<()>,
+) -> impl IntoView {
+    let name = person.display_name();
+    let is_unnamed = person.name.is_none();
+
+    view! {
+        <button
+            class="flex flex-col items-center gap-2 p-2 rounded-xl transition hover:bg-gray-100"
+            class=("bg-gray-100", move || is_open.get())
+            on:click=move |_| on_click.run(())
+        >
+            <div
+                class="relative w-20 h-20 md:w-24 md:h-24 rounded-full overflow-hidden bg-gray-200 ring-offset-2"
+                class=("ring-4", move || is_selected.get())
+                class=("ring-blue-500", move || is_selected.get())
+            >
+                {match person.cover_url {
+                    Some(url) => view! { <img src=url class="w-full h-full object-cover" loading="lazy" /> }.into_any(),
+                    None => view! { <div class="w-full h-full flex items-center justify-center text-3xl">"👤"</div> }.into_any(),
+                }}
+                <Show when=move || is_selected.get()>
+                    <div class="absolute inset-0 flex items-center justify-center bg-blue-500/40 text-2xl text-white">"✓"</div>
+                </Show>
+            </div>
+            <div class="w-full text-center">
+                <div class="text-sm font-medium truncate" class=("text-gray-400", is_unnamed)>{name}</div>
+                <div class="text-xs text-gray-500">{format!("{} photos", person.face_count)}</div>
+            </div>
+        </button>
+    }
+}
@@ -0,0 +1,94 @@
+use crate::domain::value_objects::person::{Person, PersonFace};
+use crate::presentation::view_models::people_vm::PeopleViewModel;
+use leptos::prelude::*;
+
+/// 펼친 인물의 이름 편집, 사진 보기, 얼굴 분리 패널
+#[component]
+pub fn PersonDetail(
+    person: Person,
+    on_show_photos: Callback<Person>,
+    on_close: Callback<()>,
+) -> impl IntoView {
+    let vm = use_context::<PeopleViewModel>().expect("PeopleViewModel required");
+    let (name, set_name) = signal(person.name.clone().unwrap_or_default());
+    let person_id = person.id;
+    let show_person = person.clone();
+
+    view! {
+        <section class="mb-6 p-4 rounded-xl border border-gray-200 bg-white shadow-sm">
+            <div class="flex flex-wrap items-center gap-2">
+                <input
+                    class="flex-1 min-w-40 px-3 py-2 rounded-lg border border-gray-300 text-sm"
+                    placeholder="Add a name"
+                    prop:value=name
+                    on:input=move |ev| set_name.set(event_target_value(&ev))
+                    on:keydown=move |ev| if ev.key() == "Enter" { vm.rename(person_id, name.get_untracked()) }
+                />
+                <button
+                    class="px-3 py-2 rounded-lg bg-gray-900 text-white text-sm disabled:opacity-50"
+                    disabled=move || vm.state.get().is_saving
+                    on:click=move |_| vm.rename(person_id, name.get_untracked())
+                >
+                    "Save name"
+                </button>
+                <button
+                    class="px-3 py-2 rounded-lg border border-gray-300 text-sm hover:bg-gray-50"
+                    on:click=move |_| on_show_photos.run(show_person.clone())
+                >
+                    "Show photos"
+                </button>
+                <button class="px-3 py-2 text-sm text-gray-500 hover:text-gray-800" on:click=move |_| on_close.run(())>
+                    "Close"
+                </button>
+            </div>
+            <SplitToolbar />
+            <div class="mt-3 grid grid-cols-5 sm:grid-cols-8 md:grid-cols-12 gap-2">
+                <For
+                    each=move || vm.state.get().faces
+                    key=|face| face.id
+                    children=move |face| view! { <FaceThumb face=face /> }
+                />
+            </div>
+        </section>
+    }
+}
+
+#[component]
+fn SplitToolbar() -> impl IntoView {
+    let vm = use_context::<PeopleViewModel>().expect("PeopleViewModel required");
+    let selected_count = move || vm.state.get().selected_faces.len();
+
+    view! {
+        <div class="mt-4 flex items-center justify-between text-sm text-gray-600">
+            <span>"Select faces that belong to someone else"</span>
+            <button
+                class="px-3 py-1 rounded-full bg-blue-600 text-white disabled:opacity-40"
+                disabled=move || selected_count() == 0 || vm.state.get().is_saving
+                on:click=move |_| vm.split_selected()
+            >
+                {move || format!("Move {} to new person", selected_count())}
+            </button>
+        </div>
+    }
+}
+
+#[component]
+fn FaceThumb(face: PersonFace) -> impl IntoView {
+    let vm = use_context::<PeopleViewModel>().expect("PeopleViewModel required");
+    let face_id = face.id;
+    let is_selected = move || vm.state.get().selected_faces.contains(&face_id);
+
+    view! {
+        <button
+            class="relative aspect-square rounded-lg overflow-hidden bg-gray-200 ring-offset-1"
+            class=("ring-2", is_selected)
+            class=("ring-blue-500", is_selected)
+            on:click=move |_| vm.toggle_face(face_id)
+        >
+            <img src=face.crop_url class="w-full h-full object-cover" loading="lazy" />
+            <Show when=is_selected>
+                <div class="absolute inset-0 bg-blue-500/40 flex items-center justify-center text-white">"✓"</div>
+            </Show>
+        </button>
+    }
+}
@@ -0,0 +1,271 @@
+use crate::application::services::face_geometry::{contain_rect, ContainRect};
+use crate::domain::value_objects::person::{FaceAssignment, FaceRect, PhotoFace};
+use crate::presentation::hooks::use_event_listener::use_window_event_listener;
+use crate::presentation::view_models::face_overlay_vm::{FaceEdit, FaceOverlayViewModel};
+use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+
+/// 미리보기 이미지 위의 얼굴 상자 + 직접 그리기 레이어
+///
+/// `object-contain` 으로 그려진 실제 이미지 영역에 맞춰 배치하며,
+/// 이미지 로드(`loaded` 변경)와 창 크기 변경 시 다시 측정합니다.
+#[component]
+pub fn FaceOverlay(
+    image: NodeRef<html::Img>,
+    natural_size: (u32, u32),
+    #[prop(into)] loaded: Signal<u32>,
+) -> impl IntoView {
+    let vm = use_context::<FaceOverlayViewModel>().expect("FaceOverlayViewModel required");
+    let layer = NodeRef::<html::Div>::new();
+    let (area, set_area) = signal(ContainRect::default());
+
+    let measure = move || {
+        let Some(img) = image.get_untracked() else {
+            return;
+        };
+        let rect = contain_rect(
+            img.client_width() as f64,
+            img.client_height() as f64,
+            natural_size.0 as f64,
+            natural_size.1 as f64,
+        );
+        set_area.set(ContainRect {
+            left: img.offset_left() as f64 + rect.left,
+            top: img.offset_top() as f64 + rect.top,
+            ..rect
+        });
+    };
+    Effect::new(move |_| {
+        loaded.track();
+        if image.get().is_some() {
+            measure();
+        }
+    });
+    use_window_event_listener(ev::resize, move |_| measure());
+
+    // 레이어 기준 0~1 좌표
+    let to_point = move |ev: &ev::PointerEvent| {
+        let rect = layer.get_untracked()?.get_bounding_client_rect();
+        if rect.width() <= 0.0 || rect.height() <= 0.0 {
+            return None;
+        }
+        Some((
+            (ev.client_x() as f64 - rect.left()) / rect.width(),
+            (ev.client_y() as f64 - rect.top()) / rect.height(),
+        ))
+    };
+    let on_pointer_down = move |ev: ev::PointerEvent| {
+        if !vm.state.with_untracked(|s| s.is_drawing) {
+            return;
+        }
+        if let Some(el) = layer.get_untracked() {
+            let _ = el.set_pointer_capture(ev.pointer_id());
+        }
+        if let Some(point) = to_point(&ev) {
+            vm.begin_stroke(point);
+        }
+    };
+    let on_pointer_move = move |ev: ev::PointerEvent| {
+        if let Some(point) = to_point(&ev) {
+            vm.move_stroke(point);
+        }
+    };
+
+    let is_drawing = move || vm.state.with(|s| s.is_drawing);
+
+    view! {
+        <div
+            node_ref=layer
+            class="absolute z-20 select-none"
+            class=("pointer-events-none", move || !is_drawing())
+            class=("cursor-crosshair", is_drawing)
+            class=("touch-none", is_drawing)
+            style:left=move || format!("{}px", area.get().left)
+            style:top=move || format!("{}px", area.get().top)
+            style:width=move || format!("{}px", area.get().width)
+            style:height=move || format!("{}px", area.get().height)
+            on:pointerdown=on_pointer_down
+            on:pointermove=on_pointer_move
+            on:pointerup=move |_| vm.end_stroke()
+            on:pointercancel=move |_| vm.end_stroke()
+            // 그리는 동안 모달의 좌우 스와이프가 동작하지 않도록
+            on:touchstart=move |ev| if is_drawing() { ev.stop_propagation() }
+            on:touchmove=move |ev| if is_drawing() { ev.stop_propagation() }
+            on:touchend=move |ev| if is_drawing() { ev.stop_propagation() }
+        >
+            <For
+                each=move || vm.state.get().faces
+                key=|face| (face.id, face.person.clone())
+                children=move |face| view! { <FaceBox face=face /> }
+            />
+            {move || vm.state.get().stroke_rect().map(|rect| view! {
+                <div class="absolute border-2 border-dashed border-blue-400 bg-blue-400/10" style=rect_style(&rect)></div>
+            })}
+            <FacePicker />
+        </div>
+    }
+}
+
+#[component]
+fn FaceBox(face: PhotoFace) -> impl IntoView {
+    let vm = use_context::<FaceOverlayViewModel>().expect("FaceOverlayViewModel required");
+    let face_id = face.id;
+    let is_editing = move || vm.state.with(|s| s.editing == Some(FaceEdit::Existing(face_id)));
+    let label = face
+        .person
+        .map(|person| person.name)
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    view! {
+        <button
+            class="absolute pointer-events-auto border-2 rounded-sm shadow-[0_0_0_1px_rgba(0,0,0,0.4)]"
+            class=("border-white/90", move || !is_editing())
+            class=("border-blue-400", is_editing)
+            class=("border-dashed", face.is_manual)
+            style=rect_style(&face.rectangle)
+            on:click=move |ev| {
+                ev.stop_propagation();
+                vm.edit(face_id);
+            }
+        >
+            <span class="absolute left-0 top-full mt-1 px-1.5 py-0.5 rounded bg-black/70 text-white text-xs whitespace-nowrap">
+                {label}
+            </span>
+        </button>
+    }
+}
+
+/// 편집 중인 얼굴 아래에 띄우는 인물 선택 팝오버
+#[component]
+fn FacePicker() -> impl IntoView {
+    let vm = use_context::<FaceOverlayViewModel>().expect("FaceOverlayViewModel required");
+    let (name, set_name) = signal(String::new());
+
+    // 편집 대상이 바뀔 때만 다시 그려 입력 중인 이름이 유지되도록
+    let target = Memo::new(move |_| {
+        vm.state.with(|s| match s.editing? {
+            FaceEdit::New(rect) => Some((rect, false)),
+            FaceEdit::Existing(_) => s.editing_face().map(|face| (face.rectangle, true)),
+        })
+    });
+    let create_person = move || {
+        let name = name.get_untracked().trim().to_string();
+        set_name.set(String::new());
+        vm.assign(FaceAssignment::NewPerson(Some(name).filter(|n| !n.is_empty())));
+    };
+
+    move || {
+        let (rect, is_existing) = target.get()?;
+        let style = format!(
+            "left: {}%; top: {}%;",
+            rect.x * 100.0,
+            (rect.y + rect.height) * 100.0
+        );
+
+        Some(view! {
+            <div
+                class="absolute z-30 mt-7 w-56 p-2 rounded-lg bg-white text-gray-800 text-sm shadow-xl pointer-events-auto"
+                style=style
+                on:pointerdown=|ev| ev.stop_propagation()
+                on:click=|ev| ev.stop_propagation()
+            >
+                <ul class="max-h-40 overflow-y-auto">
+                    <For
+                        each=move || vm.state.get().people
+                        key=|person| (person.id, person.name.clone())
+                        children=move |person| {
+                            let person_id = person.id;
+                            view! {
+                                <li>
+                                    <button
+                                        class="w-full px-2 py-1 rounded text-left hover:bg-gray-100 truncate"
+                                        on:click=move |_| vm.assign(FaceAssignment::Person(person_id))
+                                    >
+                                        {person.display_name()}
+                                    </button>
+                                </li>
+                            }
+                        }
+                    />
+                </ul>
+                <div class="mt-2 flex gap-1">
+                    <input
+                        class="flex-1 min-w-0 px-2 py-1 rounded border border-gray-300"
+                        placeholder="New person"
+                        prop:value=name
+                        on:input=move |ev| set_name.set(event_target_value(&ev))
+                        // 모달의 방향키/Esc 처리와 겹치지 않도록
+                        on:keydown=move |ev| {
+                            ev.stop_propagation();
+                            if ev.key() == "Enter" { create_person() }
+                        }
+                    />
+                    <button
+                        class="px-2 py-1 rounded bg-blue-600 text-white disabled:opacity-50"
+                        disabled=move || vm.state.get().is_saving
+                        on:click=move |_| create_person()
+                    >
+                        "Add"
+                    </button>
+                </div>
+                <div class="mt-2 flex justify-between">
+                    {is_existing.then(|| view! {
+                        <button class="px-2 py-1 text-red-600 hover:underline" on:click=move |_| vm.remove_editing()>
+                            "Not a face"
+                        </button>
+                    })}
+                    <button class="ml-auto px-2 py-1 text-gray-500 hover:text-gray-800" on:click=move |_| vm.cancel_edit()>
+                        "Cancel"
+                    </button>
+                </div>
+                {move || vm.state.get().error.map(|error| view! { <p class="mt-1 text-xs text-red-500">{error}</p> })}
+            </div>
+        })
+    }
+}
+
+/// 얼굴 표시 토글과 직접 그리기 버튼 (이미지 영역 왼쪽 위)
+#[component]
+pub fn FaceToolbar() -> impl IntoView {
+    let vm = use_context::<FaceOverlayViewModel>().expect("FaceOverlayViewModel required");
+    let button_class = "px-3 py-1.5 rounded-full text-sm backdrop-blur-sm border border-white/10";
+    let draw_class = "px-3 py-1.5 rounded-full text-sm text-white backdrop-blur-sm border border-white/10";
+    let is_visible = move || vm.state.with(|s| s.is_visible);
+    let is_drawing = move || vm.state.with(|s| s.is_drawing);
+
+    view! {
+        <div class="absolute top-[calc(1.5rem+env(safe-area-inset-top))] md:top-4 left-6 md:left-4 z-30 flex gap-2">
+            <button
+                class=button_class
+                class=("bg-white", is_visible)
+                class=("text-gray-900", is_visible)
+                class=("bg-black/50", move || !is_visible())
+                class=("text-white", move || !is_visible())
+                on:click=move |_| vm.toggle_visible()
+            >
+                "Faces"
+            </button>
+            <Show when=is_visible>
+                <button
+                    class=draw_class
+                    class=("bg-blue-600", is_drawing)
+                    class=("bg-black/50", move || !is_drawing())
+                    on:click=move |_| vm.toggle_drawing()
+                >
+                    {move || if is_drawing() { "Drag over a face" } else { "Add face" }}
+                </button>
+            </Show>
+        </div>
+    }
+}
+
+fn rect_style(rect: &FaceRect) -> String {
+    format!(
+        "left: {}%; top: {}%; width: {}%; height: {}%;",
+        rect.x * 100.0,
+        rect.y * 100.0,
+        rect.width * 100.0,
+        rect.height * 100.0
+    )
+}
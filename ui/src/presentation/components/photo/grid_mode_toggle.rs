@@ -0,0 +1,34 @@
+use crate::domain::value_objects::grid_mode::GridMode;
+use crate::presentation::view_models::grid_mode_vm::GridModeViewModel;
+use leptos::prelude::*;
+
+/// 헤더의 그리드 배치 방식 선택 (격자 / 행 맞춤 / 벽돌)
+#[component]
+pub fn GridModeToggle() -> impl IntoView {
+    let vm = use_context::<GridModeViewModel>().expect("GridModeViewModel required");
+
+    view! {
+        <div class="flex items-center rounded-lg border border-gray-200 p-0.5" role="group" aria-label="Layout">
+            {GridMode::ALL
+                .into_iter()
+                .map(|mode| {
+                    let is_selected = move || vm.mode.get() == mode;
+                    view! {
+                        <button
+                            class="px-2 py-1 rounded-md text-sm leading-none transition"
+                            class=("bg-gray-800", is_selected)
+                            class=("text-white", is_selected)
+                            class=("text-gray-500", move || !is_selected())
+                            class=("hover:bg-gray-100", move || !is_selected())
+                            title=mode.label()
+                            aria-pressed=move || is_selected().to_string()
+                            on:click=move |_| vm.select(mode)
+                        >
+                            {mode.icon()}
+                        </button>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
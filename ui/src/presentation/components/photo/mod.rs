@@ -1,2 +1,8 @@
+pub mod face_overlay;
+pub mod grid_mode_toggle;
 pub mod photo_card;
 pub mod photo_preview_modal;
+pub mod selection_toolbar;
+pub mod similar_photos;
+pub mod virtual_photo_grid;
+pub mod zoom_area;
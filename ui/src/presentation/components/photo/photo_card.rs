@@ -1,27 +1,94 @@
-use crate::domain::entities::photo::Photo;
-use crate::presentation::view_models::gallery_vm::GalleryViewModel;
+use crate::application::services::gesture_recognizer::GestureEvent;
+use crate::presentation::hooks::use_gesture::{use_gesture, GestureOptions};
+use crate::presentation::view_models::gallery_vm::{GalleryViewModel, PhotoItem};
+use leptos::ev;
 use leptos::prelude::*;
 
+/// 그리드의 사진 카드 (배치된 타일 크기를 채움, 좋아요 표시만 사진별 signal 로 갱신)
+///
+/// Shift+클릭은 범위 선택, Ctrl/Cmd+클릭 또는 선택 모드의 클릭은 선택 토글,
+/// 터치에서는 길게 눌러 선택 모드를 시작합니다.
 #[component]
-pub fn PhotoCard(photo: Photo) -> impl IntoView {
+pub fn PhotoCard(item: PhotoItem) -> impl IntoView {
     let vm = use_context::<GalleryViewModel>().expect("GalleryViewModel required");
 
-    let pid = photo.id;
-    let is_liked = photo.is_liked;
-    let photo_for_click = photo.clone();
+    let pid = item.id();
+    let is_liked = item.is_liked.clone();
+    let photo = item.photo.clone();
+    let is_selected = Memo::new(move |_| vm.is_selected(pid));
+
+    // 터치 길게 누르기 (움직이면 스크롤로 보고 취소, 탭은 click 으로 처리)
+    let is_pressing = StoredValue::new(false);
+    let (long_pressed, set_long_pressed) = signal(false);
+    let gesture = use_gesture(GestureOptions::default(), move |event| match event {
+        GestureEvent::Press { .. } => {
+            is_pressing.set_value(true);
+            set_long_pressed.set(false);
+        }
+        GestureEvent::LongPress { .. } => {
+            set_long_pressed.set(true);
+            vm.long_press(pid);
+        }
+        GestureEvent::PanStart { .. } | GestureEvent::Cancel => is_pressing.set_value(false),
+        _ => {}
+    });
+    let on_pointer_down = move |e: ev::PointerEvent| {
+        if e.pointer_type() == "touch" {
+            gesture.down.run(e);
+        }
+    };
+    let on_pointer_up = move |e: ev::PointerEvent| {
+        is_pressing.set_value(false);
+        gesture.up.run(e);
+    };
+    let on_pointer_cancel = move |e: ev::PointerEvent| {
+        is_pressing.set_value(false);
+        gesture.cancel.run(e);
+    };
+
+    let on_click = move |e: ev::MouseEvent| {
+        // 길게 누른 뒤 따라오는 click 은 무시
+        if long_pressed.get_untracked() {
+            set_long_pressed.set(false);
+            return;
+        }
+        if e.shift_key() {
+            vm.select_range(pid);
+        } else if e.ctrl_key() || e.meta_key() || vm.selection.with_untracked(|s| !s.is_empty()) {
+            vm.toggle_selected(pid);
+        } else {
+            vm.select_photo(item.snapshot());
+        }
+    };
 
     view! {
-        <div 
-            class="relative group rounded-lg overflow-hidden shadow-lg bg-gray-800 cursor-pointer"
-            style="content-visibility: auto;"
-            on:click=move |_| vm.select_photo(photo_for_click.clone())
+        <div
+            class="relative group w-full h-full rounded-lg overflow-hidden shadow-lg bg-gray-800 cursor-pointer select-none"
+            class=("ring-4", is_selected)
+            class=("ring-blue-500", is_selected)
+            style="content-visibility: auto; -webkit-touch-callout: none;"
+            aria-selected=move || is_selected.get().to_string()
+            on:click=on_click
+            on:pointerdown=on_pointer_down
+            on:pointermove=move |e| gesture.moved.run(e)
+            on:pointerup=on_pointer_up
+            on:pointercancel=on_pointer_cancel
+            on:pointerleave=on_pointer_cancel
+            on:contextmenu=move |e| {
+                if long_pressed.get_untracked() || is_pressing.get_value() {
+                    e.prevent_default();
+                }
+            }
         >
-            <img 
-                src=photo.url 
+            <img
+                src=photo.url
                 loading="lazy"
                 decoding="async"
-                class="w-full h-48 object-cover transition-transform duration-300 group-hover:scale-105" 
+                draggable="false"
+                class="w-full h-full object-cover transition-transform duration-300 group-hover:scale-105"
+                class=("scale-95", is_selected)
             />
+            <SelectionMark is_selected=is_selected />
             <div class="absolute bottom-0 left-0 right-0 p-3 bg-gradient-to-t from-black/90 via-black/50 to-transparent pt-8">
                 <p class="text-white text-sm font-medium truncate">{photo.title}</p>
                 <button
@@ -31,10 +98,35 @@ pub fn PhotoCard(photo: Photo) -> impl IntoView {
                     }
                     class="mt-1 flex items-center gap-1 text-xs text-gray-300 hover:text-red-400 p-1 -ml-1 rounded transition-colors active:scale-95"
                 >
-                    {if is_liked { "❤️" } else { "🤍" }}
-                    <span>{if is_liked { "Liked" } else { "Like" }}</span>
+                    {move || {
+                        let liked = is_liked.get();
+                        view! {
+                            {if liked { "❤️" } else { "🤍" }}
+                            <span>{if liked { "Liked" } else { "Like" }}</span>
+                        }
+                    }}
                 </button>
             </div>
         </div>
     }
 }
+
+/// 왼쪽 위 선택 표시 (선택 모드이거나 마우스를 올렸을 때만 보임)
+#[component]
+fn SelectionMark(is_selected: Memo<bool>) -> impl IntoView {
+    let vm = use_context::<GalleryViewModel>().expect("GalleryViewModel required");
+    let is_visible = move || is_selected.get() || vm.is_selecting();
+
+    view! {
+        <span
+            class="absolute top-2 left-2 flex h-6 w-6 items-center justify-center rounded-full border-2 border-white text-xs text-white shadow transition-opacity"
+            class=("bg-blue-500", is_selected)
+            class=("bg-black/30", move || !is_selected.get())
+            class=("opacity-0", move || !is_visible())
+            class=("group-hover:opacity-100", move || !is_visible())
+            aria-hidden="true"
+        >
+            {move || is_selected.get().then_some("✓")}
+        </span>
+    }
+}
@@ -1,67 +1,113 @@
+use crate::application::services::gesture_recognizer::{Axis, GestureEvent, SwipeDirection};
+use crate::application::services::swipe_gesture::{backdrop_opacity, page_direction, should_dismiss};
+use crate::domain::value_objects::quality_filter::QualityField;
+use crate::presentation::components::photo::face_overlay::{FaceOverlay, FaceToolbar};
+use crate::presentation::components::photo::similar_photos::SimilarPhotos;
+use crate::presentation::components::photo::zoom_area::ZoomArea;
+use crate::presentation::hooks::use_event_listener::use_window_event_listener;
+use crate::presentation::hooks::use_gesture::{use_gesture, GestureOptions};
+use crate::presentation::view_models::face_overlay_vm::FaceOverlayViewModel;
 use crate::presentation::view_models::gallery_vm::GalleryViewModel;
+use crate::presentation::view_models::preview_zoom_vm::PreviewZoomViewModel;
+use crate::presentation::view_models::search_vm::SearchViewModel;
+use crate::presentation::view_models::similar_photos_vm::SimilarPhotosViewModel;
+use leptos::ev;
+use leptos::html;
 use leptos::portal::Portal;
 use leptos::prelude::*;
-use wasm_bindgen::prelude::*;
 
 #[component]
 pub fn PhotoPreviewModal(
     on_close: Callback<()>
 ) -> impl IntoView {
     let vm = use_context::<GalleryViewModel>().expect("GalleryViewModel required");
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
 
     // 현재 선택된 사진을 반응형으로 구독
     let photo_memo = Memo::new(move |_| {
-        vm.state.get().selected_photo
+        vm.selected_photo.get()
     });
 
-    // Swipe 제스처 상태
-    let (drag_x, set_drag_x) = signal(0.0);
-    let (start_x, set_start_x) = signal::<Option<f64>>(None);
-    let (is_animating, set_is_animating) = signal(false);
-
-    // 터치 시작
-    let on_touch_start = move |e: web_sys::TouchEvent| {
-        if let Some(touch) = e.touches().item(0) {
-            set_start_x.set(Some(touch.client_x() as f64));
-            set_is_animating.set(false); // 드래그 중에는 애니메이션 끄기 (즉각 반응)
+    // 얼굴 표시/태깅 상태 (사진이 바뀌면 해당 사진의 얼굴로 교체)
+    let face_vm = FaceOverlayViewModel::new();
+    provide_context(face_vm);
+    Effect::new(move |_| {
+        if let Some(photo) = photo_memo.get() {
+            face_vm.show_photo(photo.id);
         }
-    };
+    });
 
-    // 터치 이동
-    let on_touch_move = move |e: web_sys::TouchEvent| {
-        if let Some(start) = start_x.get() {
-            if let Some(touch) = e.touches().item(0) {
-                let current_x = touch.client_x() as f64;
-                let diff = current_x - start;
-                set_drag_x.set(diff);
-            }
-        }
-    };
+    // 비슷한 사진 목록 (사진이 바뀌면 비움, 썸네일을 누르면 그 사진으로 이동)
+    let similar_vm = SimilarPhotosViewModel::new();
+    provide_context(similar_vm);
+    Effect::new(move |_| {
+        photo_memo.track();
+        similar_vm.reset();
+    });
+    let on_similar_select = Callback::new(move |photo| vm.replace_preview(photo));
 
-    // 터치 종료
-    let on_touch_end = move |_| {
-        if let Some(_) = start_x.get() {
-            let diff = drag_x.get();
-            let threshold = 50.0; // 스와이프 민감도
+    // 확대 상태 (사진이 바뀌면 1배로, 확대 중에는 스와이프로 넘기지 않음)
+    let zoom_vm = PreviewZoomViewModel::new();
+    provide_context(zoom_vm);
+    Effect::new(move |_| {
+        photo_memo.track();
+        zoom_vm.reset();
+    });
+    let is_face_visible = Signal::derive(move || face_vm.state.with(|s| s.is_visible));
+    let image_ref = NodeRef::<html::Img>::new();
+    let (image_loads, set_image_loads) = signal(0u32);
 
-            if diff.abs() > threshold {
-                // 임계값을 넘으면 페이지 이동
-                if diff > 0.0 {
-                    vm.prev_photo();
-                } else {
-                    leptos::task::spawn_local(async move {
-                        vm.next_photo().await;
-                    });
+    // Swipe 제스처 상태 (처음 몇 px 움직인 방향으로 좌우 넘기기 / 아래로 닫기를 고정)
+    let (drag_x, set_drag_x) = signal(0.0);
+    let (drag_y, set_drag_y) = signal(0.0);
+    let (is_animating, set_is_animating) = signal(false);
+    // 놓기 직전 빠르게 튕긴 방향
+    let swipe = StoredValue::new(None::<SwipeDirection>);
+    let viewport_height = || web_sys::window()
+        .and_then(|w| w.inner_height().ok())
+        .and_then(|h| h.as_f64())
+        .unwrap_or(0.0);
+    let reset_drag = move || {
+        set_is_animating.set(true); // 복귀 시 애니메이션 켜기 (부드럽게)
+        set_drag_x.set(0.0);
+        set_drag_y.set(0.0);
+        swipe.set_value(None);
+    };
+
+    // 놓았을 때: 좌우는 이전/다음 사진, 아래는 닫기
+    let finish_drag = move |axis: Axis, offset: (f64, f64)| {
+        let flung = swipe.get_value();
+        reset_drag();
+        match axis {
+            Axis::Horizontal => match page_direction(offset.0, flung) {
+                Some(-1) => vm.prev_photo(),
+                Some(_) => leptos::task::spawn_local(async move {
+                    vm.next_photo().await;
+                }),
+                None => {}
+            },
+            Axis::Vertical => {
+                if should_dismiss(offset.1, flung, viewport_height()) {
+                    on_close.run(());
                 }
             }
-            
-            // 상태 초기화
-            set_is_animating.set(true); // 복귀 시 애니메이션 켜기 (부드럽게)
-            set_drag_x.set(0.0);
-            set_start_x.set(None);
         }
     };
 
+    let gesture = use_gesture(GestureOptions::default(), move |event| match event {
+        GestureEvent::PinchStart { .. } | GestureEvent::Cancel => reset_drag(),
+        // 확대 중 이동은 이미지 영역이 처리
+        _ if zoom_vm.is_zoomed_untracked() => {}
+        // 드래그 중에는 애니메이션 끄기 (즉각 반응)
+        GestureEvent::PanStart { .. } => set_is_animating.set(false),
+        GestureEvent::Pan { axis: Axis::Horizontal, offset, .. } => set_drag_x.set(offset.0),
+        // 위로는 끌리지 않음
+        GestureEvent::Pan { axis: Axis::Vertical, offset, .. } => set_drag_y.set(offset.1.max(0.0)),
+        GestureEvent::Swipe { direction, .. } => swipe.set_value(Some(direction)),
+        GestureEvent::PanEnd { axis, offset, .. } => finish_drag(axis, offset),
+        _ => {}
+    });
+
     // 닫기 이벤트 핸들러
     let on_backdrop_click = move |_| on_close.run(());
 
@@ -71,31 +117,15 @@ pub fn PhotoPreviewModal(
     };
 
     // 키보드 이벤트 핸들러
-    Effect::new(move |_| {
-        let handle_keydown = move |e: web_sys::KeyboardEvent| {
-            match e.key().as_str() {
-                "ArrowLeft" => vm.prev_photo(),
-                "ArrowRight" => {
-                    leptos::task::spawn_local(async move {
-                        vm.next_photo().await;
-                    });
-                },
-                "Escape" => on_close.run(()),
-                _ => {}
-            }
-        };
-
-        let closure = Closure::wrap(Box::new(handle_keydown) as Box<dyn FnMut(web_sys::KeyboardEvent)>);
-        
-        if let Some(window) = web_sys::window() {
-            let _ = window.add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref());
-        }
-
-        move || {
-            if let Some(window) = web_sys::window() {
-                let _ = window.remove_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref());
-            }
+    use_window_event_listener(ev::keydown, move |e| match e.key().as_str() {
+        "ArrowLeft" => vm.prev_photo(),
+        "ArrowRight" => {
+            leptos::task::spawn_local(async move {
+                vm.next_photo().await;
+            });
         }
+        "Escape" => on_close.run(()),
+        _ => {}
     });
 
     view! {
@@ -104,13 +134,30 @@ pub fn PhotoPreviewModal(
                 // photo_memo가 Some일 때만 렌더링
                 if let Some(photo) = photo_memo.get() {
                     let photo_url = photo.preview_url.clone();
+                    let original_url = photo.original_url.clone();
+                    let zoom_original_url = photo.original_url.clone();
                     let photo_title = photo.title.clone();
+                    let photo_uuid = photo.id;
                     let photo_id = photo.id.to_string();
+                    // 확대 영역 안쪽(모바일 정보 오버레이)에서 쓰는 사본
+                    let zoom_title = photo_title.clone();
+                    let zoom_photo_id = photo_id.clone();
+                    let natural_size = (photo.width, photo.height);
+                    let dominant_colors = photo.dominant_colors.clone();
+                    let quality_scores: Vec<(QualityField, f32)> = QualityField::ALL
+                        .into_iter()
+                        .filter_map(|field| field.score(&photo).map(|score| (field, score)))
+                        .collect();
                     
                     view! {
                         // Backdrop (Fixed overlay)
                         <div 
-                            class="fixed inset-0 z-50 flex items-center justify-center bg-black/90 p-0 md:p-4 transition-opacity duration-300"
+                            class="fixed inset-0 z-50 flex items-center justify-center p-0 md:p-4 transition-opacity duration-300"
+                            // 아래로 끄는 거리만큼 배경이 옅어짐
+                            style:background-color=move || format!(
+                                "rgba(0, 0, 0, {})",
+                                0.9 * backdrop_opacity(drag_y.get(), viewport_height())
+                            )
                             on:click=on_backdrop_click
                         >
                             // Navigation Buttons (Left) - Hidden on Mobile
@@ -145,13 +192,20 @@ pub fn PhotoPreviewModal(
                             <div 
                                 class="relative w-full h-full md:w-full md:max-w-6xl md:h-[90vh] bg-black md:bg-gray-900 md:rounded-lg shadow-2xl overflow-hidden flex flex-col md:flex-row"
                                 on:click=on_content_click
-                                on:touchstart=on_touch_start
-                                on:touchmove=on_touch_move
-                                on:touchend=on_touch_end
+                                on:pointerdown=move |e| {
+                                    // 얼굴 상자를 그리는 동안은 넘기기/닫기 제스처를 쓰지 않음
+                                    if !is_face_visible.get_untracked() {
+                                        gesture.down.run(e)
+                                    }
+                                }
+                                on:pointermove=move |e| gesture.moved.run(e)
+                                on:pointerup=move |e| gesture.up.run(e)
+                                on:pointercancel=move |e| gesture.cancel.run(e)
                                 style=move || {
                                     format!(
-                                        "transform: translateX({}px); transition: {}", 
+                                        "transform: translate({}px, {}px); transition: {}",
                                         drag_x.get(),
+                                        drag_y.get(),
                                         if is_animating.get() { "transform 0.3s ease-out" } else { "none" }
                                     )
                                 }
@@ -166,31 +220,42 @@ pub fn PhotoPreviewModal(
                                     </svg>
                                 </button>
 
-                                // Image Section
-                                <div class="flex-1 bg-black flex items-center justify-center overflow-hidden relative min-h-[50vh] min-w-[50vw] p-0 md:p-4">
+                                // Image Section (핀치/휠/두 번 탭 확대)
+                                <ZoomArea image=image_ref original_url=zoom_original_url disabled=is_face_visible>
                                     // Loading Spinner (Always behind, visible when image is loading or hidden)
                                     <div class="absolute inset-0 flex items-center justify-center z-0">
                                         <div class="animate-spin rounded-full h-12 w-12 border-4 border-gray-700 border-t-white"></div>
                                     </div>
 
+                                    <FaceToolbar />
+
                                     <img 
-                                        src=photo_url
-                                        alt=photo_title.clone()
+                                        node_ref=image_ref
+                                        on:load=move |_| set_image_loads.update(|n| *n += 1)
+                                        src=move || if zoom_vm.use_original.get() { original_url.clone() } else { photo_url.clone() }
+                                        alt=zoom_title.clone()
                                         class=move || format!(
                                             "max-w-full max-h-full object-contain block z-10 relative transition-opacity duration-300 {}",
-                                            if vm.state.get().is_loading { "opacity-0" } else { "opacity-100" }
+                                            if vm.status.with(|s| s.is_loading) { "opacity-0" } else { "opacity-100" }
                                         )
-                                        style="min-height: 200px; min-width: 200px;" 
+                                        style="min-height: 200px; min-width: 200px;"
+                                        style:transform=move || zoom_vm.transform.with(|t| format!("translate({}px, {}px) scale({})", t.x, t.y, t.scale))
+                                        style:transition=move || if zoom_vm.is_animated.get() { "transform 0.25s ease-out" } else { "none" }
+                                        draggable="false"
                                     />
+
+                                    <Show when=move || is_face_visible.get() && !zoom_vm.is_zoomed()>
+                                        <FaceOverlay image=image_ref natural_size=natural_size loaded=image_loads />
+                                    </Show>
                                     
                                     // Mobile Image Info Overlay (Bottom Gradient)
                                     
                                     // Mobile Image Info Overlay (Bottom Gradient)
                                     <div class="md:hidden absolute bottom-0 left-0 right-0 p-6 pb-[calc(2.5rem+env(safe-area-inset-bottom))] bg-gradient-to-t from-black/90 via-black/60 to-transparent text-white pt-24 pointer-events-none">
-                                        <h2 class="text-xl font-bold truncate leading-tight">{photo_title.clone()}</h2>
-                                        <p class="text-xs text-gray-300 mt-1 font-mono opacity-80">{photo_id.clone()}</p>
+                                        <h2 class="text-xl font-bold truncate leading-tight">{zoom_title}</h2>
+                                        <p class="text-xs text-gray-300 mt-1 font-mono opacity-80">{zoom_photo_id}</p>
                                     </div>
-                                </div>
+                                </ZoomArea>
 
                                 // Desktop Details Sidebar (Hidden on Mobile)
                                 <div class="hidden md:flex w-80 p-6 flex-col border-l border-gray-800 text-gray-200 bg-gray-900">
@@ -211,6 +276,60 @@ pub fn PhotoPreviewModal(
                                             <span class="text-xs font-semibold text-gray-500 uppercase tracking-wider">"Photo ID"</span>
                                             <p class="font-mono text-sm break-all">{photo_id}</p>
                                         </div>
+
+                                        // 대표 색 (누르면 같은 색이 있는 사진 검색)
+                                        {(!dominant_colors.is_empty()).then(|| view! {
+                                            <div class="space-y-2">
+                                                <span class="text-xs font-semibold text-gray-500 uppercase tracking-wider">"Colors"</span>
+                                                <div class="flex flex-wrap gap-2">
+                                                    {dominant_colors.into_iter().map(|color| {
+                                                        let search_color = color.clone();
+                                                        view! {
+                                                            <button
+                                                                class="flex flex-col items-center gap-1 text-[10px] font-mono text-gray-400 hover:text-white"
+                                                                title="Find photos with this color"
+                                                                on:click=move |_| {
+                                                                    let color = search_color.clone();
+                                                                    search_vm.update_active(|f| f.color = Some(color));
+                                                                    on_close.run(());
+                                                                }
+                                                            >
+                                                                <span class="w-8 h-8 rounded-md border border-white/10" style:background-color=color.clone()></span>
+                                                                {color}
+                                                            </button>
+                                                        }
+                                                    }).collect_view()}
+                                                </div>
+                                            </div>
+                                        })}
+
+                                        // 화질 점수 (기준보다 낮으면 빨간색)
+                                        {(!quality_scores.is_empty()).then(|| view! {
+                                            <div class="space-y-2">
+                                                <span class="text-xs font-semibold text-gray-500 uppercase tracking-wider">"Quality"</span>
+                                                {quality_scores.into_iter().map(|(field, score)| {
+                                                    let is_low = f64::from(score) < field.threshold();
+                                                    view! {
+                                                        <div class="text-sm">
+                                                            <div class="flex justify-between text-gray-400">
+                                                                <span>{field.title()}</span>
+                                                                <span class=("text-red-400", is_low)>{format!("{:.0}%", score * 100.0)}</span>
+                                                            </div>
+                                                            <div class="mt-1 h-1.5 rounded-full bg-gray-700 overflow-hidden">
+                                                                <div
+                                                                    class="h-full rounded-full"
+                                                                    class=("bg-green-500", !is_low)
+                                                                    class=("bg-red-500", is_low)
+                                                                    style:width=format!("{}%", score * 100.0)
+                                                                ></div>
+                                                            </div>
+                                                        </div>
+                                                    }
+                                                }).collect_view()}
+                                            </div>
+                                        })}
+
+                                        <SimilarPhotos photo_id=photo_uuid on_select=on_similar_select />
                                         
                                         <div class="p-4 bg-gray-800 rounded-lg border border-gray-700">
                                             <h3 class="text-sm font-semibold text-gray-300 mb-2">"Information"</h3>
@@ -236,9 +355,10 @@ pub fn PhotoPreviewModal(
                         </div>
                     }.into_any()
                 } else {
-                    view! {}.into_any()
+                    ().into_any()
                 }
             }}
         </Portal>
     }
 }
+
@@ -0,0 +1,226 @@
+use crate::presentation::hooks::use_event_listener::use_window_event_listener;
+use crate::presentation::view_models::bulk_actions_vm::{BulkActionsViewModel, BulkPanel};
+use crate::presentation::view_models::gallery_vm::GalleryViewModel;
+use leptos::ev;
+use leptos::prelude::*;
+
+/// 선택 모드의 하단 도구 모음 (선택 수, 일괄 작업 버튼, 앨범/태그 입력 패널)
+#[component]
+pub fn SelectionToolbar() -> impl IntoView {
+    let gallery = use_context::<GalleryViewModel>().expect("GalleryViewModel required");
+    let vm = use_context::<BulkActionsViewModel>().expect("BulkActionsViewModel required");
+
+    let is_working = move || vm.state.with(|s| s.is_working);
+    let all_liked = Memo::new(move |_| gallery.all_selected_liked());
+
+    // 선택 중에는 Esc 로 선택 해제
+    use_window_event_listener(ev::keydown, move |e| {
+        if e.key() == "Escape" && gallery.is_selecting() {
+            gallery.clear_selection();
+        }
+    });
+
+    let on_trash = move |_| {
+        let count = gallery.selection.with_untracked(|s| s.len());
+        let message = format!("Move {} photos to trash?", count);
+        let confirmed = web_sys::window()
+            .and_then(|w| w.confirm_with_message(&message).ok())
+            .unwrap_or(false);
+        if confirmed {
+            vm.trash_selected();
+        }
+    };
+
+    view! {
+        <Show when=move || gallery.is_selecting()>
+            <div class="fixed inset-x-0 bottom-0 z-40 px-2 pb-[env(safe-area-inset-bottom)]">
+                <div class="mx-auto mb-2 max-w-3xl rounded-xl bg-gray-900 text-white shadow-2xl">
+                    <BulkPanelView />
+                    <StatusLine />
+                    <div class="flex items-center gap-1 p-2 overflow-x-auto">
+                        <button
+                            class="px-2 py-1 rounded-md hover:bg-gray-700"
+                            title="Clear selection"
+                            on:click=move |_| gallery.clear_selection()
+                        >
+                            "✕"
+                        </button>
+                        <span class="px-2 text-sm whitespace-nowrap">
+                            {move || format!("{} selected", gallery.selection.with(|s| s.len()))}
+                        </span>
+                        <div class="flex-1"></div>
+                        <ToolbarButton
+                            label=Signal::derive(move || if all_liked.get() { "Unlike" } else { "Like" })
+                            icon=Signal::derive(move || if all_liked.get() { "🤍" } else { "❤️" })
+                            disabled=Signal::derive(is_working)
+                            on_click=Callback::new(move |_| vm.toggle_like_selected())
+                        />
+                        <ToolbarButton
+                            label=Signal::derive(|| "Album")
+                            icon=Signal::derive(|| "🗂️")
+                            disabled=Signal::derive(is_working)
+                            on_click=Callback::new(move |_| vm.toggle_panel(BulkPanel::Album))
+                        />
+                        <ToolbarButton
+                            label=Signal::derive(|| "Tag")
+                            icon=Signal::derive(|| "🏷️")
+                            disabled=Signal::derive(is_working)
+                            on_click=Callback::new(move |_| vm.toggle_panel(BulkPanel::Tag))
+                        />
+                        <ToolbarButton
+                            label=Signal::derive(|| "Download")
+                            icon=Signal::derive(|| "⬇️")
+                            disabled=Signal::derive(is_working)
+                            on_click=Callback::new(move |_| vm.download_selected())
+                        />
+                        <ToolbarButton
+                            label=Signal::derive(|| "Share")
+                            icon=Signal::derive(|| "📤")
+                            disabled=Signal::derive(is_working)
+                            on_click=Callback::new(move |_| vm.share_selected())
+                        />
+                        <ToolbarButton
+                            label=Signal::derive(|| "Delete")
+                            icon=Signal::derive(|| "🗑️")
+                            disabled=Signal::derive(is_working)
+                            on_click=Callback::new(on_trash)
+                        />
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+#[component]
+fn ToolbarButton(
+    label: Signal<&'static str>,
+    icon: Signal<&'static str>,
+    disabled: Signal<bool>,
+    on_click: Callback<()>,
+) -> impl IntoView {
+    view! {
+        <button
+            class="flex flex-col items-center px-2 py-1 rounded-md text-xs hover:bg-gray-700 disabled:opacity-40"
+            title=label
+            disabled=disabled
+            on:click=move |_| on_click.run(())
+        >
+            <span class="text-base leading-none">{icon}</span>
+            <span class="mt-0.5">{label}</span>
+        </button>
+    }
+}
+
+/// 작업 중 표시, 결과 메시지 또는 오류
+#[component]
+fn StatusLine() -> impl IntoView {
+    let vm = use_context::<BulkActionsViewModel>().expect("BulkActionsViewModel required");
+
+    move || {
+        let (is_working, message, error) = vm
+            .state
+            .with(|s| (s.is_working, s.message.clone(), s.error.clone()));
+        let (text, class) = match (is_working, error, message) {
+            (true, _, _) => ("Working…".to_string(), "text-gray-300"),
+            (false, Some(error), _) => (error, "text-red-400"),
+            (false, None, Some(message)) => (message, "text-green-300"),
+            (false, None, None) => return None,
+        };
+        Some(view! {
+            <div class="flex items-center gap-2 px-3 pt-2 text-sm">
+                <span class=class>{text}</span>
+                <button class="ml-auto text-gray-400 hover:text-white" on:click=move |_| vm.dismiss_message()>
+                    "Dismiss"
+                </button>
+            </div>
+        })
+    }
+}
+
+/// 앨범 선택/새 앨범 또는 태그 입력 패널
+#[component]
+fn BulkPanelView() -> impl IntoView {
+    let vm = use_context::<BulkActionsViewModel>().expect("BulkActionsViewModel required");
+
+    move || match vm.state.with(|s| s.panel) {
+        Some(BulkPanel::Album) => Some(view! { <AlbumPanel /> }.into_any()),
+        Some(BulkPanel::Tag) => Some(view! { <TagPanel /> }.into_any()),
+        None => None,
+    }
+}
+
+#[component]
+fn AlbumPanel() -> impl IntoView {
+    let vm = use_context::<BulkActionsViewModel>().expect("BulkActionsViewModel required");
+    let (title, set_title) = signal(String::new());
+
+    let on_create = move |e: ev::SubmitEvent| {
+        e.prevent_default();
+        let title = title.get_untracked().trim().to_string();
+        if !title.is_empty() {
+            vm.add_to_new_album(title);
+        }
+    };
+
+    view! {
+        <div class="border-b border-gray-700 p-3 space-y-2">
+            <div class="flex flex-wrap gap-2 max-h-32 overflow-y-auto">
+                {move || {
+                    vm.state
+                        .get()
+                        .albums
+                        .into_iter()
+                        .map(|album| {
+                            let label = format!("{} ({})", album.title, album.photo_count);
+                            view! {
+                                <button
+                                    class="px-2 py-1 rounded-md bg-gray-800 text-sm hover:bg-gray-700"
+                                    on:click=move |_| vm.add_to_album(album.clone())
+                                >
+                                    {label}
+                                </button>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </div>
+            <form class="flex gap-2" on:submit=on_create>
+                <input
+                    class="flex-1 rounded-md bg-gray-800 px-2 py-1 text-sm"
+                    placeholder="New album"
+                    prop:value=title
+                    on:input=move |e| set_title.set(event_target_value(&e))
+                />
+                <button type="submit" class="px-3 py-1 rounded-md bg-blue-600 text-sm hover:bg-blue-500">
+                    "Create"
+                </button>
+            </form>
+        </div>
+    }
+}
+
+#[component]
+fn TagPanel() -> impl IntoView {
+    let vm = use_context::<BulkActionsViewModel>().expect("BulkActionsViewModel required");
+    let (tags, set_tags) = signal(String::new());
+
+    let on_submit = move |e: ev::SubmitEvent| {
+        e.prevent_default();
+        vm.tag_selected(&tags.get_untracked());
+    };
+
+    view! {
+        <form class="flex gap-2 border-b border-gray-700 p-3" on:submit=on_submit>
+            <input
+                class="flex-1 rounded-md bg-gray-800 px-2 py-1 text-sm"
+                placeholder="Tags, separated by commas"
+                prop:value=tags
+                on:input=move |e| set_tags.set(event_target_value(&e))
+            />
+            <button type="submit" class="px-3 py-1 rounded-md bg-blue-600 text-sm hover:bg-blue-500">
+                "Add tags"
+            </button>
+        </form>
+    }
+}
@@ -0,0 +1,57 @@
+use crate::domain::entities::photo::Photo;
+use crate::presentation::view_models::similar_photos_vm::SimilarPhotosViewModel;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// 미리보기 사이드바의 "More like this" 버튼과 썸네일 목록
+#[component]
+pub fn SimilarPhotos(photo_id: Uuid, on_select: Callback<Photo>) -> impl IntoView {
+    let vm = use_context::<SimilarPhotosViewModel>().expect("SimilarPhotosViewModel required");
+    let has_results = move || vm.state.with(|s| s.photo_id == Some(photo_id));
+
+    view! {
+        <div class="space-y-2">
+            <div class="flex items-center justify-between">
+                <span class="text-xs font-semibold text-gray-500 uppercase tracking-wider">"Similar"</span>
+                <button
+                    class="text-xs px-2 py-1 rounded bg-gray-800 hover:bg-gray-700 border border-gray-600 disabled:opacity-50"
+                    disabled=move || vm.state.with(|s| s.is_loading)
+                    on:click=move |_| vm.load(photo_id)
+                >
+                    "More like this"
+                </button>
+            </div>
+            <Show when=has_results>
+                {move || vm.state.with(|s| s.error.clone()).map(|error| view! {
+                    <p class="text-xs text-red-400">{error}</p>
+                })}
+                <Show when=move || vm.state.with(|s| s.is_loading)>
+                    <p class="text-xs text-gray-500">"Searching..."</p>
+                </Show>
+                <Show when=move || vm.state.with(|s| s.is_empty_result())>
+                    <p class="text-xs text-gray-500">"No similar photos yet."</p>
+                </Show>
+                <div class="grid grid-cols-3 gap-1">
+                    <For
+                        each=move || vm.state.get().photos
+                        key=|similar| similar.photo.id
+                        children=move |similar| {
+                            let title = format!("{} ({:.0}%)", similar.photo.title, similar.similarity * 100.0);
+                            let url = similar.photo.url.clone();
+                            let photo = similar.photo;
+                            view! {
+                                <button
+                                    class="aspect-square overflow-hidden rounded hover:ring-2 hover:ring-blue-500"
+                                    title=title
+                                    on:click=move |_| on_select.run(photo.clone())
+                                >
+                                    <img src=url class="w-full h-full object-cover" loading="lazy" />
+                                </button>
+                            }
+                        }
+                    />
+                </div>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,264 @@
+use crate::application::services::gallery_snapshot::ScrollAnchor;
+use crate::application::services::timeline_layout::group_by_month_and_day;
+use crate::application::services::virtual_grid::{GridLayout, GridMetrics, GridRow};
+use crate::domain::value_objects::timeline::{day_title, month_title};
+use crate::presentation::components::photo::photo_card::PhotoCard;
+use crate::presentation::hooks::use_resize_observer::use_resize_observer;
+use crate::presentation::view_models::gallery_vm::{GalleryViewModel, PhotoItem, ScrollTarget};
+use crate::presentation::view_models::grid_mode_vm::GridModeViewModel;
+use crate::presentation::view_models::scroll_vm::ScrollViewModel;
+use leptos::html;
+use leptos::prelude::*;
+
+/// 달/날짜 구간으로 묶은 가상 스크롤 사진 그리드
+///
+/// 보이는 행과 앞뒤 여유 행만 DOM 으로 그리고, 전체 높이는 계산한 행 높이로 유지합니다.
+/// 스크롤 중에는 현재 달/날짜 머리글을 상단에 고정해 보여줍니다.
+/// 배치 방식(`GridModeViewModel`)이나 너비가 바뀌면 다시 배치하고, 보던 사진이 같은 위치에 오도록 스크롤을 맞춥니다.
+/// 보던 사진은 view model 에도 알려 두어, 화면을 다시 만들면 같은 위치로 복원합니다.
+#[component]
+pub fn VirtualPhotoGrid() -> impl IntoView {
+    let vm = use_context::<GalleryViewModel>().expect("GalleryViewModel required");
+    let scroll = use_context::<ScrollViewModel>().expect("ScrollViewModel required");
+    let grid_mode = use_context::<GridModeViewModel>().expect("GridModeViewModel required");
+    let container = NodeRef::<html::Div>::new();
+
+    // 그리드 너비 (마운트 시 + 크기가 바뀔 때마다)
+    let (width, set_width) = signal(0.0);
+    let measure_width = move || {
+        if let Some(el) = container.get_untracked() {
+            let measured = el.client_width() as f64;
+            if measured != width.get_untracked() {
+                set_width.set(measured);
+            }
+        }
+    };
+    Effect::new(move |_| {
+        if let Some(el) = container.get() {
+            use_resize_observer(&el, measure_width);
+        }
+    });
+
+    let arrangement =
+        Memo::new(move |_| (GridMetrics::for_width(width.get()), grid_mode.mode.get()));
+    let layout = Memo::new(move |_| {
+        let (metrics, mode) = arrangement.get();
+        vm.photos.with(|items| {
+            let aspects: Vec<f64> = items.iter().map(|item| item.photo.aspect_ratio()).collect();
+            GridLayout::build(
+                &group_by_month_and_day(items.iter().map(|item| &item.photo)),
+                &aspects,
+                metrics,
+                mode,
+            )
+        })
+    });
+
+    // 그리드 기준으로 보이는 구간 [start, end)
+    let window = Memo::new(move |_| {
+        let viewport = scroll.viewport.get();
+        let (Some(el), Some(main)) = (container.get(), scroll.node.get_untracked()) else {
+            return (0.0, viewport.height);
+        };
+        let start = grid_offset(&el, &main);
+        (start, start + viewport.height)
+    });
+
+    let visible_rows = Memo::new(move |_| {
+        let (start, end) = window.get();
+        layout.with(|layout| {
+            layout
+                .visible(start, end)
+                .map(|index| (index, row_key(&layout.rows[index])))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // 보고 있던 사진과 그 사진이 보이는 구간 시작으로부터의 거리
+    let anchor = StoredValue::new(None::<(usize, f64)>);
+    Effect::new(move |_| {
+        let (start, _) = window.get();
+        let current = layout.with_untracked(|l| {
+            let photo = l.photo_at(start)?;
+            Some((photo, l.photo_top(photo)? - start))
+        });
+        anchor.set_value(current);
+        vm.remember_anchor(current.and_then(|(photo, offset)| {
+            let photo_id = vm
+                .photos
+                .with_untracked(|items| items.get(photo).map(PhotoItem::id))?;
+            Some(ScrollAnchor { photo_id, offset })
+        }));
+    });
+    // 다시 배치된 뒤 같은 사진이 같은 거리에 오도록 스크롤 (처음 배치는 그대로)
+    Effect::new(move |previous: Option<_>| {
+        let current = arrangement.get();
+        let rearranged = previous.is_some_and(|previous| previous != current);
+        if let Some((photo, offset)) = anchor.get_value().filter(|_| rearranged) {
+            let (start, _) = window.get_untracked();
+            if let Some(top) = layout.with_untracked(|l| l.photo_top(photo)) {
+                scroll.scroll_by(top - offset - start);
+            }
+        }
+        current
+    });
+    // 복원한 위치나 미리보기에서 마지막으로 본 사진으로 스크롤 (너비를 잰 뒤 배치 기준)
+    Effect::new(move |_| {
+        let Some(target) = vm.scroll_target.get() else {
+            return;
+        };
+        if width.get() <= 0.0 {
+            return;
+        }
+        let (Some(el), Some(main)) = (container.get_untracked(), scroll.node.get_untracked())
+        else {
+            return;
+        };
+        // 앞선 스크롤 보정이 바로 반영되도록 DOM 에서 다시 측정
+        let start = grid_offset(&el, &main);
+        let end = start + main.client_height() as f64;
+        let delta = vm.index_of(target.photo_id()).and_then(|photo| {
+            layout.with(|l| match target {
+                ScrollTarget::Anchor(anchor) => Some(l.photo_top(photo)? - anchor.offset - start),
+                ScrollTarget::Reveal(_) => l.reveal_delta(photo, start, end),
+            })
+        });
+        if let Some(delta) = delta {
+            scroll.scroll_by(delta);
+        }
+        vm.clear_scroll_target();
+    });
+
+    // 그리드 윗부분이 스크롤되어 가려지면 현재 달/날짜를 고정 머리글로 표시
+    let sticky_section = Memo::new(move |_| {
+        let (start, _) = window.get();
+        (start > 0.0).then(|| layout.with(|layout| layout.section_at(start)))
+    });
+
+    view! {
+        <div
+            node_ref=container
+            class="relative"
+            style:height=move || format!("{}px", layout.with(|l| l.total_height))
+        >
+            <div class="sticky top-0 z-20 h-0">
+                {move || {
+                    sticky_section
+                        .get()
+                        .map(|(month, day)| {
+                            view! {
+                                <div class="flex items-baseline gap-3 h-11 -mx-1 px-1 bg-gray-50/95 backdrop-blur">
+                                    <span class="text-lg font-semibold text-gray-800">
+                                        {month.map(|(year, month)| month_title(year, month))}
+                                    </span>
+                                    <span class="text-sm font-medium text-gray-500">
+                                        {day.map(day_title)}
+                                    </span>
+                                </div>
+                            }
+                        })
+                }}
+            </div>
+            <For
+                each=move || visible_rows.get()
+                key=|row| row.clone()
+                children=move |(index, row)| grid_row(vm, layout, index, row)
+            />
+        </div>
+    }
+}
+
+/// 스크롤 영역 위쪽 끝의 그리드 기준 위치 (px)
+fn grid_offset(grid: &web_sys::Element, main: &web_sys::Element) -> f64 {
+    main.get_bounding_client_rect().top() - grid.get_bounding_client_rect().top()
+}
+
+/// 행 key: 사진 줄은 시작 위치만 사용
+///
+/// 마지막 줄에 다음 페이지 사진이 채워지거나 좋아요가 바뀌어도 줄과 카드를 다시 만들지 않습니다.
+fn row_key(row: &GridRow) -> GridRow {
+    match row {
+        GridRow::Photos(range) => GridRow::Photos(range.start..range.start),
+        other => other.clone(),
+    }
+}
+
+fn grid_row(vm: GalleryViewModel, layout: Memo<GridLayout>, index: usize, row: GridRow) -> AnyView {
+    let top = move || format!("{}px", layout.with(|l| l.top(index)));
+    match row {
+        GridRow::Month { year, month } => view! {
+            <h2
+                class="absolute inset-x-0 h-11 flex items-center text-lg font-semibold text-gray-800"
+                style:top=top
+            >
+                {month_title(year, month)}
+            </h2>
+        }
+        .into_any(),
+        GridRow::Day(date) => view! {
+            <h3
+                class="absolute inset-x-0 h-7 flex items-center text-sm font-medium text-gray-500"
+                style:top=top
+            >
+                {day_title(date)}
+            </h3>
+        }
+        .into_any(),
+        GridRow::Photos(_) => view! { <PhotoRow vm=vm layout=layout index=index /> }.into_any(),
+    }
+}
+
+/// 사진 한 줄 (사진 id 로 key 를 잡아 목록이 바뀌어도 기존 카드는 유지)
+///
+/// 각 사진은 배치 결과의 위치와 크기로 절대 배치합니다.
+/// 앞쪽 사진이 빠지면 목록 위치가 바뀌므로, 카드마다 id 로 현재 위치를 다시 찾습니다.
+#[component]
+fn PhotoRow(vm: GalleryViewModel, layout: Memo<GridLayout>, index: usize) -> impl IntoView {
+    let range = Memo::new(move |_| {
+        layout.with(|l| match l.rows.get(index) {
+            Some(GridRow::Photos(range)) => range.clone(),
+            _ => 0..0,
+        })
+    });
+    let items = move || {
+        let range = range.get();
+        vm.photos.with(|items| items.get(range).unwrap_or_default().to_vec())
+    };
+
+    view! {
+        <div
+            class="absolute inset-x-0"
+            style:top=move || format!("{}px", layout.with(|l| l.top(index)))
+        >
+            <For
+                each=items
+                key=PhotoItem::id
+                children=move |item| {
+                    let id = item.id();
+                    let tile = Memo::new(move |_| {
+                        let range = range.get();
+                        let photo = vm.photos.with(|items| {
+                            let offset = items
+                                .get(range.clone())?
+                                .iter()
+                                .position(|item| item.id() == id)?;
+                            Some(range.start + offset)
+                        });
+                        photo.map(|photo| layout.with(|l| l.tile(photo))).unwrap_or_default()
+                    });
+                    view! {
+                        <div
+                            class="absolute"
+                            style:left=move || format!("{}px", tile.get().left)
+                            style:top=move || format!("{}px", tile.get().top)
+                            style:width=move || format!("{}px", tile.get().width)
+                            style:height=move || format!("{}px", tile.get().height)
+                        >
+                            <PhotoCard item=item />
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
+}
@@ -0,0 +1,145 @@
+use crate::application::services::gesture_recognizer::GestureEvent;
+use crate::application::services::zoom_transform::Size;
+use crate::presentation::hooks::use_gesture::{use_gesture, GestureOptions};
+use crate::presentation::view_models::preview_zoom_vm::PreviewZoomViewModel;
+use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+
+/// 미리보기 이미지 영역의 확대 제스처
+///
+/// 두 손가락 핀치, 확대 중 끌기(관성 포함), 두 번 탭/더블 클릭,
+/// 휠(트랙패드 핀치 포함)로 `image` 를 확대합니다.
+/// 미리보기 해상도를 넘게 확대하면 `original_url` 을 불러와 교체합니다.
+#[component]
+pub fn ZoomArea(
+    image: NodeRef<html::Img>,
+    original_url: String,
+    /// 얼굴 편집 중처럼 다른 조작이 이미지를 쓰는 동안 끔
+    #[prop(into)]
+    disabled: Signal<bool>,
+    children: Children,
+) -> impl IntoView {
+    let vm = use_context::<PreviewZoomViewModel>().expect("PreviewZoomViewModel required");
+    let area = NodeRef::<html::Div>::new();
+    let original_url = StoredValue::new(original_url);
+
+    // 조작을 시작할 때 크기를 재고, 화면 중심 기준 좌표를 계산
+    let measure = move || {
+        let (Some(el), Some(img)) = (area.get_untracked(), image.get_untracked()) else {
+            return;
+        };
+        vm.set_bounds(
+            Size {
+                width: img.offset_width() as f64,
+                height: img.offset_height() as f64,
+            },
+            Size {
+                width: el.client_width() as f64,
+                height: el.client_height() as f64,
+            },
+        );
+    };
+    let to_focal = move |x: f64, y: f64| {
+        area.get_untracked().map_or((0.0, 0.0), |el| {
+            let rect = el.get_bounding_client_rect();
+            (
+                x - rect.left() - rect.width() / 2.0,
+                y - rect.top() - rect.height() / 2.0,
+            )
+        })
+    };
+    let load_original = move || {
+        if let Some(img) = image.get_untracked() {
+            original_url.with_value(|url| {
+                vm.load_original_if_needed(
+                    url,
+                    img.offset_width() as f64,
+                    img.natural_width() as f64,
+                )
+            });
+        }
+    };
+
+    // 핀치 시작 시 배율
+    let pinch_start = StoredValue::new(1.0);
+    let options = GestureOptions {
+        capture: true,
+        ..GestureOptions::default()
+    };
+    let gesture = use_gesture(options, move |event| {
+        match event {
+            GestureEvent::Press { .. } => {
+                vm.stop_fling();
+                measure();
+            }
+            GestureEvent::PinchStart { .. } => {
+                pinch_start.set_value(vm.transform.get_untracked().scale)
+            }
+            GestureEvent::Pinch { scale, center } => {
+                vm.zoom_at(
+                    pinch_start.get_value() * scale,
+                    to_focal(center.0, center.1),
+                );
+                load_original();
+            }
+            // 1배일 때의 이동은 바깥의 스와이프 처리로 넘김
+            GestureEvent::Pan { delta, .. } if vm.is_zoomed_untracked() => {
+                vm.pan_by(delta.0, delta.1)
+            }
+            GestureEvent::PanEnd { velocity, .. } if vm.is_zoomed_untracked() => {
+                vm.fling(velocity.0, velocity.1)
+            }
+            GestureEvent::DoubleTap { x, y } => {
+                vm.toggle_at(to_focal(x, y));
+                load_original();
+            }
+            _ => {}
+        }
+    });
+
+    let on_wheel = move |e: ev::WheelEvent| {
+        if disabled.get_untracked() {
+            return;
+        }
+        e.prevent_default();
+        vm.stop_fling();
+        measure();
+        vm.zoom_at(
+            vm.transform.get_untracked().scale * wheel_factor(&e),
+            to_focal(e.client_x() as f64, e.client_y() as f64),
+        );
+        load_original();
+    };
+
+    view! {
+        <div
+            node_ref=area
+            class="flex-1 bg-black flex items-center justify-center overflow-hidden relative min-h-[50vh] min-w-[50vw] p-0 md:p-4"
+            class=("cursor-grab", move || vm.is_zoomed())
+            style:touch-action=move || if disabled.get() { "auto" } else { "none" }
+            on:pointerdown=move |e| {
+                if !disabled.get_untracked() {
+                    gesture.down.run(e)
+                }
+            }
+            on:pointermove=move |e| gesture.moved.run(e)
+            on:pointerup=move |e| gesture.up.run(e)
+            on:pointercancel=move |e| gesture.cancel.run(e)
+            on:wheel=on_wheel
+        >
+            {children()}
+        </div>
+    }
+}
+
+/// 휠 한 번의 확대 비율 (Ctrl+휠은 트랙패드 핀치라 더 민감하게)
+fn wheel_factor(e: &ev::WheelEvent) -> f64 {
+    let delta = match e.delta_mode() {
+        web_sys::WheelEvent::DOM_DELTA_LINE => e.delta_y() * 16.0,
+        web_sys::WheelEvent::DOM_DELTA_PAGE => e.delta_y() * 400.0,
+        _ => e.delta_y(),
+    };
+    let sensitivity = if e.ctrl_key() { 0.01 } else { 0.002 };
+    (-delta * sensitivity).exp()
+}
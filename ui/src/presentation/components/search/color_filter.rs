@@ -0,0 +1,75 @@
+use crate::domain::value_objects::color::normalize_hex;
+use crate::presentation::view_models::search_vm::SearchViewModel;
+use leptos::prelude::*;
+
+/// 빠른 선택용 색 (이름, `#rrggbb`)
+const PRESET_COLORS: [(&str, &str); 12] = [
+    ("Red", "#e53935"),
+    ("Orange", "#fb8c00"),
+    ("Yellow", "#fdd835"),
+    ("Green", "#43a047"),
+    ("Teal", "#00897b"),
+    ("Blue", "#1e88e5"),
+    ("Purple", "#8e24aa"),
+    ("Pink", "#ec407a"),
+    ("Brown", "#6d4c41"),
+    ("Black", "#212121"),
+    ("Gray", "#9e9e9e"),
+    ("White", "#fafafa"),
+];
+
+/// 색상 견본 필터: 팔레트에 고른 색과 비슷한 색이 있는 사진만 표시
+///
+/// 같은 견본을 다시 누르면 조건을 해제합니다.
+#[component]
+pub fn ColorFilter() -> impl IntoView {
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let selected = Memo::new(move |_| search_vm.state.get().active_filter.and_then(|f| f.color));
+
+    let toggle = move |color: String| {
+        search_vm.update_active(|f| {
+            f.color = if f.color.as_ref() == Some(&color) {
+                None
+            } else {
+                Some(color)
+            };
+        });
+    };
+    let pick = move |value: String| {
+        if let Some(color) = normalize_hex(&value) {
+            search_vm.update_active(|f| f.color = Some(color));
+        }
+    };
+
+    view! {
+        <div class="mt-3 flex flex-wrap items-center gap-2">
+            <span class="text-xs font-semibold text-gray-500 uppercase tracking-wider">"Color"</span>
+            {PRESET_COLORS.into_iter().map(|(name, hex)| {
+                let is_selected = move || selected.get().as_deref() == Some(hex);
+                view! {
+                    <button
+                        class="w-6 h-6 rounded-full border border-gray-300 shadow-sm ring-offset-1 transition"
+                        class=("ring-2", is_selected)
+                        class=("ring-blue-500", is_selected)
+                        style:background-color=hex
+                        title=name
+                        aria-label=name
+                        on:click=move |_| toggle(hex.to_string())
+                    ></button>
+                }
+            }).collect_view()}
+            <label class="relative w-6 h-6 rounded-full border border-dashed border-gray-400 overflow-hidden cursor-pointer" title="Custom color">
+                <span class="absolute inset-0 flex items-center justify-center text-xs text-gray-500 pointer-events-none">"+"</span>
+                <input
+                    type="color"
+                    class="absolute inset-0 opacity-0 cursor-pointer"
+                    prop:value=move || selected.get().unwrap_or_else(|| "#ffffff".to_string())
+                    on:change=move |ev| pick(event_target_value(&ev))
+                />
+            </label>
+            {move || selected.get().filter(|color| !PRESET_COLORS.iter().any(|(_, hex)| hex == color)).map(|color| view! {
+                <span class="w-6 h-6 rounded-full border border-gray-300 ring-2 ring-blue-500 ring-offset-1" style:background-color=color.clone() title=color></span>
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,194 @@
+use crate::domain::value_objects::exif_filter::{ExifField, NumericRange};
+use crate::domain::value_objects::search_facets::RangeFacet;
+use crate::presentation::view_models::exif_filter_vm::ExifFilterViewModel;
+use crate::presentation::view_models::search_vm::SearchViewModel;
+use leptos::prelude::*;
+
+const MAX_VISIBLE_LENSES: usize = 12;
+
+/// 렌즈/초점거리/조리개/셔터/ISO/플래시 facet 패널
+///
+/// 각 값 옆에 현재 조건에서의 사진 수를 표시하고, 누르면 해당 조건을 켜고 끕니다.
+#[component]
+pub fn ExifFilterPanel() -> impl IntoView {
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let vm = ExifFilterViewModel::new();
+    provide_context(vm);
+
+    // 검색 조건이 바뀌면 facet 다시 집계 (패널이 열려 있을 때만)
+    let filter = Memo::new(move |_| search_vm.state.get().active_filter.unwrap_or_default());
+    let is_open = Memo::new(move |_| vm.state.get().is_open);
+    Effect::new(move |_| {
+        if is_open.get() {
+            vm.load_facets(filter.get());
+        }
+    });
+
+    view! {
+        <div class="mt-3">
+            <button
+                class="inline-flex items-center gap-1 px-3 py-1 rounded-full border border-gray-300 text-sm text-gray-700 hover:bg-gray-100"
+                on:click=move |_| vm.toggle_open()
+            >
+                "📷 EXIF filters"
+                <span class="text-gray-400">{move || if is_open.get() { "▴" } else { "▾" }}</span>
+            </button>
+            <Show when=move || is_open.get()>
+                <div class="mt-3 p-4 bg-white rounded-lg border border-gray-200 shadow-sm space-y-4">
+                    <FacetStatus />
+                    <LensFacets />
+                    {ExifField::ALL.into_iter().map(|field| view! { <RangeFacets field=field /> }).collect_view()}
+                    <FlashFacets />
+                </div>
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+fn FacetStatus() -> impl IntoView {
+    let vm = use_context::<ExifFilterViewModel>().expect("ExifFilterViewModel required");
+
+    move || {
+        let state = vm.state.get();
+        if let Some(error) = state.error {
+            view! { <p class="text-sm text-red-500">{error}</p> }.into_any()
+        } else if state.is_loading && state.facets.is_none() {
+            view! { <p class="text-sm text-gray-400">"Loading…"</p> }.into_any()
+        } else {
+            ().into_any()
+        }
+    }
+}
+
+#[component]
+fn LensFacets() -> impl IntoView {
+    let vm = use_context::<ExifFilterViewModel>().expect("ExifFilterViewModel required");
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let selected = move || search_vm.state.get().active_filter.and_then(|f| f.lens);
+
+    let toggle = move |lens: String| {
+        search_vm.update_active(|f| {
+            f.lens = if f.lens.as_ref() == Some(&lens) { None } else { Some(lens) };
+        });
+    };
+
+    let lenses = move || {
+        let facets = vm.state.get().facets.unwrap_or_default();
+        facets.lenses.into_iter().take(MAX_VISIBLE_LENSES).map(|facet| {
+            let lens = facet.value.clone();
+            let is_selected = Signal::derive({
+                let lens = lens.clone();
+                move || selected().as_ref() == Some(&lens)
+            });
+            view! {
+                <FacetButton
+                    label=facet.value
+                    count=facet.count
+                    is_selected=is_selected
+                    on_click=Callback::new(move |_| toggle(lens.clone()))
+                />
+            }
+        }).collect_view()
+    };
+
+    view! { <FacetGroup title="Lens">{lenses}</FacetGroup> }
+}
+
+#[component]
+fn RangeFacets(field: ExifField) -> impl IntoView {
+    let vm = use_context::<ExifFilterViewModel>().expect("ExifFilterViewModel required");
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let selected = move || {
+        let filter = search_vm.state.get().active_filter.unwrap_or_default();
+        field.range(&filter.exif)
+    };
+
+    let toggle = move |range: NumericRange| {
+        search_vm.update_active(|f| {
+            let current = field.range_mut(&mut f.exif);
+            *current = if *current == range { NumericRange::default() } else { range };
+        });
+    };
+
+    let buckets = move || {
+        let facets = vm.state.get().facets.unwrap_or_default();
+        facets.ranges(field).iter().cloned().map(|RangeFacet { label, range, count }| {
+            view! {
+                <FacetButton
+                    label=label
+                    count=count
+                    is_selected=Signal::derive(move || selected() == range)
+                    on_click=Callback::new(move |_| toggle(range))
+                />
+            }
+        }).collect_view()
+    };
+
+    view! { <FacetGroup title=field.title()>{buckets}</FacetGroup> }
+}
+
+#[component]
+fn FlashFacets() -> impl IntoView {
+    let vm = use_context::<ExifFilterViewModel>().expect("ExifFilterViewModel required");
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let selected = move || search_vm.state.get().active_filter.and_then(|f| f.exif.flash);
+
+    let toggle = move |flash: bool| {
+        search_vm.update_active(|f| {
+            f.exif.flash = if f.exif.flash == Some(flash) { None } else { Some(flash) };
+        });
+    };
+
+    let options = move || {
+        let facets = vm.state.get().facets.unwrap_or_default();
+        facets.flash.into_iter().map(|facet| {
+            let flash = facet.value;
+            view! {
+                <FacetButton
+                    label=if flash { "Fired" } else { "Off" }.to_string()
+                    count=facet.count
+                    is_selected=Signal::derive(move || selected() == Some(flash))
+                    on_click=Callback::new(move |_| toggle(flash))
+                />
+            }
+        }).collect_view()
+    };
+
+    view! { <FacetGroup title="Flash">{options}</FacetGroup> }
+}
+
+#[component]
+fn FacetGroup(title: &'static str, children: Children) -> impl IntoView {
+    view! {
+        <section>
+            <h3 class="text-xs font-semibold text-gray-500 uppercase tracking-wider mb-2">{title}</h3>
+            <div class="flex flex-wrap gap-2">{children()}</div>
+        </section>
+    }
+}
+
+/// 값 하나 (선택 시 강조, 사진이 없는 값은 흐리게 표시)
+#[component]
+fn FacetButton(
+    label: String,
+    count: i64,
+    #[prop(into)] is_selected: Signal<bool>,
+    on_click: Callback<()>,
+) -> impl IntoView {
+    view! {
+        <button
+            class="inline-flex items-center gap-1 px-2 py-1 rounded border text-xs transition"
+            class=("bg-blue-600", move || is_selected.get())
+            class=("border-blue-600", move || is_selected.get())
+            class=("text-white", move || is_selected.get())
+            class=("border-gray-300", move || !is_selected.get())
+            class=("text-gray-700", move || !is_selected.get())
+            class=("opacity-40", move || count == 0 && !is_selected.get())
+            on:click=move |_| on_click.run(())
+        >
+            <span>{label}</span>
+            <span class="opacity-70">{count}</span>
+        </button>
+    }
+}
@@ -0,0 +1,5 @@
+pub mod color_filter;
+pub mod exif_filter_panel;
+pub mod quality_filter_bar;
+pub mod search_bar;
+pub mod search_filter_chips;
@@ -0,0 +1,57 @@
+use crate::domain::value_objects::exif_filter::NumericRange;
+use crate::domain::value_objects::quality_filter::QualityField;
+use crate::presentation::view_models::search_vm::SearchViewModel;
+use leptos::prelude::*;
+
+/// 선명도/노출 점수 빠른 필터 (예: 흐린 사진만, 노출이 적정한 사진만)
+///
+/// 같은 버튼을 다시 누르면 조건을 해제합니다.
+#[component]
+pub fn QualityFilterBar() -> impl IntoView {
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let quality = Memo::new(move |_| {
+        search_vm
+            .state
+            .get()
+            .active_filter
+            .map(|f| f.quality)
+            .unwrap_or_default()
+    });
+
+    let toggle = move |field: QualityField, range: NumericRange| {
+        search_vm.update_active(|f| {
+            let current = field.range_mut(&mut f.quality);
+            *current = if *current == range {
+                NumericRange::default()
+            } else {
+                range
+            };
+        });
+    };
+
+    view! {
+        <div class="mt-3 flex flex-wrap items-center gap-2">
+            <span class="text-xs font-semibold text-gray-500 uppercase tracking-wider">"Quality"</span>
+            {QualityField::ALL
+                .into_iter()
+                .flat_map(|field| field.presets().map(|(label, range)| (field, label, range)))
+                .map(|(field, label, range)| {
+                    let is_selected = move || field.range(&quality.get()) == range;
+                    view! {
+                        <button
+                            class="px-3 py-1 rounded-full text-xs border transition"
+                            class=("bg-blue-600", is_selected)
+                            class=("text-white", is_selected)
+                            class=("border-blue-600", is_selected)
+                            class=("border-gray-300", move || !is_selected())
+                            class=("text-gray-600", move || !is_selected())
+                            on:click=move |_| toggle(field, range)
+                        >
+                            {label}
+                        </button>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
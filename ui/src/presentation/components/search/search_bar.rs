@@ -0,0 +1,254 @@
+use crate::presentation::view_models::search_vm::SearchViewModel;
+use chrono::NaiveDate;
+use leptos::prelude::*;
+
+#[component]
+pub fn SearchBar() -> impl IntoView {
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+
+    // 드롭다운(필터 패널 + 최근 검색) 열림 여부
+    let is_open = move || search_vm.state.get().is_panel_open;
+
+    let draft_text = move || search_vm.state.get().draft.text.unwrap_or_default();
+    let is_active = move || search_vm.state.get().active_filter.is_some();
+
+    let submit = move || search_vm.submit();
+
+    view! {
+        <div class="relative">
+            // 드롭다운 외부 클릭 시 닫기
+            <Show when=is_open>
+                <div class="fixed inset-0 z-20" on:click=move |_| search_vm.close_panel()></div>
+            </Show>
+
+            <div class="relative z-30 flex items-center bg-gray-100 rounded-full px-3 h-9 w-40 sm:w-64 focus-within:ring-2 focus-within:ring-blue-500">
+                <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="2" stroke="currentColor" class="w-4 h-4 text-gray-500 flex-none">
+                    <path stroke-linecap="round" stroke-linejoin="round" d="M21 21l-5.197-5.197m0 0A7.5 7.5 0 105.196 5.196a7.5 7.5 0 0010.607 10.607z" />
+                </svg>
+                <input
+                    type="search"
+                    placeholder="작년 여름 바다, last summer beach..."
+                    class="flex-1 min-w-0 bg-transparent text-sm text-gray-800 px-2 focus:outline-none"
+                    prop:value=draft_text
+                    on:focus=move |_| search_vm.open_panel()
+                    on:input=move |e| {
+                        let text = event_target_value(&e);
+                        search_vm.update_draft(|f| f.text = Some(text));
+                    }
+                    on:keydown=move |e| {
+                        if e.key() == "Enter" {
+                            submit();
+                        }
+                    }
+                />
+                <Show when=is_active>
+                    <button
+                        class="text-gray-400 hover:text-gray-700 flex-none"
+                        on:click=move |_| search_vm.clear()
+                    >
+                        <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" stroke-width="2" stroke="currentColor" class="w-4 h-4">
+                            <path stroke-linecap="round" stroke-linejoin="round" d="M6 18L18 6M6 6l12 12" />
+                        </svg>
+                    </button>
+                </Show>
+            </div>
+
+            <Show when=is_open>
+                <div class="absolute right-0 top-full mt-2 z-30 w-[calc(100vw-2rem)] max-w-sm bg-white rounded-lg shadow-xl border border-gray-200 p-4 space-y-4">
+                    <SearchFilterFields />
+                    <button
+                        class="w-full py-2 bg-blue-600 hover:bg-blue-500 text-white text-sm font-medium rounded transition"
+                        on:click=move |_| submit()
+                    >
+                        "Search"
+                    </button>
+                    <RecentSearches />
+                </div>
+            </Show>
+        </div>
+    }
+}
+
+/// 날짜 범위, 카메라, 렌즈, 좋아요, 위치 정보 필터 입력
+#[component]
+fn SearchFilterFields() -> impl IntoView {
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let draft = move || search_vm.state.get().draft;
+
+    view! {
+        <div class="grid grid-cols-2 gap-3 text-sm">
+            <label class="flex flex-col gap-1 text-xs text-gray-500">
+                "From"
+                <input
+                    type="date"
+                    class="border border-gray-300 rounded px-2 py-1 text-sm text-gray-800"
+                    prop:value=move || draft().date_from.map(|d| d.to_string()).unwrap_or_default()
+                    on:change=move |e| {
+                        let date = parse_date(&event_target_value(&e));
+                        search_vm.update_draft(|f| f.date_from = date);
+                    }
+                />
+            </label>
+            <label class="flex flex-col gap-1 text-xs text-gray-500">
+                "To"
+                <input
+                    type="date"
+                    class="border border-gray-300 rounded px-2 py-1 text-sm text-gray-800"
+                    prop:value=move || draft().date_to.map(|d| d.to_string()).unwrap_or_default()
+                    on:change=move |e| {
+                        let date = parse_date(&event_target_value(&e));
+                        search_vm.update_draft(|f| f.date_to = date);
+                    }
+                />
+            </label>
+            <label class="flex flex-col gap-1 text-xs text-gray-500">
+                "Camera"
+                <input
+                    type="text"
+                    placeholder="e.g. Sony A7"
+                    class="border border-gray-300 rounded px-2 py-1 text-sm text-gray-800"
+                    prop:value=move || draft().camera.unwrap_or_default()
+                    on:input=move |e| {
+                        let camera = Some(event_target_value(&e));
+                        search_vm.update_draft(|f| f.camera = camera);
+                    }
+                />
+            </label>
+            <label class="flex flex-col gap-1 text-xs text-gray-500">
+                "Lens"
+                <input
+                    type="text"
+                    placeholder="e.g. 35mm"
+                    class="border border-gray-300 rounded px-2 py-1 text-sm text-gray-800"
+                    prop:value=move || draft().lens.unwrap_or_default()
+                    on:input=move |e| {
+                        let lens = Some(event_target_value(&e));
+                        search_vm.update_draft(|f| f.lens = lens);
+                    }
+                />
+            </label>
+            <label class="flex flex-col gap-1 text-xs text-gray-500">
+                "Place"
+                <input
+                    type="text"
+                    placeholder="e.g. Jeju"
+                    class="border border-gray-300 rounded px-2 py-1 text-sm text-gray-800"
+                    prop:value=move || draft().place.unwrap_or_default()
+                    on:input=move |e| {
+                        let place = Some(event_target_value(&e));
+                        search_vm.update_draft(|f| f.place = place);
+                    }
+                />
+            </label>
+            <label class="flex flex-col gap-1 text-xs text-gray-500">
+                "Tags"
+                <input
+                    type="text"
+                    placeholder="beach, sunset"
+                    class="border border-gray-300 rounded px-2 py-1 text-sm text-gray-800"
+                    prop:value=move || draft().tags.join(", ")
+                    on:change=move |e| {
+                        let tags = parse_tags(&event_target_value(&e));
+                        search_vm.update_draft(|f| f.tags = tags);
+                    }
+                />
+            </label>
+            <TriStateSelect
+                label="Liked"
+                options=("Liked", "Not liked")
+                value=Signal::derive(move || draft().is_liked)
+                on_change=Callback::new(move |value| search_vm.update_draft(|f| f.is_liked = value))
+            />
+            <TriStateSelect
+                label="Location"
+                options=("With location", "Without location")
+                value=Signal::derive(move || draft().has_location)
+                on_change=Callback::new(move |value| search_vm.update_draft(|f| f.has_location = value))
+            />
+        </div>
+    }
+}
+
+/// 전체 / 예 / 아니오 3가지 상태 선택
+#[component]
+fn TriStateSelect(
+    label: &'static str,
+    options: (&'static str, &'static str),
+    value: Signal<Option<bool>>,
+    on_change: Callback<Option<bool>>,
+) -> impl IntoView {
+    view! {
+        <label class="flex flex-col gap-1 text-xs text-gray-500">
+            {label}
+            <select
+                class="border border-gray-300 rounded px-2 py-1 text-sm text-gray-800 bg-white"
+                prop:value=move || match value.get() {
+                    None => "any",
+                    Some(true) => "yes",
+                    Some(false) => "no",
+                }
+                on:change=move |e| {
+                    let selected = match event_target_value(&e).as_str() {
+                        "yes" => Some(true),
+                        "no" => Some(false),
+                        _ => None,
+                    };
+                    on_change.run(selected);
+                }
+            >
+                <option value="any">"Any"</option>
+                <option value="yes">{options.0}</option>
+                <option value="no">{options.1}</option>
+            </select>
+        </label>
+    }
+}
+
+#[component]
+fn RecentSearches() -> impl IntoView {
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let recent_searches = move || search_vm.state.get().recent_searches;
+
+    view! {
+        <Show when=move || !recent_searches().is_empty()>
+            <div class="border-t border-gray-100 pt-3">
+                <h3 class="text-xs font-semibold text-gray-500 uppercase tracking-wider mb-2">"Recent"</h3>
+                <ul class="space-y-1">
+                    {move || recent_searches().into_iter().map(|filter| {
+                        let filter_for_remove = filter.clone();
+                        let label = filter.label();
+                        view! {
+                            <li class="flex items-center justify-between gap-2 group">
+                                <button
+                                    class="flex-1 text-left text-sm text-gray-700 truncate hover:text-blue-600"
+                                    on:click=move |_| search_vm.apply_recent(filter.clone())
+                                >
+                                    {label}
+                                </button>
+                                <button
+                                    class="text-gray-300 hover:text-gray-600 text-xs"
+                                    on:click=move |_| search_vm.remove_recent(&filter_for_remove)
+                                >
+                                    "✕"
+                                </button>
+                            </li>
+                        }
+                    }).collect_view()}
+                </ul>
+            </div>
+        </Show>
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+fn parse_tags(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
@@ -0,0 +1,43 @@
+use crate::presentation::view_models::search_vm::SearchViewModel;
+use leptos::prelude::*;
+
+/// 적용된 검색 조건(자연어 해석 결과 포함)을 칩으로 표시
+///
+/// 칩을 누르면 필터 패널에서 편집하고, ✕ 를 누르면 해당 조건만 제거합니다.
+#[component]
+pub fn SearchFilterChips() -> impl IntoView {
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+
+    let chips = move || {
+        search_vm
+            .state
+            .get()
+            .active_filter
+            .map(|filter| filter.chips())
+            .unwrap_or_default()
+    };
+
+    view! {
+        <div class="flex flex-wrap gap-2 mt-2">
+            {move || chips().into_iter().map(|chip| {
+                let label = chip.label();
+                view! {
+                    <span class="inline-flex items-center rounded-full bg-blue-50 border border-blue-200 text-blue-800 text-xs">
+                        <button
+                            class="pl-3 pr-1 py-1 hover:underline"
+                            on:click=move |_| search_vm.edit_active()
+                        >
+                            {label}
+                        </button>
+                        <button
+                            class="pr-2 pl-1 py-1 text-blue-400 hover:text-blue-700"
+                            on:click=move |_| search_vm.remove_chip(&chip)
+                        >
+                            "✕"
+                        </button>
+                    </span>
+                }
+            }).collect_view()}
+        </div>
+    }
+}
@@ -0,0 +1,94 @@
+use crate::application::services::timeline_layout::{month_offset, year_marks};
+use crate::presentation::view_models::gallery_vm::GalleryViewModel;
+use crate::presentation::view_models::scroll_vm::ScrollViewModel;
+use crate::presentation::view_models::timeline_vm::TimelineViewModel;
+use leptos::ev::PointerEvent;
+use leptos::html;
+use leptos::prelude::*;
+
+/// 화면 오른쪽 가장자리의 타임라인 스크러버
+///
+/// 누른 채 위아래로 움직이면 달을 보여주고, 놓으면 갤러리를 그 달부터 다시 불러옵니다.
+#[component]
+pub fn TimelineScrubber() -> impl IntoView {
+    let vm = use_context::<TimelineViewModel>().expect("TimelineViewModel required");
+    let gallery_vm = use_context::<GalleryViewModel>().expect("GalleryViewModel required");
+    let scroll = use_context::<ScrollViewModel>().expect("ScrollViewModel required");
+    let track = NodeRef::<html::Div>::new();
+
+    // 트랙 안에서의 세로 위치 (0~1)
+    let fraction = move |e: &PointerEvent| {
+        let rect = track.get_untracked()?.get_bounding_client_rect();
+        (rect.height() > 0.0).then(|| (e.client_y() as f64 - rect.top()) / rect.height())
+    };
+
+    let on_pointer_down = move |e: PointerEvent| {
+        // 바깥의 당겨서 새로고침 제스처로 전달되지 않도록
+        e.stop_propagation();
+        if let Some(el) = track.get_untracked() {
+            let _ = el.set_pointer_capture(e.pointer_id());
+        }
+        if let Some(f) = fraction(&e) {
+            vm.scrub(f);
+        }
+    };
+    let on_pointer_move = move |e: PointerEvent| {
+        if !vm.state.with_untracked(|s| s.scrubbing.is_some()) {
+            return;
+        }
+        if let Some(f) = fraction(&e) {
+            vm.scrub(f);
+        }
+    };
+    let on_pointer_up = move |_: PointerEvent| {
+        let Some(cursor) = vm.finish_scrub() else {
+            return;
+        };
+        gallery_vm.jump_to(cursor);
+        scroll.scroll_to_top();
+    };
+
+    let bubble = move || {
+        vm.state.with(|s| {
+            let index = s.scrubbing?;
+            let month = s.timeline.months.get(index)?;
+            Some((month.label(), month_offset(&s.timeline, index)))
+        })
+    };
+
+    view! {
+        <Show when=move || vm.state.with(|s| s.timeline.months.len() > 1)>
+            <div
+                node_ref=track
+                class="fixed right-0 top-20 bottom-6 w-8 md:w-12 z-30 select-none touch-none cursor-row-resize"
+                on:pointerdown=on_pointer_down
+                on:pointermove=on_pointer_move
+                on:pointerup=on_pointer_up
+                on:pointercancel=move |_| vm.cancel_scrub()
+            >
+                <div class="absolute right-2 top-0 bottom-0 w-0.5 rounded bg-gray-300/70"></div>
+                {move || {
+                    vm.state.with(|s| year_marks(&s.timeline))
+                        .into_iter()
+                        .map(|(year, offset)| view! {
+                            <span
+                                class="absolute right-3 -translate-y-1/2 text-[10px] font-medium text-gray-500"
+                                style:top=format!("{}%", offset * 100.0)
+                            >
+                                {year}
+                            </span>
+                        })
+                        .collect_view()
+                }}
+                {move || bubble().map(|(label, offset)| view! {
+                    <div
+                        class="absolute right-10 md:right-14 -translate-y-1/2 whitespace-nowrap rounded-full bg-gray-900 px-3 py-1 text-sm text-white shadow-lg"
+                        style:top=format!("{}%", offset * 100.0)
+                    >
+                        {label}
+                    </div>
+                })}
+            </div>
+        </Show>
+    }
+}
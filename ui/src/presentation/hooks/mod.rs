@@ -0,0 +1,107 @@
+pub mod use_event_listener;
+pub mod use_gesture;
+pub mod use_intersection_observer;
+pub mod use_media_query;
+pub mod use_resize_observer;
+
+use crate::application::services::subscription::Subscription;
+use leptos::prelude::*;
+
+/// 현재 owner(컴포넌트/Effect)가 정리될 때 해제
+///
+/// Effect 안에서 등록하면 Effect 가 다시 실행될 때마다 이전 등록이 해제됩니다.
+fn unsubscribe_on_cleanup<T: 'static>(subscription: Subscription<T>) {
+    let subscription = StoredValue::new_local(subscription);
+    on_cleanup(move || subscription.update_value(Subscription::unsubscribe));
+}
+
+/// 요소를 관찰하고 관찰을 끝낼 수 있는 observer (테스트에서는 가짜 observer)
+trait Observer: 'static {
+    type Target;
+    fn observe(&self, target: &Self::Target);
+    fn disconnect(&self);
+}
+
+/// `target` 관찰을 시작하고, 현재 owner 가 정리될 때 관찰을 끝냄 (그때까지 `callback` 도 유지)
+fn observe_until_cleanup<O: Observer, C: 'static>(observer: O, target: &O::Target, callback: C) {
+    observer.observe(target);
+    unsubscribe_on_cleanup(Subscription::new((observer, callback), |(observer, _)| {
+        observer.disconnect()
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    /// 해제된 횟수를 세는 가짜 리스너
+    fn counted(detached: &Rc<Cell<u32>>) -> Subscription<Rc<Cell<u32>>> {
+        Subscription::new(detached.clone(), |detached| {
+            detached.set(detached.get() + 1)
+        })
+    }
+
+    /// 관찰 중인 대상 목록 (가짜 observer)
+    #[derive(Clone, Default)]
+    struct FakeObserver(Rc<RefCell<Vec<&'static str>>>);
+
+    impl Observer for FakeObserver {
+        type Target = &'static str;
+
+        fn observe(&self, target: &&'static str) {
+            self.0.borrow_mut().push(target);
+        }
+
+        fn disconnect(&self) {
+            self.0.borrow_mut().clear();
+        }
+    }
+
+    #[test]
+    fn test_disposing_the_owner_disconnects_the_observer_and_drops_the_callback() {
+        let observer = FakeObserver::default();
+        let callback = Rc::new(());
+        let owner = Owner::new();
+        owner.with(|| observe_until_cleanup(observer.clone(), &"grid", callback.clone()));
+        assert_eq!(*observer.0.borrow(), vec!["grid"]);
+        // 관찰하는 동안 콜백이 살아 있어야 브라우저가 호출할 수 있음
+        assert_eq!(Rc::strong_count(&callback), 2);
+
+        owner.cleanup();
+
+        assert!(observer.0.borrow().is_empty());
+        assert_eq!(Rc::strong_count(&callback), 1);
+    }
+
+    #[test]
+    fn test_disposing_the_owner_unsubscribes_once() {
+        let detached = Rc::new(Cell::new(0));
+        let owner = Owner::new();
+        owner.with(|| unsubscribe_on_cleanup(counted(&detached)));
+        assert_eq!(detached.get(), 0);
+        owner.cleanup();
+        assert_eq!(detached.get(), 1);
+        owner.cleanup();
+        assert_eq!(detached.get(), 1);
+    }
+
+    #[test]
+    fn test_disposing_a_child_owner_leaves_the_parent_subscribed() {
+        let parent_detached = Rc::new(Cell::new(0));
+        let child_detached = Rc::new(Cell::new(0));
+        let parent = Owner::new();
+        let child = parent.with(|| {
+            unsubscribe_on_cleanup(counted(&parent_detached));
+            let child = Owner::new();
+            child.with(|| unsubscribe_on_cleanup(counted(&child_detached)));
+            child
+        });
+        child.cleanup();
+        assert_eq!((parent_detached.get(), child_detached.get()), (0, 1));
+        // 부모가 정리되면 남은 등록도 해제
+        parent.cleanup();
+        assert_eq!((parent_detached.get(), child_detached.get()), (1, 1));
+    }
+}
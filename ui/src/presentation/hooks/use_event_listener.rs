@@ -0,0 +1,127 @@
+use super::unsubscribe_on_cleanup;
+use crate::application::services::subscription::Subscription;
+use leptos::ev::EventDescriptor;
+use leptos::prelude::*;
+use std::borrow::Cow;
+use wasm_bindgen::prelude::*;
+
+/// 리스너를 붙이고 뗄 수 있는 이벤트 대상 (테스트에서는 가짜 대상)
+trait ListenerTarget: 'static {
+    type Callback: 'static;
+    fn add(&self, name: &str, callback: &Self::Callback);
+    fn remove(&self, name: &str, callback: &Self::Callback);
+}
+
+impl ListenerTarget for web_sys::EventTarget {
+    type Callback = Closure<dyn Fn(web_sys::Event)>;
+
+    fn add(&self, name: &str, callback: &Self::Callback) {
+        let _ = self.add_event_listener_with_callback(name, callback.as_ref().unchecked_ref());
+    }
+
+    fn remove(&self, name: &str, callback: &Self::Callback) {
+        let _ = self.remove_event_listener_with_callback(name, callback.as_ref().unchecked_ref());
+    }
+}
+
+/// `target` 의 `event` 를 듣고, 현재 owner 가 정리될 때 리스너를 제거
+///
+/// ```ignore
+/// use_event_listener(container, ev::scroll, move |_| ...);
+/// ```
+pub fn use_event_listener<E>(
+    target: impl Into<web_sys::EventTarget>,
+    event: E,
+    handler: impl Fn(E::EventType) + 'static,
+) where
+    E: EventDescriptor + 'static,
+    E::EventType: JsCast,
+{
+    let target = target.into();
+    let name = event.name();
+    let callback = Closure::<dyn Fn(web_sys::Event)>::new(move |e: web_sys::Event| {
+        handler(e.unchecked_into())
+    });
+    listen(target, name, callback);
+}
+
+/// `window` 의 `event` 를 듣고, 현재 owner 가 정리될 때 리스너를 제거
+pub fn use_window_event_listener<E>(event: E, handler: impl Fn(E::EventType) + 'static)
+where
+    E: EventDescriptor + 'static,
+    E::EventType: JsCast,
+{
+    use_event_listener(window(), event, handler);
+}
+
+/// `target` 에 리스너를 붙이고, 현재 owner 가 정리될 때 같은 리스너를 뗌
+fn listen<T: ListenerTarget>(target: T, name: Cow<'static, str>, callback: T::Callback) {
+    target.add(&name, &callback);
+    unsubscribe_on_cleanup(Subscription::new(
+        (target, name, callback),
+        |(target, name, callback)| target.remove(name, callback),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// 붙어 있는 (이벤트 이름, 콜백 번호) 목록 (가짜 이벤트 대상)
+    #[derive(Clone, Default)]
+    struct FakeTarget(Rc<RefCell<Vec<(String, u32)>>>);
+
+    impl ListenerTarget for FakeTarget {
+        type Callback = u32;
+
+        fn add(&self, name: &str, callback: &u32) {
+            self.0.borrow_mut().push((name.to_string(), *callback));
+        }
+
+        fn remove(&self, name: &str, callback: &u32) {
+            self.0
+                .borrow_mut()
+                .retain(|listener| *listener != (name.to_string(), *callback));
+        }
+    }
+
+    #[test]
+    fn test_disposing_the_owner_removes_the_same_listener() {
+        let target = FakeTarget::default();
+        let other = FakeTarget::default();
+        let owner = Owner::new();
+        owner.with(|| {
+            listen(target.clone(), "scroll".into(), 1);
+            listen(target.clone(), "scroll".into(), 2);
+            // `use_window_event_listener` 도 window 를 대상으로 같은 경로를 씀
+            listen(other.clone(), "popstate".into(), 3);
+        });
+        // 같은 이벤트에 다른 곳에서 붙인 리스너는 그대로 남아야 함
+        target.add("scroll", &9);
+        assert_eq!(target.0.borrow().len(), 3);
+
+        owner.cleanup();
+
+        assert_eq!(*target.0.borrow(), vec![("scroll".to_string(), 9)]);
+        assert!(other.0.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_listener_stays_attached_while_the_owner_lives() {
+        let target = FakeTarget::default();
+        let owner = Owner::new();
+        let child = owner.with(|| {
+            listen(target.clone(), "resize".into(), 1);
+            let child = Owner::new();
+            child.with(|| listen(target.clone(), "keydown".into(), 2));
+            child
+        });
+
+        child.cleanup();
+        assert_eq!(*target.0.borrow(), vec![("resize".to_string(), 1)]);
+        owner.cleanup();
+        assert!(target.0.borrow().is_empty());
+    }
+}
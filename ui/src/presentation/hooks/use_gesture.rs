@@ -0,0 +1,145 @@
+use crate::application::services::gesture_recognizer::{
+    GestureConfig, GestureEvent, GestureRecognizer, PointerSample,
+};
+use gloo_timers::callback::Timeout;
+use leptos::ev;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GestureOptions {
+    pub config: GestureConfig,
+    /// 이동/핀치가 시작되면 포인터를 요소에 캡처 (요소 밖으로 나가도 계속 추적)
+    ///
+    /// 안쪽 요소가 캡처해도 이벤트는 바깥으로 전달되므로, 바깥 요소는 보통 끕니다.
+    pub capture: bool,
+}
+
+/// 요소의 포인터 이벤트 처리기 (`on:pointerdown` 등에 연결)
+#[derive(Clone, Copy)]
+pub struct GestureHandlers {
+    pub down: Callback<ev::PointerEvent>,
+    pub moved: Callback<ev::PointerEvent>,
+    pub up: Callback<ev::PointerEvent>,
+    pub cancel: Callback<ev::PointerEvent>,
+}
+
+/// Pointer Events 로 터치/마우스/펜 제스처를 인식해 `on_gesture` 로 전달
+///
+/// ```ignore
+/// let gesture = use_gesture(GestureOptions::default(), move |event| { ... });
+/// view! {
+///     <div
+///         on:pointerdown=move |e| gesture.down.run(e)
+///         on:pointermove=move |e| gesture.moved.run(e)
+///         on:pointerup=move |e| gesture.up.run(e)
+///         on:pointercancel=move |e| gesture.cancel.run(e)
+///     />
+/// }
+/// ```
+pub fn use_gesture(
+    options: GestureOptions,
+    on_gesture: impl Fn(GestureEvent) + Send + Sync + 'static,
+) -> GestureHandlers {
+    let recognizer = StoredValue::new(GestureRecognizer::new(options.config));
+    let long_press_timer = StoredValue::new_local(None::<Timeout>);
+    let on_gesture = Callback::new(on_gesture);
+
+    let emit = move |events: Vec<GestureEvent>, e: &ev::PointerEvent| {
+        for event in events {
+            if options.capture && starts_tracking(&event) {
+                capture_pointer(e);
+            }
+            if !matches!(event, GestureEvent::Press { .. }) {
+                long_press_timer.set_value(None);
+            }
+            on_gesture.run(event);
+        }
+    };
+
+    let down = Callback::new(move |e: ev::PointerEvent| {
+        // 마우스는 왼쪽 버튼만
+        if e.pointer_type() == "mouse" && e.button() != 0 {
+            return;
+        }
+        let events = recognizer
+            .try_update_value(|r| r.down(sample(&e)))
+            .unwrap_or_default();
+        let is_press = events
+            .iter()
+            .any(|event| matches!(event, GestureEvent::Press { .. }));
+        emit(events, &e);
+        if is_press {
+            start_long_press(recognizer, long_press_timer, on_gesture, options.config);
+        }
+    });
+    let moved = Callback::new(move |e: ev::PointerEvent| {
+        let events = recognizer
+            .try_update_value(|r| r.move_to(sample(&e)))
+            .unwrap_or_default();
+        emit(events, &e);
+    });
+    let up = Callback::new(move |e: ev::PointerEvent| {
+        long_press_timer.set_value(None);
+        let events = recognizer
+            .try_update_value(|r| r.up(sample(&e)))
+            .unwrap_or_default();
+        emit(events, &e);
+    });
+    let cancel = Callback::new(move |e: ev::PointerEvent| {
+        long_press_timer.set_value(None);
+        let events = recognizer
+            .try_update_value(GestureRecognizer::cancel)
+            .unwrap_or_default();
+        emit(events, &e);
+    });
+
+    GestureHandlers {
+        down,
+        moved,
+        up,
+        cancel,
+    }
+}
+
+fn start_long_press(
+    recognizer: StoredValue<GestureRecognizer>,
+    timer: StoredValue<Option<Timeout>, LocalStorage>,
+    on_gesture: Callback<GestureEvent>,
+    config: GestureConfig,
+) {
+    let timeout = Timeout::new(config.long_press_ms, move || {
+        if let Some(event) = recognizer
+            .try_update_value(GestureRecognizer::long_press)
+            .flatten()
+        {
+            on_gesture.run(event);
+        }
+    });
+    timer.set_value(Some(timeout));
+}
+
+fn starts_tracking(event: &GestureEvent) -> bool {
+    matches!(
+        event,
+        GestureEvent::PanStart { .. } | GestureEvent::PinchStart { .. }
+    )
+}
+
+fn capture_pointer(e: &ev::PointerEvent) {
+    if let Some(el) = e
+        .current_target()
+        .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+    {
+        let _ = el.set_pointer_capture(e.pointer_id());
+    }
+}
+
+fn sample(e: &ev::PointerEvent) -> PointerSample {
+    PointerSample {
+        id: e.pointer_id(),
+        x: e.client_x() as f64,
+        y: e.client_y() as f64,
+        time: e.time_stamp(),
+    }
+}
@@ -0,0 +1,42 @@
+use super::{observe_until_cleanup, Observer};
+use wasm_bindgen::prelude::*;
+use web_sys::{IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+type IntersectionCallback = Closure<dyn Fn(Vec<IntersectionObserverEntry>, IntersectionObserver)>;
+
+/// `target` 이 화면(`root_margin` 여유 포함)에 들어오거나 나가면 `on_change(보이는지)` 호출
+///
+/// 관찰을 시작하면 현재 교차 상태를 한 번 알려주며, 현재 owner 가 정리될 때 관찰을 끝냅니다.
+pub fn use_intersection_observer(
+    target: &web_sys::Element,
+    root_margin: &str,
+    on_change: impl Fn(bool) + 'static,
+) {
+    let callback = IntersectionCallback::new(
+        move |entries: Vec<IntersectionObserverEntry>, _: IntersectionObserver| {
+            if let Some(entry) = entries.last() {
+                on_change(entry.is_intersecting());
+            }
+        },
+    );
+    let options = IntersectionObserverInit::new();
+    options.set_root_margin(root_margin);
+    let Ok(observer) =
+        IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &options)
+    else {
+        return;
+    };
+    observe_until_cleanup(observer, target, callback);
+}
+
+impl Observer for IntersectionObserver {
+    type Target = web_sys::Element;
+
+    fn observe(&self, target: &web_sys::Element) {
+        IntersectionObserver::observe(self, target);
+    }
+
+    fn disconnect(&self) {
+        IntersectionObserver::disconnect(self);
+    }
+}
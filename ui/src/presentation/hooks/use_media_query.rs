@@ -0,0 +1,15 @@
+use super::use_event_listener::use_event_listener;
+use leptos::ev;
+use leptos::prelude::*;
+
+/// CSS 미디어 쿼리(`"(pointer: fine)"` 등)가 맞는지, 바뀌면 갱신
+pub fn use_media_query(query: &str) -> Signal<bool> {
+    let list = window().match_media(query).ok().flatten();
+    let (matches, set_matches) = signal(list.as_ref().is_some_and(|l| l.matches()));
+    if let Some(list) = list {
+        use_event_listener(list.clone(), ev::change, move |_| {
+            set_matches.set(list.matches())
+        });
+    }
+    matches.into()
+}
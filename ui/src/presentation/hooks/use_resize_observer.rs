@@ -0,0 +1,27 @@
+use super::{observe_until_cleanup, Observer};
+use wasm_bindgen::prelude::*;
+use web_sys::ResizeObserver;
+
+/// `target` 의 크기가 바뀔 때마다 `on_resize` 호출 (한 프레임에 한 번)
+///
+/// 창 크기뿐 아니라 레이아웃 변화로 요소만 커지거나 줄어도 알 수 있습니다.
+/// 관찰을 시작하면 한 번 호출되며, 현재 owner 가 정리될 때 관찰을 끝냅니다.
+pub fn use_resize_observer(target: &web_sys::Element, on_resize: impl Fn() + 'static) {
+    let callback = Closure::<dyn Fn()>::new(on_resize);
+    let Ok(observer) = ResizeObserver::new(callback.as_ref().unchecked_ref()) else {
+        return;
+    };
+    observe_until_cleanup(observer, target, callback);
+}
+
+impl Observer for ResizeObserver {
+    type Target = web_sys::Element;
+
+    fn observe(&self, target: &web_sys::Element) {
+        ResizeObserver::observe(self, target);
+    }
+
+    fn disconnect(&self) {
+        ResizeObserver::disconnect(self);
+    }
+}
@@ -1,7 +1,25 @@
+use crate::presentation::components::common::nav_tabs::NavTabs;
+use crate::presentation::components::photo::grid_mode_toggle::GridModeToggle;
+use crate::presentation::components::search::search_bar::SearchBar;
+use crate::presentation::hooks::use_resize_observer::use_resize_observer;
+use crate::presentation::view_models::grid_mode_vm::GridModeViewModel;
+use crate::presentation::view_models::scroll_vm::ScrollViewModel;
 use leptos::prelude::*;
 
 #[component]
 pub fn MainLayout(children: Children) -> impl IntoView {
+    // 메인 스크롤 영역 (가상 그리드가 보이는 범위를 계산할 때 사용)
+    let scroll = ScrollViewModel::new();
+    provide_context(scroll);
+    Effect::new(move |_| {
+        if let Some(main) = scroll.node.get() {
+            use_resize_observer(&main, move || scroll.measure());
+        }
+    });
+
+    // 갤러리 그리드 배치 방식 (헤더 토글)
+    provide_context(GridModeViewModel::new());
+
     view! {
         <div class="h-dvh w-full flex flex-col bg-gray-50 overflow-hidden pt-[env(safe-area-inset-top)] pb-[env(safe-area-inset-bottom)] pl-[env(safe-area-inset-left)] pr-[env(safe-area-inset-right)]">
             // Fixed Header (Title Bar)
@@ -9,15 +27,21 @@ pub fn MainLayout(children: Children) -> impl IntoView {
                 <div class="w-full px-4 h-14 flex items-center justify-between">
                     <div class="flex items-center space-x-4">
                         <h1 class="text-lg font-bold text-gray-800 tracking-tight">"PhotoVault"</h1>
+                        <NavTabs />
                     </div>
                     <div class="flex items-center space-x-2">
-                        // Placeholder for right-aligned actions
+                        <GridModeToggle />
+                        <SearchBar />
                     </div>
                 </div>
             </header>
 
             // Scrollable Main Content
-            <main class="flex-1 overflow-y-auto overflow-x-hidden">
+            <main
+                node_ref=scroll.node
+                class="flex-1 overflow-y-auto overflow-x-hidden"
+                on:scroll=move |_| scroll.measure()
+            >
                  {children()}
             </main>
         </div>
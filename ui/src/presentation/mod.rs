@@ -1,4 +1,5 @@
 pub mod components;
+pub mod hooks;
 pub mod layouts;
 pub mod pages;
 pub mod view_models;
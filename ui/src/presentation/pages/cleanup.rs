@@ -0,0 +1,105 @@
+use crate::domain::value_objects::cleanup::CleanupSuggestion;
+use crate::presentation::view_models::cleanup_vm::CleanupViewModel;
+use leptos::prelude::*;
+
+/// 흐리거나 노출이 크게 어긋난 사진 정리 제안
+///
+/// 가장 나쁜 점수 순으로 보여주며, 선택한 사진을 한 번에 휴지통으로 보냅니다.
+#[component]
+pub fn CleanupPage() -> impl IntoView {
+    let vm = CleanupViewModel::new();
+    provide_context(vm);
+    vm.load();
+
+    view! {
+        <div class="w-full md:container md:mx-auto px-2 md:px-4 py-4 md:py-8">
+            <div class="mb-4 md:mb-6 px-1 flex items-center justify-between">
+                <div>
+                    <h1 class="text-2xl md:text-3xl font-bold text-gray-800">"Cleanup"</h1>
+                    <p class="text-sm text-gray-500">"Blurry and badly exposed shots, worst first."</p>
+                </div>
+                <CleanupToolbar />
+            </div>
+            <SuggestionGrid />
+        </div>
+    }
+}
+
+#[component]
+fn CleanupToolbar() -> impl IntoView {
+    let vm = use_context::<CleanupViewModel>().expect("CleanupViewModel required");
+    let selected_count = move || vm.state.get().selected.len();
+    let has_suggestions = move || !vm.state.get().suggestions.is_empty();
+
+    view! {
+        <Show when=has_suggestions>
+            <div class="flex items-center gap-2 text-sm">
+                <button
+                    class="px-3 py-1 rounded-full border border-gray-300 hover:bg-gray-100"
+                    on:click=move |_| vm.toggle_all()
+                >
+                    "Select all"
+                </button>
+                <button
+                    class="px-3 py-1 rounded-full bg-red-600 text-white disabled:opacity-40"
+                    disabled=move || selected_count() == 0 || vm.state.get().is_saving
+                    on:click=move |_| vm.trash_selected()
+                >
+                    {move || format!("Trash {}", selected_count())}
+                </button>
+            </div>
+        </Show>
+    }
+}
+
+#[component]
+fn SuggestionGrid() -> impl IntoView {
+    let vm = use_context::<CleanupViewModel>().expect("CleanupViewModel required");
+
+    move || {
+        let state = vm.state.get();
+        if let Some(error) = state.error {
+            view! { <p class="px-1 text-sm text-red-500">{error}</p> }.into_any()
+        } else if state.is_loading && state.suggestions.is_empty() {
+            view! { <div class="mx-auto animate-spin rounded-full h-8 w-8 border-b-2 border-gray-900"></div> }.into_any()
+        } else if state.suggestions.is_empty() {
+            view! { <p class="px-1 text-gray-500">"Nothing to clean up."</p> }.into_any()
+        } else {
+            view! {
+                <div class="grid grid-cols-2 sm:grid-cols-3 md:grid-cols-4 lg:grid-cols-6 gap-2 md:gap-4">
+                    <For
+                        each=move || vm.state.get().suggestions
+                        key=|suggestion| suggestion.photo.id
+                        children=move |suggestion| view! { <SuggestionCard suggestion=suggestion /> }
+                    />
+                </div>
+            }.into_any()
+        }
+    }
+}
+
+#[component]
+fn SuggestionCard(suggestion: CleanupSuggestion) -> impl IntoView {
+    let vm = use_context::<CleanupViewModel>().expect("CleanupViewModel required");
+    let photo_id = suggestion.photo.id;
+    let is_selected = move || vm.state.with(|s| s.selected.contains(&photo_id));
+
+    view! {
+        <button
+            class="relative aspect-square rounded-lg overflow-hidden bg-gray-200 ring-offset-2"
+            class=("ring-4", is_selected)
+            class=("ring-red-500", is_selected)
+            on:click=move |_| vm.toggle(photo_id)
+        >
+            <img src=suggestion.photo.url class="w-full h-full object-cover" loading="lazy" />
+            <div class="absolute left-1 top-1 flex flex-wrap gap-1">
+                {suggestion.issues.into_iter().map(|issue| view! {
+                    <span class="px-2 py-0.5 rounded-full bg-black/70 text-white text-xs">{issue.label()}</span>
+                }).collect_view()}
+            </div>
+            <Show when=is_selected>
+                <div class="absolute inset-0 flex items-center justify-center bg-red-500/30 text-2xl text-white">"🗑"</div>
+            </Show>
+        </button>
+    }
+}
@@ -0,0 +1,52 @@
+use crate::presentation::components::duplicates::duplicate_group::DuplicateGroupRow;
+use crate::presentation::view_models::duplicates_vm::DuplicatesViewModel;
+use leptos::prelude::*;
+
+/// 지각 해시로 찾은 유사 중복 사진 검토
+///
+/// 그룹마다 가장 좋은 사본(해상도, 파일 크기 순)을 남기고 나머지를 버리도록 미리 선택해 둡니다.
+#[component]
+pub fn DuplicatesPage() -> impl IntoView {
+    let vm = DuplicatesViewModel::new();
+    provide_context(vm);
+    vm.load();
+
+    view! {
+        <div class="w-full md:container md:mx-auto px-2 md:px-4 py-4 md:py-8">
+            <div class="mb-4 md:mb-6 px-1">
+                <h1 class="text-2xl md:text-3xl font-bold text-gray-800">"Duplicates"</h1>
+                <p class="text-sm text-gray-500">"Near-identical photos, best copy first."</p>
+            </div>
+            <DuplicateList />
+        </div>
+    }
+}
+
+#[component]
+fn DuplicateList() -> impl IntoView {
+    let vm = use_context::<DuplicatesViewModel>().expect("DuplicatesViewModel required");
+
+    move || {
+        let state = vm.state.get();
+        if let Some(error) = state.error {
+            view! { <p class="px-1 text-sm text-red-500">{error}</p> }.into_any()
+        } else if state.is_loading && state.groups.is_empty() {
+            view! { <div class="mx-auto animate-spin rounded-full h-8 w-8 border-b-2 border-gray-900"></div> }.into_any()
+        } else if state.groups.is_empty() {
+            view! { <p class="px-1 text-gray-500">"No duplicates found."</p> }.into_any()
+        } else {
+            view! {
+                <div class="flex flex-col gap-4">
+                    {state.truncated.then(|| view! {
+                        <p class="px-1 text-sm text-amber-600">
+                            "Showing the closest matches only. Clean these up and reload to review more."
+                        </p>
+                    })}
+                    {state.groups.into_iter().enumerate().map(|(index, group)| view! {
+                        <DuplicateGroupRow group=group index=index />
+                    }).collect_view()}
+                </div>
+            }.into_any()
+        }
+    }
+}
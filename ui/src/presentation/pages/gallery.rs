@@ -1,123 +1,171 @@
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::infrastructure::api::album_api::fetch_album;
 use crate::presentation::components::common::pull_to_refresh::PullToRefresh;
-use crate::presentation::components::photo::photo_card::PhotoCard;
 use crate::presentation::components::photo::photo_preview_modal::PhotoPreviewModal;
+use crate::presentation::components::photo::selection_toolbar::SelectionToolbar;
+use crate::presentation::components::photo::virtual_photo_grid::VirtualPhotoGrid;
+use crate::presentation::components::search::color_filter::ColorFilter;
+use crate::presentation::components::search::exif_filter_panel::ExifFilterPanel;
+use crate::presentation::components::search::quality_filter_bar::QualityFilterBar;
+use crate::presentation::components::search::search_filter_chips::SearchFilterChips;
+use crate::presentation::components::timeline::timeline_scrubber::TimelineScrubber;
+use crate::presentation::hooks::use_event_listener::use_window_event_listener;
+use crate::presentation::hooks::use_intersection_observer::use_intersection_observer;
+use crate::presentation::view_models::bulk_actions_vm::BulkActionsViewModel;
 use crate::presentation::view_models::gallery_vm::GalleryViewModel;
+use crate::presentation::view_models::navigation_vm::NavigationViewModel;
+use crate::presentation::view_models::search_vm::SearchViewModel;
+use crate::presentation::view_models::timeline_vm::TimelineViewModel;
+use leptos::ev;
 use leptos::html;
 use leptos::prelude::*;
-use wasm_bindgen::prelude::*;
-use web_sys::{IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+use uuid::Uuid;
 
 #[component]
 pub fn GalleryPage() -> impl IntoView {
     // 1. ViewModel 생성 (Provider Pattern)
-    let vm = GalleryViewModel::new();
+    let navigation = use_context::<NavigationViewModel>().expect("NavigationViewModel required");
+    let vm = GalleryViewModel::new(navigation);
     provide_context(vm);
+    let timeline_vm = TimelineViewModel::new();
+    provide_context(timeline_vm);
+    provide_context(BulkActionsViewModel::new(vm));
 
     // Sentinel Element Reference
     let load_trigger = NodeRef::<html::Div>::new();
 
-    // 2. 초기 데이터 로드 & Intersection Observer 설정
-    Effect::new(move |_| {
-        // 초기 로드
-        vm.load_more();
+    // 앨범 경로(`/albums/:id`, 앨범에서 연 미리보기 `/albums/:id/photo/:id`)의 앨범
+    let album_id = Memo::new(move |_| navigation.route.get().album());
+    let album_title = album_title(album_id);
+
+    // 헤더 검색창에서 적용한 검색 조건 (앨범 경로면 앨범 조건 추가)
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let active_filter = Memo::new(move |_| {
+        let filter = search_vm.state.get().active_filter;
+        match album_id.get() {
+            Some(id) => Some(SearchFilter {
+                album_id: Some(id),
+                ..filter.unwrap_or_default()
+            }),
+            None => filter,
+        }
     });
 
-    // PopState(뒤로가기) 이벤트 리스너
-    Effect::new(move |_| {
-        let handle_popstate = move |_| {
-            vm.sync_on_popstate();
-        };
-
-        let closure = Closure::wrap(Box::new(handle_popstate) as Box<dyn FnMut(web_sys::Event)>);
-        
-        if let Some(window) = web_sys::window() {
-            let _ = window.add_event_listener_with_callback("popstate", closure.as_ref().unchecked_ref());
+    // 2. 초기 데이터 로드 & Intersection Observer 설정
+    Effect::new(move |previous: Option<()>| {
+        // 초기 로드 (검색 조건이 바뀔 때마다 처음부터 다시 로드)
+        // 처음에는 같은 조건으로 저장해 둔 목록이 있으면 보던 위치 그대로 복원
+        let filter = active_filter.get();
+        timeline_vm.load(filter.clone());
+        if previous.is_some() || !vm.restore(&filter) {
+            vm.apply_filter(filter);
         }
-
-        // Cleanup function (Leptos Effects can return a cleanup closure, but here we keep it simple or let it leak for page lifetime)
-        // In a real app, use `leptos-use` or proper cleanup.
-        // For this page which is likely always mounted or main, it is acceptable.
-        closure.forget(); 
     });
 
-    // Observer 연결 Effect
-    Effect::new(move |prev_observer: Option<Option<IntersectionObserver>>| {
-        // Clean up previous observer if exists
-        if let Some(Some(observer)) = prev_observer {
-            observer.disconnect();
-        }
+    // 다른 화면으로 옮기거나 새로고침할 때 목록과 보던 위치 저장
+    on_cleanup(move || vm.save_snapshot());
+    use_window_event_listener(ev::pagehide, move |_| vm.save_snapshot());
+
+    // 주소창 경로(`/photo/:id`)에 맞춰 미리보기 열기/닫기
+    Effect::new(move |_| vm.show_route(navigation.route.get()));
 
-        let trigger_el = load_trigger.get();
-        if let Some(el) = trigger_el {
-            // Callback Closure
-            let callback = move |entries: Vec<IntersectionObserverEntry>,
-                                 _: IntersectionObserver| {
-                if entries.first().is_some_and(|entry| entry.is_intersecting()) {
+    // 목록 끝의 트리거가 보이면 다음 페이지 요청
+    Effect::new(move |_| {
+        // 페이지를 불러올 때마다 다시 관찰: 새 observer 는 처음에 현재 교차 상태를 알려주므로
+        // 불러온 뒤에도 트리거가 화면 안에 있으면 이어서 다음 페이지를 요청
+        vm.photos.with(Vec::len);
+        if let Some(el) = load_trigger.get() {
+            // 미리 로드하기 위해 200px 여유
+            use_intersection_observer(&el, "200px", move |is_visible| {
+                if is_visible {
                     vm.load_more();
                 }
-            };
-
-            // Rust Closure -> JS Function
-            let closure = Closure::wrap(Box::new(callback)
-                as Box<dyn FnMut(Vec<IntersectionObserverEntry>, IntersectionObserver)>);
-            let js_callback = closure.as_ref().unchecked_ref();
-
-            // Create Observer
-            let options = IntersectionObserverInit::new();
-            options.set_root_margin("200px"); // 미리 로드하기 위해 200px 여유
-
-            let observer = IntersectionObserver::new_with_options(js_callback, &options)
-                .expect("Failed to create IntersectionObserver");
-
-            observer.observe(&el);
-
-            // Keep closure alive properly (Leptos handles cleanup usually but manual management is safer here or utilize leptos-use in real world)
-            closure.forget();
-
-            return Some(observer);
+            });
         }
-        None
     });
 
     view! {
         <PullToRefresh on_refresh=move || async move { vm.refresh().await }>
-            <div class="w-full md:container md:mx-auto px-2 md:px-4 py-4 md:py-8">
-                <h1 class="text-2xl md:text-3xl font-bold text-gray-800 mb-4 md:mb-6 px-1">"My Gallery"</h1>
-
-                {move || {
-                    let state = vm.state.get();
-
-                    view! {
-                        <div>
-                                                    // Modal (Overlay)
-                                                    <PhotoPreviewModal 
-                                                        on_close=Callback::new(move |_| vm.close_preview()) 
-                                                    />
-                                                        <div class="grid grid-cols-2 sm:grid-cols-3 md:grid-cols-4 lg:grid-cols-5 xl:grid-cols-6 gap-2 md:gap-4">
-                                {state.photos.into_iter()
-                                    .map(|photo| view! { <PhotoCard photo=photo /> })
-                                    .collect_view()}
-                            </div>
-
-                            // Load More Trigger Sentinel
-                            <div
-                                node_ref=load_trigger
-                                class="h-20 flex justify-center items-center mt-8"
-                            >
-                                {if state.is_loading {
-                                    view! {
-                                        <div class="animate-spin rounded-full h-8 w-8 border-b-2 border-gray-900"></div>
-                                    }.into_any()
-                                } else if !state.has_more {
-                                    view! { <span class="text-gray-500">"No more photos"</span> }.into_any()
-                                } else {
-                                    view! { <span class="text-transparent">"Loading trigger"</span> }.into_any()
-                                }}
-                            </div>
-                        </div>
-                    }
-                }}
+            <TimelineScrubber />
+            <div class="w-full md:container md:mx-auto pl-2 pr-8 md:pl-4 md:pr-12 py-4 md:py-8">
+                <div class="mb-4 md:mb-6 px-1">
+                    <h1 class="text-2xl md:text-3xl font-bold text-gray-800">
+                        {move || match album_title.get() {
+                            Some(title) => title,
+                            None if active_filter.get().is_some() => "Search Results".to_string(),
+                            None => "My Gallery".to_string(),
+                        }}
+                    </h1>
+                    <SearchFilterChips />
+                    <ColorFilter />
+                    <QualityFilterBar />
+                    <ExifFilterPanel />
+                </div>
+
+                // Modal (Overlay)
+                <PhotoPreviewModal
+                    on_close=Callback::new(move |_| vm.close_preview())
+                />
+
+                // 선택 모드 하단 도구 모음
+                <SelectionToolbar />
+
+                // 달/날짜별 구간 (보이는 행만 그리는 가상 그리드)
+                <VirtualPhotoGrid />
+
+                // Load More Trigger Sentinel (그리드 전체 높이 바로 아래에 위치)
+                <div
+                    node_ref=load_trigger
+                    class="h-20 flex justify-center items-center mt-8"
+                >
+                    {move || {
+                        let status = vm.status.get();
+                        if status.is_loading {
+                            view! {
+                                <div class="animate-spin rounded-full h-8 w-8 border-b-2 border-gray-900"></div>
+                            }.into_any()
+                        } else if let Some(error) = status.error {
+                            view! {
+                                <div class="flex flex-col items-center gap-2 text-sm">
+                                    <span class="text-red-500">{error}</span>
+                                    <button
+                                        class="px-3 py-1 rounded border border-gray-300 text-gray-700 hover:bg-gray-100"
+                                        on:click=move |_| vm.load_more()
+                                    >
+                                        "Retry"
+                                    </button>
+                                </div>
+                            }.into_any()
+                        } else if !status.has_more && vm.photos.with(Vec::is_empty) {
+                            view! { <span class="text-gray-500">"No photos found"</span> }.into_any()
+                        } else if !status.has_more {
+                            view! { <span class="text-gray-500">"No more photos"</span> }.into_any()
+                        } else {
+                            view! { <span class="text-transparent">"Loading trigger"</span> }.into_any()
+                        }
+                    }}
+                </div>
             </div>
         </PullToRefresh>
     }
 }
+
+/// 앨범 경로일 때 제목으로 보여줄 앨범 이름
+fn album_title(album_id: Memo<Option<Uuid>>) -> ReadSignal<Option<String>> {
+    let (title, set_title) = signal(None);
+    Effect::new(move |_| {
+        let Some(id) = album_id.get() else {
+            set_title.set(None);
+            return;
+        };
+        leptos::task::spawn_local(async move {
+            let title = fetch_album(id)
+                .await
+                .map_or_else(|_| "Album".to_string(), |album| album.title);
+            if album_id.get_untracked() == Some(id) {
+                set_title.set(Some(title));
+            }
+        });
+    });
+    title
+}
@@ -0,0 +1,64 @@
+use crate::domain::value_objects::geo_area::{GeoArea, GeoCluster};
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::presentation::components::map::slippy_map::SlippyMap;
+use crate::presentation::view_models::map_vm::MapViewModel;
+use crate::presentation::view_models::navigation_vm::{AppPage, NavigationViewModel};
+use crate::presentation::view_models::search_vm::SearchViewModel;
+use leptos::prelude::*;
+
+/// 클러스터 영역을 넓히는 정도 (한 지점뿐인 클러스터도 검색되도록, 약 100m)
+const CLUSTER_PADDING_DEGREES: f64 = 0.001;
+
+#[component]
+pub fn MapPage() -> impl IntoView {
+    let vm = MapViewModel::new();
+    provide_context(vm);
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let navigation = use_context::<NavigationViewModel>().expect("NavigationViewModel required");
+
+    // 지도 영역을 뺀 나머지 검색 조건 (바뀌면 클러스터 다시 조회)
+    let filter = Memo::new(move |_| SearchFilter {
+        area: None,
+        ..search_vm.state.get().active_filter.unwrap_or_default()
+    });
+    Effect::new(move |_| vm.load_clusters(filter.get()));
+
+    // 클러스터를 누르면 해당 영역의 사진을 갤러리 그리드로 표시
+    let open_cluster = Callback::new(move |cluster: GeoCluster| {
+        let area = GeoArea::BoundingBox(cluster.bounds.padded(CLUSTER_PADDING_DEGREES));
+        navigation.navigate(AppPage::Gallery);
+        search_vm.update_active(|f| f.area = Some(area));
+    });
+
+    view! {
+        <div class="h-full flex flex-col">
+            <div class="flex-none px-4 py-3 flex items-center justify-between">
+                <h1 class="text-2xl md:text-3xl font-bold text-gray-800">"Map"</h1>
+                <MapStatus />
+            </div>
+            <div class="flex-1 min-h-0">
+                <SlippyMap
+                    on_settle=Callback::new(move |_| vm.load_clusters(filter.get_untracked()))
+                    on_cluster_click=open_cluster
+                />
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn MapStatus() -> impl IntoView {
+    let vm = use_context::<MapViewModel>().expect("MapViewModel required");
+
+    move || {
+        let state = vm.state.get();
+        if let Some(error) = state.error {
+            view! { <span class="text-sm text-red-500">{error}</span> }.into_any()
+        } else if state.is_loading {
+            view! { <span class="text-sm text-gray-400">"Loading…"</span> }.into_any()
+        } else {
+            let count: i64 = state.clusters.iter().map(|c| c.count).sum();
+            view! { <span class="text-sm text-gray-500">{format!("{} photos in view", count)}</span> }.into_any()
+        }
+    }
+}
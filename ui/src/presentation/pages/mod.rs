@@ -1 +1,6 @@
+pub mod cleanup;
+pub mod duplicates;
 pub mod gallery;
+pub mod map;
+pub mod people;
+pub mod places;
@@ -0,0 +1,118 @@
+use crate::application::services::route_parser::Route;
+use crate::domain::value_objects::person::Person;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::presentation::components::people::person_card::PersonCard;
+use crate::presentation::components::people::person_detail::PersonDetail;
+use crate::presentation::view_models::navigation_vm::{AppPage, NavigationViewModel};
+use crate::presentation::view_models::people_vm::PeopleViewModel;
+use crate::presentation::view_models::search_vm::SearchViewModel;
+use leptos::prelude::*;
+
+/// 얼굴 군집으로 묶인 인물 목록
+///
+/// 인물을 누르면 이름 편집/얼굴 분리 패널이 열리고(`/people/:id`), 선택 모드에서는 여러 인물을 하나로 합칩니다.
+#[component]
+pub fn PeoplePage() -> impl IntoView {
+    let vm = PeopleViewModel::new();
+    provide_context(vm);
+    vm.load();
+
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let navigation = use_context::<NavigationViewModel>().expect("NavigationViewModel required");
+    // 주소창 경로에 맞춰 상세 패널 열기/닫기
+    Effect::new(move |_| match navigation.route.get() {
+        Route::Person(person_id) => vm.open(person_id),
+        _ => vm.close(),
+    });
+    let close_person = Callback::new(move |_: ()| navigation.push(Route::People));
+    let show_photos = Callback::new(move |person: Person| {
+        navigation.navigate(AppPage::Gallery);
+        search_vm.apply(SearchFilter {
+            person: Some(person.to_ref()),
+            ..SearchFilter::default()
+        });
+    });
+
+    view! {
+        <div class="w-full md:container md:mx-auto px-2 md:px-4 py-4 md:py-8">
+            <div class="mb-4 md:mb-6 px-1 flex items-center justify-between">
+                <h1 class="text-2xl md:text-3xl font-bold text-gray-800">"People"</h1>
+                <MergeToolbar />
+            </div>
+            {move || vm.state.get().open_person().map(|person| view! {
+                <PersonDetail person=person on_show_photos=show_photos on_close=close_person />
+            })}
+            <PeopleGrid />
+        </div>
+    }
+}
+
+#[component]
+fn MergeToolbar() -> impl IntoView {
+    let vm = use_context::<PeopleViewModel>().expect("PeopleViewModel required");
+    let is_selecting = move || vm.state.get().is_selecting;
+    let selected_count = move || vm.state.get().selected_people.len();
+
+    view! {
+        <div class="flex items-center gap-2 text-sm">
+            <Show when=is_selecting>
+                <button
+                    class="px-3 py-1 rounded-full bg-blue-600 text-white disabled:opacity-40"
+                    disabled=move || selected_count() < 2 || vm.state.get().is_saving
+                    on:click=move |_| vm.merge_selected()
+                >
+                    {move || format!("Merge {}", selected_count())}
+                </button>
+            </Show>
+            <button
+                class="px-3 py-1 rounded-full border border-gray-300 hover:bg-gray-100"
+                on:click=move |_| vm.toggle_selecting()
+            >
+                {move || if is_selecting() { "Cancel" } else { "Select" }}
+            </button>
+        </div>
+    }
+}
+
+#[component]
+fn PeopleGrid() -> impl IntoView {
+    let vm = use_context::<PeopleViewModel>().expect("PeopleViewModel required");
+
+    move || {
+        let state = vm.state.get();
+        if let Some(error) = state.error {
+            view! { <p class="px-1 text-sm text-red-500">{error}</p> }.into_any()
+        } else if state.is_loading && state.people.is_empty() {
+            view! { <div class="mx-auto animate-spin rounded-full h-8 w-8 border-b-2 border-gray-900"></div> }.into_any()
+        } else if state.people.is_empty() {
+            view! { <p class="px-1 text-gray-500">"No people yet. Faces will be grouped here once photos are processed."</p> }.into_any()
+        } else {
+            view! {
+                <div class="grid grid-cols-3 sm:grid-cols-4 md:grid-cols-6 lg:grid-cols-8 gap-2 md:gap-4">
+                    {state.people.into_iter().map(|person| view! { <PeopleGridItem person=person /> }).collect_view()}
+                </div>
+            }.into_any()
+        }
+    }
+}
+
+#[component]
+fn PeopleGridItem(person: Person) -> impl IntoView {
+    let vm = use_context::<PeopleViewModel>().expect("PeopleViewModel required");
+    let navigation = use_context::<NavigationViewModel>().expect("NavigationViewModel required");
+    let person_id = person.id;
+    let is_selected = Signal::derive(move || vm.state.get().selected_people.contains(&person_id));
+    let is_open = Signal::derive(move || vm.state.get().open_person == Some(person_id));
+    let on_click = Callback::new(move |_: ()| {
+        if vm.state.get_untracked().is_selecting {
+            vm.toggle_person(person_id);
+        } else if is_open.get_untracked() {
+            // 같은 인물을 다시 누르면 접기
+            navigation.push(Route::People);
+        } else {
+            navigation.push(Route::Person(person_id));
+        }
+    });
+
+    view! { <PersonCard person=person is_selected=is_selected is_open=is_open on_click=on_click /> }
+}
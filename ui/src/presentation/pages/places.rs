@@ -0,0 +1,92 @@
+use crate::domain::value_objects::place::{CityPlace, CountryPlaces};
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::presentation::view_models::navigation_vm::{AppPage, NavigationViewModel};
+use crate::presentation::view_models::places_vm::PlacesViewModel;
+use crate::presentation::view_models::search_vm::SearchViewModel;
+use leptos::prelude::*;
+
+/// 역지오코딩된 위치명을 국가 → 도시 순으로 둘러보는 화면
+///
+/// 국가나 도시를 누르면 해당 장소의 사진을 갤러리에서 보여줍니다.
+#[component]
+pub fn PlacesPage() -> impl IntoView {
+    let vm = PlacesViewModel::new();
+    vm.load();
+
+    let search_vm = use_context::<SearchViewModel>().expect("SearchViewModel required");
+    let navigation = use_context::<NavigationViewModel>().expect("NavigationViewModel required");
+    let open_place = Callback::new(move |place: String| {
+        // 먼저 이동해야 장소 화면 주소가 이전 검색 조건 그대로 히스토리에 남음
+        navigation.navigate(AppPage::Gallery);
+        search_vm.apply(SearchFilter {
+            place: Some(place),
+            ..SearchFilter::default()
+        });
+    });
+
+    view! {
+        <div class="w-full md:container md:mx-auto px-2 md:px-4 py-4 md:py-8">
+            <h1 class="mb-4 md:mb-6 px-1 text-2xl md:text-3xl font-bold text-gray-800">"Places"</h1>
+            {move || {
+                let state = vm.state.get();
+                if let Some(error) = state.error {
+                    view! { <p class="px-1 text-sm text-red-500">{error}</p> }.into_any()
+                } else if state.is_loading && state.countries.is_empty() {
+                    view! { <div class="mx-auto animate-spin rounded-full h-8 w-8 border-b-2 border-gray-900"></div> }.into_any()
+                } else if state.countries.is_empty() {
+                    view! { <p class="px-1 text-gray-500">"No places yet. Photos with GPS data will appear here once processed."</p> }.into_any()
+                } else {
+                    state.countries.into_iter()
+                        .map(|country| view! { <CountrySection country=country on_open=open_place /> })
+                        .collect_view()
+                        .into_any()
+                }
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn CountrySection(country: CountryPlaces, on_open: Callback<String>) -> impl IntoView {
+    let name = country.country.clone();
+
+    view! {
+        <section class="mb-8">
+            <button
+                class="flex items-baseline gap-2 mb-3 px-1 hover:underline"
+                on:click=move |_| on_open.run(name.clone())
+            >
+                <span class="text-xl">{country.flag()}</span>
+                <h2 class="text-lg font-semibold text-gray-800">{country.country.clone()}</h2>
+                <span class="text-sm text-gray-500">{country.count}</span>
+            </button>
+            <div class="grid grid-cols-2 sm:grid-cols-3 md:grid-cols-4 lg:grid-cols-6 gap-2 md:gap-4">
+                {country.cities.into_iter()
+                    .map(|city| view! { <CityCard city=city on_open=on_open /> })
+                    .collect_view()}
+            </div>
+        </section>
+    }
+}
+
+#[component]
+fn CityCard(city: CityPlace, on_open: Callback<String>) -> impl IntoView {
+    let location_name = city.location_name.clone();
+
+    view! {
+        <button
+            class="relative aspect-square rounded-lg overflow-hidden bg-gray-200 text-left group"
+            on:click=move |_| on_open.run(location_name.clone())
+        >
+            <img
+                src=city.cover_thumbnail_url
+                class="w-full h-full object-cover group-hover:scale-105 transition-transform"
+                loading="lazy"
+            />
+            <div class="absolute inset-x-0 bottom-0 p-2 bg-gradient-to-t from-black/70 to-transparent text-white">
+                <div class="text-sm font-semibold truncate">{city.city}</div>
+                <div class="text-xs opacity-80 truncate">{format!("{} · {}", city.region, city.count)}</div>
+            </div>
+        </button>
+    }
+}
@@ -0,0 +1,220 @@
+use crate::domain::value_objects::album::Album;
+use crate::infrastructure::api::album_api::{add_photos_to_album, create_album, fetch_albums};
+use crate::infrastructure::api::duplicate_api::trash_photos;
+use crate::infrastructure::api::photo_batch_api::{download_photos, set_favorites, tag_photos};
+use crate::infrastructure::api::api_client::get_bytes;
+use crate::infrastructure::browser::file_download::download_url;
+use crate::infrastructure::browser::web_share::{share_files, ShareOutcome, SharedFile};
+use crate::presentation::view_models::gallery_vm::GalleryViewModel;
+use leptos::prelude::*;
+use std::future::Future;
+
+/// 한 번에 공유할 수 있는 최대 사진 수 (미리보기 이미지를 받아 파일로 보냄)
+const MAX_SHARE_PHOTOS: usize = 10;
+
+/// 선택 도구 모음에서 펼치는 입력 패널
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BulkPanel {
+    Album,
+    Tag,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BulkActionsState {
+    pub panel: Option<BulkPanel>,
+    pub albums: Vec<Album>,
+    pub is_working: bool,
+    /// 마지막 작업 결과 (예: "Added 3 photos to Trip")
+    pub message: Option<String>,
+    pub error: Option<String>,
+}
+
+/// 선택한 사진에 대한 일괄 작업 (좋아요, 앨범, 태그, ZIP 다운로드, 공유, 삭제)
+///
+/// 선택 자체는 `GalleryViewModel` 에 있고, 여기서는 작업 진행 상태와 결과만 관리합니다.
+#[derive(Clone, Copy)]
+pub struct BulkActionsViewModel {
+    pub state: ReadSignal<BulkActionsState>,
+    set_state: WriteSignal<BulkActionsState>,
+    gallery: GalleryViewModel,
+}
+
+impl BulkActionsViewModel {
+    pub fn new(gallery: GalleryViewModel) -> Self {
+        let (state, set_state) = signal(BulkActionsState::default());
+        Self {
+            state,
+            set_state,
+            gallery,
+        }
+    }
+
+    /// 같은 패널을 다시 누르면 닫음 (앨범 패널은 열 때 목록을 새로 받음)
+    pub fn toggle_panel(&self, panel: BulkPanel) {
+        let is_open = self.state.with_untracked(|s| s.panel == Some(panel));
+        self.set_state.update(|s| {
+            s.panel = (!is_open).then_some(panel);
+            s.error = None;
+        });
+        if panel == BulkPanel::Album && !is_open {
+            let set_state = self.set_state;
+            leptos::task::spawn_local(async move {
+                let result = fetch_albums().await;
+                set_state.update(|s| match result {
+                    Ok(albums) => s.albums = albums,
+                    Err(e) => s.error = Some(e.to_string()),
+                });
+            });
+        }
+    }
+
+    /// 전부 좋아요 상태면 해제, 아니면 모두 좋아요
+    pub fn toggle_like_selected(&self) {
+        let gallery = self.gallery;
+        let liked = !untrack(|| gallery.all_selected_liked());
+        let photo_ids = gallery.selected_ids();
+        self.run(async move {
+            let updated = set_favorites(photo_ids.clone(), liked)
+                .await
+                .map_err(|e| e.to_string())?;
+            gallery.set_liked(&photo_ids, liked);
+            Ok(match liked {
+                true => format!("Liked {} photos", updated),
+                false => format!("Unliked {} photos", updated),
+            })
+        });
+    }
+
+    pub fn add_to_album(&self, album: Album) {
+        let photo_ids = self.gallery.selected_ids();
+        self.run(async move {
+            let added = add_photos_to_album(album.id, photo_ids)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(format!("Added {} photos to {}", added, album.title))
+        });
+    }
+
+    /// 새 앨범을 만들고 선택한 사진을 추가
+    pub fn add_to_new_album(&self, title: String) {
+        let photo_ids = self.gallery.selected_ids();
+        self.run(async move {
+            let album = create_album(&title).await.map_err(|e| e.to_string())?;
+            let added = add_photos_to_album(album.id, photo_ids)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(format!("Added {} photos to {}", added, album.title))
+        });
+    }
+
+    /// 쉼표로 구분한 태그를 선택한 사진에 붙임
+    pub fn tag_selected(&self, input: &str) {
+        let tags: Vec<String> = input
+            .split(',')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .map(str::to_string)
+            .collect();
+        if tags.is_empty() {
+            return;
+        }
+        let photo_ids = self.gallery.selected_ids();
+        self.run(async move {
+            let count = tags.len();
+            tag_photos(photo_ids, tags)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(format!("Added {} tags", count))
+        });
+    }
+
+    /// 원본 ZIP 은 브라우저가 직접 받아 디스크에 저장
+    pub fn download_selected(&self) {
+        let photo_ids = self.gallery.selected_ids();
+        self.run(async move {
+            let count = photo_ids.len();
+            let url = download_photos(photo_ids)
+                .await
+                .map_err(|e| e.to_string())?;
+            download_url(&url)?;
+            Ok(format!("Downloading {} photos", count))
+        });
+    }
+
+    /// 불러온 사진 중 선택한 사진의 미리보기 이미지를 파일로 공유
+    pub fn share_selected(&self) {
+        let photos = self.gallery.selected_photos();
+        self.run(async move {
+            if photos.len() > MAX_SHARE_PHOTOS {
+                return Err(format!("Select up to {} photos to share", MAX_SHARE_PHOTOS));
+            }
+            let mut files = Vec::with_capacity(photos.len());
+            for photo in photos {
+                let (bytes, mime_type) = get_bytes(&photo.preview_url)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                files.push(SharedFile {
+                    name: file_name(&photo.preview_url),
+                    mime_type,
+                    bytes,
+                });
+            }
+            match share_files("PhotoVault", &files)? {
+                ShareOutcome::Shared => Ok(format!("Shared {} photos", files.len())),
+                ShareOutcome::Unsupported => Err("Sharing is not supported here".to_string()),
+            }
+        });
+    }
+
+    /// 휴지통으로 보내고 목록과 선택에서 제거
+    pub fn trash_selected(&self) {
+        let gallery = self.gallery;
+        let photo_ids = gallery.selected_ids();
+        self.run(async move {
+            let trashed = trash_photos(photo_ids.clone())
+                .await
+                .map_err(|e| e.to_string())?;
+            gallery.remove_photos(&photo_ids);
+            Ok(format!("Moved {} photos to trash", trashed))
+        });
+    }
+
+    pub fn dismiss_message(&self) {
+        self.set_state
+            .update(|s| (s.message, s.error) = (None, None));
+    }
+
+    /// 작업을 하나씩 실행하고 결과 메시지 또는 오류를 표시
+    fn run<F>(&self, action: F)
+    where
+        F: Future<Output = Result<String, String>> + 'static,
+    {
+        if self.gallery.selected_ids().is_empty() {
+            return;
+        }
+        if self.state.with_untracked(|s| s.is_working) {
+            return;
+        }
+        let set_state = self.set_state;
+        set_state.update(|s| (s.is_working, s.panel) = (true, None));
+        leptos::task::spawn_local(async move {
+            let result = action.await;
+            set_state.update(|s| {
+                s.is_working = false;
+                (s.message, s.error) = match result {
+                    Ok(message) => (Some(message), None),
+                    Err(error) => (None, Some(error)),
+                };
+            });
+        });
+    }
+}
+
+/// 주소의 마지막 경로 조각 (공유할 파일 이름)
+fn file_name(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    match path.rsplit('/').next() {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => "photo".to_string(),
+    }
+}
@@ -0,0 +1,96 @@
+use crate::domain::value_objects::cleanup::CleanupSuggestion;
+use crate::infrastructure::api::cleanup_api::fetch_cleanup_suggestions;
+use crate::infrastructure::api::duplicate_api::trash_photos;
+use crate::presentation::view_models::gallery_vm::forget_trashed_photos;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Default)]
+pub struct CleanupState {
+    pub suggestions: Vec<CleanupSuggestion>,
+    pub is_loading: bool,
+    pub is_saving: bool,
+    pub error: Option<String>,
+    /// 휴지통으로 보낼 사진
+    pub selected: Vec<Uuid>,
+}
+
+/// 정리 후보(흐림, 노출 부족/과다) 검토 화면
+#[derive(Clone, Copy)]
+pub struct CleanupViewModel {
+    pub state: ReadSignal<CleanupState>,
+    set_state: WriteSignal<CleanupState>,
+}
+
+impl CleanupViewModel {
+    pub fn new() -> Self {
+        let (state, set_state) = signal(CleanupState::default());
+        Self { state, set_state }
+    }
+
+    pub fn load(&self) {
+        let set_state = self.set_state;
+        set_state.update(|s| s.is_loading = true);
+
+        leptos::task::spawn_local(async move {
+            let result = fetch_cleanup_suggestions().await;
+            set_state.update(|s| {
+                match result {
+                    Ok(suggestions) => {
+                        (s.suggestions, s.error) = (suggestions, None);
+                        s.selected.clear();
+                    }
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+                s.is_loading = false;
+            });
+        });
+    }
+
+    pub fn toggle(&self, photo_id: Uuid) {
+        self.set_state.update(|s| {
+            if let Some(index) = s.selected.iter().position(|id| *id == photo_id) {
+                s.selected.remove(index);
+            } else {
+                s.selected.push(photo_id);
+            }
+        });
+    }
+
+    /// 전부 선택되어 있으면 모두 해제, 아니면 모두 선택
+    pub fn toggle_all(&self) {
+        self.set_state.update(|s| {
+            if s.selected.len() == s.suggestions.len() {
+                s.selected.clear();
+            } else {
+                s.selected = s.suggestions.iter().map(|x| x.photo.id).collect();
+            }
+        });
+    }
+
+    /// 선택한 사진을 휴지통으로 보내고 목록에서 제거
+    pub fn trash_selected(&self) {
+        let photo_ids = self.state.get_untracked().selected;
+        if photo_ids.is_empty() {
+            return;
+        }
+
+        let set_state = self.set_state;
+        set_state.update(|s| s.is_saving = true);
+        leptos::task::spawn_local(async move {
+            let result = trash_photos(photo_ids.clone()).await;
+            set_state.update(|s| {
+                s.is_saving = false;
+                match result {
+                    Ok(_) => {
+                        forget_trashed_photos(&photo_ids);
+                        s.suggestions.retain(|x| !photo_ids.contains(&x.photo.id));
+                        s.selected.clear();
+                        s.error = None;
+                    }
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+            });
+        });
+    }
+}
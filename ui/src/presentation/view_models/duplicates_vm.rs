@@ -0,0 +1,133 @@
+use crate::domain::value_objects::duplicate::DuplicateGroup;
+use crate::infrastructure::api::duplicate_api::{fetch_duplicates, trash_photos};
+use crate::presentation::view_models::gallery_vm::forget_trashed_photos;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Default)]
+pub struct DuplicatesState {
+    pub groups: Vec<DuplicateGroup>,
+    /// 검토할 사진이 너무 많아 가장 비슷한 사진들만 보여 주는지
+    pub truncated: bool,
+    pub is_loading: bool,
+    pub is_saving: bool,
+    pub error: Option<String>,
+    /// 휴지통으로 보낼 사진 (처음에는 그룹마다 가장 좋은 사본만 남기도록 선택)
+    pub marked: Vec<Uuid>,
+}
+
+impl DuplicatesState {
+    pub fn is_marked(&self, photo_id: Uuid) -> bool {
+        self.marked.contains(&photo_id)
+    }
+
+    /// 그룹에서 휴지통으로 보낼 사진 (모두 선택한 경우는 없도록 화면에서 막음)
+    pub fn marked_in(&self, group: &DuplicateGroup) -> Vec<Uuid> {
+        group
+            .photos
+            .iter()
+            .map(|p| p.photo.id)
+            .filter(|id| self.is_marked(*id))
+            .collect()
+    }
+}
+
+/// 가장 좋은 사본을 뺀 나머지
+fn default_marks(groups: &[DuplicateGroup]) -> Vec<Uuid> {
+    groups
+        .iter()
+        .flat_map(|group| group.photos.iter().skip(1).map(|p| p.photo.id))
+        .collect()
+}
+
+/// 유사 중복 검토 화면
+#[derive(Clone, Copy)]
+pub struct DuplicatesViewModel {
+    pub state: ReadSignal<DuplicatesState>,
+    set_state: WriteSignal<DuplicatesState>,
+}
+
+impl DuplicatesViewModel {
+    pub fn new() -> Self {
+        let (state, set_state) = signal(DuplicatesState::default());
+        Self { state, set_state }
+    }
+
+    pub fn load(&self) {
+        let set_state = self.set_state;
+        set_state.update(|s| s.is_loading = true);
+
+        leptos::task::spawn_local(async move {
+            let result = fetch_duplicates().await;
+            set_state.update(|s| {
+                match result {
+                    Ok(review) => {
+                        s.marked = default_marks(&review.groups);
+                        (s.groups, s.truncated, s.error) = (review.groups, review.truncated, None);
+                    }
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+                s.is_loading = false;
+            });
+        });
+    }
+
+    /// 남길지/버릴지 전환 (그룹의 마지막 남은 사본은 버릴 수 없음)
+    pub fn toggle_mark(&self, group_index: usize, photo_id: Uuid) {
+        self.set_state.update(|s| {
+            if let Some(index) = s.marked.iter().position(|id| *id == photo_id) {
+                s.marked.remove(index);
+                return;
+            }
+            let Some(group) = s.groups.get(group_index) else {
+                return;
+            };
+            if s.marked_in(group).len() + 1 < group.photos.len() {
+                s.marked.push(photo_id);
+            }
+        });
+    }
+
+    /// 그룹에서 선택한 사본을 휴지통으로 보내고 그룹을 목록에서 제거
+    pub fn resolve_group(&self, group_index: usize) {
+        let state = self.state.get_untracked();
+        let Some(group) = state.groups.get(group_index) else {
+            return;
+        };
+        let photo_ids = state.marked_in(group);
+        if photo_ids.is_empty() {
+            self.dismiss_group(group_index);
+            return;
+        }
+
+        let set_state = self.set_state;
+        set_state.update(|s| s.is_saving = true);
+        leptos::task::spawn_local(async move {
+            let result = trash_photos(photo_ids.clone()).await;
+            set_state.update(|s| {
+                s.is_saving = false;
+                match result {
+                    Ok(_) => {
+                        forget_trashed_photos(&photo_ids);
+                        s.groups
+                            .retain(|g| !g.photos.iter().any(|p| photo_ids.contains(&p.photo.id)));
+                        s.marked.retain(|id| !photo_ids.contains(id));
+                        s.error = None;
+                    }
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+            });
+        });
+    }
+
+    /// 중복이 아닌 그룹은 이번 검토에서만 숨김
+    pub fn dismiss_group(&self, group_index: usize) {
+        self.set_state.update(|s| {
+            if group_index < s.groups.len() {
+                let group = s.groups.remove(group_index);
+                s.marked
+                    .retain(|id| !group.photos.iter().any(|p| p.photo.id == *id));
+            }
+        });
+    }
+}
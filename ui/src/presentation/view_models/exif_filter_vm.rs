@@ -0,0 +1,55 @@
+use crate::domain::value_objects::search_facets::SearchFacets;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::infrastructure::api::search_api::fetch_search_facets;
+use leptos::prelude::*;
+
+#[derive(Clone, Debug, Default)]
+pub struct ExifFilterState {
+    pub is_open: bool,
+    pub facets: Option<SearchFacets>,
+    pub is_loading: bool,
+    pub error: Option<String>,
+    // 마지막으로 요청한 조건 (늦게 도착한 이전 응답은 무시)
+    filter: SearchFilter,
+}
+
+/// 갤러리 EXIF 필터 패널의 facet 집계 상태
+#[derive(Clone, Copy)]
+pub struct ExifFilterViewModel {
+    pub state: ReadSignal<ExifFilterState>,
+    set_state: WriteSignal<ExifFilterState>,
+}
+
+impl ExifFilterViewModel {
+    pub fn new() -> Self {
+        let (state, set_state) = signal(ExifFilterState::default());
+        Self { state, set_state }
+    }
+
+    pub fn toggle_open(&self) {
+        self.set_state.update(|s| s.is_open = !s.is_open);
+    }
+
+    /// 검색 조건이 바뀔 때마다 facet 별 사진 수를 다시 집계
+    pub fn load_facets(&self, filter: SearchFilter) {
+        let set_state = self.set_state;
+        set_state.update(|s| {
+            s.filter = filter.clone();
+            s.is_loading = true;
+        });
+
+        leptos::task::spawn_local(async move {
+            let result = fetch_search_facets(&filter).await;
+            set_state.update(|s| {
+                if s.filter != filter {
+                    return;
+                }
+                match result {
+                    Ok(facets) => (s.facets, s.error) = (Some(facets), None),
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+                s.is_loading = false;
+            });
+        });
+    }
+}
@@ -0,0 +1,199 @@
+use crate::application::services::face_geometry::{is_usable, rect_from_points};
+use crate::domain::value_objects::person::{FaceAssignment, FaceRect, Person, PhotoFace};
+use crate::infrastructure::api::api_client::ApiResult;
+use crate::infrastructure::api::person_api::{
+    add_face, assign_face, fetch_people, fetch_photo_faces, remove_face,
+};
+use leptos::prelude::*;
+use std::future::Future;
+use uuid::Uuid;
+
+/// 인물을 지정하려는 얼굴
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FaceEdit {
+    /// 검출된(또는 저장된) 얼굴의 인물 변경
+    Existing(Uuid),
+    /// 직접 그린 새 영역
+    New(FaceRect),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FaceOverlayState {
+    pub is_visible: bool,
+    pub photo_id: Option<Uuid>,
+    pub faces: Vec<PhotoFace>,
+    /// 인물 선택 목록 (처음 펼칠 때 한 번 로드)
+    pub people: Vec<Person>,
+    pub is_saving: bool,
+    pub error: Option<String>,
+    /// 새 얼굴 그리기 모드
+    pub is_drawing: bool,
+    /// 그리는 중인 영역의 시작점과 현재점 (이미지 대비 0~1)
+    pub stroke: Option<((f64, f64), (f64, f64))>,
+    pub editing: Option<FaceEdit>,
+}
+
+impl FaceOverlayState {
+    pub fn stroke_rect(&self) -> Option<FaceRect> {
+        self.stroke.map(|(start, end)| rect_from_points(start, end))
+    }
+
+    pub fn editing_face(&self) -> Option<&PhotoFace> {
+        match self.editing {
+            Some(FaceEdit::Existing(id)) => self.faces.iter().find(|f| f.id == id),
+            _ => None,
+        }
+    }
+}
+
+/// 미리보기의 얼굴 상자 표시와 수동 태깅
+#[derive(Clone, Copy)]
+pub struct FaceOverlayViewModel {
+    pub state: ReadSignal<FaceOverlayState>,
+    set_state: WriteSignal<FaceOverlayState>,
+}
+
+impl FaceOverlayViewModel {
+    pub fn new() -> Self {
+        let (state, set_state) = signal(FaceOverlayState::default());
+        Self { state, set_state }
+    }
+
+    pub fn toggle_visible(&self) {
+        let state = self.state.get_untracked();
+        self.set_state.update(|s| {
+            s.is_visible = !s.is_visible;
+            (s.is_drawing, s.stroke, s.editing) = (false, None, None);
+        });
+        if !state.is_visible {
+            self.reload();
+            if state.people.is_empty() {
+                self.load_people();
+            }
+        }
+    }
+
+    /// 미리보기 사진이 바뀌면 편집 상태를 초기화하고 (표시 중이면) 얼굴을 다시 불러옴
+    pub fn show_photo(&self, photo_id: Uuid) {
+        self.set_state.update(|s| {
+            s.photo_id = Some(photo_id);
+            s.faces.clear();
+            (s.is_drawing, s.stroke, s.editing, s.error) = (false, None, None, None);
+        });
+        if self.state.get_untracked().is_visible {
+            self.reload();
+        }
+    }
+
+    pub fn toggle_drawing(&self) {
+        self.set_state.update(|s| {
+            s.is_drawing = !s.is_drawing;
+            (s.stroke, s.editing) = (None, None);
+        });
+    }
+
+    pub fn begin_stroke(&self, point: (f64, f64)) {
+        self.set_state.update(|s| {
+            if s.is_drawing {
+                (s.stroke, s.editing) = (Some((point, point)), None);
+            }
+        });
+    }
+
+    pub fn move_stroke(&self, point: (f64, f64)) {
+        self.set_state.update(|s| {
+            if let Some((_, end)) = s.stroke.as_mut() {
+                *end = point;
+            }
+        });
+    }
+
+    /// 충분히 큰 영역이면 인물 선택으로 넘어감
+    pub fn end_stroke(&self) {
+        self.set_state.update(|s| {
+            let rect = s.stroke_rect().filter(is_usable);
+            s.stroke = None;
+            if let Some(rect) = rect {
+                (s.is_drawing, s.editing) = (false, Some(FaceEdit::New(rect)));
+            }
+        });
+    }
+
+    pub fn edit(&self, face_id: Uuid) {
+        self.set_state
+            .update(|s| s.editing = Some(FaceEdit::Existing(face_id)));
+    }
+
+    pub fn cancel_edit(&self) {
+        self.set_state.update(|s| s.editing = None);
+    }
+
+    /// 편집 중인 얼굴(또는 새 영역)을 인물에 배정
+    pub fn assign(&self, assignment: FaceAssignment) {
+        let state = self.state.get_untracked();
+        let (Some(photo_id), Some(edit)) = (state.photo_id, state.editing) else {
+            return;
+        };
+        self.save(async move {
+            match edit {
+                FaceEdit::Existing(face_id) => assign_face(face_id, assignment).await,
+                FaceEdit::New(rect) => add_face(photo_id, rect, assignment).await,
+            }
+        });
+    }
+
+    /// 편집 중인 얼굴이 얼굴이 아니면 삭제
+    pub fn remove_editing(&self) {
+        if let Some(FaceEdit::Existing(face_id)) = self.state.get_untracked().editing {
+            self.save(async move { remove_face(face_id).await });
+        }
+    }
+
+    fn reload(&self) {
+        let Some(photo_id) = self.state.get_untracked().photo_id else {
+            return;
+        };
+        let set_state = self.set_state;
+
+        leptos::task::spawn_local(async move {
+            let result = fetch_photo_faces(photo_id).await;
+            set_state.update(|s| {
+                if s.photo_id != Some(photo_id) {
+                    return;
+                }
+                match result {
+                    Ok(faces) => (s.faces, s.error) = (faces, None),
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+            });
+        });
+    }
+
+    fn load_people(&self) {
+        let set_state = self.set_state;
+        leptos::task::spawn_local(async move {
+            if let Ok(people) = fetch_people().await {
+                set_state.update(|s| s.people = people);
+            }
+        });
+    }
+
+    /// 변경 요청 후 얼굴과 인물 목록(새 인물이 생겼을 수 있음)을 다시 불러옴
+    fn save(&self, request: impl Future<Output = ApiResult<()>> + 'static) {
+        let vm = *self;
+        vm.set_state.update(|s| s.is_saving = true);
+
+        leptos::task::spawn_local(async move {
+            let result = request.await;
+            vm.set_state.update(|s| {
+                s.is_saving = false;
+                match result {
+                    Ok(()) => (s.editing, s.error) = (None, None),
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+            });
+            vm.reload();
+            vm.load_people();
+        });
+    }
+}
@@ -1,185 +1,625 @@
+use crate::application::services::gallery_snapshot::{self, GallerySnapshot, ScrollAnchor};
+use crate::application::services::page_requests::{PageRequests, PageTicket, PageUpdate};
+use crate::application::services::photo_selection::PhotoSelection;
+use crate::application::services::route_parser::Route;
 use crate::domain::entities::photo::Photo;
+use crate::domain::value_objects::photo_page::PhotoContext;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::infrastructure::api::photo_api::fetch_photo_context;
+use crate::infrastructure::api::photo_batch_api::set_favorites;
+use crate::infrastructure::api::search_api::search_photos;
+#[cfg(feature = "mock-photos")]
 use crate::infrastructure::repositories::mock_photo_repository::fetch_mock_photos;
+use crate::infrastructure::storage::session_storage::{self, load_json, save_json};
+use crate::infrastructure::storage::url_query::go_back;
+use crate::presentation::view_models::navigation_vm::NavigationViewModel;
 use leptos::prelude::*;
 use uuid::Uuid;
-use wasm_bindgen::JsValue;
 
+const PAGE_SIZE: usize = 20;
+const SNAPSHOT_KEY: &str = "photovault.gallery";
+
+/// 갤러리 밖(중복, 정리 화면)에서 휴지통으로 보낸 사진을 저장해 둔 목록에서도 제거
+///
+/// 갤러리로 돌아왔을 때 지운 사진이 복원되지 않게 하고, 목록이 비면 스냅샷을 지웁니다.
+pub fn forget_trashed_photos(photo_ids: &[Uuid]) {
+    let Some(snapshot) = load_json::<GallerySnapshot>(SNAPSHOT_KEY) else {
+        return;
+    };
+    let snapshot = snapshot.without_photos(photo_ids);
+    if gallery_snapshot::fits(snapshot.photos.len()) {
+        save_json(SNAPSHOT_KEY, &snapshot);
+    } else {
+        session_storage::remove(SNAPSHOT_KEY);
+    }
+}
+
+/// 그리드의 사진 한 장
+///
+/// 사진 정보는 바뀌지 않고, 좋아요처럼 바뀌는 값만 사진별 signal 로 둡니다.
+/// 목록 signal 을 건드리지 않으므로 한 장을 바꿔도 다른 카드는 다시 그려지지 않습니다.
 #[derive(Clone, Debug)]
-pub struct GalleryState {
-    pub photos: Vec<Photo>,
+pub struct PhotoItem {
+    pub photo: Photo,
+    pub is_liked: ArcRwSignal<bool>,
+}
+
+impl PhotoItem {
+    fn new(photo: Photo) -> Self {
+        Self {
+            is_liked: ArcRwSignal::new(photo.is_liked),
+            photo,
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.photo.id
+    }
+
+    /// 현재 상태를 반영한 사진 (미리보기로 넘길 때 사용)
+    pub fn snapshot(&self) -> Photo {
+        Photo {
+            is_liked: self.is_liked.get_untracked(),
+            ..self.photo.clone()
+        }
+    }
+}
+
+/// 목록 로딩 상태
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoadStatus {
     pub is_loading: bool,
-    pub page: usize,
     pub has_more: bool,
-    pub selected_photo: Option<Photo>,
+    pub error: Option<String>,
 }
 
-impl Default for GalleryState {
+impl Default for LoadStatus {
     fn default() -> Self {
         Self {
-            photos: vec![],
             is_loading: false,
-            page: 1,
             has_more: true,
-            selected_photo: None,
+            error: None,
+        }
+    }
+}
+
+/// 그리드가 배치를 마친 뒤 맞출 스크롤 위치
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScrollTarget {
+    /// 저장해 둔 위치 그대로 복원
+    Anchor(ScrollAnchor),
+    /// 사진이 보이도록 필요한 만큼만 이동 (미리보기를 닫은 뒤)
+    Reveal(Uuid),
+}
+
+impl ScrollTarget {
+    pub fn photo_id(&self) -> Uuid {
+        match self {
+            ScrollTarget::Anchor(anchor) => anchor.photo_id,
+            ScrollTarget::Reveal(photo_id) => *photo_id,
         }
     }
 }
 
+/// 다음 페이지 요청 조건 (화면에 표시하지 않으므로 signal 이 아님)
+#[derive(Clone, Debug, Default)]
+struct PageQuery {
+    // 검색 조건 (None이면 전체 사진)
+    filter: Option<SearchFilter>,
+    // 검색 결과 다음 페이지 커서
+    cursor: Option<String>,
+}
+
+/// 한 번의 목록 요청 결과
+struct FetchedPage {
+    photos: Vec<Photo>,
+    has_more: bool,
+    cursor: Option<String>,
+}
+
+/// 검색 조건(없으면 전체 사진)으로 `cursor` 다음 페이지를 가져옴
+///
+/// `mock-photos` feature 로 빌드하면 전체 사진 목록은 백엔드 없이 가짜 사진으로 채웁니다.
+async fn fetch_next_page(query: PageQuery) -> Result<FetchedPage, String> {
+    #[cfg(feature = "mock-photos")]
+    if query.filter.is_none() {
+        let (photos, cursor) = fetch_mock_photos(query.cursor.as_deref(), PAGE_SIZE).await;
+        return Ok(FetchedPage {
+            has_more: cursor.is_some(),
+            photos,
+            cursor,
+        });
+    }
+    search_photos(
+        &query.filter.unwrap_or_default(),
+        query.cursor.as_deref(),
+        PAGE_SIZE,
+    )
+    .await
+    .map(|result| FetchedPage {
+        has_more: result.next_cursor.is_some(),
+        photos: result.photos,
+        cursor: result.next_cursor,
+    })
+    .map_err(|e| e.to_string())
+}
+
+/// 갤러리 목록, 로딩 상태, 미리보기 선택, 여러 장 선택을 각각 별도 signal 로 관리
+///
+/// 로딩 표시나 선택이 바뀌어도 사진 목록을 구독하는 그리드는 다시 그려지지 않습니다.
+/// 목록을 새로 시작한 뒤 도착한 이전 목록의 응답은 버립니다(`PageRequests`).
+/// 여러 장 선택은 목록을 다시 불러와도 유지되고, 선택을 모두 해제하면 선택 모드가 끝납니다.
+/// 미리보기는 주소창 경로(`/photo/:id`)와 함께 열리고 닫힙니다.
+/// 화면을 떠날 때 목록과 스크롤 위치를 저장해 두고, 같은 조건으로 돌아오면 그대로 복원합니다.
 #[derive(Clone, Copy)]
 pub struct GalleryViewModel {
-    pub state: ReadSignal<GalleryState>,
-    set_state: WriteSignal<GalleryState>,
+    pub photos: ReadSignal<Vec<PhotoItem>>,
+    set_photos: WriteSignal<Vec<PhotoItem>>,
+    pub status: ReadSignal<LoadStatus>,
+    set_status: WriteSignal<LoadStatus>,
+    pub selected_photo: ReadSignal<Option<Photo>>,
+    set_selected_photo: WriteSignal<Option<Photo>>,
+    pub selection: ReadSignal<PhotoSelection>,
+    set_selection: WriteSignal<PhotoSelection>,
+    pub scroll_target: ReadSignal<Option<ScrollTarget>>,
+    set_scroll_target: WriteSignal<Option<ScrollTarget>>,
+    // 그리드가 마지막으로 알려준 보던 위치 (저장할 때 사용)
+    anchor: StoredValue<Option<ScrollAnchor>>,
+    query: StoredValue<PageQuery>,
+    requests: StoredValue<PageRequests>,
+    navigation: NavigationViewModel,
+    // 그리드에서 눌러 연 미리보기인지 (닫을 때 뒤로가기로 히스토리 항목을 되돌림)
+    opened_in_app: StoredValue<bool>,
 }
 
 impl GalleryViewModel {
-    pub fn new() -> Self {
-        let (state, set_state) = signal(GalleryState::default());
-        Self { state, set_state }
+    pub fn new(navigation: NavigationViewModel) -> Self {
+        let (photos, set_photos) = signal(Vec::new());
+        let (status, set_status) = signal(LoadStatus::default());
+        let (selected_photo, set_selected_photo) = signal(None);
+        let (selection, set_selection) = signal(PhotoSelection::default());
+        let (scroll_target, set_scroll_target) = signal(None);
+        Self {
+            photos,
+            set_photos,
+            status,
+            set_status,
+            selected_photo,
+            set_selected_photo,
+            selection,
+            set_selection,
+            scroll_target,
+            set_scroll_target,
+            anchor: StoredValue::new(None),
+            query: StoredValue::new(PageQuery::default()),
+            requests: StoredValue::new(PageRequests::default()),
+            navigation,
+            opened_in_app: StoredValue::new(false),
+        }
     }
 
     pub fn load_more(&self) {
-        let state = self.state.get_untracked();
-        if state.is_loading || !state.has_more {
+        let Some(ticket) = self.begin_next_page() else {
             return;
+        };
+        let vm = *self;
+        leptos::task::spawn_local(async move {
+            vm.fetch_next(ticket).await;
+        });
+    }
+
+    /// 다음 페이지 요청 시작 (이미 요청 중이거나 더 없으면 `None`)
+    fn begin_next_page(&self) -> Option<PageTicket> {
+        let has_more = self.status.with_untracked(|s| s.has_more);
+        let ticket = self
+            .requests
+            .try_update_value(|r| r.begin_next(has_more))??;
+        self.set_status.update(|s| s.is_loading = true);
+        Some(ticket)
+    }
+
+    /// 다음 페이지를 받아 목록 뒤에 붙임 (붙였으면 `true`)
+    async fn fetch_next(&self, ticket: PageTicket) -> bool {
+        let result = fetch_next_page(self.query.get_value()).await;
+        let update = self
+            .requests
+            .try_update_value(|r| r.finish_next(ticket, result));
+        match update {
+            Some(PageUpdate::Append(fetched)) => {
+                self.append_page(fetched);
+                true
+            }
+            Some(PageUpdate::Fail(message)) => {
+                self.set_status.update(|s| {
+                    s.is_loading = false;
+                    s.error = Some(message);
+                });
+                false
+            }
+            _ => false,
         }
+    }
 
-        let set_state = self.set_state;
-        set_state.update(|s| s.is_loading = true);
+    /// 검색 조건 변경 시 목록을 초기화하고 첫 페이지부터 다시 로드
+    pub fn apply_filter(&self, filter: Option<SearchFilter>) {
+        self.reset(PageQuery {
+            filter,
+            cursor: None,
+        });
+        self.load_more();
+    }
 
-        let current_page = state.page;
+    /// 같은 조건으로 저장해 둔 목록이 있으면 복원하고 저장한 스크롤 위치로 이동 (없으면 `false`)
+    pub fn restore(&self, filter: &Option<SearchFilter>) -> bool {
+        let Some(snapshot) =
+            load_json::<GallerySnapshot>(SNAPSHOT_KEY).and_then(|s| s.restorable(filter))
+        else {
+            return false;
+        };
+        self.reset(PageQuery {
+            filter: snapshot.filter,
+            cursor: None,
+        });
+        self.append_page(FetchedPage {
+            photos: snapshot.photos,
+            has_more: snapshot.has_more,
+            cursor: snapshot.cursor,
+        });
+        self.set_scroll_target
+            .set(snapshot.anchor.map(ScrollTarget::Anchor));
+        true
+    }
+
+    /// 목록과 보던 위치를 저장 (화면을 떠나거나 새로고침할 때)
+    ///
+    /// 비었거나 너무 긴 목록은 저장하지 않고 이전 스냅샷도 지워, 돌아오면 처음부터 불러옵니다.
+    pub fn save_snapshot(&self) {
+        let len = self.photos.with_untracked(Vec::len);
+        if !gallery_snapshot::fits(len) {
+            session_storage::remove(SNAPSHOT_KEY);
+            return;
+        }
+        let photos = self
+            .photos
+            .with_untracked(|items| items.iter().map(PhotoItem::snapshot).collect());
+        let query = self.query.get_value();
+        save_json(
+            SNAPSHOT_KEY,
+            &GallerySnapshot {
+                filter: query.filter,
+                cursor: query.cursor,
+                has_more: self.status.with_untracked(|s| s.has_more),
+                photos,
+                anchor: self.anchor.get_value(),
+            },
+        );
+    }
 
+    /// 그리드에서 보고 있는 위치 기록
+    pub fn remember_anchor(&self, anchor: Option<ScrollAnchor>) {
+        self.anchor.set_value(anchor);
+    }
+
+    /// 그리드가 스크롤을 맞춘 뒤 호출
+    pub fn clear_scroll_target(&self) {
+        self.set_scroll_target.set(None);
+    }
+
+    /// 불러온 목록에서 사진의 위치
+    pub fn index_of(&self, photo_id: Uuid) -> Option<usize> {
+        self.photos
+            .with_untracked(|items| items.iter().position(|item| item.id() == photo_id))
+    }
+
+    /// 타임라인의 특정 날짜로 이동: 목록을 비우고 `cursor` 위치부터 다시 로드 (`None` 이면 처음부터)
+    pub fn jump_to(&self, cursor: Option<String>) {
+        let filter = self.query.with_value(|q| q.filter.clone());
+        self.reset(PageQuery { filter, cursor });
+        self.load_more();
+    }
+
+    /// 해당 사진의 좋아요 signal 만 바로 바꾸고 서버에 저장 (실패하면 되돌림)
+    pub fn toggle_like(&self, photo_id: Uuid) {
+        let Some(liked) = self.photos.with_untracked(|items| {
+            let item = items.iter().find(|item| item.id() == photo_id)?;
+            Some(!item.is_liked.get_untracked())
+        }) else {
+            return;
+        };
+
+        let vm = *self;
+        vm.set_liked(&[photo_id], liked);
         leptos::task::spawn_local(async move {
-            let limit = 20;
-            let new_photos = fetch_mock_photos(current_page, limit).await;
-
-            set_state.update(|s| {
-                if new_photos.is_empty() {
-                    s.has_more = false;
-                } else {
-                    s.photos.extend(new_photos);
-                    s.page += 1;
-                }
-                s.is_loading = false;
-            });
+            if set_favorites(vec![photo_id], liked).await.is_err() {
+                vm.set_liked(&[photo_id], !liked);
+            }
         });
     }
 
-    pub fn toggle_like(&self, photo_id: Uuid) {
-        self.set_state.update(|s| {
-            if let Some(photo) = s.photos.iter_mut().find(|p| p.id == photo_id) {
-                photo.is_liked = !photo.is_liked;
+    /// 불러온 사진의 좋아요 표시 변경 (목록은 그대로)
+    pub fn set_liked(&self, photo_ids: &[Uuid], liked: bool) {
+        self.photos.with_untracked(|items| {
+            for item in items.iter().filter(|item| photo_ids.contains(&item.id())) {
+                item.is_liked.set(liked);
             }
         });
     }
 
-    pub fn select_photo(&self, photo: Photo) {
-        self.set_state.update(|s| s.selected_photo = Some(photo));
+    /// 선택한 사진이 모두 좋아요 상태인지 (일괄 좋아요/해제 결정)
+    pub fn all_selected_liked(&self) -> bool {
+        self.selection.with(|selection| {
+            self.photos.with(|items| {
+                items
+                    .iter()
+                    .filter(|item| selection.contains(item.id()))
+                    .all(|item| item.is_liked.get())
+            })
+        })
+    }
 
-        // 브라우저 히스토리에 상태 추가
-        if let Some(window) = web_sys::window() {
-            if let Ok(history) = window.history() {
-                let _ = history.push_state_with_url(&JsValue::NULL, "", Some("#preview"));
-            }
+    /// 휴지통으로 보낸 사진을 목록과 선택에서 제거
+    pub fn remove_photos(&self, photo_ids: &[Uuid]) {
+        self.set_photos
+            .update(|items| items.retain(|item| !photo_ids.contains(&item.id())));
+        self.set_selection.update(|s| s.remove(photo_ids));
+    }
+
+    pub fn is_selecting(&self) -> bool {
+        self.selection.with(|s| !s.is_empty())
+    }
+
+    pub fn is_selected(&self, photo_id: Uuid) -> bool {
+        self.selection.with(|s| s.contains(photo_id))
+    }
+
+    /// 길게 눌러 선택 모드 시작 (이미 선택 중이면 범위 선택)
+    pub fn long_press(&self, photo_id: Uuid) {
+        if self.selection.with_untracked(PhotoSelection::is_empty) {
+            self.set_selection.update(|s| s.select(photo_id));
+        } else {
+            self.select_range(photo_id);
         }
     }
 
-    // 뒤로가기 이벤트(PopState) 발생 시 상태 동기화
-    pub fn sync_on_popstate(&self) {
-        // 해시가 없으면 모달 닫기
-        if let Some(window) = web_sys::window() {
-            let location = window.location();
-            if let Ok(hash) = location.hash() {
-                if hash.is_empty() {
-                    self.set_state.update(|s| s.selected_photo = None);
-                }
-            }
+    pub fn toggle_selected(&self, photo_id: Uuid) {
+        self.set_selection.update(|s| s.toggle(photo_id));
+    }
+
+    /// 마지막으로 선택한 사진부터 `photo_id` 까지 불러온 목록 순서대로 선택
+    pub fn select_range(&self, photo_id: Uuid) {
+        let order: Vec<Uuid> = self
+            .photos
+            .with_untracked(|items| items.iter().map(PhotoItem::id).collect());
+        self.set_selection
+            .update(|s| s.select_range(&order, photo_id));
+    }
+
+    pub fn clear_selection(&self) {
+        self.set_selection.update(PhotoSelection::clear);
+    }
+
+    pub fn selected_ids(&self) -> Vec<Uuid> {
+        self.selection.with_untracked(PhotoSelection::ids)
+    }
+
+    /// 선택한 사진 중 불러온 사진 (공유 링크 등)
+    pub fn selected_photos(&self) -> Vec<Photo> {
+        self.selection.with_untracked(|selection| {
+            self.photos.with_untracked(|items| {
+                items
+                    .iter()
+                    .filter(|item| selection.contains(item.id()))
+                    .map(PhotoItem::snapshot)
+                    .collect()
+            })
+        })
+    }
+
+    /// 미리보기 열기 (브라우저 히스토리에 `/photo/:id`, 앨범이면 `/albums/:id/photo/:id` 추가)
+    pub fn select_photo(&self, photo: Photo) {
+        let route = self.navigation.route.get_untracked().with_photo(photo.id);
+        self.set_selected_photo.set(Some(photo));
+        self.opened_in_app.set_value(true);
+        self.navigation.push(route);
+    }
+
+    /// 열려 있는 미리보기의 사진만 교체 (비슷한 사진 이동, 히스토리 항목은 주소만 교체)
+    pub fn replace_preview(&self, photo: Photo) {
+        let route = self.navigation.route.get_untracked().with_photo(photo.id);
+        self.set_selected_photo.set(Some(photo));
+        self.navigation.replace(route);
+    }
+
+    /// 주소창 경로에 맞춰 미리보기 열기/닫기 (링크로 바로 열거나 뒤로/앞으로가기)
+    pub fn show_route(&self, route: Route) {
+        match route.photo() {
+            Some(photo_id) => self.show_photo(photo_id),
+            None => self.hide_preview(),
         }
     }
 
-    // 사용자가 닫기 버튼을 눌렀을 때
+    /// 사용자가 닫기 버튼을 눌렀을 때
     pub fn close_preview(&self) {
-        if let Some(window) = web_sys::window() {
-            if let Ok(history) = window.history() {
-                // history.back()을 호출하면 popstate 이벤트가 발생하여 sync_on_popstate가 호출됨
-                let _ = history.back();
-            }
+        if self.opened_in_app.get_value() {
+            // 뒤로가기가 끝나면 popstate 로 경로가 바뀌어 미리보기가 닫힘
+            go_back();
+        } else {
+            self.close_to_list();
         }
     }
 
-    pub async fn next_photo(&self) {
-        let state = self.state.get_untracked();
-        if let Some(current) = &state.selected_photo {
-            if let Some(index) = state.photos.iter().position(|p| p.id == current.id) {
-                // 다음 사진이 있는 경우
-                if index + 1 < state.photos.len() {
-                    self.set_state.update(|s| {
-                        s.selected_photo = Some(s.photos[index + 1].clone());
-                    });
-                } 
-                // 마지막 사진이고 더 불러올 데이터가 있는 경우
-                else if state.has_more {
-                    let set_state = self.set_state;
-                    set_state.update(|s| s.is_loading = true);
-                    
-                    let current_page = state.page;
-                    let limit = 20;
-                    
-                    // 데이터 로드
-                    let new_photos = fetch_mock_photos(current_page, limit).await;
-                    
-                    set_state.update(|s| {
-                        if new_photos.is_empty() {
-                            s.has_more = false;
-                        } else {
-                            s.photos.extend(new_photos.clone());
-                            s.page += 1;
-                            // 새로 로드된 데이터의 첫 번째 사진(전체 리스트의 다음 사진)으로 이동
-                            if let Some(first_new) = new_photos.first() {
-                                s.selected_photo = Some(first_new.clone());
-                            }
-                        }
-                        s.is_loading = false;
-                    });
-                }
-            }
+    /// 미리보기 경로를 갤러리(또는 앨범) 경로로 바꿈
+    fn close_to_list(&self) {
+        let route = self.navigation.route.get_untracked().without_photo();
+        self.navigation.replace(route);
+    }
+
+    /// 미리보기를 닫고, 마지막으로 본 사진이 그리드에 보이도록 스크롤
+    fn hide_preview(&self) {
+        self.opened_in_app.set_value(false);
+        if let Some(photo_id) = self
+            .selected_photo
+            .with_untracked(|p| p.as_ref().map(|p| p.id))
+        {
+            self.set_selected_photo.set(None);
+            self.set_scroll_target
+                .set(Some(ScrollTarget::Reveal(photo_id)));
         }
     }
 
-    pub fn prev_photo(&self) {
-        self.set_state.update(|s| {
-            if let Some(current) = &s.selected_photo {
-                if let Some(index) = s.photos.iter().position(|p| p.id == current.id) {
-                    if index > 0 {
-                        s.selected_photo = Some(s.photos[index - 1].clone());
-                    }
+    /// 불러온 목록에 있으면 바로 열고, 없으면 사진과 앞뒤 사진을 받아 목록을 그 위치로 바꿈
+    fn show_photo(&self, photo_id: Uuid) {
+        let is_shown = self
+            .selected_photo
+            .with_untracked(|p| p.as_ref().is_some_and(|p| p.id == photo_id));
+        if is_shown {
+            return;
+        }
+        let loaded = self.photos.with_untracked(|items| {
+            let item = items.iter().find(|item| item.id() == photo_id)?;
+            Some(item.snapshot())
+        });
+        match loaded {
+            Some(photo) => self.set_selected_photo.set(Some(photo)),
+            None => self.load_photo_context(photo_id),
+        }
+    }
+
+    fn load_photo_context(&self, photo_id: Uuid) {
+        let ticket = self.requests.with_value(PageRequests::begin_reload);
+        let filter = self.query.with_value(|q| q.filter.clone());
+        let vm = *self;
+        leptos::task::spawn_local(async move {
+            let result =
+                fetch_photo_context(photo_id, &filter.clone().unwrap_or_default(), PAGE_SIZE)
+                    .await
+                    .map_err(|e| e.to_string());
+            // 받는 사이 다른 경로로 옮겼으면 버림 (목록을 새로 시작했는지는 finish_reload 가 확인)
+            if vm.navigation.route.get_untracked().photo() != Some(photo_id) {
+                return;
+            }
+            let update = vm
+                .requests
+                .try_update_value(|r| r.finish_reload(ticket, result));
+            match update {
+                Some(PageUpdate::Replace(context)) => vm.show_context(filter, context),
+                Some(PageUpdate::Fail(message)) => {
+                    vm.set_status.update(|s| s.error = Some(message));
+                    vm.close_to_list();
                 }
+                _ => {}
             }
         });
     }
 
-    pub async fn refresh(&self) {
-        let set_state = self.set_state;
-        
-        // 상태 초기화 및 로딩 시작
-        set_state.update(|s| {
-            s.is_loading = true;
-            s.photos.clear();
-            s.page = 1;
-            s.has_more = true;
+    /// 목록을 사진 앞뒤 구간으로 바꾸고 미리보기 열기 (이후 사진은 이어서 불러옴)
+    fn show_context(&self, filter: Option<SearchFilter>, context: PhotoContext) {
+        self.clear(PageQuery {
+            filter,
+            cursor: None,
         });
+        let photo = context.photo.clone();
+        let photos = context
+            .previous
+            .into_iter()
+            .chain([context.photo])
+            .chain(context.next.photos)
+            .collect();
+        self.append_page(FetchedPage {
+            photos,
+            has_more: context.next.next_cursor.is_some(),
+            cursor: context.next.next_cursor,
+        });
+        self.set_selected_photo.set(Some(photo));
+    }
 
-        // 첫 페이지 로드
-        let limit = 20;
-        let new_photos = fetch_mock_photos(1, limit).await;
+    pub async fn next_photo(&self) {
+        let Some(index) = self.selected_index() else {
+            return;
+        };
+        let len = self.photos.with_untracked(Vec::len);
+        // 다음 사진이 있는 경우
+        if index + 1 < len {
+            self.select_at(index + 1);
+            return;
+        }
+        // 마지막 사진이면 다음 페이지를 불러온 뒤 (그 사이 다른 사진으로 옮기지 않았을 때만)
+        // 새로 로드된 데이터의 첫 번째 사진(전체 리스트의 다음 사진)으로 이동
+        let Some(ticket) = self.begin_next_page() else {
+            return;
+        };
+        if self.fetch_next(ticket).await && self.selected_index() == Some(index) {
+            self.select_at(len);
+        }
+    }
 
-        set_state.update(|s| {
-            if new_photos.is_empty() {
-                s.has_more = false;
-            } else {
-                s.photos = new_photos;
-                s.page = 2; // 다음 로드할 페이지
+    pub fn prev_photo(&self) {
+        if let Some(index) = self.selected_index().filter(|index| *index > 0) {
+            self.select_at(index - 1);
+        }
+    }
+
+    pub async fn refresh(&self) {
+        // 첫 페이지를 먼저 받은 뒤 교체 (취소되거나 실패하면 기존 목록 유지, 검색 조건은 유지)
+        let query = PageQuery {
+            filter: self.query.with_value(|q| q.filter.clone()),
+            cursor: None,
+        };
+        let ticket = self.requests.with_value(PageRequests::begin_reload);
+        let result = fetch_next_page(query.clone()).await;
+        // 받는 사이 검색 조건 변경이나 날짜 이동으로 목록이 새로 시작됐으면 버림
+        let update = self
+            .requests
+            .try_update_value(|r| r.finish_reload(ticket, result));
+        match update {
+            Some(PageUpdate::Replace(fetched)) => {
+                self.clear(query);
+                self.append_page(fetched);
             }
-            s.is_loading = false;
+            Some(PageUpdate::Fail(message)) => {
+                self.set_status.update(|s| s.error = Some(message));
+            }
+            _ => {}
+        }
+    }
+
+    /// 목록을 비우고 새로 시작 (진행 중인 요청의 응답은 버림)
+    fn reset(&self, query: PageQuery) {
+        self.requests.update_value(PageRequests::restart);
+        self.clear(query);
+    }
+
+    /// 목록과 상태만 비움 (요청 세대는 호출한 쪽에서 이미 새로 시작)
+    fn clear(&self, query: PageQuery) {
+        self.query.set_value(query);
+        self.set_photos.set(Vec::new());
+        self.set_status.set(LoadStatus::default());
+    }
+
+    fn append_page(&self, fetched: FetchedPage) {
+        self.query.update_value(|q| q.cursor = fetched.cursor);
+        self.set_photos
+            .update(|items| items.extend(fetched.photos.into_iter().map(PhotoItem::new)));
+        self.set_status.set(LoadStatus {
+            is_loading: false,
+            has_more: fetched.has_more,
+            error: None,
         });
     }
+
+    fn selected_index(&self) -> Option<usize> {
+        let current = self
+            .selected_photo
+            .with_untracked(|p| p.as_ref().map(|p| p.id))?;
+        self.index_of(current)
+    }
+
+    fn select_at(&self, index: usize) {
+        if let Some(photo) = self
+            .photos
+            .with_untracked(|items| items.get(index).map(PhotoItem::snapshot))
+        {
+            self.replace_preview(photo);
+        }
+    }
 }
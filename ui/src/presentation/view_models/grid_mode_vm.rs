@@ -0,0 +1,24 @@
+use crate::domain::value_objects::grid_mode::GridMode;
+use crate::infrastructure::storage::local_storage::{load_json, save_json};
+use leptos::prelude::*;
+
+const GRID_MODE_KEY: &str = "photovault.grid_mode";
+
+/// 갤러리 그리드 배치 방식 (헤더에서 바꾸고 localStorage 에 기억)
+#[derive(Clone, Copy)]
+pub struct GridModeViewModel {
+    pub mode: ReadSignal<GridMode>,
+    set_mode: WriteSignal<GridMode>,
+}
+
+impl GridModeViewModel {
+    pub fn new() -> Self {
+        let (mode, set_mode) = signal(load_json(GRID_MODE_KEY).unwrap_or_default());
+        Self { mode, set_mode }
+    }
+
+    pub fn select(&self, mode: GridMode) {
+        self.set_mode.set(mode);
+        save_json(GRID_MODE_KEY, &mode);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::application::services::web_mercator::MapViewport;
+use crate::domain::value_objects::geo_area::{GeoCluster, GeoPoint};
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::infrastructure::api::geo_api::fetch_clusters_in_bbox;
+use leptos::prelude::*;
+
+/// 처음 지도를 열 때의 화면 (대한민국 전역)
+const INITIAL_VIEWPORT: MapViewport = MapViewport {
+    center: GeoPoint {
+        lat: 36.3,
+        lng: 127.8,
+    },
+    zoom: 7,
+    width: 0.0,
+    height: 0.0,
+};
+
+#[derive(Clone, Debug)]
+pub struct MapState {
+    pub viewport: MapViewport,
+    pub clusters: Vec<GeoCluster>,
+    pub is_loading: bool,
+    pub error: Option<String>,
+    // 마지막으로 클러스터를 요청한 화면 (늦게 도착한 이전 응답은 무시)
+    requested: Option<MapViewport>,
+}
+
+impl Default for MapState {
+    fn default() -> Self {
+        Self {
+            viewport: INITIAL_VIEWPORT,
+            clusters: vec![],
+            is_loading: false,
+            error: None,
+            requested: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MapViewModel {
+    pub state: ReadSignal<MapState>,
+    set_state: WriteSignal<MapState>,
+}
+
+impl MapViewModel {
+    pub fn new() -> Self {
+        let (state, set_state) = signal(MapState::default());
+        Self { state, set_state }
+    }
+
+    pub fn resize(&self, width: f64, height: f64) {
+        self.set_state.update(|s| (s.viewport.width, s.viewport.height) = (width, height));
+    }
+
+    pub fn pan_by(&self, dx: f64, dy: f64) {
+        self.set_state.update(|s| s.viewport.pan_by(dx, dy));
+    }
+
+    /// 화면 좌표 `anchor` 를 기준으로 한 단계 확대(`steps > 0`) 또는 축소
+    pub fn zoom_by(&self, steps: i8, anchor: Option<(f64, f64)>) {
+        self.set_state.update(|s| {
+            let viewport = &mut s.viewport;
+            let anchor = anchor.unwrap_or((viewport.width / 2.0, viewport.height / 2.0));
+            let zoom = viewport.zoom.saturating_add_signed(steps);
+            viewport.zoom_at(zoom, anchor);
+        });
+    }
+
+    /// 현재 화면 영역의 클러스터를 다시 조회
+    pub fn load_clusters(&self, filter: SearchFilter) {
+        let viewport = self.state.get_untracked().viewport;
+        if viewport.width <= 0.0 || viewport.height <= 0.0 {
+            return;
+        }
+
+        let set_state = self.set_state;
+        set_state.update(|s| {
+            s.requested = Some(viewport);
+            s.is_loading = true;
+        });
+
+        leptos::task::spawn_local(async move {
+            let result = fetch_clusters_in_bbox(viewport.bounds(), viewport.zoom, &filter).await;
+            set_state.update(|s| {
+                if s.requested != Some(viewport) {
+                    return;
+                }
+                match result {
+                    Ok(clusters) => (s.clusters, s.error) = (clusters, None),
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+                s.is_loading = false;
+            });
+        });
+    }
+}
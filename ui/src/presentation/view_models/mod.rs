@@ -1 +1,16 @@
+pub mod bulk_actions_vm;
+pub mod cleanup_vm;
+pub mod duplicates_vm;
+pub mod exif_filter_vm;
+pub mod face_overlay_vm;
 pub mod gallery_vm;
+pub mod grid_mode_vm;
+pub mod map_vm;
+pub mod navigation_vm;
+pub mod people_vm;
+pub mod places_vm;
+pub mod preview_zoom_vm;
+pub mod scroll_vm;
+pub mod search_vm;
+pub mod similar_photos_vm;
+pub mod timeline_vm;
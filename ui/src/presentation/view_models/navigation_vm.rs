@@ -0,0 +1,122 @@
+use crate::application::services::route_parser::{route_url, Route};
+use crate::infrastructure::storage::url_query::{
+    current_path, current_search, push_url, replace_url,
+};
+use leptos::prelude::*;
+
+/// 상단 탭으로 전환하는 화면
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AppPage {
+    #[default]
+    Gallery,
+    Map,
+    Places,
+    People,
+    Duplicates,
+    Cleanup,
+}
+
+impl AppPage {
+    pub const ALL: [AppPage; 6] = [
+        AppPage::Gallery,
+        AppPage::Map,
+        AppPage::Places,
+        AppPage::People,
+        AppPage::Duplicates,
+        AppPage::Cleanup,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            AppPage::Gallery => "Gallery",
+            AppPage::Map => "Map",
+            AppPage::Places => "Places",
+            AppPage::People => "People",
+            AppPage::Duplicates => "Duplicates",
+            AppPage::Cleanup => "Cleanup",
+        }
+    }
+
+    /// 탭을 눌렀을 때 이동할 경로
+    fn route(self) -> Route {
+        match self {
+            AppPage::Gallery => Route::Gallery,
+            AppPage::Map => Route::Map,
+            AppPage::Places => Route::Places,
+            AppPage::People => Route::People,
+            AppPage::Duplicates => Route::Duplicates,
+            AppPage::Cleanup => Route::Cleanup,
+        }
+    }
+
+    /// 경로가 속한 화면 (사진/앨범은 갤러리, 인물 상세는 인물 화면)
+    fn of(route: Route) -> Self {
+        match route {
+            Route::Gallery | Route::Photo(_) | Route::Album(_) | Route::AlbumPhoto(..) => {
+                AppPage::Gallery
+            }
+            Route::Map => AppPage::Map,
+            Route::Places => AppPage::Places,
+            Route::People | Route::Person(_) => AppPage::People,
+            Route::Duplicates => AppPage::Duplicates,
+            Route::Cleanup => AppPage::Cleanup,
+        }
+    }
+}
+
+/// 주소창 경로와 현재 화면 (App 최상위에서 제공)
+///
+/// 경로가 바뀌어도 같은 화면이면 `page` 는 그대로라 화면을 다시 만들지 않습니다.
+/// 쿼리(검색 조건)는 `SearchViewModel` 이 관리하고, 경로를 바꿀 때 그대로 유지합니다.
+#[derive(Clone, Copy)]
+pub struct NavigationViewModel {
+    pub route: ReadSignal<Route>,
+    set_route: WriteSignal<Route>,
+    pub page: Memo<AppPage>,
+}
+
+impl NavigationViewModel {
+    /// 주소창 경로로 시작 (공유/새로고침한 링크 복원)
+    pub fn new() -> Self {
+        let (route, set_route) = signal(Route::parse(&current_path()));
+        let page = Memo::new(move |_| AppPage::of(route.get()));
+        Self {
+            route,
+            set_route,
+            page,
+        }
+    }
+
+    pub fn navigate(&self, page: AppPage) {
+        self.push(page.route());
+    }
+
+    /// 히스토리 항목을 추가하며 이동 (뒤로가기로 돌아올 수 있음)
+    pub fn push(&self, route: Route) {
+        push_url(&route_url(route, &current_search()));
+        self.set(route);
+    }
+
+    /// 현재 히스토리 항목을 바꾸며 이동 (미리보기에서 사진 넘기기 등)
+    pub fn replace(&self, route: Route) {
+        replace_url(&route_url(route, &current_search()));
+        self.set(route);
+    }
+
+    /// 검색 조건이 바뀐 뒤 경로를 다시 맞춤 (갤러리는 `/` ↔ `/search`)
+    pub fn sync_path(&self) {
+        replace_url(&route_url(self.route.get_untracked(), &current_search()));
+    }
+
+    /// 뒤로/앞으로가기(PopState) 후 주소창 경로로 동기화
+    pub fn sync_on_popstate(&self) {
+        self.set(Route::parse(&current_path()));
+    }
+
+    /// 경로가 실제로 바뀔 때만 알림
+    fn set(&self, route: Route) {
+        if self.route.get_untracked() != route {
+            self.set_route.set(route);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::domain::value_objects::person::{Person, PersonFace};
+use crate::infrastructure::api::api_client::ApiResult;
+use crate::infrastructure::api::person_api::{
+    fetch_people, fetch_person_faces, merge_people, rename_person, split_person,
+};
+use leptos::prelude::*;
+use std::future::Future;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Default)]
+pub struct PeopleState {
+    pub people: Vec<Person>,
+    pub is_loading: bool,
+    /// 이름 변경/병합/분리 요청 중
+    pub is_saving: bool,
+    pub error: Option<String>,
+    /// 병합할 인물 선택 모드
+    pub is_selecting: bool,
+    pub selected_people: Vec<Uuid>,
+    /// 얼굴 목록을 펼친 인물
+    pub open_person: Option<Uuid>,
+    pub faces: Vec<PersonFace>,
+    /// 새 인물로 분리할 얼굴
+    pub selected_faces: Vec<Uuid>,
+}
+
+impl PeopleState {
+    pub fn open_person(&self) -> Option<Person> {
+        let id = self.open_person?;
+        self.people.iter().find(|p| p.id == id).cloned()
+    }
+}
+
+fn toggle(ids: &mut Vec<Uuid>, id: Uuid) {
+    match ids.iter().position(|selected| *selected == id) {
+        Some(index) => {
+            ids.remove(index);
+        }
+        None => ids.push(id),
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct PeopleViewModel {
+    pub state: ReadSignal<PeopleState>,
+    set_state: WriteSignal<PeopleState>,
+}
+
+impl PeopleViewModel {
+    pub fn new() -> Self {
+        let (state, set_state) = signal(PeopleState::default());
+        Self { state, set_state }
+    }
+
+    pub fn load(&self) {
+        let set_state = self.set_state;
+        set_state.update(|s| s.is_loading = true);
+
+        leptos::task::spawn_local(async move {
+            let result = fetch_people().await;
+            set_state.update(|s| {
+                match result {
+                    Ok(people) => (s.people, s.error) = (people, None),
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+                s.is_loading = false;
+            });
+        });
+    }
+
+    pub fn toggle_selecting(&self) {
+        self.set_state.update(|s| {
+            s.is_selecting = !s.is_selecting;
+            s.selected_people.clear();
+        });
+    }
+
+    pub fn toggle_person(&self, person_id: Uuid) {
+        self.set_state
+            .update(|s| toggle(&mut s.selected_people, person_id));
+    }
+
+    /// 선택한 인물을 목록에서 가장 앞선 인물(이름 있는 인물 우선)로 합침
+    pub fn merge_selected(&self) {
+        let state = self.state.get_untracked();
+        let mut selected = state
+            .people
+            .iter()
+            .map(|p| p.id)
+            .filter(|id| state.selected_people.contains(id));
+        let Some(target_id) = selected.next() else {
+            return;
+        };
+        let sources: Vec<Uuid> = selected.collect();
+        if sources.is_empty() {
+            return;
+        }
+
+        let vm = *self;
+        self.save(async move {
+            merge_people(target_id, sources).await?;
+            vm.set_state
+                .update(|s| (s.is_selecting, s.selected_people) = (false, vec![]));
+            Ok(())
+        });
+    }
+
+    pub fn rename(&self, person_id: Uuid, name: String) {
+        let name = Some(name.trim().to_string()).filter(|name| !name.is_empty());
+        self.save(async move { rename_person(person_id, name).await });
+    }
+
+    /// 얼굴 목록 펼치기 (이미 펼친 인물이면 그대로)
+    pub fn open(&self, person_id: Uuid) {
+        let set_state = self.set_state;
+        if self.state.get_untracked().open_person == Some(person_id) {
+            return;
+        }
+
+        set_state.update(|s| {
+            s.open_person = Some(person_id);
+            s.faces.clear();
+            s.selected_faces.clear();
+        });
+        self.load_faces(person_id);
+    }
+
+    pub fn close(&self) {
+        self.set_state
+            .update(|s| (s.open_person, s.faces) = (None, vec![]));
+    }
+
+    pub fn toggle_face(&self, face_id: Uuid) {
+        self.set_state
+            .update(|s| toggle(&mut s.selected_faces, face_id));
+    }
+
+    pub fn split_selected(&self) {
+        let state = self.state.get_untracked();
+        let Some(person_id) = state.open_person else {
+            return;
+        };
+        if state.selected_faces.is_empty() {
+            return;
+        }
+
+        let vm = *self;
+        let face_ids = state.selected_faces;
+        self.save(async move {
+            split_person(person_id, face_ids).await?;
+            vm.load_faces(person_id);
+            Ok(())
+        });
+    }
+
+    fn load_faces(&self, person_id: Uuid) {
+        let set_state = self.set_state;
+        leptos::task::spawn_local(async move {
+            let result = fetch_person_faces(person_id).await;
+            set_state.update(|s| {
+                if s.open_person != Some(person_id) {
+                    return;
+                }
+                match result {
+                    Ok(faces) => (s.faces, s.selected_faces) = (faces, vec![]),
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+            });
+        });
+    }
+
+    /// 변경 요청을 보내고 끝나면 인물 목록을 다시 불러옴
+    fn save(&self, request: impl Future<Output = ApiResult<()>> + 'static) {
+        let vm = *self;
+        vm.set_state.update(|s| s.is_saving = true);
+
+        leptos::task::spawn_local(async move {
+            let result = request.await;
+            vm.set_state.update(|s| {
+                s.is_saving = false;
+                s.error = result.err().map(|e| e.to_string());
+            });
+            vm.load();
+        });
+    }
+}
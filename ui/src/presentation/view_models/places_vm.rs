@@ -0,0 +1,39 @@
+use crate::domain::value_objects::place::CountryPlaces;
+use crate::infrastructure::api::place_api::fetch_places;
+use leptos::prelude::*;
+
+#[derive(Clone, Debug, Default)]
+pub struct PlacesState {
+    pub countries: Vec<CountryPlaces>,
+    pub is_loading: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+pub struct PlacesViewModel {
+    pub state: ReadSignal<PlacesState>,
+    set_state: WriteSignal<PlacesState>,
+}
+
+impl PlacesViewModel {
+    pub fn new() -> Self {
+        let (state, set_state) = signal(PlacesState::default());
+        Self { state, set_state }
+    }
+
+    pub fn load(&self) {
+        let set_state = self.set_state;
+        set_state.update(|s| s.is_loading = true);
+
+        leptos::task::spawn_local(async move {
+            let result = fetch_places().await;
+            set_state.update(|s| {
+                match result {
+                    Ok(countries) => (s.countries, s.error) = (countries, None),
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+                s.is_loading = false;
+            });
+        });
+    }
+}
@@ -0,0 +1,145 @@
+use crate::application::services::zoom_transform::{Inertia, Size, ZoomTransform};
+use leptos::prelude::*;
+use wasm_bindgen::prelude::*;
+
+/// 미리보기 이미지의 확대/이동 상태 (제스처 영역과 스와이프 처리가 함께 사용)
+#[derive(Clone, Copy)]
+pub struct PreviewZoomViewModel {
+    pub transform: ReadSignal<ZoomTransform>,
+    set_transform: WriteSignal<ZoomTransform>,
+    /// 두 번 탭/초기화처럼 한 번에 바뀌는 변경은 부드럽게 전환
+    pub is_animated: ReadSignal<bool>,
+    set_is_animated: WriteSignal<bool>,
+    /// 원본 파일을 표시할지 (미리보기 해상도를 넘게 확대해 원본을 불러온 뒤 켜짐)
+    pub use_original: ReadSignal<bool>,
+    set_use_original: WriteSignal<bool>,
+    /// 계산에 쓰는 1배 이미지 크기와 화면 크기
+    bounds: StoredValue<(Size, Size)>,
+    /// 관성 이동 세대 (새 조작이 시작되면 진행 중인 관성을 멈춤)
+    fling: StoredValue<u32>,
+    /// 원본을 불러오는 중이거나 불러온 사진 주소
+    original_requested: StoredValue<Option<String>>,
+}
+
+impl PreviewZoomViewModel {
+    pub fn new() -> Self {
+        let (transform, set_transform) = signal(ZoomTransform::default());
+        let (is_animated, set_is_animated) = signal(false);
+        let (use_original, set_use_original) = signal(false);
+        Self {
+            transform,
+            set_transform,
+            is_animated,
+            set_is_animated,
+            use_original,
+            set_use_original,
+            bounds: StoredValue::new(Default::default()),
+            fling: StoredValue::new(0),
+            original_requested: StoredValue::new(None),
+        }
+    }
+
+    pub fn is_zoomed(&self) -> bool {
+        self.transform.with(ZoomTransform::is_zoomed)
+    }
+
+    pub fn is_zoomed_untracked(&self) -> bool {
+        self.transform.with_untracked(ZoomTransform::is_zoomed)
+    }
+
+    /// 조작 시작 시 현재 이미지(1배)와 화면 크기를 기록
+    pub fn set_bounds(&self, content: Size, viewport: Size) {
+        self.bounds.set_value((content, viewport));
+    }
+
+    /// `focal` (화면 중심 기준 좌표) 을 중심으로 지정 배율로 확대
+    pub fn zoom_at(&self, scale: f64, focal: (f64, f64)) {
+        let (content, viewport) = self.bounds.get_value();
+        self.apply(false, |t| t.zoom_at(scale, focal, content, viewport));
+    }
+
+    pub fn pan_by(&self, dx: f64, dy: f64) {
+        let (content, viewport) = self.bounds.get_value();
+        self.apply(false, |t| t.pan_by(dx, dy, content, viewport));
+    }
+
+    /// 두 번 탭/더블 클릭: 2배 확대 또는 원래대로
+    pub fn toggle_at(&self, focal: (f64, f64)) {
+        let (content, viewport) = self.bounds.get_value();
+        self.apply(true, |t| t.toggled_at(focal, content, viewport));
+    }
+
+    /// 사진이 바뀌면 확대와 원본 표시를 초기화
+    pub fn reset(&self) {
+        self.stop_fling();
+        self.set_is_animated.set(false);
+        self.set_transform.set(ZoomTransform::default());
+        self.set_use_original.set(false);
+        self.original_requested.set_value(None);
+    }
+
+    /// 손을 뗀 속도(px/ms)로 이어서 이동하다 멈춤
+    pub fn fling(&self, vx: f64, vy: f64) {
+        let generation = self.next_fling();
+        self.fling_frame(Inertia { vx, vy }, generation, js_sys::Date::now());
+    }
+
+    pub fn stop_fling(&self) {
+        self.next_fling();
+    }
+
+    /// 확대해서 미리보기 해상도가 부족해지면 원본을 미리 받아 두고 교체
+    pub fn load_original_if_needed(&self, url: &str, rendered_width: f64, natural_width: f64) {
+        let pixel_ratio = web_sys::window().map_or(1.0, |w| w.device_pixel_ratio());
+        let exceeds = self
+            .transform
+            .with_untracked(|t| t.exceeds_resolution(rendered_width, natural_width, pixel_ratio));
+        let requested = self
+            .original_requested
+            .with_value(|r| r.as_deref() == Some(url));
+        if !exceeds || requested {
+            return;
+        }
+        self.original_requested.set_value(Some(url.to_string()));
+
+        let Ok(image) = web_sys::HtmlImageElement::new() else {
+            return;
+        };
+        let vm = *self;
+        let expected = url.to_string();
+        let on_load = Closure::once_into_js(move || {
+            // 불러오는 동안 다른 사진으로 넘어갔으면 무시
+            if vm
+                .original_requested
+                .with_value(|r| r.as_deref() == Some(&expected))
+            {
+                vm.set_use_original.set(true);
+            }
+        });
+        image.set_onload(Some(on_load.unchecked_ref()));
+        image.set_src(url);
+    }
+
+    fn apply(&self, animated: bool, update: impl FnOnce(ZoomTransform) -> ZoomTransform) {
+        self.set_is_animated.set(animated);
+        let next = update(self.transform.get_untracked());
+        self.set_transform.set(next);
+    }
+
+    fn next_fling(&self) -> u32 {
+        self.fling.update_value(|g| *g = g.wrapping_add(1));
+        self.fling.get_value()
+    }
+
+    fn fling_frame(self, mut inertia: Inertia, generation: u32, last: f64) {
+        request_animation_frame(move || {
+            if self.fling.get_value() != generation || inertia.is_stopped() {
+                return;
+            }
+            let now = js_sys::Date::now();
+            let (dx, dy) = inertia.step(now - last);
+            self.pan_by(dx, dy);
+            self.fling_frame(inertia, generation, now);
+        });
+    }
+}
@@ -0,0 +1,53 @@
+use leptos::html;
+use leptos::prelude::*;
+
+/// 스크롤 영역의 현재 위치와 보이는 높이 (px)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollViewport {
+    pub top: f64,
+    pub height: f64,
+}
+
+/// 앱의 메인 스크롤 영역(`<main>`)
+///
+/// `MainLayout` 에서 제공하며, 가상 그리드와 타임라인 스크러버가 스크롤 위치를 읽고 바꿀 때 사용합니다.
+#[derive(Clone, Copy)]
+pub struct ScrollViewModel {
+    pub node: NodeRef<html::Main>,
+    pub viewport: ReadSignal<ScrollViewport>,
+    set_viewport: WriteSignal<ScrollViewport>,
+}
+
+impl ScrollViewModel {
+    pub fn new() -> Self {
+        let (viewport, set_viewport) = signal(ScrollViewport::default());
+        Self {
+            node: NodeRef::new(),
+            viewport,
+            set_viewport,
+        }
+    }
+
+    /// 스크롤/크기 변경 시 현재 값 반영
+    pub fn measure(&self) {
+        if let Some(main) = self.node.get_untracked() {
+            self.set_viewport.set(ScrollViewport {
+                top: main.scroll_top() as f64,
+                height: main.client_height() as f64,
+            });
+        }
+    }
+
+    /// 현재 위치에서 `delta` 만큼 이동 (배치가 바뀌어도 보던 사진 유지)
+    pub fn scroll_by(&self, delta: f64) {
+        if let Some(main) = self.node.get_untracked() {
+            main.set_scroll_top(main.scroll_top() + delta.round() as i32);
+        }
+    }
+
+    pub fn scroll_to_top(&self) {
+        if let Some(main) = self.node.get_untracked() {
+            main.set_scroll_top(0);
+        }
+    }
+}
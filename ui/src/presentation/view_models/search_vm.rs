@@ -0,0 +1,195 @@
+use crate::application::services::search_query_parser::parse_search_query;
+use crate::domain::value_objects::search_filter::{FilterChip, SearchFilter};
+use crate::infrastructure::api::search_query::{filter_from_query, filter_to_query};
+use crate::infrastructure::storage::local_storage::{load_json, save_json};
+use crate::infrastructure::storage::url_query::{current_query, replace_query};
+use crate::presentation::view_models::navigation_vm::NavigationViewModel;
+use chrono::Local;
+use leptos::prelude::*;
+
+const RECENT_SEARCHES_KEY: &str = "photovault.recent_searches";
+const MAX_RECENT_SEARCHES: usize = 8;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchState {
+    /// 검색창과 필터 패널에서 편집 중인 조건
+    pub draft: SearchFilter,
+    /// 갤러리에 적용된 조건 (`None`이면 전체 사진)
+    pub active_filter: Option<SearchFilter>,
+    pub recent_searches: Vec<SearchFilter>,
+    /// 필터 패널 + 최근 검색 드롭다운 열림 여부
+    pub is_panel_open: bool,
+}
+
+/// 헤더 검색창과 갤러리가 공유하는 전역 검색 상태 (App 최상위에서 제공)
+#[derive(Clone, Copy)]
+pub struct SearchViewModel {
+    pub state: ReadSignal<SearchState>,
+    set_state: WriteSignal<SearchState>,
+    navigation: NavigationViewModel,
+}
+
+impl SearchViewModel {
+    /// 주소창 쿼리에 검색 조건이 있으면 그 조건으로 시작 (공유/새로고침한 링크 복원)
+    pub fn new(navigation: NavigationViewModel) -> Self {
+        let recent_searches = load_json(RECENT_SEARCHES_KEY).unwrap_or_default();
+        let active_filter = url_filter();
+        let (state, set_state) = signal(SearchState {
+            draft: active_filter.clone().unwrap_or_default(),
+            active_filter,
+            recent_searches,
+            ..SearchState::default()
+        });
+        Self {
+            state,
+            set_state,
+            navigation,
+        }
+    }
+
+    /// 뒤로/앞으로가기(PopState) 후 주소창 쿼리의 조건으로 동기화 (같은 조건이면 그대로)
+    pub fn sync_on_popstate(&self) {
+        let filter = url_filter();
+        if self.state.with_untracked(|s| s.active_filter == filter) {
+            return;
+        }
+        self.set_state.update(|s| {
+            s.draft = filter.clone().unwrap_or_default();
+            s.active_filter = filter;
+        });
+    }
+
+    pub fn update_draft(&self, edit: impl FnOnce(&mut SearchFilter)) {
+        self.set_state.update(|s| edit(&mut s.draft));
+    }
+
+    pub fn open_panel(&self) {
+        self.set_state.update(|s| s.is_panel_open = true);
+    }
+
+    pub fn close_panel(&self) {
+        self.set_state.update(|s| s.is_panel_open = false);
+    }
+
+    /// 편집 중인 조건으로 검색 실행
+    ///
+    /// 검색어는 자연어 파서로 해석하여 날짜/장소/태그/카메라 조건으로 바꾸고,
+    /// 해석되지 않은 단어만 텍스트 검색어로 남깁니다.
+    pub fn submit(&self) {
+        let filter = interpret(normalize(self.state.get_untracked().draft));
+        if filter.is_empty() {
+            self.clear();
+            return;
+        }
+
+        self.set_state.update(|s| {
+            s.is_panel_open = false;
+            remember(&mut s.recent_searches, filter.clone());
+        });
+        self.set_active(Some(filter));
+        save_json(RECENT_SEARCHES_KEY, &self.state.get_untracked().recent_searches);
+    }
+
+    /// 적용된 조건에서 칩 하나를 제거하고 다시 검색
+    pub fn remove_chip(&self, chip: &FilterChip) {
+        let Some(active_filter) = self.state.get_untracked().active_filter else {
+            return;
+        };
+        let filter = active_filter.without(chip);
+        self.set_active(Some(filter).filter(|f| !f.is_empty()));
+    }
+
+    /// 기존 조건을 버리고 `filter` 로 새로 검색 (Places 화면 등에서 바로 이동)
+    pub fn apply(&self, filter: SearchFilter) {
+        self.set_active(Some(filter).filter(|f| !f.is_empty()));
+    }
+
+    /// 적용된 조건을 직접 수정하여 다시 검색 (EXIF 필터 패널 등)
+    pub fn update_active(&self, edit: impl FnOnce(&mut SearchFilter)) {
+        let mut filter = self.state.get_untracked().active_filter.unwrap_or_default();
+        edit(&mut filter);
+        self.set_active(Some(filter).filter(|f| !f.is_empty()));
+    }
+
+    /// 적용된 조건을 필터 패널로 불러와 편집
+    pub fn edit_active(&self) {
+        self.set_state.update(|s| {
+            if let Some(active_filter) = &s.active_filter {
+                s.draft = active_filter.clone();
+            }
+            s.is_panel_open = true;
+        });
+    }
+
+    pub fn apply_recent(&self, filter: SearchFilter) {
+        self.set_state.update(|s| s.draft = filter);
+        self.submit();
+    }
+
+    pub fn remove_recent(&self, filter: &SearchFilter) {
+        self.set_state.update(|s| s.recent_searches.retain(|f| f != filter));
+        save_json(RECENT_SEARCHES_KEY, &self.state.get_untracked().recent_searches);
+    }
+
+    /// 검색 해제 후 전체 사진으로 복귀
+    pub fn clear(&self) {
+        self.set_state.update(|s| s.is_panel_open = false);
+        self.set_active(None);
+    }
+
+    /// 적용 조건 변경 (편집 중인 조건과 주소창 쿼리/경로도 함께 맞춤)
+    fn set_active(&self, filter: Option<SearchFilter>) {
+        replace_query(&filter.as_ref().map(filter_to_query).unwrap_or_default());
+        self.navigation.sync_path();
+        self.set_state.update(|s| {
+            s.draft = filter.clone().unwrap_or_default();
+            s.active_filter = filter;
+        });
+    }
+}
+
+/// 주소창 쿼리의 검색 조건 (없으면 `None`)
+fn url_filter() -> Option<SearchFilter> {
+    current_query()
+        .map(|params| filter_from_query(|name| params.get(name)))
+        .filter(|filter| !filter.is_empty())
+}
+
+/// 검색어를 해석한 조건으로 빈 필드를 채움 (패널에서 직접 입력한 조건이 우선)
+fn interpret(draft: SearchFilter) -> SearchFilter {
+    let Some(text) = draft.text.clone() else {
+        return draft;
+    };
+    let parsed = parse_search_query(&text, Local::now().date_naive());
+
+    SearchFilter { text: None, ..draft }.or(parsed)
+}
+
+/// 앞뒤 공백 제거, 빈 문자열 조건은 제거
+fn normalize(filter: SearchFilter) -> SearchFilter {
+    let trimmed = |value: Option<String>| {
+        value
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    SearchFilter {
+        text: trimmed(filter.text),
+        camera: trimmed(filter.camera),
+        lens: trimmed(filter.lens),
+        place: trimmed(filter.place),
+        tags: filter
+            .tags
+            .into_iter()
+            .filter_map(|tag| trimmed(Some(tag)))
+            .collect(),
+        ..filter
+    }
+}
+
+/// 가장 최근 검색이 맨 앞에 오도록 중복 없이 기록
+fn remember(recent_searches: &mut Vec<SearchFilter>, filter: SearchFilter) {
+    recent_searches.retain(|f| *f != filter);
+    recent_searches.insert(0, filter);
+    recent_searches.truncate(MAX_RECENT_SEARCHES);
+}
@@ -0,0 +1,68 @@
+use crate::domain::value_objects::similar::SimilarPhoto;
+use crate::infrastructure::api::similar_api::fetch_similar_photos;
+use leptos::prelude::*;
+use uuid::Uuid;
+
+/// 미리보기에 보여줄 비슷한 사진 수
+const SIMILAR_LIMIT: usize = 12;
+
+#[derive(Clone, Debug, Default)]
+pub struct SimilarPhotosState {
+    /// 결과를 요청한 사진 (다른 사진으로 넘어가면 결과를 버림)
+    pub photo_id: Option<Uuid>,
+    pub photos: Vec<SimilarPhoto>,
+    pub is_loading: bool,
+    pub error: Option<String>,
+}
+
+impl SimilarPhotosState {
+    /// 검색을 마쳤지만 결과가 없음 (아직 분석 전일 수 있음)
+    pub fn is_empty_result(&self) -> bool {
+        self.photo_id.is_some()
+            && !self.is_loading
+            && self.error.is_none()
+            && self.photos.is_empty()
+    }
+}
+
+/// 미리보기의 "More like this" 목록
+#[derive(Clone, Copy)]
+pub struct SimilarPhotosViewModel {
+    pub state: ReadSignal<SimilarPhotosState>,
+    set_state: WriteSignal<SimilarPhotosState>,
+}
+
+impl SimilarPhotosViewModel {
+    pub fn new() -> Self {
+        let (state, set_state) = signal(SimilarPhotosState::default());
+        Self { state, set_state }
+    }
+
+    /// 미리보기 사진이 바뀌면 이전 결과 제거
+    pub fn reset(&self) {
+        self.set_state.set(SimilarPhotosState::default());
+    }
+
+    pub fn load(&self, photo_id: Uuid) {
+        let set_state = self.set_state;
+        set_state.set(SimilarPhotosState {
+            photo_id: Some(photo_id),
+            is_loading: true,
+            ..Default::default()
+        });
+
+        leptos::task::spawn_local(async move {
+            let result = fetch_similar_photos(photo_id, SIMILAR_LIMIT).await;
+            set_state.update(|s| {
+                if s.photo_id != Some(photo_id) {
+                    return;
+                }
+                match result {
+                    Ok(photos) => s.photos = photos,
+                    Err(e) => s.error = Some(e.to_string()),
+                }
+                s.is_loading = false;
+            });
+        });
+    }
+}
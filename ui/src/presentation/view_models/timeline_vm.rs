@@ -0,0 +1,58 @@
+use crate::application::services::timeline_layout::month_at;
+use crate::domain::value_objects::search_filter::SearchFilter;
+use crate::domain::value_objects::timeline::Timeline;
+use crate::infrastructure::api::timeline_api::fetch_timeline;
+use leptos::prelude::*;
+
+#[derive(Clone, Debug, Default)]
+pub struct TimelineState {
+    pub timeline: Timeline,
+    pub error: Option<String>,
+    /// 스크러버를 누르고 있는 동안 가리키는 달
+    pub scrubbing: Option<usize>,
+}
+
+/// 갤러리 오른쪽 타임라인 스크러버
+#[derive(Clone, Copy)]
+pub struct TimelineViewModel {
+    pub state: ReadSignal<TimelineState>,
+    set_state: WriteSignal<TimelineState>,
+}
+
+impl TimelineViewModel {
+    pub fn new() -> Self {
+        let (state, set_state) = signal(TimelineState::default());
+        Self { state, set_state }
+    }
+
+    /// 갤러리와 같은 검색 조건(없으면 전체 사진)의 월별 개수 로드
+    pub fn load(&self, filter: Option<SearchFilter>) {
+        let set_state = self.set_state;
+        leptos::task::spawn_local(async move {
+            let result = fetch_timeline(&filter.unwrap_or_default()).await;
+            set_state.update(|s| match result {
+                Ok(timeline) => (s.timeline, s.error) = (timeline, None),
+                Err(e) => (s.timeline, s.error) = (Timeline::default(), Some(e.to_string())),
+            });
+        });
+    }
+
+    /// 스크러버 위치(0~1)의 달 표시
+    pub fn scrub(&self, fraction: f64) {
+        self.set_state
+            .update(|s| s.scrubbing = month_at(&s.timeline, fraction));
+    }
+
+    pub fn cancel_scrub(&self) {
+        self.set_state.update(|s| s.scrubbing = None);
+    }
+
+    /// 스크러버를 놓은 달의 커서 (첫 달이면 처음부터 불러오도록 `Some(None)`)
+    pub fn finish_scrub(&self) -> Option<Option<String>> {
+        let state = self.state.get_untracked();
+        self.cancel_scrub();
+        let index = state.scrubbing?;
+        let month = state.timeline.months.get(index)?;
+        Some((index > 0).then(|| month.cursor.clone()))
+    }
+}